@@ -0,0 +1,54 @@
+extern crate rand;
+extern crate wld_edit;
+
+use std::time::Instant;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use wld_edit::{read, write, Block, Slope, Tile, TileGrid, Wall, Wld};
+
+const WIDTH: u32 = 8400;
+const HEIGHT: u32 = 2400;
+const RUNS: u32 = 3;
+
+// A large world with terrain-like columns: sky, dirt with walls, then stone scattered with ores
+fn generate() -> Wld {
+	let mut rng = StdRng::seed_from_u64(0);
+	let air = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+	let block = |id| Tile { block: Some(Block { id, color: None, uv: None, inactive: false, slope: Slope::Full }), ..air };
+	
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.name = String::from("bench");
+	w.width = WIDTH;
+	w.height = HEIGHT;
+	w.importance = vec![false; 700];
+	w.tiles = TileGrid::new(WIDTH, HEIGHT, air);
+	
+	let mut surface: i32 = 400;
+	for x in 0..WIDTH {
+		surface = (surface + rng.gen_range(-2..=2)).clamp(300, 500);
+		for y in surface as u32..HEIGHT {
+			let tile = match y < surface as u32 + 60 {
+				true => Tile { wall: Some(Wall { id: 2, color: None }), ..block(0) },
+				false => match rng.gen_range(0..40) {
+					0 => block(rng.gen_range(6..10)),
+					_ => block(1)
+				}
+			};
+			w.tiles.set(x, y, tile);
+		}
+	}
+	w
+}
+
+fn main() {
+	let data = write::write(&generate()).unwrap();
+	println!("generated {}x{} world, {} bytes", WIDTH, HEIGHT, data.len());
+	
+	let start = Instant::now();
+	for _ in 0..RUNS {
+		read::read(data.clone()).unwrap();
+	}
+	let elapsed = start.elapsed() / RUNS;
+	println!("read: {:?} per world, {:.1} MB/s", elapsed, data.len() as f64 / elapsed.as_secs_f64() / 1e6);
+}
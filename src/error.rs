@@ -0,0 +1,148 @@
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Section {
+	FileHeader,
+	Header,
+	Tiles,
+	Chests,
+	Signs,
+	NPCs,
+	TileEntities,
+	PressurePlates,
+	TownManager,
+	Bestiary,
+	CreativePowers,
+	Footer
+}
+
+impl Section {
+	pub const ALL: [Section; 12] = [Section::FileHeader, Section::Header, Section::Tiles, Section::Chests, Section::Signs, Section::NPCs, Section::TileEntities, Section::PressurePlates, Section::TownManager, Section::Bestiary, Section::CreativePowers, Section::Footer];
+
+	// Sections are stored in file order, so the pointer at `positions[i]` marks the end of `ALL[i]`
+	pub fn index(self) -> usize {
+		self as usize
+	}
+
+	pub fn name(self) -> &'static str {
+		match self {
+			Section::FileHeader => "file header",
+			Section::Header => "header",
+			Section::Tiles => "tiles",
+			Section::Chests => "chests",
+			Section::Signs => "signs",
+			Section::NPCs => "npcs",
+			Section::TileEntities => "tile entities",
+			Section::PressurePlates => "pressure plates",
+			Section::TownManager => "town manager",
+			Section::Bestiary => "bestiary",
+			Section::CreativePowers => "creative powers",
+			Section::Footer => "footer"
+		}
+	}
+}
+
+impl fmt::Display for Section {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+
+
+#[derive(Debug)]
+pub enum WldError {
+	Io(std::io::Error),
+	Truncated { section: Section, offset: usize },
+	InvalidUtf8 { section: Section, offset: usize },
+	MissingSection { section: Section, offset: usize },
+	SectionMismatch { section: Section, expected: usize, actual: usize },
+	UnknownTileId { id: u16, offset: usize },
+	InvalidTileData { x: u32, y: u32, offset: usize },
+	InvalidChestSize { size: u16, offset: usize },
+	UnknownTileEntity { kind: u8, offset: usize },
+	UnknownCreativePower { id: u16, offset: usize },
+	FooterMismatch { offset: usize },
+	UnsupportedVersion { version: u32 },
+	UnknownNPCName { name: String, offset: usize },
+	UnsupportedFeature { feature: &'static str, version: u32 },
+	WorldTooLarge { width: u32, height: u32 },
+	TileGridMismatch { width: u32, height: u32, tiles_width: u32, tiles_height: u32 },
+	TooManyEntries { section: Section, count: usize }
+}
+
+impl WldError {
+	pub fn section(&self) -> Option<Section> {
+		match *self {
+			WldError::Io(_) | WldError::UnsupportedFeature { .. } | WldError::WorldTooLarge { .. } => None,
+			WldError::UnsupportedVersion { .. } => Some(Section::FileHeader),
+			WldError::UnknownNPCName { .. } => Some(Section::NPCs),
+			WldError::Truncated { section, .. } |
+			WldError::InvalidUtf8 { section, .. } |
+			WldError::MissingSection { section, .. } |
+			WldError::SectionMismatch { section, .. } |
+			WldError::TooManyEntries { section, .. } => Some(section),
+			WldError::UnknownTileId { .. } | WldError::InvalidTileData { .. } | WldError::TileGridMismatch { .. } => Some(Section::Tiles),
+			WldError::InvalidChestSize { .. } => Some(Section::Chests),
+			WldError::UnknownTileEntity { .. } => Some(Section::TileEntities),
+			WldError::UnknownCreativePower { .. } => Some(Section::CreativePowers),
+			WldError::FooterMismatch { .. } => Some(Section::Footer)
+		}
+	}
+
+	pub fn offset(&self) -> Option<usize> {
+		match *self {
+			WldError::Io(_) | WldError::UnsupportedVersion { .. } | WldError::UnsupportedFeature { .. } | WldError::WorldTooLarge { .. } | WldError::TileGridMismatch { .. } | WldError::TooManyEntries { .. } => None,
+			WldError::SectionMismatch { actual, .. } => Some(actual),
+			WldError::Truncated { offset, .. } |
+			WldError::InvalidUtf8 { offset, .. } |
+			WldError::MissingSection { offset, .. } |
+			WldError::UnknownTileId { offset, .. } |
+			WldError::InvalidTileData { offset, .. } |
+			WldError::InvalidChestSize { offset, .. } |
+			WldError::UnknownTileEntity { offset, .. } |
+			WldError::UnknownCreativePower { offset, .. } |
+			WldError::UnknownNPCName { offset, .. } |
+			WldError::FooterMismatch { offset } => Some(offset)
+		}
+	}
+}
+
+impl fmt::Display for WldError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			WldError::Io(e) => write!(f, "{}", e),
+			WldError::Truncated { section, offset } => write!(f, "{}: file ends unexpectedly at byte {}", section, offset),
+			WldError::InvalidUtf8 { section, offset } => write!(f, "{}: invalid UTF-8 in string at byte {}", section, offset),
+			WldError::MissingSection { section, offset } => write!(f, "{}: no section pointer in file (at byte {})", section, offset),
+			WldError::SectionMismatch { section, expected, actual } => write!(f, "{}: section should end at byte {} but ended at {}", section, expected, actual),
+			WldError::UnknownTileId { id, offset } => write!(f, "tiles: block id {} has no frame importance entry at byte {}", id, offset),
+			WldError::InvalidTileData { x, y, offset } => write!(f, "tiles: invalid tile data for ({}, {}) at byte {}", x, y, offset),
+			WldError::InvalidChestSize { size, offset } => write!(f, "chests: expected 40 items per chest, found {} at byte {}", size, offset),
+			WldError::UnknownTileEntity { kind, offset } => write!(f, "tile entities: unknown tile entity type {} at byte {}", kind, offset),
+			WldError::UnknownCreativePower { id, offset } => write!(f, "creative powers: unknown power id {} at byte {}", id, offset),
+			WldError::FooterMismatch { offset } => write!(f, "footer: name or id does not match the header at byte {}", offset),
+			WldError::UnsupportedVersion { version } => write!(f, "file header: world version {} is not supported (oldest is {}, newest known is {})", version, crate::version::MIN_VERSION, crate::version::LATEST_VERSION),
+			WldError::UnknownNPCName { name, offset } => write!(f, "npcs: unknown npc type \"{}\" at byte {}", name, offset),
+			WldError::UnsupportedFeature { feature, version } => write!(f, "world version {} cannot store {}", version, feature),
+			WldError::WorldTooLarge { width, height } => write!(f, "{}x{} is larger than the game can load ({}x{})", width, height, crate::resize::MAX_WIDTH, crate::resize::MAX_HEIGHT),
+			WldError::TileGridMismatch { width, height, tiles_width, tiles_height } => write!(f, "tiles: the world is {}x{} but its tile grid is {}x{}", width, height, tiles_width, tiles_height),
+			WldError::TooManyEntries { section, count } => write!(f, "{}: {} entries do not fit in the file, the most is {}", section, count, u16::MAX)
+		}
+	}
+}
+
+impl std::error::Error for WldError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			WldError::Io(e) => Some(e),
+			_ => None
+		}
+	}
+}
+
+impl From<std::io::Error> for WldError {
+	fn from(e: std::io::Error) -> Self {
+		WldError::Io(e)
+	}
+}
@@ -0,0 +1,169 @@
+use std::{collections::VecDeque, ops::BitOr};
+
+use crate::{region::Region, structs::{Block, Tile}, wld::Wld};
+
+// Which parts of a `Tile` an operation writes, the others are left as they are
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct Layers {
+	pub block: bool,
+	pub wall: bool,
+	pub liquid: bool,
+	// The four wire colours and the actuator
+	pub wires: bool
+}
+
+impl Layers {
+	pub const NONE: Layers = Layers { block: false, wall: false, liquid: false, wires: false };
+	pub const ALL: Layers = Layers { block: true, wall: true, liquid: true, wires: true };
+	pub const BLOCK: Layers = Layers { block: true, ..Layers::NONE };
+	pub const WALL: Layers = Layers { wall: true, ..Layers::NONE };
+	pub const LIQUID: Layers = Layers { liquid: true, ..Layers::NONE };
+	pub const WIRES: Layers = Layers { wires: true, ..Layers::NONE };
+	
+	// `target` with the selected layers taken from `source`
+	pub fn apply(self, target: &Tile, source: &Tile) -> Tile {
+		let mut tile = *target;
+		if self.block {
+			tile.block = source.block;
+		}
+		if self.wall {
+			tile.wall = source.wall;
+		}
+		if self.liquid {
+			tile.liquid = source.liquid;
+		}
+		if self.wires {
+			tile.red_wire = source.red_wire;
+			tile.green_wire = source.green_wire;
+			tile.blue_wire = source.blue_wire;
+			tile.yellow_wire = source.yellow_wire;
+			tile.actuator = source.actuator;
+		}
+		tile
+	}
+}
+
+impl BitOr for Layers {
+	type Output = Layers;
+	
+	fn bitor(self, other: Layers) -> Layers {
+		Layers { block: self.block || other.block, wall: self.wall || other.wall, liquid: self.liquid || other.liquid, wires: self.wires || other.wires }
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Connectivity {
+	// Only up, down, left and right
+	Four,
+	// Diagonals too
+	Eight
+}
+
+impl Wld {
+	// Replaces every tile in `region` for which `f` returns a new one, going through the palette so each distinct tile is only looked at once
+	fn update(&mut self, region: Region, f: impl Fn(&Tile) -> Option<Tile>) -> usize {
+		let region = region.clamp(self.tiles.width(), self.tiles.height());
+		// Tiles added while updating only end up in cells that were already visited, so this covers every index still to come
+		let changes: Vec<Option<Tile>> = self.tiles.palette().iter().map(|tile| f(tile).filter(|new| new != tile)).collect();
+		let mut changed = 0;
+		for x in region.x..region.right() {
+			for y in region.y..region.bottom() {
+				if let Some(tile) = changes[self.tiles.index(x, y) as usize] {
+					self.set_block(x, y, tile);
+					changed += 1;
+				}
+			}
+		}
+		changed
+	}
+	
+	// The whole world, for operations without a region
+	fn everywhere(&self) -> Region {
+		Region::new(0, 0, self.tiles.width(), self.tiles.height())
+	}
+	
+	// Block `id` in place of `block`, keeping paint, slope and actuation. Frame important blocks keep their uv or start at (0, 0)
+	fn retype(&self, block: Block, id: u16) -> Block {
+		let uv = match self.importance.get(id as usize).copied().unwrap_or(false) {
+			true => block.uv.or(Some((0, 0))),
+			false => None
+		};
+		Block { id, uv, ..block }
+	}
+	
+	// Returns how many tiles changed
+	pub fn fill(&mut self, region: Region, tile: Tile, layers: Layers) -> usize {
+		self.update(region, |old| Some(layers.apply(old, &tile)))
+	}
+	
+	pub fn replace_block(&mut self, from: u16, to: u16) -> usize {
+		let region = self.everywhere();
+		let retyped: Vec<(Block, Block)> = self.tiles.palette().iter().filter_map(|tile| tile.block).filter(|block| block.id == from).map(|block| (block, self.retype(block, to))).collect();
+		self.update(region, |tile| {
+			let block = tile.block?;
+			retyped.iter().find(|(old, _)| *old == block).map(|&(_, new)| Tile { block: Some(new), ..*tile })
+		})
+	}
+	
+	pub fn replace_wall(&mut self, from: u16, to: u16) -> usize {
+		let region = self.everywhere();
+		self.update(region, |tile| {
+			let mut wall = tile.wall.filter(|wall| wall.id == from)?;
+			wall.id = to;
+			Some(Tile { wall: Some(wall), ..*tile })
+		})
+	}
+	
+	// Paints every block of `id`, None removes the paint
+	pub fn paint_blocks(&mut self, id: u16, color: Option<u8>) -> usize {
+		let region = self.everywhere();
+		self.update(region, |tile| {
+			let mut block = tile.block.filter(|block| block.id == id)?;
+			block.color = color;
+			Some(Tile { block: Some(block), ..*tile })
+		})
+	}
+	
+	pub fn paint_walls(&mut self, id: u16, color: Option<u8>) -> usize {
+		let region = self.everywhere();
+		self.update(region, |tile| {
+			let mut wall = tile.wall.filter(|wall| wall.id == id)?;
+			wall.color = color;
+			Some(Tile { wall: Some(wall), ..*tile })
+		})
+	}
+	
+	// Fills the area around (x, y) where `inside` holds, starting tile included. Returns how many tiles were filled
+	pub fn flood_fill(&mut self, x: u32, y: u32, tile: Tile, layers: Layers, connectivity: Connectivity, inside: impl Fn(&Tile) -> bool) -> usize {
+		let (width, height) = (self.tiles.width(), self.tiles.height());
+		if x >= width || y >= height || !inside(self.tiles.get(x, y)) {
+			return 0;
+		}
+		let neighbours: &[(i64, i64)] = match connectivity {
+			Connectivity::Four => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+			Connectivity::Eight => &[(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (1, -1), (-1, 1), (1, 1)]
+		};
+		
+		let mut visited = vec![false; width as usize * height as usize];
+		let mut queue = VecDeque::new();
+		visited[x as usize * height as usize + y as usize] = true;
+		queue.push_back((x, y));
+		let mut filled = 0;
+		while let Some((x, y)) = queue.pop_front() {
+			let old = *self.tiles.get(x, y);
+			self.set_block(x, y, layers.apply(&old, &tile));
+			filled += 1;
+			for &(dx, dy) in neighbours {
+				let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+				if nx < 0 || ny < 0 || nx >= width as i64 || ny >= height as i64 { continue }
+				let (nx, ny) = (nx as u32, ny as u32);
+				let seen = &mut visited[nx as usize * height as usize + ny as usize];
+				if !*seen && inside(self.tiles.get(nx, ny)) {
+					*seen = true;
+					queue.push_back((nx, ny));
+				}
+			}
+		}
+		filled
+	}
+}
@@ -0,0 +1,1163 @@
+use std::fmt;
+
+// Static data for block and wall ids, names follow the game's TileID/WallID constants
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TileInfo {
+	pub id: u16,
+	pub name: &'static str,
+	pub display_name: &'static str,
+	// Frame important tiles store their uv in the world file
+	pub frame_important: bool,
+	pub solid: bool,
+	// Approximate colour used on the map
+	pub map_color: [u8; 3]
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct WallInfo {
+	pub id: u16,
+	pub name: &'static str,
+	pub display_name: &'static str,
+	pub map_color: [u8; 3]
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct TileId(pub u16);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct WallId(pub u16);
+
+impl TileId {
+	// Accepts the internal name ("Containers") or the display name ("Chest"), the latter ignoring case.
+	// A display name shared by several tiles matches none of them
+	pub fn from_name(name: &str) -> Option<TileId> {
+		TILES.iter().find(|t| t.name == name)
+			.or_else(|| only(TILES.iter().filter(|t| t.display_name.eq_ignore_ascii_case(name))))
+			.map(|t| TileId(t.id))
+	}
+	
+	pub fn info(self) -> Option<&'static TileInfo> {
+		TILES.get(self.0 as usize)
+	}
+	pub fn name(self) -> Option<&'static str> {
+		self.info().map(|t| t.name)
+	}
+	pub fn display_name(self) -> Option<&'static str> {
+		self.info().map(|t| t.display_name)
+	}
+	pub fn is_frame_important(self) -> bool {
+		self.info().is_some_and(|t| t.frame_important)
+	}
+	pub fn is_solid(self) -> bool {
+		self.info().is_some_and(|t| t.solid)
+	}
+	pub fn map_color(self) -> Option<[u8; 3]> {
+		self.info().map(|t| t.map_color)
+	}
+}
+
+impl WallId {
+	pub fn from_name(name: &str) -> Option<WallId> {
+		WALLS.iter().find(|w| w.name == name)
+			.or_else(|| only(WALLS.iter().filter(|w| w.display_name.eq_ignore_ascii_case(name))))
+			.map(|w| WallId(w.id))
+	}
+	
+	pub fn info(self) -> Option<&'static WallInfo> {
+		WALLS.get(self.0 as usize)
+	}
+	pub fn name(self) -> Option<&'static str> {
+		self.info().map(|w| w.name)
+	}
+	pub fn display_name(self) -> Option<&'static str> {
+		self.info().map(|w| w.display_name)
+	}
+	pub fn map_color(self) -> Option<[u8; 3]> {
+		self.info().map(|w| w.map_color)
+	}
+}
+
+impl fmt::Display for TileId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.name() {
+			Some(name) => write!(f, "{} ({})", name, self.0),
+			None => write!(f, "unknown tile {}", self.0)
+		}
+	}
+}
+
+impl fmt::Display for WallId {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.name() {
+			Some(name) => write!(f, "{} ({})", name, self.0),
+			None => write!(f, "unknown wall {}", self.0)
+		}
+	}
+}
+
+pub(crate) fn only<T>(mut iter: impl Iterator<Item = T>) -> Option<T> {
+	let first = iter.next()?;
+	match iter.next() {
+		Some(_) => None,
+		None => Some(first)
+	}
+}
+
+// The importance vector the game writes into new worlds
+pub fn default_importance() -> Vec<bool> {
+	TILES.iter().map(|t| t.frame_important).collect()
+}
+
+const fn tile(id: u16, name: &'static str, display_name: &'static str, frame_important: bool, solid: bool, map_color: [u8; 3]) -> TileInfo {
+	TileInfo { id, name, display_name, frame_important, solid, map_color }
+}
+
+const fn wall(id: u16, name: &'static str, display_name: &'static str, map_color: [u8; 3]) -> WallInfo {
+	WallInfo { id, name, display_name, map_color }
+}
+
+pub static TILES: &[TileInfo] = &[
+	tile(0, "Dirt", "Dirt Block", false, true, [151, 107, 75]),
+	tile(1, "Stone", "Stone Block", false, true, [128, 128, 128]),
+	tile(2, "Grass", "Grass", false, true, [28, 216, 94]),
+	tile(3, "Plants", "Grass Plants", true, false, [13, 165, 13]),
+	tile(4, "Torches", "Torch", true, false, [253, 221, 3]),
+	tile(5, "Trees", "Tree", true, false, [151, 107, 75]),
+	tile(6, "Iron", "Iron Ore", false, true, [140, 101, 80]),
+	tile(7, "Copper", "Copper Ore", false, true, [150, 67, 22]),
+	tile(8, "Gold", "Gold Ore", false, true, [185, 164, 23]),
+	tile(9, "Silver", "Silver Ore", false, true, [185, 194, 195]),
+	tile(10, "ClosedDoor", "Closed Door", true, true, [119, 84, 57]),
+	tile(11, "OpenDoor", "Open Door", true, false, [119, 84, 57]),
+	tile(12, "Heart", "Life Crystal", true, false, [174, 24, 69]),
+	tile(13, "Bottles", "Bottle", true, false, [190, 190, 220]),
+	tile(14, "Tables", "Table", true, false, [165, 118, 80]),
+	tile(15, "Chairs", "Chair", true, false, [160, 115, 78]),
+	tile(16, "Anvils", "Anvil", true, false, [120, 120, 130]),
+	tile(17, "Furnaces", "Furnace", true, false, [140, 90, 70]),
+	tile(18, "WorkBenches", "Work Bench", true, false, [155, 112, 76]),
+	tile(19, "Platforms", "Platform", true, true, [170, 120, 84]),
+	tile(20, "Saplings", "Sapling", true, false, [100, 160, 40]),
+	tile(21, "Containers", "Chest", true, false, [174, 129, 92]),
+	tile(22, "Demonite", "Demonite Ore", false, true, [98, 95, 167]),
+	tile(23, "CorruptGrass", "Corrupt Grass", false, true, [141, 137, 223]),
+	tile(24, "CorruptPlants", "Corrupt Plants", true, false, [109, 90, 128]),
+	tile(25, "Ebonstone", "Ebonstone", false, true, [109, 90, 128]),
+	tile(26, "DemonAltar", "Altar", true, false, [119, 105, 180]),
+	tile(27, "Sunflower", "Sunflower", true, false, [226, 196, 49]),
+	tile(28, "Pots", "Pot", true, false, [151, 79, 80]),
+	tile(29, "PiggyBank", "Piggy Bank", true, false, [233, 180, 190]),
+	tile(30, "WoodBlock", "Wood", false, true, [170, 120, 84]),
+	tile(31, "ShadowOrbs", "Shadow Orb", true, false, [105, 68, 150]),
+	tile(32, "CorruptThorns", "Corrupt Thorns", false, false, [109, 90, 128]),
+	tile(33, "Candles", "Candle", true, false, [253, 221, 3]),
+	tile(34, "Chandeliers", "Chandelier", true, false, [235, 166, 135]),
+	tile(35, "Jackolanterns", "Jack 'O Lantern", true, false, [230, 130, 30]),
+	tile(36, "Presents", "Present", true, false, [200, 60, 60]),
+	tile(37, "Meteorite", "Meteorite", false, true, [104, 86, 84]),
+	tile(38, "GrayBrick", "Gray Brick", false, true, [144, 148, 144]),
+	tile(39, "RedBrick", "Red Brick", false, true, [192, 59, 59]),
+	tile(40, "ClayBlock", "Clay Block", false, true, [146, 81, 68]),
+	tile(41, "BlueDungeonBrick", "Blue Dungeon Brick", false, true, [66, 84, 109]),
+	tile(42, "HangingLanterns", "Hanging Lanterns", true, false, [253, 221, 3]),
+	tile(43, "GreenDungeonBrick", "Green Dungeon Brick", false, true, [84, 100, 63]),
+	tile(44, "PinkDungeonBrick", "Pink Dungeon Brick", false, true, [107, 68, 99]),
+	tile(45, "GoldBrick", "Gold Brick", false, true, [185, 164, 23]),
+	tile(46, "SilverBrick", "Silver Brick", false, true, [185, 194, 195]),
+	tile(47, "CopperBrick", "Copper Brick", false, true, [150, 67, 22]),
+	tile(48, "Spikes", "Spikes", false, true, [128, 128, 128]),
+	tile(49, "WaterCandle", "Water Candle", true, false, [253, 221, 3]),
+	tile(50, "Books", "Book", true, false, [170, 48, 114]),
+	tile(51, "Cobweb", "Cobweb", false, false, [230, 230, 230]),
+	tile(52, "Vines", "Vines", false, false, [23, 177, 76]),
+	tile(53, "Sand", "Sand Block", false, true, [186, 168, 84]),
+	tile(54, "Glass", "Glass", false, true, [200, 246, 254]),
+	tile(55, "Signs", "Sign", true, false, [140, 105, 75]),
+	tile(56, "Obsidian", "Obsidian", false, true, [87, 81, 173]),
+	tile(57, "Ash", "Ash Block", false, true, [68, 68, 76]),
+	tile(58, "Hellstone", "Hellstone", false, true, [142, 66, 66]),
+	tile(59, "Mud", "Mud Block", false, true, [92, 68, 73]),
+	tile(60, "JungleGrass", "Jungle Grass", false, true, [143, 215, 29]),
+	tile(61, "JunglePlants", "Jungle Plants", true, false, [13, 165, 13]),
+	tile(62, "JungleVines", "Jungle Vines", false, false, [110, 150, 20]),
+	tile(63, "Sapphire", "Sapphire", false, true, [110, 140, 182]),
+	tile(64, "Ruby", "Ruby", false, true, [196, 96, 114]),
+	tile(65, "Emerald", "Emerald", false, true, [56, 150, 97]),
+	tile(66, "Topaz", "Topaz", false, true, [160, 118, 58]),
+	tile(67, "Amethyst", "Amethyst", false, true, [140, 58, 166]),
+	tile(68, "Diamond", "Diamond", false, true, [125, 191, 197]),
+	tile(69, "JungleThorns", "Jungle Thorns", false, false, [94, 48, 55]),
+	tile(70, "MushroomGrass", "Mushroom Grass", false, true, [93, 127, 255]),
+	tile(71, "MushroomPlants", "Mushroom Plants", true, false, [13, 165, 13]),
+	tile(72, "MushroomTrees", "Mushroom Trees", true, false, [93, 127, 255]),
+	tile(73, "Plants2", "Plants 2", true, false, [13, 165, 13]),
+	tile(74, "JunglePlants2", "Jungle Plants 2", true, false, [13, 165, 13]),
+	tile(75, "ObsidianBrick", "Obsidian Brick", false, true, [87, 81, 173]),
+	tile(76, "HellstoneBrick", "Hellstone Brick", false, true, [142, 66, 66]),
+	tile(77, "Hellforge", "Hellforge", true, false, [160, 60, 60]),
+	tile(78, "ClayPot", "Clay Pot", true, false, [146, 81, 68]),
+	tile(79, "Beds", "Bed", true, false, [190, 75, 75]),
+	tile(80, "Cactus", "Cactus", false, false, [73, 120, 17]),
+	tile(81, "Coral", "Coral", true, false, [253, 62, 3]),
+	tile(82, "ImmatureHerbs", "Immature Herbs", true, false, [120, 170, 60]),
+	tile(83, "MatureHerbs", "Mature Herbs", true, false, [120, 170, 60]),
+	tile(84, "BloomingHerbs", "Blooming Herbs", true, false, [120, 170, 60]),
+	tile(85, "Tombstones", "Tombstone", true, false, [140, 140, 140]),
+	tile(86, "Loom", "Loom", true, false, [150, 110, 80]),
+	tile(87, "Pianos", "Piano", true, false, [70, 60, 60]),
+	tile(88, "Dressers", "Dresser", true, false, [150, 110, 80]),
+	tile(89, "Benches", "Bench", true, false, [155, 112, 76]),
+	tile(90, "Bathtubs", "Bathtub", true, false, [200, 200, 210]),
+	tile(91, "Banners", "Banner", true, false, [13, 88, 130]),
+	tile(92, "Lampposts", "Lamp Post", true, false, [253, 221, 3]),
+	tile(93, "Lamps", "Lamp", true, false, [253, 221, 3]),
+	tile(94, "Kegs", "Keg", true, false, [140, 100, 60]),
+	tile(95, "ChineseLanterns", "Chinese Lanterns", true, false, [253, 221, 3]),
+	tile(96, "CookingPots", "Cooking Pot", true, false, [151, 79, 80]),
+	tile(97, "Safes", "Safe", true, false, [110, 110, 120]),
+	tile(98, "SkullLanterns", "Skull Lanterns", true, false, [253, 221, 3]),
+	tile(99, "TrashCan", "Trash Can", true, false, [120, 120, 120]),
+	tile(100, "Candelabras", "Candelabra", true, false, [253, 221, 3]),
+	tile(101, "Bookcases", "Bookcase", true, false, [150, 100, 70]),
+	tile(102, "Thrones", "Throne", true, false, [200, 170, 40]),
+	tile(103, "Bowls", "Bowl", true, false, [190, 190, 220]),
+	tile(104, "GrandfatherClocks", "Grandfather Clock", true, false, [150, 110, 80]),
+	tile(105, "Statues", "Statue", true, false, [144, 148, 144]),
+	tile(106, "Sawmill", "Sawmill", true, false, [150, 120, 90]),
+	tile(107, "Cobalt", "Cobalt Ore", false, true, [11, 80, 143]),
+	tile(108, "Mythril", "Mythril Ore", false, true, [91, 169, 169]),
+	tile(109, "HallowedGrass", "Hallowed Grass", false, true, [78, 193, 227]),
+	tile(110, "HallowedPlants", "Hallowed Plants", true, false, [181, 172, 190]),
+	tile(111, "Adamantite", "Adamantite Ore", false, true, [221, 85, 152]),
+	tile(112, "Ebonsand", "Ebonsand", false, true, [90, 90, 110]),
+	tile(113, "HallowedPlants2", "Hallowed Plants 2", true, false, [181, 172, 190]),
+	tile(114, "TinkerersWorkbench", "Tinkerers Workbench", true, false, [150, 120, 90]),
+	tile(115, "HallowedVines", "Hallowed Vines", false, false, [40, 170, 200]),
+	tile(116, "Pearlsand", "Pearlsand", false, true, [238, 225, 218]),
+	tile(117, "Pearlstone", "Pearlstone", false, true, [181, 172, 190]),
+	tile(118, "PearlstoneBrick", "Pearlstone Brick", false, true, [144, 148, 144]),
+	tile(119, "IridescentBrick", "Iridescent Brick", false, true, [144, 148, 144]),
+	tile(120, "Mudstone", "Mudstone", false, true, [132, 112, 110]),
+	tile(121, "CobaltBrick", "Cobalt Brick", false, true, [144, 148, 144]),
+	tile(122, "MythrilBrick", "Mythril Brick", false, true, [144, 148, 144]),
+	tile(123, "Silt", "Silt Block", false, true, [106, 107, 118]),
+	tile(124, "WoodenBeam", "Wooden Beam", false, false, [170, 120, 84]),
+	tile(125, "CrystalBall", "Crystal Ball", true, false, [140, 120, 230]),
+	tile(126, "DiscoBall", "Disco Ball", true, false, [200, 200, 240]),
+	tile(127, "MagicalIceBlock", "Magical Ice Block", false, true, [144, 195, 232]),
+	tile(128, "Mannequin", "Mannequin", true, false, [200, 170, 140]),
+	tile(129, "Crystals", "Crystals", true, false, [190, 80, 200]),
+	tile(130, "ActiveStoneBlock", "Active Stone Block", false, true, [144, 148, 144]),
+	tile(131, "InactiveStoneBlock", "Inactive Stone Block", false, true, [90, 90, 90]),
+	tile(132, "Lever", "Lever", true, false, [150, 150, 150]),
+	tile(133, "AdamantiteForge", "Adamantite Forge", true, false, [180, 80, 60]),
+	tile(134, "MythrilAnvil", "Mythril Anvil", true, false, [120, 120, 130]),
+	tile(135, "PressurePlates", "Pressure Plate", true, false, [190, 60, 60]),
+	tile(136, "Switches", "Switch", true, false, [150, 150, 150]),
+	tile(137, "Traps", "Dart Trap", true, false, [120, 120, 120]),
+	tile(138, "Boulder", "Boulder", true, false, [110, 100, 90]),
+	tile(139, "MusicBoxes", "Music Box", true, false, [170, 120, 84]),
+	tile(140, "DemoniteBrick", "Demonite Brick", false, true, [144, 148, 144]),
+	tile(141, "Explosives", "Explosives", true, false, [200, 50, 50]),
+	tile(142, "InletPump", "Inlet Pump", true, false, [120, 120, 130]),
+	tile(143, "OutletPump", "Outlet Pump", true, false, [120, 120, 130]),
+	tile(144, "Timers", "Timer", true, false, [200, 60, 60]),
+	tile(145, "CandyCaneBlock", "Candy Cane Block", false, true, [200, 60, 60]),
+	tile(146, "GreenCandyCaneBlock", "Green Candy Cane Block", false, true, [200, 60, 60]),
+	tile(147, "SnowBlock", "Snow Block", false, true, [211, 236, 241]),
+	tile(148, "SnowBrick", "Snow Brick", false, true, [211, 236, 241]),
+	tile(149, "HolidayLights", "Holiday Lights", true, false, [230, 60, 60]),
+	tile(150, "AdamantiteBeam", "Adamantite Beam", false, true, [221, 85, 152]),
+	tile(151, "SandstoneBrick", "Sandstone Brick", false, true, [144, 148, 144]),
+	tile(152, "EbonstoneBrick", "Ebonstone Brick", false, true, [144, 148, 144]),
+	tile(153, "RedStucco", "Red Stucco", false, true, [190, 180, 160]),
+	tile(154, "YellowStucco", "Yellow Stucco", false, true, [190, 180, 160]),
+	tile(155, "GreenStucco", "Green Stucco", false, true, [190, 180, 160]),
+	tile(156, "GrayStucco", "Gray Stucco", false, true, [190, 180, 160]),
+	tile(157, "Ebonwood", "Ebonwood", false, true, [109, 90, 128]),
+	tile(158, "RichMahogany", "Rich Mahogany", false, true, [145, 81, 85]),
+	tile(159, "Pearlwood", "Pearlwood", false, true, [181, 172, 190]),
+	tile(160, "RainbowBrick", "Rainbow Brick", false, true, [144, 148, 144]),
+	tile(161, "IceBlock", "Ice Block", false, true, [144, 195, 232]),
+	tile(162, "BreakableIce", "Breakable Ice", false, true, [144, 195, 232]),
+	tile(163, "CorruptIce", "Corrupt Ice", false, true, [144, 195, 232]),
+	tile(164, "HallowedIce", "Hallowed Ice", false, true, [144, 195, 232]),
+	tile(165, "Stalactite", "Stalactite", true, false, [128, 128, 128]),
+	tile(166, "Tin", "Tin Ore", false, true, [187, 165, 124]),
+	tile(167, "Lead", "Lead Ore", false, true, [62, 82, 114]),
+	tile(168, "Tungsten", "Tungsten Ore", false, true, [132, 157, 127]),
+	tile(169, "Platinum", "Platinum Ore", false, true, [190, 190, 190]),
+	tile(170, "PineTree", "Pine Tree", false, false, [40, 110, 40]),
+	tile(171, "ChristmasTree", "Christmas Tree", true, false, [151, 107, 75]),
+	tile(172, "Sinks", "Sink", true, false, [180, 180, 190]),
+	tile(173, "PlatinumCandelabra", "Platinum Candelabra", true, false, [253, 221, 3]),
+	tile(174, "PlatinumCandle", "Platinum Candle", true, false, [253, 221, 3]),
+	tile(175, "TinBrick", "Tin Brick", false, true, [144, 148, 144]),
+	tile(176, "TungstenBrick", "Tungsten Brick", false, true, [144, 148, 144]),
+	tile(177, "PlatinumBrick", "Platinum Brick", false, true, [144, 148, 144]),
+	tile(178, "ExposedGems", "Exposed Gems", true, false, [210, 80, 180]),
+	tile(179, "GreenMoss", "Green Moss", false, true, [49, 134, 114]),
+	tile(180, "BrownMoss", "Brown Moss", false, true, [49, 134, 114]),
+	tile(181, "RedMoss", "Red Moss", false, true, [49, 134, 114]),
+	tile(182, "BlueMoss", "Blue Moss", false, true, [49, 134, 114]),
+	tile(183, "PurpleMoss", "Purple Moss", false, true, [49, 134, 114]),
+	tile(184, "LongMoss", "Long Moss", true, false, [49, 134, 114]),
+	tile(185, "SmallPiles", "Small Piles", true, false, [128, 128, 128]),
+	tile(186, "LargePiles", "Large Piles", true, false, [128, 128, 128]),
+	tile(187, "LargePiles2", "Large Piles 2", true, false, [128, 128, 128]),
+	tile(188, "CactusBlock", "Cactus Block", false, true, [73, 120, 17]),
+	tile(189, "Cloud", "Cloud", false, true, [223, 255, 255]),
+	tile(190, "MushroomBlock", "Mushroom Block", false, true, [93, 127, 255]),
+	tile(191, "LivingWood", "Living Wood", false, true, [151, 107, 75]),
+	tile(192, "LeafBlock", "Leaf Block", false, true, [26, 196, 84]),
+	tile(193, "SlimeBlock", "Slime Block", false, true, [0, 140, 255]),
+	tile(194, "BoneBlock", "Bone Block", false, true, [214, 202, 187]),
+	tile(195, "FleshBlock", "Flesh Block", false, true, [160, 80, 80]),
+	tile(196, "RainCloud", "Rain Cloud", false, true, [146, 156, 168]),
+	tile(197, "FrozenSlimeBlock", "Frozen Slime Block", false, true, [150, 200, 240]),
+	tile(198, "Asphalt", "Asphalt", false, true, [50, 50, 60]),
+	tile(199, "CrimsonGrass", "Crimson Grass", false, true, [208, 80, 80]),
+	tile(200, "FleshIce", "Flesh Ice", false, true, [144, 195, 232]),
+	tile(201, "CrimsonPlants", "Crimson Plants", true, false, [128, 44, 45]),
+	tile(202, "Sunplate", "Sunplate", false, true, [228, 213, 73]),
+	tile(203, "Crimstone", "Crimstone", false, true, [128, 44, 45]),
+	tile(204, "Crimtane", "Crimtane Ore", false, true, [214, 71, 24]),
+	tile(205, "CrimsonVines", "Crimson Vines", false, false, [200, 60, 60]),
+	tile(206, "IceBrick", "Ice Brick", false, true, [144, 195, 232]),
+	tile(207, "WaterFountain", "Water Fountain", true, false, [60, 120, 200]),
+	tile(208, "Shadewood", "Shadewood", false, true, [119, 86, 84]),
+	tile(209, "Cannon", "Cannon", true, false, [90, 90, 90]),
+	tile(210, "LandMine", "Land Mine", true, false, [150, 70, 60]),
+	tile(211, "Chlorophyte", "Chlorophyte Ore", false, true, [163, 231, 17]),
+	tile(212, "SnowballLauncher", "Snowball Launcher", true, false, [211, 236, 241]),
+	tile(213, "Rope", "Rope", false, false, [137, 120, 67]),
+	tile(214, "Chain", "Chain", false, false, [128, 128, 128]),
+	tile(215, "Campfire", "Campfire", true, false, [254, 121, 2]),
+	tile(216, "Firework", "Firework", true, false, [230, 80, 80]),
+	tile(217, "Blendomatic", "Blendomatic", true, false, [140, 140, 150]),
+	tile(218, "MeatGrinder", "Meat Grinder", true, false, [160, 90, 90]),
+	tile(219, "Extractinator", "Extractinator", true, false, [120, 140, 120]),
+	tile(220, "Solidifier", "Solidifier", true, false, [120, 160, 200]),
+	tile(221, "Palladium", "Palladium Ore", false, true, [239, 90, 50]),
+	tile(222, "Orichalcum", "Orichalcum Ore", false, true, [214, 62, 235]),
+	tile(223, "Titanium", "Titanium Ore", false, true, [131, 137, 162]),
+	tile(224, "Slush", "Slush Block", false, true, [112, 110, 120]),
+	tile(225, "Hive", "Hive", false, true, [227, 125, 22]),
+	tile(226, "LihzahrdBrick", "Lihzahrd Brick", false, true, [141, 56, 0]),
+	tile(227, "DyePlants", "Dye Plants", true, false, [13, 165, 13]),
+	tile(228, "DyeVat", "Dye Vat", true, false, [150, 110, 160]),
+	tile(229, "HoneyBlock", "Honey Block", false, true, [255, 156, 12]),
+	tile(230, "CrispyHoneyBlock", "Crispy Honey Block", false, true, [191, 130, 40]),
+	tile(231, "Larva", "Larva", true, false, [224, 219, 236]),
+	tile(232, "WoodenSpikes", "Wooden Spikes", false, true, [170, 120, 84]),
+	tile(233, "PlantDetritus", "Plant Detritus", true, false, [60, 140, 50]),
+	tile(234, "Crimsand", "Crimsand", false, true, [156, 78, 72]),
+	tile(235, "Teleporter", "Teleporter", true, false, [80, 200, 200]),
+	tile(236, "LifeFruit", "Life Fruit", true, false, [149, 212, 89]),
+	tile(237, "LihzahrdAltar", "Lihzahrd Altar", true, false, [230, 190, 30]),
+	tile(238, "PlanteraBulb", "Plantera Bulb", true, false, [225, 128, 206]),
+	tile(239, "MetalBars", "Metal Bars", true, false, [170, 170, 170]),
+	tile(240, "Painting3X3", "Painting 3x3", true, false, [120, 85, 60]),
+	tile(241, "Painting4X3", "Painting 4x3", true, false, [120, 85, 60]),
+	tile(242, "Painting6X4", "Painting 6x4", true, false, [120, 85, 60]),
+	tile(243, "ImbuingStation", "Imbuing Station", true, false, [160, 120, 200]),
+	tile(244, "BubbleMachine", "Bubble Machine", true, false, [150, 200, 230]),
+	tile(245, "Painting2X3", "Painting 2x3", true, false, [120, 85, 60]),
+	tile(246, "Painting3X2", "Painting 3x2", true, false, [120, 85, 60]),
+	tile(247, "Autohammer", "Autohammer", true, false, [180, 150, 60]),
+	tile(248, "PalladiumColumn", "Palladium Column", false, true, [239, 90, 50]),
+	tile(249, "BubblegumBlock", "Bubblegum Block", false, true, [245, 130, 200]),
+	tile(250, "Titanstone", "Titanstone", false, true, [110, 110, 120]),
+	tile(251, "PumpkinBlock", "Pumpkin Block", false, true, [120, 120, 130]),
+	tile(252, "HayBlock", "Hay Block", false, true, [214, 186, 90]),
+	tile(253, "SpookyWood", "Spooky Wood", false, true, [170, 120, 84]),
+	tile(254, "Pumpkins", "Pumpkins", true, false, [120, 120, 130]),
+	tile(255, "AmethystGemsparkOff", "Offline Amethyst Gemspark Block", false, true, [200, 200, 230]),
+	tile(256, "TopazGemsparkOff", "Offline Topaz Gemspark Block", false, true, [200, 200, 230]),
+	tile(257, "SapphireGemsparkOff", "Offline Sapphire Gemspark Block", false, true, [200, 200, 230]),
+	tile(258, "EmeraldGemsparkOff", "Offline Emerald Gemspark Block", false, true, [200, 200, 230]),
+	tile(259, "RubyGemsparkOff", "Offline Ruby Gemspark Block", false, true, [200, 200, 230]),
+	tile(260, "DiamondGemsparkOff", "Offline Diamond Gemspark Block", false, true, [200, 200, 230]),
+	tile(261, "AmberGemsparkOff", "Offline Amber Gemspark Block", false, true, [200, 200, 230]),
+	tile(262, "AmethystGemspark", "Amethyst Gemspark Block", false, true, [200, 200, 230]),
+	tile(263, "TopazGemspark", "Topaz Gemspark Block", false, true, [200, 200, 230]),
+	tile(264, "SapphireGemspark", "Sapphire Gemspark Block", false, true, [200, 200, 230]),
+	tile(265, "EmeraldGemspark", "Emerald Gemspark Block", false, true, [200, 200, 230]),
+	tile(266, "RubyGemspark", "Ruby Gemspark Block", false, true, [200, 200, 230]),
+	tile(267, "DiamondGemspark", "Diamond Gemspark Block", false, true, [200, 200, 230]),
+	tile(268, "AmberGemspark", "Amber Gemspark Block", false, true, [200, 200, 230]),
+	tile(269, "Womannequin", "Womannequin", true, false, [200, 170, 140]),
+	tile(270, "FireflyinaBottle", "Firefly in a Bottle", true, false, [190, 190, 220]),
+	tile(271, "LightningBuginaBottle", "Lightning Bug in a Bottle", true, false, [190, 190, 220]),
+	tile(272, "Cog", "Cog", false, true, [160, 140, 100]),
+	tile(273, "StoneSlab", "Stone Slab", false, true, [130, 130, 130]),
+	tile(274, "SandStoneSlab", "Sand Stone Slab", false, true, [212, 145, 83]),
+	tile(275, "BunnyCage", "Bunny Cage", true, false, [190, 190, 220]),
+	tile(276, "SquirrelCage", "Squirrel Cage", true, false, [190, 190, 220]),
+	tile(277, "MallardDuckCage", "Mallard Duck Cage", true, false, [190, 190, 220]),
+	tile(278, "DuckCage", "Duck Cage", true, false, [190, 190, 220]),
+	tile(279, "BirdCage", "Bird Cage", true, false, [190, 190, 220]),
+	tile(280, "BlueJay", "Blue Jay", true, false, [90, 130, 200]),
+	tile(281, "CardinalCage", "Cardinal Cage", true, false, [190, 190, 220]),
+	tile(282, "FishBowl", "Fish Bowl", true, false, [190, 190, 220]),
+	tile(283, "HeavyWorkBench", "Heavy Work Bench", true, false, [155, 112, 76]),
+	tile(284, "CopperPlating", "Copper Plating", false, true, [170, 150, 120]),
+	tile(285, "SnailCage", "Snail Cage", true, false, [190, 190, 220]),
+	tile(286, "GlowingSnailCage", "Glowing Snail Cage", true, false, [190, 190, 220]),
+	tile(287, "AmmoBox", "Ammo Box", true, false, [120, 110, 70]),
+	tile(288, "MonarchButterflyJar", "Monarch Butterfly Jar", true, false, [190, 190, 220]),
+	tile(289, "PurpleEmperorButterflyJar", "Purple Emperor Butterfly Jar", true, false, [190, 190, 220]),
+	tile(290, "RedAdmiralButterflyJar", "Red Admiral Butterfly Jar", true, false, [190, 190, 220]),
+	tile(291, "UlyssesButterflyJar", "Ulysses Butterfly Jar", true, false, [190, 190, 220]),
+	tile(292, "SulphurButterflyJar", "Sulphur Butterfly Jar", true, false, [190, 190, 220]),
+	tile(293, "TreeNymphButterflyJar", "Tree Nymph Butterfly Jar", true, false, [190, 190, 220]),
+	tile(294, "ZebraSwallowtailButterflyJar", "Zebra Swallowtail Butterfly Jar", true, false, [190, 190, 220]),
+	tile(295, "JuliaButterflyJar", "Julia Butterfly Jar", true, false, [190, 190, 220]),
+	tile(296, "ScorpionCage", "Scorpion Cage", true, false, [190, 190, 220]),
+	tile(297, "BlackScorpionCage", "Black Scorpion Cage", true, false, [190, 190, 220]),
+	tile(298, "FrogCage", "Frog Cage", true, false, [190, 190, 220]),
+	tile(299, "MouseCage", "Mouse Cage", true, false, [190, 190, 220]),
+	tile(300, "BoneWelder", "Bone Welder", true, false, [214, 202, 187]),
+	tile(301, "FleshCloningVat", "Flesh Cloning Vat", true, false, [160, 80, 80]),
+	tile(302, "GlassKiln", "Glass Kiln", true, false, [200, 246, 254]),
+	tile(303, "LihzahrdFurnace", "Lihzahrd Furnace", true, false, [140, 90, 70]),
+	tile(304, "LivingLoom", "Living Loom", true, false, [150, 110, 80]),
+	tile(305, "SkyMill", "Sky Mill", true, false, [220, 220, 240]),
+	tile(306, "IceMachine", "Ice Machine", true, false, [144, 195, 232]),
+	tile(307, "SteampunkBoiler", "Steampunk Boiler", true, false, [180, 130, 70]),
+	tile(308, "HoneyDispenser", "Honey Dispenser", true, false, [255, 156, 12]),
+	tile(309, "PenguinCage", "Penguin Cage", true, false, [190, 190, 220]),
+	tile(310, "WormCage", "Worm Cage", true, false, [190, 190, 220]),
+	tile(311, "DynastyWood", "Dynasty Wood", false, true, [170, 120, 84]),
+	tile(312, "RedDynastyShingles", "Red Dynasty Shingles", false, true, [160, 50, 40]),
+	tile(313, "BlueDynastyShingles", "Blue Dynasty Shingles", false, true, [50, 80, 150]),
+	tile(314, "MinecartTrack", "Minecart Track", true, false, [120, 120, 120]),
+	tile(315, "Coralstone", "Coralstone", false, true, [195, 92, 80]),
+	tile(316, "BlueJellyfishBowl", "Blue Jellyfish Bowl", true, false, [190, 190, 220]),
+	tile(317, "GreenJellyfishBowl", "Green Jellyfish Bowl", true, false, [190, 190, 220]),
+	tile(318, "PinkJellyfishBowl", "Pink Jellyfish Bowl", true, false, [190, 190, 220]),
+	tile(319, "ShipInABottle", "Ship in a Bottle", true, false, [190, 190, 220]),
+	tile(320, "SeaweedPlanter", "Seaweed Planter", true, false, [60, 140, 100]),
+	tile(321, "BorealWood", "Boreal Wood", false, true, [170, 120, 84]),
+	tile(322, "PalmWood", "Palm Wood", false, true, [170, 120, 84]),
+	tile(323, "PalmTree", "Palm Tree", true, false, [180, 140, 90]),
+	tile(324, "BeachPiles", "Beach Piles", true, false, [128, 128, 128]),
+	tile(325, "TinPlating", "Tin Plating", false, true, [170, 150, 120]),
+	tile(326, "Waterfall", "Waterfall", false, true, [9, 61, 191]),
+	tile(327, "Lavafall", "Lavafall", false, true, [253, 32, 3]),
+	tile(328, "Confetti", "Confetti", false, true, [230, 200, 230]),
+	tile(329, "ConfettiBlack", "Confetti Black", false, true, [40, 40, 50]),
+	tile(330, "CopperCoinPile", "Copper Coin Pile", false, true, [128, 128, 128]),
+	tile(331, "SilverCoinPile", "Silver Coin Pile", false, true, [128, 128, 128]),
+	tile(332, "GoldCoinPile", "Gold Coin Pile", false, true, [128, 128, 128]),
+	tile(333, "PlatinumCoinPile", "Platinum Coin Pile", false, true, [128, 128, 128]),
+	tile(334, "WeaponsRack", "Weapons Rack", true, false, [150, 110, 80]),
+	tile(335, "FireworksBox", "Fireworks Box", true, false, [230, 80, 80]),
+	tile(336, "LivingFire", "Living Fire", false, true, [254, 121, 2]),
+	tile(337, "AlphabetStatues", "Alphabet Statues", true, false, [144, 148, 144]),
+	tile(338, "FireworkFountain", "Firework Fountain", true, false, [230, 80, 80]),
+	tile(339, "GrasshopperCage", "Grasshopper Cage", true, false, [190, 190, 220]),
+	tile(340, "LivingCursedFire", "Living Cursed Fire", false, true, [150, 220, 40]),
+	tile(341, "LivingDemonFire", "Living Demon Fire", false, true, [180, 60, 200]),
+	tile(342, "LivingFrostFire", "Living Frost Fire", false, true, [120, 200, 240]),
+	tile(343, "LivingIchor", "Living Ichor", false, true, [230, 200, 40]),
+	tile(344, "LivingUltrabrightFire", "Living Ultrabright Fire", false, true, [120, 230, 240]),
+	tile(345, "Honeyfall", "Honeyfall", false, true, [255, 156, 12]),
+	tile(346, "ChlorophyteBrick", "Chlorophyte Brick", false, true, [144, 148, 144]),
+	tile(347, "CrimtaneBrick", "Crimtane Brick", false, true, [144, 148, 144]),
+	tile(348, "ShroomitePlating", "Shroomite Plating", false, true, [170, 150, 120]),
+	tile(349, "MushroomStatue", "Mushroom Statue", true, false, [144, 148, 144]),
+	tile(350, "MartianConduitPlating", "Martian Conduit Plating", false, true, [170, 150, 120]),
+	tile(351, "ChimneySmoke", "Chimney Smoke", false, false, [120, 100, 100]),
+	tile(352, "CrimsonThorns", "Crimson Thorns", false, false, [128, 44, 45]),
+	tile(353, "VineRope", "Vine Rope", false, false, [137, 120, 67]),
+	tile(354, "BewitchingTable", "Bewitching Table", true, false, [165, 118, 80]),
+	tile(355, "AlchemyTable", "Alchemy Table", true, false, [165, 118, 80]),
+	tile(356, "Sundial", "Sundial", true, false, [220, 190, 90]),
+	tile(357, "MarbleBlock", "Smooth Marble Block", false, true, [190, 198, 218]),
+	tile(358, "GoldBirdCage", "Gold Bird Cage", true, false, [190, 190, 220]),
+	tile(359, "GoldBunnyCage", "Gold Bunny Cage", true, false, [190, 190, 220]),
+	tile(360, "GoldButterflyCage", "Gold Butterfly Cage", true, false, [190, 190, 220]),
+	tile(361, "GoldFrogCage", "Gold Frog Cage", true, false, [190, 190, 220]),
+	tile(362, "GoldGrasshopperCage", "Gold Grasshopper Cage", true, false, [190, 190, 220]),
+	tile(363, "GoldMouseCage", "Gold Mouse Cage", true, false, [190, 190, 220]),
+	tile(364, "GoldWormCage", "Gold Worm Cage", true, false, [190, 190, 220]),
+	tile(365, "SilkRope", "Silk Rope", false, false, [137, 120, 67]),
+	tile(366, "WebRope", "Web Rope", false, false, [137, 120, 67]),
+	tile(367, "Marble", "Marble Block", false, true, [168, 178, 204]),
+	tile(368, "Granite", "Granite Block", false, true, [50, 46, 104]),
+	tile(369, "GraniteBlock", "Smooth Granite Block", false, true, [70, 66, 130]),
+	tile(370, "MeteoriteBrick", "Meteorite Brick", false, true, [144, 148, 144]),
+	tile(371, "PinkSlimeBlock", "Pink Slime Block", false, true, [240, 130, 200]),
+	tile(372, "PeaceCandle", "Peace Candle", true, false, [253, 221, 3]),
+	tile(373, "WaterDrip", "Water Drip", true, false, [9, 61, 191]),
+	tile(374, "LavaDrip", "Lava Drip", true, false, [9, 61, 191]),
+	tile(375, "HoneyDrip", "Honey Drip", true, false, [9, 61, 191]),
+	tile(376, "FishingCrate", "Fishing Crate", true, false, [160, 120, 80]),
+	tile(377, "SharpeningStation", "Sharpening Station", true, false, [140, 140, 140]),
+	tile(378, "TargetDummy", "Target Dummy", true, false, [180, 150, 100]),
+	tile(379, "Bubble", "Bubble", false, false, [200, 230, 250]),
+	tile(380, "PlanterBox", "Planter Box", true, true, [150, 100, 70]),
+	tile(381, "LavaMoss", "Lava Moss", false, true, [49, 134, 114]),
+	tile(382, "VineFlowers", "Vine Flowers", false, false, [200, 120, 200]),
+	tile(383, "LivingMahogany", "Living Mahogany", false, true, [145, 81, 85]),
+	tile(384, "LivingMahoganyLeaves", "Living Mahogany Leaves", false, true, [145, 81, 85]),
+	tile(385, "CrystalBlock", "Crystal Block", false, true, [190, 80, 200]),
+	tile(386, "TrapdoorOpen", "Trapdoor Open", true, false, [119, 84, 57]),
+	tile(387, "TrapdoorClosed", "Trapdoor Closed", true, true, [119, 84, 57]),
+	tile(388, "TallGateClosed", "Tall Gate Closed", true, true, [119, 84, 57]),
+	tile(389, "TallGateOpen", "Tall Gate Open", true, false, [119, 84, 57]),
+	tile(390, "LavaLamp", "Lava Lamp", true, false, [253, 221, 3]),
+	tile(391, "CageEnchantedNightcrawler", "Cage Enchanted Nightcrawler", true, false, [190, 190, 220]),
+	tile(392, "CageBuggy", "Cage Buggy", true, false, [190, 190, 220]),
+	tile(393, "CageGrubby", "Cage Grubby", true, false, [190, 190, 220]),
+	tile(394, "CageSluggy", "Cage Sluggy", true, false, [190, 190, 220]),
+	tile(395, "ItemFrame", "Item Frame", true, false, [150, 110, 80]),
+	tile(396, "Sandstone", "Sandstone", false, true, [212, 145, 83]),
+	tile(397, "HardenedSand", "Hardened Sand", false, true, [198, 150, 103]),
+	tile(398, "CorruptHardenedSand", "Corrupt Hardened Sand", false, true, [109, 90, 128]),
+	tile(399, "CrimsonHardenedSand", "Crimson Hardened Sand", false, true, [128, 44, 45]),
+	tile(400, "CorruptSandstone", "Corrupt Sandstone", false, true, [109, 90, 128]),
+	tile(401, "CrimsonSandstone", "Crimson Sandstone", false, true, [128, 44, 45]),
+	tile(402, "HallowHardenedSand", "Hallow Hardened Sand", false, true, [181, 172, 190]),
+	tile(403, "HallowSandstone", "Hallow Sandstone", false, true, [181, 172, 190]),
+	tile(404, "DesertFossil", "Desert Fossil", false, true, [140, 120, 90]),
+	tile(405, "Fireplace", "Fireplace", true, false, [160, 90, 70]),
+	tile(406, "Chimney", "Chimney", true, false, [120, 100, 100]),
+	tile(407, "FossilOre", "Fossil Ore", false, true, [120, 100, 80]),
+	tile(408, "LunarOre", "Luminite", false, true, [117, 227, 210]),
+	tile(409, "LunarBrick", "Lunar Brick", false, true, [144, 148, 144]),
+	tile(410, "LunarMonolith", "Lunar Monolith", true, false, [144, 148, 144]),
+	tile(411, "Detonator", "Detonator", true, false, [190, 60, 60]),
+	tile(412, "LunarCraftingStation", "Lunar Crafting Station", true, false, [117, 227, 210]),
+	tile(413, "SquirrelOrangeCage", "Squirrel Orange Cage", true, false, [190, 190, 220]),
+	tile(414, "SquirrelGoldCage", "Squirrel Gold Cage", true, false, [190, 190, 220]),
+	tile(415, "LunarBlockSolar", "Lunar Block Solar", false, true, [254, 150, 40]),
+	tile(416, "LunarBlockVortex", "Lunar Block Vortex", false, true, [50, 200, 160]),
+	tile(417, "LunarBlockNebula", "Lunar Block Nebula", false, true, [220, 90, 230]),
+	tile(418, "LunarBlockStardust", "Lunar Block Stardust", false, true, [100, 170, 240]),
+	tile(419, "LogicGateLamp", "Logic Gate Lamp", true, false, [253, 221, 3]),
+	tile(420, "LogicGate", "Logic Gate", true, false, [170, 170, 180]),
+	tile(421, "ConveyorBeltLeft", "Conveyor Belt Left", false, true, [90, 90, 100]),
+	tile(422, "ConveyorBeltRight", "Conveyor Belt Right", false, true, [90, 90, 100]),
+	tile(423, "LogicSensor", "Logic Sensor", true, false, [170, 170, 180]),
+	tile(424, "WirePipe", "Wire Pipe", true, false, [150, 150, 150]),
+	tile(425, "AnnouncementBox", "Announcement Box", true, false, [150, 110, 80]),
+	tile(426, "TeamBlockRed", "Team Block Red", false, true, [200, 200, 200]),
+	tile(427, "TeamBlockRedPlatform", "Team Block Red Platform", true, true, [170, 120, 84]),
+	tile(428, "WeightedPressurePlate", "Weighted Pressure Plate", true, false, [190, 60, 60]),
+	tile(429, "WireBulb", "Wire Bulb", true, false, [240, 230, 150]),
+	tile(430, "TeamBlockGreen", "Team Block Green", false, true, [200, 200, 200]),
+	tile(431, "TeamBlockBlue", "Team Block Blue", false, true, [200, 200, 200]),
+	tile(432, "TeamBlockYellow", "Team Block Yellow", false, true, [200, 200, 200]),
+	tile(433, "TeamBlockPink", "Team Block Pink", false, true, [200, 200, 200]),
+	tile(434, "TeamBlockWhite", "Team Block White", false, true, [200, 200, 200]),
+	tile(435, "TeamBlockGreenPlatform", "Team Block Green Platform", true, true, [170, 120, 84]),
+	tile(436, "TeamBlockBluePlatform", "Team Block Blue Platform", true, true, [170, 120, 84]),
+	tile(437, "TeamBlockYellowPlatform", "Team Block Yellow Platform", true, true, [170, 120, 84]),
+	tile(438, "TeamBlockPinkPlatform", "Team Block Pink Platform", true, true, [170, 120, 84]),
+	tile(439, "TeamBlockWhitePlatform", "Team Block White Platform", true, true, [170, 120, 84]),
+	tile(440, "GemLocks", "Gem Locks", true, false, [170, 150, 200]),
+	tile(441, "FakeContainers", "Fake Chest", true, false, [174, 129, 92]),
+	tile(442, "ProjectilePressurePad", "Projectile Pressure Pad", true, false, [140, 140, 150]),
+	tile(443, "GeyserTrap", "Geyser Trap", true, false, [140, 90, 80]),
+	tile(444, "BeeHive", "Bee Hive", true, false, [227, 125, 22]),
+	tile(445, "PixelBox", "Pixel Box", true, false, [200, 200, 200]),
+	tile(446, "SillyBalloonPink", "Silly Balloon Pink", false, true, [230, 120, 180]),
+	tile(447, "SillyBalloonPurple", "Silly Balloon Purple", false, true, [230, 120, 180]),
+	tile(448, "SillyBalloonGreen", "Silly Balloon Green", false, true, [230, 120, 180]),
+	tile(449, "SillyStreamerBlue", "Silly Streamer Blue", false, false, [80, 120, 230]),
+	tile(450, "SillyStreamerGreen", "Silly Streamer Green", false, false, [80, 200, 80]),
+	tile(451, "SillyStreamerPink", "Silly Streamer Pink", false, false, [240, 120, 200]),
+	tile(452, "SillyBalloonMachine", "Silly Balloon Machine", true, false, [230, 120, 180]),
+	tile(453, "SillyBalloonTile", "Silly Balloon Tile", true, false, [230, 120, 180]),
+	tile(454, "Pigronata", "Pigronata", true, false, [230, 120, 180]),
+	tile(455, "PartyMonolith", "Party Monolith", true, false, [144, 148, 144]),
+	tile(456, "PartyBundleOfBalloonTile", "Party Bundle Of Balloon Tile", true, false, [230, 120, 180]),
+	tile(457, "PartyPresent", "Party Present", true, false, [200, 60, 60]),
+	tile(458, "SandFallBlock", "Sand Fall Block", false, true, [212, 145, 83]),
+	tile(459, "SnowFallBlock", "Snow Fall Block", false, true, [211, 236, 241]),
+	tile(460, "SnowCloud", "Snow Cloud", false, true, [211, 236, 241]),
+	tile(461, "SandDrip", "Sand Drip", true, false, [212, 145, 83]),
+	tile(462, "DjinnLamp", "Djinn Lamp", true, false, [253, 221, 3]),
+	tile(463, "DefendersForge", "Defenders Forge", true, false, [180, 80, 60]),
+	tile(464, "WarTable", "War Table", true, false, [165, 118, 80]),
+	tile(465, "WarTableBanner", "War Table Banner", true, false, [13, 88, 130]),
+	tile(466, "ElderCrystalStand", "Elder Crystal Stand", true, false, [200, 170, 90]),
+	tile(467, "Containers2", "Chest 2", true, false, [174, 129, 92]),
+	tile(468, "FakeContainers2", "Fake Chest 2", true, false, [174, 129, 92]),
+	tile(469, "Tables2", "Table 2", true, false, [165, 118, 80]),
+	tile(470, "DisplayDoll", "Display Doll", true, false, [200, 170, 140]),
+	tile(471, "WeaponsRack2", "Weapons Rack 2", true, false, [150, 110, 80]),
+	tile(472, "IronBrick", "Iron Brick", false, true, [144, 148, 144]),
+	tile(473, "LeadBrick", "Lead Brick", false, true, [144, 148, 144]),
+	tile(474, "LesionBlock", "Lesion Block", false, true, [110, 100, 150]),
+	tile(475, "HatRack", "Hat Rack", true, false, [150, 110, 80]),
+	tile(476, "GolfHole", "Golf Hole", true, false, [40, 120, 40]),
+	tile(477, "GolfGrass", "Golf Grass", false, true, [60, 200, 80]),
+	tile(478, "CrimstoneBrick", "Crimstone Brick", false, true, [144, 148, 144]),
+	tile(479, "SmoothSandstone", "Smooth Sandstone", false, true, [212, 145, 83]),
+	tile(480, "BloodMoonMonolith", "Blood Moon Monolith", true, false, [144, 148, 144]),
+	tile(481, "CrackedBlueDungeonBrick", "Cracked Blue Dungeon Brick", false, true, [66, 84, 109]),
+	tile(482, "CrackedGreenDungeonBrick", "Cracked Green Dungeon Brick", false, true, [66, 84, 109]),
+	tile(483, "CrackedPinkDungeonBrick", "Cracked Pink Dungeon Brick", false, true, [66, 84, 109]),
+	tile(484, "RollingCactus", "Rolling Cactus", true, false, [73, 120, 17]),
+	tile(485, "AntlionLarva", "Antlion Larva", true, false, [224, 219, 236]),
+	tile(486, "DrumSet", "Drum Set", true, false, [170, 60, 60]),
+	tile(487, "PicnicTable", "Picnic Table", true, false, [165, 118, 80]),
+	tile(488, "FallenLog", "Fallen Log", true, false, [9, 61, 191]),
+	tile(489, "PinWheel", "Pin Wheel", true, false, [220, 80, 180]),
+	tile(490, "WeatherVane", "Weather Vane", true, false, [130, 130, 140]),
+	tile(491, "VoidVault", "Void Vault", true, false, [110, 60, 160]),
+	tile(492, "GolfGrassHallowed", "Golf Grass Hallowed", false, true, [181, 172, 190]),
+	tile(493, "GolfCupFlag", "Golf Cup Flag", true, false, [220, 50, 50]),
+	tile(494, "GolfTee", "Golf Tee", true, false, [230, 230, 230]),
+	tile(495, "ShellPile", "Shell Pile", false, true, [128, 128, 128]),
+	tile(496, "AntiPortalBlock", "Anti Portal Block", false, true, [70, 70, 80]),
+	tile(497, "Toilets", "Toilet", true, false, [220, 220, 230]),
+	tile(498, "Spider", "Spider", false, true, [60, 60, 60]),
+	tile(499, "LesionStation", "Lesion Station", true, false, [120, 110, 160]),
+	tile(500, "SolarBrick", "Solar Brick", false, true, [144, 148, 144]),
+	tile(501, "VortexBrick", "Vortex Brick", false, true, [144, 148, 144]),
+	tile(502, "NebulaBrick", "Nebula Brick", false, true, [144, 148, 144]),
+	tile(503, "StardustBrick", "Stardust Brick", false, true, [144, 148, 144]),
+	tile(504, "MysticSnakeRope", "Mystic Snake Rope", false, false, [137, 120, 67]),
+	tile(505, "GoldGoldfishBowl", "Gold Goldfish Bowl", true, false, [190, 190, 220]),
+	tile(506, "CatBast", "Cat Bast", true, false, [200, 170, 90]),
+	tile(507, "GoldStarryGlassBlock", "Gold Starry Glass Block", false, true, [200, 246, 254]),
+	tile(508, "BlueStarryGlassBlock", "Blue Starry Glass Block", false, true, [200, 246, 254]),
+	tile(509, "VoidMonolith", "Void Monolith", true, false, [144, 148, 144]),
+	tile(510, "ArrowSign", "Arrow Sign", true, false, [140, 105, 75]),
+	tile(511, "PaintedArrowSign", "Painted Arrow Sign", true, false, [140, 105, 75]),
+	tile(512, "GreenMossBrick", "Green Moss Brick", false, true, [49, 134, 114]),
+	tile(513, "BrownMossBrick", "Brown Moss Brick", false, true, [49, 134, 114]),
+	tile(514, "RedMossBrick", "Red Moss Brick", false, true, [49, 134, 114]),
+	tile(515, "BlueMossBrick", "Blue Moss Brick", false, true, [49, 134, 114]),
+	tile(516, "PurpleMossBrick", "Purple Moss Brick", false, true, [49, 134, 114]),
+	tile(517, "LavaMossBrick", "Lava Moss Brick", false, true, [49, 134, 114]),
+	tile(518, "LilyPad", "Lily Pad", true, false, [60, 160, 60]),
+	tile(519, "Cattail", "Cattail", true, false, [110, 150, 60]),
+	tile(520, "FoodPlatter", "Food Platter", true, false, [200, 200, 210]),
+	tile(521, "BlackDragonflyJar", "Black Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(522, "BlueDragonflyJar", "Blue Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(523, "GreenDragonflyJar", "Green Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(524, "OrangeDragonflyJar", "Orange Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(525, "RedDragonflyJar", "Red Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(526, "YellowDragonflyJar", "Yellow Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(527, "GoldDragonflyJar", "Gold Dragonfly Jar", true, false, [190, 190, 220]),
+	tile(528, "MushroomVines", "Mushroom Vines", false, false, [23, 177, 76]),
+	tile(529, "SeaOats", "Sea Oats", true, false, [200, 190, 120]),
+	tile(530, "OasisPlants", "Oasis Plants", true, false, [13, 165, 13]),
+	tile(531, "BoulderStatue", "Boulder Statue", true, false, [144, 148, 144]),
+	tile(532, "MaggotCage", "Maggot Cage", true, false, [190, 190, 220]),
+	tile(533, "RatCage", "Rat Cage", true, false, [190, 190, 220]),
+	tile(534, "KryptonMoss", "Krypton Moss", false, true, [49, 134, 114]),
+	tile(535, "KryptonMossBrick", "Krypton Moss Brick", false, true, [49, 134, 114]),
+	tile(536, "XenonMoss", "Xenon Moss", false, true, [49, 134, 114]),
+	tile(537, "XenonMossBrick", "Xenon Moss Brick", false, true, [49, 134, 114]),
+	tile(538, "LadybugCage", "Ladybug Cage", true, false, [190, 190, 220]),
+	tile(539, "ArgonMoss", "Argon Moss", false, true, [49, 134, 114]),
+	tile(540, "ArgonMossBrick", "Argon Moss Brick", false, true, [49, 134, 114]),
+	tile(541, "EchoBlock", "Echo Block", false, true, [160, 180, 200]),
+	tile(542, "OwlCage", "Owl Cage", true, false, [190, 190, 220]),
+	tile(543, "PupfishBowl", "Pupfish Bowl", true, false, [190, 190, 220]),
+	tile(544, "GoldLadybugCage", "Gold Ladybug Cage", true, false, [190, 190, 220]),
+	tile(545, "LawnFlamingo", "Lawn Flamingo", true, false, [240, 110, 160]),
+	tile(546, "Grate", "Grate", false, false, [110, 110, 110]),
+	tile(547, "PottedPlants1", "Potted Plants 1", true, false, [13, 165, 13]),
+	tile(548, "PottedPlants2", "Potted Plants 2", true, false, [13, 165, 13]),
+	tile(549, "Seaweed", "Seaweed", true, false, [40, 140, 90]),
+	tile(550, "TurtleCage", "Turtle Cage", true, false, [190, 190, 220]),
+	tile(551, "TurtleJungleCage", "Turtle Jungle Cage", true, false, [190, 190, 220]),
+	tile(552, "Sandcastles", "Sandcastles", true, false, [212, 145, 83]),
+	tile(553, "GrebeCage", "Grebe Cage", true, false, [190, 190, 220]),
+	tile(554, "SeagullCage", "Seagull Cage", true, false, [190, 190, 220]),
+	tile(555, "WaterStriderCage", "Water Strider Cage", true, false, [190, 190, 220]),
+	tile(556, "GoldWaterStriderCage", "Gold Water Strider Cage", true, false, [190, 190, 220]),
+	tile(557, "GrateClosed", "Grate Closed", false, true, [90, 90, 90]),
+	tile(558, "SeahorseCage", "Seahorse Cage", true, false, [190, 190, 220]),
+	tile(559, "GoldSeahorseCage", "Gold Seahorse Cage", true, false, [190, 190, 220]),
+	tile(560, "GolfTrophies", "Golf Trophies", true, false, [200, 170, 60]),
+	tile(561, "MarbleColumn", "Marble Column", false, false, [190, 198, 218]),
+	tile(562, "BambooBlock", "Bamboo Block", false, true, [120, 170, 60]),
+	tile(563, "LargeBambooBlock", "Large Bamboo Block", false, true, [120, 170, 60]),
+	tile(564, "PlasmaLamp", "Plasma Lamp", true, false, [253, 221, 3]),
+	tile(565, "FogMachine", "Fog Machine", true, false, [140, 140, 150]),
+	tile(566, "AmberStoneBlock", "Amber Stone Block", false, true, [200, 130, 40]),
+	tile(567, "GardenGnome", "Garden Gnome", true, false, [180, 60, 60]),
+	tile(568, "PinkFairyJar", "Pink Fairy Jar", true, false, [190, 190, 220]),
+	tile(569, "GreenFairyJar", "Green Fairy Jar", true, false, [190, 190, 220]),
+	tile(570, "BlueFairyJar", "Blue Fairy Jar", true, false, [190, 190, 220]),
+	tile(571, "Bamboo", "Bamboo", true, false, [120, 170, 60]),
+	tile(572, "SoulBottles", "Soul Bottles", true, false, [190, 190, 220]),
+	tile(573, "TatteredWoodSign", "Tattered Wood Sign", true, false, [170, 120, 84]),
+	tile(574, "BorealBeam", "Boreal Beam", false, false, [110, 90, 70]),
+	tile(575, "RichMahoganyBeam", "Rich Mahogany Beam", false, false, [145, 81, 85]),
+	tile(576, "GraniteColumn", "Granite Column", false, false, [70, 66, 130]),
+	tile(577, "SandstoneColumn", "Sandstone Column", false, false, [212, 145, 83]),
+	tile(578, "MushroomBeam", "Mushroom Beam", false, false, [93, 127, 255]),
+	tile(579, "RockGolemHead", "Rock Golem Head", true, false, [110, 110, 120]),
+	tile(580, "HellButterflyJar", "Hell Butterfly Jar", true, false, [190, 190, 220]),
+	tile(581, "LavaflyinaBottle", "Lavafly in a Bottle", true, false, [190, 190, 220]),
+	tile(582, "MagmaSnailCage", "Magma Snail Cage", true, false, [190, 190, 220]),
+	tile(583, "TreeTopaz", "Tree Topaz", true, false, [151, 107, 75]),
+	tile(584, "TreeAmethyst", "Tree Amethyst", true, false, [151, 107, 75]),
+	tile(585, "TreeSapphire", "Tree Sapphire", true, false, [151, 107, 75]),
+	tile(586, "TreeEmerald", "Tree Emerald", true, false, [151, 107, 75]),
+	tile(587, "TreeRuby", "Tree Ruby", true, false, [151, 107, 75]),
+	tile(588, "TreeDiamond", "Tree Diamond", true, false, [151, 107, 75]),
+	tile(589, "TreeAmber", "Tree Amber", true, false, [151, 107, 75]),
+	tile(590, "GemSaplings", "Gem Saplings", true, false, [100, 160, 40]),
+	tile(591, "PotsSuspended", "Pots Suspended", true, false, [151, 79, 80]),
+	tile(592, "BrazierSuspended", "Brazier Suspended", true, false, [254, 121, 2]),
+	tile(593, "VolcanoSmall", "Volcano Small", true, false, [120, 70, 60]),
+	tile(594, "VolcanoLarge", "Volcano Large", true, false, [120, 70, 60]),
+	tile(595, "VanityTreeSakuraSaplings", "Vanity Tree Sakura Saplings", true, false, [100, 160, 40]),
+	tile(596, "VanityTreeSakura", "Vanity Tree Sakura", true, false, [151, 107, 75]),
+	tile(597, "TeleportationPylon", "Teleportation Pylon", true, false, [120, 200, 230]),
+	tile(598, "LavafishBowl", "Lavafish Bowl", true, false, [190, 190, 220]),
+	tile(599, "AmethystBunnyCage", "Amethyst Bunny Cage", true, false, [190, 190, 220]),
+	tile(600, "TopazBunnyCage", "Topaz Bunny Cage", true, false, [190, 190, 220]),
+	tile(601, "SapphireBunnyCage", "Sapphire Bunny Cage", true, false, [190, 190, 220]),
+	tile(602, "EmeraldBunnyCage", "Emerald Bunny Cage", true, false, [190, 190, 220]),
+	tile(603, "RubyBunnyCage", "Ruby Bunny Cage", true, false, [190, 190, 220]),
+	tile(604, "DiamondBunnyCage", "Diamond Bunny Cage", true, false, [190, 190, 220]),
+	tile(605, "AmberBunnyCage", "Amber Bunny Cage", true, false, [190, 190, 220]),
+	tile(606, "AmethystSquirrelCage", "Amethyst Squirrel Cage", true, false, [190, 190, 220]),
+	tile(607, "TopazSquirrelCage", "Topaz Squirrel Cage", true, false, [190, 190, 220]),
+	tile(608, "SapphireSquirrelCage", "Sapphire Squirrel Cage", true, false, [190, 190, 220]),
+	tile(609, "EmeraldSquirrelCage", "Emerald Squirrel Cage", true, false, [190, 190, 220]),
+	tile(610, "RubySquirrelCage", "Ruby Squirrel Cage", true, false, [190, 190, 220]),
+	tile(611, "DiamondSquirrelCage", "Diamond Squirrel Cage", true, false, [190, 190, 220]),
+	tile(612, "AmberSquirrelCage", "Amber Squirrel Cage", true, false, [190, 190, 220]),
+	tile(613, "PottedLavaPlants", "Potted Lava Plants", true, false, [13, 165, 13]),
+	tile(614, "PottedLavaPlantTendrils", "Potted Lava Plant Tendrils", true, false, [200, 90, 40]),
+	tile(615, "VanityTreeWillowSaplings", "Vanity Tree Willow Saplings", true, false, [100, 160, 40]),
+	tile(616, "VanityTreeYellowWillow", "Vanity Tree Yellow Willow", true, false, [151, 107, 75]),
+	tile(617, "MasterTrophyBase", "Master Trophy Base", true, false, [180, 150, 50]),
+	tile(618, "AccentSlab", "Accent Slab", false, true, [140, 140, 140]),
+	tile(619, "TruffleWormCage", "Truffle Worm Cage", true, false, [190, 190, 220]),
+	tile(620, "EmpressButterflyJar", "Empress Butterfly Jar", true, false, [190, 190, 220]),
+	tile(621, "SliceOfCake", "Slice Of Cake", true, false, [240, 200, 170]),
+	tile(622, "TeaKettle", "Tea Kettle", true, false, [170, 170, 190]),
+	tile(623, "PottedCrystalPlants", "Potted Crystal Plants", true, false, [13, 165, 13]),
+	tile(624, "AbigailsFlower", "Abigails Flower", true, false, [230, 230, 240]),
+	tile(625, "VioletMoss", "Violet Moss", false, true, [49, 134, 114]),
+	tile(626, "VioletMossBrick", "Violet Moss Brick", false, true, [49, 134, 114]),
+	tile(627, "RainbowMoss", "Rainbow Moss", false, true, [49, 134, 114]),
+	tile(628, "RainbowMossBrick", "Rainbow Moss Brick", false, true, [49, 134, 114]),
+	tile(629, "StinkbugCage", "Stinkbug Cage", true, false, [190, 190, 220]),
+	tile(630, "StinkbugHousingBlocker", "Stinkbug Housing Blocker", true, false, [120, 150, 60]),
+	tile(631, "StinkbugHousingBlockerEcho", "Stinkbug Housing Blocker (Echo)", true, false, [120, 150, 60]),
+	tile(632, "ScarletMacawCage", "Scarlet Macaw Cage", true, false, [190, 190, 220]),
+	tile(633, "AshGrass", "Ash Grass", false, true, [120, 90, 80]),
+	tile(634, "TreeAsh", "Tree Ash", true, false, [151, 107, 75]),
+	tile(635, "AshWood", "Ash Wood", false, true, [170, 120, 84]),
+	tile(636, "CorruptVines", "Corrupt Vines", false, false, [130, 110, 190]),
+	tile(637, "AshPlants", "Ash Plants", true, false, [13, 165, 13]),
+	tile(638, "AshVines", "Ash Vines", false, false, [23, 177, 76]),
+	tile(639, "ManaCrystal", "Mana Crystal", true, false, [50, 90, 230]),
+	tile(640, "BlueMacawCage", "Blue Macaw Cage", true, false, [190, 190, 220]),
+	tile(641, "ReefBlock", "Reef Block", false, true, [210, 120, 120]),
+	tile(642, "ChlorophyteExtractinator", "Chlorophyte Extractinator", true, false, [120, 140, 120]),
+	tile(643, "ToucanCage", "Toucan Cage", true, false, [190, 190, 220]),
+	tile(644, "YellowCockatielCage", "Yellow Cockatiel Cage", true, false, [190, 190, 220]),
+	tile(645, "GrayCockatielCage", "Gray Cockatiel Cage", true, false, [190, 190, 220]),
+	tile(646, "ShadowCandle", "Shadow Candle", true, false, [253, 221, 3]),
+	tile(647, "LargePilesEcho", "Large Piles (Echo)", true, false, [128, 128, 128]),
+	tile(648, "LargePiles2Echo", "Large Piles 2 (Echo)", true, false, [128, 128, 128]),
+	tile(649, "SmallPiles2x1Echo", "Small Piles 2x1 (Echo)", true, false, [128, 128, 128]),
+	tile(650, "SmallPiles1x1Echo", "Small Piles 1x1 (Echo)", true, false, [128, 128, 128]),
+	tile(651, "PlantDetritus3x2Echo", "Plant Detritus 3x2 (Echo)", true, false, [60, 140, 50]),
+	tile(652, "PlantDetritus2x2Echo", "Plant Detritus 2x2 (Echo)", true, false, [60, 140, 50]),
+	tile(653, "PlantDetritus1x2Echo", "Plant Detritus 1x2 (Echo)", true, false, [60, 140, 50]),
+	tile(654, "TNTBarrel", "TNT Barrel", true, false, [200, 50, 50]),
+	tile(655, "PlanteraThorns", "Plantera Thorns", true, false, [180, 90, 160]),
+	tile(656, "GlowTulip", "Glow Tulip", true, false, [120, 200, 230]),
+	tile(657, "EchoMonolith", "Echo Monolith", true, false, [144, 148, 144]),
+	tile(658, "ShimmerMonolith", "Shimmer Monolith", true, false, [144, 148, 144]),
+	tile(659, "ShimmerBlock", "Aetherium Block", false, true, [190, 160, 230]),
+	tile(660, "ShimmerflyinaBottle", "Faeling in a Bottle", true, false, [190, 190, 220]),
+	tile(661, "CorruptJungleGrass", "Corrupt Jungle Grass", false, true, [109, 90, 128]),
+	tile(662, "CrimsonJungleGrass", "Crimson Jungle Grass", false, true, [128, 44, 45]),
+	tile(663, "Moondial", "Moondial", true, false, [140, 140, 220]),
+	tile(664, "BouncyBoulder", "Bouncy Boulder", true, false, [110, 100, 90]),
+	tile(665, "LifeCrystalBoulder", "Life Crystal Boulder", true, false, [110, 100, 90]),
+	tile(666, "PoopBlock", "Poo Block", false, true, [120, 80, 50]),
+	tile(667, "ShimmerBrick", "Aetherium Brick", false, true, [144, 148, 144]),
+	tile(668, "DirtiestBlock", "Dirtiest Block", false, true, [80, 60, 45]),
+	tile(669, "LunarRustBrick", "Lunar Rust Brick", false, true, [144, 148, 144]),
+	tile(670, "DarkCelestialBrick", "Dark Celestial Brick", false, true, [144, 148, 144]),
+	tile(671, "AstraBrick", "Astra Brick", false, true, [144, 148, 144]),
+	tile(672, "CosmicEmberBrick", "Cosmic Ember Brick", false, true, [144, 148, 144]),
+	tile(673, "CryocoreBrick", "Cryocore Brick", false, true, [144, 148, 144]),
+	tile(674, "MercuryBrick", "Mercury Brick", false, true, [144, 148, 144]),
+	tile(675, "StarRoyaleBrick", "Star Royale Brick", false, true, [144, 148, 144]),
+	tile(676, "HeavenforgeBrick", "Heavenforge Brick", false, true, [144, 148, 144]),
+	tile(677, "AncientBlueBrick", "Ancient Blue Brick", false, true, [144, 148, 144]),
+	tile(678, "AncientGreenBrick", "Ancient Green Brick", false, true, [144, 148, 144]),
+	tile(679, "AncientPinkBrick", "Ancient Pink Brick", false, true, [144, 148, 144]),
+	tile(680, "AncientGoldBrick", "Ancient Gold Brick", false, true, [144, 148, 144]),
+	tile(681, "AncientSilverBrick", "Ancient Silver Brick", false, true, [144, 148, 144]),
+	tile(682, "AncientCopperBrick", "Ancient Copper Brick", false, true, [144, 148, 144]),
+	tile(683, "AncientCobaltBrick", "Ancient Cobalt Brick", false, true, [144, 148, 144]),
+	tile(684, "AncientMythrilBrick", "Ancient Mythril Brick", false, true, [144, 148, 144]),
+	tile(685, "AncientObsidianBrick", "Ancient Obsidian Brick", false, true, [144, 148, 144]),
+	tile(686, "AncientHellstoneBrick", "Ancient Hellstone Brick", false, true, [144, 148, 144]),
+	tile(687, "LavaMossBlock", "Lava Moss Block", false, true, [49, 134, 114]),
+	tile(688, "ArgonMossBlock", "Argon Moss Block", false, true, [49, 134, 114]),
+	tile(689, "KryptonMossBlock", "Krypton Moss Block", false, true, [49, 134, 114]),
+	tile(690, "XenonMossBlock", "Xenon Moss Block", false, true, [49, 134, 114]),
+	tile(691, "VioletMossBlock", "Violet Moss Block", false, true, [49, 134, 114]),
+	tile(692, "RainbowMossBlock", "Rainbow Moss Block", false, true, [49, 134, 114])
+];
+
+pub static WALLS: &[WallInfo] = &[
+	wall(0, "None", "None", [0, 0, 0]),
+	wall(1, "Stone", "Stone Wall", [52, 52, 52]),
+	wall(2, "DirtUnsafe", "Dirt Wall", [88, 61, 46]),
+	wall(3, "EbonstoneUnsafe", "Ebonstone Wall", [61, 58, 78]),
+	wall(4, "Wood", "Wood Wall", [73, 51, 36]),
+	wall(5, "GrayBrick", "Gray Brick Wall", [72, 74, 72]),
+	wall(6, "RedBrick", "Red Brick Wall", [72, 74, 72]),
+	wall(7, "BlueDungeonUnsafe", "Blue Dungeon Wall", [30, 40, 60]),
+	wall(8, "GreenDungeonUnsafe", "Green Dungeon Wall", [30, 40, 60]),
+	wall(9, "PinkDungeonUnsafe", "Pink Dungeon Wall", [30, 40, 60]),
+	wall(10, "GoldBrick", "Gold Brick Wall", [72, 74, 72]),
+	wall(11, "SilverBrick", "Silver Brick Wall", [72, 74, 72]),
+	wall(12, "CopperBrick", "Copper Brick Wall", [72, 74, 72]),
+	wall(13, "Hellstone", "Hellstone Wall", [70, 30, 30]),
+	wall(14, "ObsidianBrickUnsafe", "Obsidian Brick Wall", [35, 33, 71]),
+	wall(15, "MudUnsafe", "Mud Wall", [61, 46, 54]),
+	wall(16, "Dirt", "Dirt Wall", [88, 61, 46]),
+	wall(17, "BlueDungeon", "Blue Dungeon Wall", [30, 40, 60]),
+	wall(18, "GreenDungeon", "Green Dungeon Wall", [30, 40, 60]),
+	wall(19, "PinkDungeon", "Pink Dungeon Wall", [30, 40, 60]),
+	wall(20, "ObsidianBrick", "Obsidian Brick Wall", [35, 33, 71]),
+	wall(21, "Glass", "Glass Wall", [100, 123, 127]),
+	wall(22, "PearlstoneBrick", "Pearlstone Brick Wall", [96, 90, 100]),
+	wall(23, "IridescentBrick", "Iridescent Brick Wall", [72, 74, 72]),
+	wall(24, "MudstoneBrick", "Mudstone Brick Wall", [61, 46, 54]),
+	wall(25, "CobaltBrick", "Cobalt Brick Wall", [72, 74, 72]),
+	wall(26, "MythrilBrick", "Mythril Brick Wall", [72, 74, 72]),
+	wall(27, "Planked", "Planked Wall", [64, 64, 64]),
+	wall(28, "PearlstoneBrickUnsafe", "Pearlstone Brick Wall", [96, 90, 100]),
+	wall(29, "CandyCane", "Candy Cane Wall", [64, 64, 64]),
+	wall(30, "GreenCandyCane", "Green Candy Cane Wall", [64, 64, 64]),
+	wall(31, "SnowBrick", "Snow Brick Wall", [106, 118, 120]),
+	wall(32, "AdamantiteBeam", "Adamantite Beam Wall", [64, 64, 64]),
+	wall(33, "DemoniteBrick", "Demonite Brick Wall", [72, 74, 72]),
+	wall(34, "SandstoneBrick", "Sandstone Brick Wall", [106, 72, 41]),
+	wall(35, "EbonstoneBrick", "Ebonstone Brick Wall", [61, 58, 78]),
+	wall(36, "RedStucco", "Red Stucco Wall", [64, 64, 64]),
+	wall(37, "YellowStucco", "Yellow Stucco Wall", [64, 64, 64]),
+	wall(38, "GreenStucco", "Green Stucco Wall", [64, 64, 64]),
+	wall(39, "Gray", "Gray Stucco Wall", [64, 64, 64]),
+	wall(40, "Ebonwood", "Ebonwood Wall", [64, 64, 64]),
+	wall(41, "RichMaogany", "Rich Mahogany Wall", [64, 64, 64]),
+	wall(42, "Pearlwood", "Pearlwood Wall", [96, 90, 100]),
+	wall(43, "RainbowBrick", "Rainbow Brick Wall", [72, 74, 72]),
+	wall(44, "TinBrick", "Tin Brick Wall", [72, 74, 72]),
+	wall(45, "TungstenBrick", "Tungsten Brick Wall", [72, 74, 72]),
+	wall(46, "PlatinumBrick", "Platinum Brick Wall", [72, 74, 72]),
+	wall(47, "AmethystUnsafe", "Amethyst Wall", [64, 64, 64]),
+	wall(48, "TopazUnsafe", "Topaz Wall", [64, 64, 64]),
+	wall(49, "SapphireUnsafe", "Sapphire Wall", [64, 64, 64]),
+	wall(50, "EmeraldUnsafe", "Emerald Wall", [64, 64, 64]),
+	wall(51, "RubyUnsafe", "Ruby Wall", [64, 64, 64]),
+	wall(52, "DiamondUnsafe", "Diamond Wall", [64, 64, 64]),
+	wall(53, "CaveUnsafe", "Cave Wall", [52, 52, 52]),
+	wall(54, "Cave2Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(55, "Cave3Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(56, "Cave4Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(57, "Cave5Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(58, "Cave6Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(59, "LivingLeaf", "Living Leaf Wall", [20, 96, 20]),
+	wall(60, "Cave7Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(61, "SpiderUnsafe", "Spider Wall", [40, 40, 40]),
+	wall(62, "GrassUnsafe", "Grass Wall", [30, 80, 48]),
+	wall(63, "JungleUnsafe", "Jungle Wall", [48, 73, 24]),
+	wall(64, "FlowerUnsafe", "Flower Wall", [30, 80, 48]),
+	wall(65, "Grass", "Grass Wall", [30, 80, 48]),
+	wall(66, "Jungle", "Jungle Wall", [48, 73, 24]),
+	wall(67, "Flower", "Flower Wall", [30, 80, 48]),
+	wall(68, "CorruptGrassUnsafe", "Corrupt Grass Wall", [30, 80, 48]),
+	wall(69, "HallowedGrassUnsafe", "Hallowed Grass Wall", [30, 80, 48]),
+	wall(70, "IceUnsafe", "Ice Wall", [72, 97, 116]),
+	wall(71, "Cactus", "Cactus Wall", [64, 64, 64]),
+	wall(72, "Cloud", "Cloud Wall", [64, 64, 64]),
+	wall(73, "Mushroom", "Mushroom Wall", [64, 64, 64]),
+	wall(74, "Bone", "Bone Wall", [64, 64, 64]),
+	wall(75, "Slime", "Slime Wall", [64, 64, 64]),
+	wall(76, "Flesh", "Flesh Wall", [64, 64, 64]),
+	wall(77, "LivingWood", "Living Wood Wall", [73, 51, 36]),
+	wall(78, "ObsidianBackUnsafe", "Obsidian Back Wall", [35, 33, 71]),
+	wall(79, "MushroomUnsafe", "Mushroom Wall", [64, 64, 64]),
+	wall(80, "CrimsonGrassUnsafe", "Crimson Grass Wall", [30, 80, 48]),
+	wall(81, "DiscWall", "Disc Wall", [64, 64, 64]),
+	wall(82, "CrimstoneUnsafe", "Crimstone Wall", [80, 30, 30]),
+	wall(83, "IceBrick", "Ice Brick Wall", [72, 97, 116]),
+	wall(84, "Shadewood", "Shadewood Wall", [64, 64, 64]),
+	wall(85, "HiveUnsafe", "Hive Wall", [113, 62, 11]),
+	wall(86, "LihzahrdBrickUnsafe", "Lihzahrd Brick Wall", [71, 28, 0]),
+	wall(87, "PurpleStainedGlass", "Purple Stained Glass Wall", [100, 123, 127]),
+	wall(88, "YellowStainedGlass", "Yellow Stained Glass Wall", [100, 123, 127]),
+	wall(89, "BlueStainedGlass", "Blue Stained Glass Wall", [100, 123, 127]),
+	wall(90, "GreenStainedGlass", "Green Stained Glass Wall", [100, 123, 127]),
+	wall(91, "RedStainedGlass", "Red Stained Glass Wall", [100, 123, 127]),
+	wall(92, "RainbowStainedGlass", "Rainbow Stained Glass Wall", [100, 123, 127]),
+	wall(93, "BlueDungeonSlabUnsafe", "Blue Dungeon Slab Wall", [30, 40, 60]),
+	wall(94, "BlueDungeonTileUnsafe", "Blue Dungeon Tile Wall", [30, 40, 60]),
+	wall(95, "PinkDungeonSlabUnsafe", "Pink Dungeon Slab Wall", [30, 40, 60]),
+	wall(96, "PinkDungeonTileUnsafe", "Pink Dungeon Tile Wall", [30, 40, 60]),
+	wall(97, "GreenDungeonSlabUnsafe", "Green Dungeon Slab Wall", [30, 40, 60]),
+	wall(98, "GreenDungeonTileUnsafe", "Green Dungeon Tile Wall", [30, 40, 60]),
+	wall(99, "BlueDungeonSlab", "Blue Dungeon Slab Wall", [30, 40, 60]),
+	wall(100, "BlueDungeonTile", "Blue Dungeon Tile Wall", [30, 40, 60]),
+	wall(101, "PinkDungeonSlab", "Pink Dungeon Slab Wall", [30, 40, 60]),
+	wall(102, "PinkDungeonTile", "Pink Dungeon Tile Wall", [30, 40, 60]),
+	wall(103, "GreenDungeonSlab", "Green Dungeon Slab Wall", [30, 40, 60]),
+	wall(104, "GreenDungeonTile", "Green Dungeon Tile Wall", [30, 40, 60]),
+	wall(105, "WoodenFence", "Wooden Fence", [73, 51, 36]),
+	wall(106, "MetalFence", "Metal Fence", [73, 51, 36]),
+	wall(107, "Hive", "Hive Wall", [113, 62, 11]),
+	wall(108, "PalladiumColumn", "Palladium Column Wall", [64, 64, 64]),
+	wall(109, "BubblegumBlock", "Bubblegum Block Wall", [64, 64, 64]),
+	wall(110, "TitanstoneBlock", "Titanstone Block Wall", [64, 64, 64]),
+	wall(111, "LihzahrdBrick", "Lihzahrd Brick Wall", [71, 28, 0]),
+	wall(112, "Pumpkin", "Pumpkin Wall", [64, 64, 64]),
+	wall(113, "Hay", "Hay Wall", [64, 64, 64]),
+	wall(114, "SpookyWood", "Spooky Wood Wall", [73, 51, 36]),
+	wall(115, "ChristmasTreeWallpaper", "Christmas Tree Wallpaper", [100, 80, 90]),
+	wall(116, "OrnamentWallpaper", "Ornament Wallpaper", [100, 80, 90]),
+	wall(117, "CandyCaneWallpaper", "Candy Cane Wallpaper", [100, 80, 90]),
+	wall(118, "FestiveWallpaper", "Festive Wallpaper", [100, 80, 90]),
+	wall(119, "StarsWallpaper", "Stars Wallpaper", [100, 80, 90]),
+	wall(120, "SquigglesWallpaper", "Squiggles Wallpaper", [100, 80, 90]),
+	wall(121, "SnowflakeWallpaper", "Snowflake Wallpaper", [106, 118, 120]),
+	wall(122, "KrampusHornWallpaper", "Krampus Horn Wallpaper", [100, 80, 90]),
+	wall(123, "BluegreenWallpaper", "Bluegreen Wallpaper", [100, 80, 90]),
+	wall(124, "GrinchFingerWallpaper", "Grinch Finger Wallpaper", [100, 80, 90]),
+	wall(125, "FancyGrayWallpaper", "Fancy Gray Wallpaper", [100, 80, 90]),
+	wall(126, "IceFloeWallpaper", "Ice Floe Wallpaper", [72, 97, 116]),
+	wall(127, "MusicWallpaper", "Music Wallpaper", [100, 80, 90]),
+	wall(128, "PurpleRainWallpaper", "Purple Rain Wallpaper", [100, 80, 90]),
+	wall(129, "RainbowWallpaper", "Rainbow Wallpaper", [100, 80, 90]),
+	wall(130, "SparkleStoneWallpaper", "Sparkle Stone Wallpaper", [52, 52, 52]),
+	wall(131, "StarlitHeavenWallpaper", "Starlit Heaven Wallpaper", [100, 80, 90]),
+	wall(132, "BubbleWallpaper", "Bubble Wallpaper", [100, 80, 90]),
+	wall(133, "CopperPipeWallpaper", "Copper Pipe Wallpaper", [100, 80, 90]),
+	wall(134, "DuckyWallpaper", "Ducky Wallpaper", [100, 80, 90]),
+	wall(135, "Waterfall", "Waterfall Wall", [64, 64, 64]),
+	wall(136, "Lavafall", "Lavafall Wall", [70, 30, 30]),
+	wall(137, "EbonwoodFence", "Ebonwood Fence", [73, 51, 36]),
+	wall(138, "RichMahoganyFence", "Rich Mahogany Fence", [73, 41, 45]),
+	wall(139, "PearlwoodFence", "Pearlwood Fence", [96, 90, 100]),
+	wall(140, "ShadewoodFence", "Shadewood Fence", [73, 51, 36]),
+	wall(141, "WhiteDynasty", "White Dynasty Wall", [64, 64, 64]),
+	wall(142, "BlueDynasty", "Blue Dynasty Wall", [64, 64, 64]),
+	wall(143, "ArcaneRunes", "Arcane Runes Wall", [64, 64, 64]),
+	wall(144, "IronFence", "Iron Fence", [73, 51, 36]),
+	wall(145, "CopperPlating", "Copper Plating Wall", [64, 64, 64]),
+	wall(146, "StoneSlab", "Stone Slab Wall", [52, 52, 52]),
+	wall(147, "Sail", "Sail Wall", [64, 64, 64]),
+	wall(148, "BorealWood", "Boreal Wood Wall", [73, 51, 36]),
+	wall(149, "BorealWoodFence", "Boreal Wood Fence", [73, 51, 36]),
+	wall(150, "PalmWood", "Palm Wood Wall", [73, 51, 36]),
+	wall(151, "PalmWoodFence", "Palm Wood Fence", [73, 51, 36]),
+	wall(152, "AmberGemspark", "Amber Gemspark Wall", [100, 100, 115]),
+	wall(153, "AmethystGemspark", "Amethyst Gemspark Wall", [100, 100, 115]),
+	wall(154, "DiamondGemspark", "Diamond Gemspark Wall", [100, 100, 115]),
+	wall(155, "EmeraldGemspark", "Emerald Gemspark Wall", [100, 100, 115]),
+	wall(156, "AmberGemsparkOff", "Offline Amber Gemspark Wall", [100, 100, 115]),
+	wall(157, "AmethystGemsparkOff", "Offline Amethyst Gemspark Wall", [100, 100, 115]),
+	wall(158, "DiamondGemsparkOff", "Offline Diamond Gemspark Wall", [100, 100, 115]),
+	wall(159, "EmeraldGemsparkOff", "Offline Emerald Gemspark Wall", [100, 100, 115]),
+	wall(160, "RubyGemsparkOff", "Offline Ruby Gemspark Wall", [100, 100, 115]),
+	wall(161, "SapphireGemsparkOff", "Offline Sapphire Gemspark Wall", [100, 100, 115]),
+	wall(162, "TopazGemsparkOff", "Offline Topaz Gemspark Wall", [100, 100, 115]),
+	wall(163, "RubyGemspark", "Ruby Gemspark Wall", [100, 100, 115]),
+	wall(164, "SapphireGemspark", "Sapphire Gemspark Wall", [100, 100, 115]),
+	wall(165, "TopazGemspark", "Topaz Gemspark Wall", [100, 100, 115]),
+	wall(166, "TinPlating", "Tin Plating Wall", [64, 64, 64]),
+	wall(167, "Confetti", "Confetti Wall", [64, 64, 64]),
+	wall(168, "ConfettiBlack", "Confetti Black Wall", [64, 64, 64]),
+	wall(169, "CaveWall", "Cave Wall", [52, 52, 52]),
+	wall(170, "CaveWall2", "Cave Wall", [52, 52, 52]),
+	wall(171, "Honeyfall", "Honeyfall Wall", [64, 64, 64]),
+	wall(172, "ChlorophyteBrick", "Chlorophyte Brick Wall", [72, 74, 72]),
+	wall(173, "CrimtaneBrick", "Crimtane Brick Wall", [80, 30, 30]),
+	wall(174, "ShroomitePlating", "Shroomite Plating Wall", [64, 64, 64]),
+	wall(175, "MartianConduit", "Martian Conduit Wall", [64, 64, 64]),
+	wall(176, "HellstoneBrick", "Hellstone Brick Wall", [70, 30, 30]),
+	wall(177, "MarbleUnsafe", "Marble Wall", [84, 89, 102]),
+	wall(178, "MarbleBlock", "Marble Block Wall", [84, 89, 102]),
+	wall(179, "GraniteUnsafe", "Granite Wall", [25, 23, 52]),
+	wall(180, "GraniteBlock", "Granite Block Wall", [25, 23, 52]),
+	wall(181, "MeteoriteBrick", "Meteorite Brick Wall", [72, 74, 72]),
+	wall(182, "Marble", "Marble Wall", [84, 89, 102]),
+	wall(183, "Granite", "Granite Wall", [25, 23, 52]),
+	wall(184, "Cave8Unsafe", "Cave Wall", [52, 52, 52]),
+	wall(185, "Crystal", "Crystal Wall", [64, 64, 64]),
+	wall(186, "Sandstone", "Sandstone Wall", [106, 72, 41]),
+	wall(187, "CorruptionUnsafe1", "Corruption Wall", [61, 58, 78]),
+	wall(188, "CorruptionUnsafe2", "Corruption Wall", [61, 58, 78]),
+	wall(189, "CorruptionUnsafe3", "Corruption Wall", [61, 58, 78]),
+	wall(190, "CorruptionUnsafe4", "Corruption Wall", [61, 58, 78]),
+	wall(191, "CrimsonUnsafe1", "Crimson Wall", [80, 30, 30]),
+	wall(192, "CrimsonUnsafe2", "Crimson Wall", [80, 30, 30]),
+	wall(193, "CrimsonUnsafe3", "Crimson Wall", [80, 30, 30]),
+	wall(194, "CrimsonUnsafe4", "Crimson Wall", [80, 30, 30]),
+	wall(195, "DirtUnsafe1", "Dirt Wall", [88, 61, 46]),
+	wall(196, "DirtUnsafe2", "Dirt Wall", [88, 61, 46]),
+	wall(197, "DirtUnsafe3", "Dirt Wall", [88, 61, 46]),
+	wall(198, "DirtUnsafe4", "Dirt Wall", [88, 61, 46]),
+	wall(199, "HallowUnsafe1", "Hallow Wall", [70, 80, 110]),
+	wall(200, "HallowUnsafe2", "Hallow Wall", [70, 80, 110]),
+	wall(201, "HallowUnsafe3", "Hallow Wall", [70, 80, 110]),
+	wall(202, "HallowUnsafe4", "Hallow Wall", [70, 80, 110]),
+	wall(203, "JungleUnsafe1", "Jungle Wall", [48, 73, 24]),
+	wall(204, "JungleUnsafe2", "Jungle Wall", [48, 73, 24]),
+	wall(205, "JungleUnsafe3", "Jungle Wall", [48, 73, 24]),
+	wall(206, "JungleUnsafe4", "Jungle Wall", [48, 73, 24]),
+	wall(207, "LavaUnsafe1", "Lava Wall", [70, 30, 30]),
+	wall(208, "LavaUnsafe2", "Lava Wall", [70, 30, 30]),
+	wall(209, "LavaUnsafe3", "Lava Wall", [70, 30, 30]),
+	wall(210, "LavaUnsafe4", "Lava Wall", [70, 30, 30]),
+	wall(211, "RocksUnsafe1", "Rocks Wall", [52, 52, 52]),
+	wall(212, "RocksUnsafe2", "Rocks Wall", [52, 52, 52]),
+	wall(213, "RocksUnsafe3", "Rocks Wall", [52, 52, 52]),
+	wall(214, "RocksUnsafe4", "Rocks Wall", [52, 52, 52]),
+	wall(215, "HardenedSand", "Hardened Sand Wall", [106, 72, 41]),
+	wall(216, "CorruptHardenedSand", "Corrupt Hardened Sand Wall", [61, 58, 78]),
+	wall(217, "CrimsonHardenedSand", "Crimson Hardened Sand Wall", [80, 30, 30]),
+	wall(218, "HallowHardenedSand", "Hallow Hardened Sand Wall", [70, 80, 110]),
+	wall(219, "CorruptSandstone", "Corrupt Sandstone Wall", [61, 58, 78]),
+	wall(220, "CrimsonSandstone", "Crimson Sandstone Wall", [80, 30, 30]),
+	wall(221, "HallowSandstone", "Hallow Sandstone Wall", [70, 80, 110]),
+	wall(222, "DesertFossil", "Desert Fossil Wall", [64, 64, 64]),
+	wall(223, "LunarBrickWall", "Lunar Brick Wall", [72, 74, 72]),
+	wall(224, "CogWall", "Cog Wall", [64, 64, 64]),
+	wall(225, "SandFall", "Sand Fall Wall", [106, 72, 41]),
+	wall(226, "SnowFall", "Snow Fall Wall", [106, 118, 120]),
+	wall(227, "SillyBalloonPinkWall", "Silly Balloon Pink Wall", [64, 64, 64]),
+	wall(228, "SillyBalloonPurpleWall", "Silly Balloon Purple Wall", [64, 64, 64]),
+	wall(229, "SillyBalloonGreenWall", "Silly Balloon Green Wall", [64, 64, 64]),
+	wall(230, "IronBrick", "Iron Brick Wall", [72, 74, 72]),
+	wall(231, "LeadBrick", "Lead Brick Wall", [72, 74, 72]),
+	wall(232, "LesionBlock", "Lesion Block Wall", [64, 64, 64]),
+	wall(233, "CrimstoneBrick", "Crimstone Brick Wall", [80, 30, 30]),
+	wall(234, "SmoothSandstone", "Smooth Sandstone Wall", [106, 72, 41]),
+	wall(235, "Spider", "Spider Wall", [40, 40, 40]),
+	wall(236, "SolarBrick", "Solar Brick Wall", [72, 74, 72]),
+	wall(237, "VortexBrick", "Vortex Brick Wall", [72, 74, 72]),
+	wall(238, "NebulaBrick", "Nebula Brick Wall", [72, 74, 72]),
+	wall(239, "StardustBrick", "Stardust Brick Wall", [72, 74, 72]),
+	wall(240, "OrangeStainedGlass", "Orange Stained Glass Wall", [100, 123, 127]),
+	wall(241, "GoldStarryGlassWall", "Gold Starry Glass Wall", [100, 123, 127]),
+	wall(242, "BlueStarryGlassWall", "Blue Starry Glass Wall", [100, 123, 127]),
+	wall(243, "LivingWoodUnsafe", "Living Wood Wall", [73, 51, 36]),
+	wall(244, "WroughtIronFence", "Wrought Iron Fence", [73, 51, 36]),
+	wall(245, "EbonstoneEcho", "Ebonstone Wall (Echo)", [61, 58, 78]),
+	wall(246, "MudWallEcho", "Mud Wall (Echo)", [61, 46, 54]),
+	wall(247, "PearlstoneEcho", "Pearlstone Wall (Echo)", [96, 90, 100]),
+	wall(248, "SnowWallEcho", "Snow Wall (Echo)", [106, 118, 120]),
+	wall(249, "AmethystEcho", "Amethyst Wall (Echo)", [64, 64, 64]),
+	wall(250, "TopazEcho", "Topaz Wall (Echo)", [64, 64, 64]),
+	wall(251, "SapphireEcho", "Sapphire Wall (Echo)", [64, 64, 64]),
+	wall(252, "EmeraldEcho", "Emerald Wall (Echo)", [64, 64, 64]),
+	wall(253, "RubyEcho", "Ruby Wall (Echo)", [64, 64, 64]),
+	wall(254, "DiamondEcho", "Diamond Wall (Echo)", [64, 64, 64]),
+	wall(255, "Cave1Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(256, "Cave2Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(257, "Cave3Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(258, "Cave4Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(259, "Cave5Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(260, "Cave6Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(261, "Cave7Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(262, "SpiderEcho", "Spider Wall (Echo)", [40, 40, 40]),
+	wall(263, "CorruptGrassEcho", "Corrupt Grass Wall (Echo)", [30, 80, 48]),
+	wall(264, "HallowedGrassEcho", "Hallowed Grass Wall (Echo)", [30, 80, 48]),
+	wall(265, "IceEcho", "Ice Wall (Echo)", [72, 97, 116]),
+	wall(266, "ObsidianBackEcho", "Obsidian Back Wall (Echo)", [35, 33, 71]),
+	wall(267, "CrimsonGrassEcho", "Crimson Grass Wall (Echo)", [30, 80, 48]),
+	wall(268, "CrimstoneEcho", "Crimstone Wall (Echo)", [80, 30, 30]),
+	wall(269, "CaveWall1Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(270, "CaveWall2Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(271, "Cave8Echo", "Cave Wall (Echo)", [52, 52, 52]),
+	wall(272, "Corruption1Echo", "Corruption Wall (Echo)", [61, 58, 78]),
+	wall(273, "Corruption2Echo", "Corruption Wall (Echo)", [61, 58, 78]),
+	wall(274, "Corruption3Echo", "Corruption Wall (Echo)", [61, 58, 78]),
+	wall(275, "Corruption4Echo", "Corruption Wall (Echo)", [61, 58, 78]),
+	wall(276, "Crimson1Echo", "Crimson Wall (Echo)", [80, 30, 30]),
+	wall(277, "Crimson2Echo", "Crimson Wall (Echo)", [80, 30, 30]),
+	wall(278, "Crimson3Echo", "Crimson Wall (Echo)", [80, 30, 30]),
+	wall(279, "Crimson4Echo", "Crimson Wall (Echo)", [80, 30, 30]),
+	wall(280, "Dirt1Echo", "Dirt Wall (Echo)", [88, 61, 46]),
+	wall(281, "Dirt2Echo", "Dirt Wall (Echo)", [88, 61, 46]),
+	wall(282, "Dirt3Echo", "Dirt Wall (Echo)", [88, 61, 46]),
+	wall(283, "Dirt4Echo", "Dirt Wall (Echo)", [88, 61, 46]),
+	wall(284, "Hallow1Echo", "Hallow Wall (Echo)", [70, 80, 110]),
+	wall(285, "Hallow2Echo", "Hallow Wall (Echo)", [70, 80, 110]),
+	wall(286, "Hallow3Echo", "Hallow Wall (Echo)", [70, 80, 110]),
+	wall(287, "Hallow4Echo", "Hallow Wall (Echo)", [70, 80, 110]),
+	wall(288, "Jungle1Echo", "Jungle Wall (Echo)", [48, 73, 24]),
+	wall(289, "Jungle2Echo", "Jungle Wall (Echo)", [48, 73, 24]),
+	wall(290, "Jungle3Echo", "Jungle Wall (Echo)", [48, 73, 24]),
+	wall(291, "Jungle4Echo", "Jungle Wall (Echo)", [48, 73, 24]),
+	wall(292, "Lava1Echo", "Lava Wall (Echo)", [70, 30, 30]),
+	wall(293, "Lava2Echo", "Lava Wall (Echo)", [70, 30, 30]),
+	wall(294, "Lava3Echo", "Lava Wall (Echo)", [70, 30, 30]),
+	wall(295, "Lava4Echo", "Lava Wall (Echo)", [70, 30, 30]),
+	wall(296, "Rocks1Echo", "Rocks Wall (Echo)", [52, 52, 52]),
+	wall(297, "Rocks2Echo", "Rocks Wall (Echo)", [52, 52, 52]),
+	wall(298, "Rocks3Echo", "Rocks Wall (Echo)", [52, 52, 52]),
+	wall(299, "Rocks4Echo", "Rocks Wall (Echo)", [52, 52, 52]),
+	wall(300, "HardenedSandEcho", "Hardened Sand Wall (Echo)", [106, 72, 41]),
+	wall(301, "CorruptHardenedSandEcho", "Corrupt Hardened Sand Wall (Echo)", [61, 58, 78]),
+	wall(302, "CrimsonHardenedSandEcho", "Crimson Hardened Sand Wall (Echo)", [80, 30, 30]),
+	wall(303, "HallowHardenedSandEcho", "Hallow Hardened Sand Wall (Echo)", [70, 80, 110]),
+	wall(304, "CorruptSandstoneEcho", "Corrupt Sandstone Wall (Echo)", [61, 58, 78]),
+	wall(305, "CrimsonSandstoneEcho", "Crimson Sandstone Wall (Echo)", [80, 30, 30]),
+	wall(306, "HallowSandstoneEcho", "Hallow Sandstone Wall (Echo)", [70, 80, 110]),
+	wall(307, "DesertFossilEcho", "Desert Fossil Wall (Echo)", [64, 64, 64]),
+	wall(308, "BambooBlockWall", "Bamboo Block Wall", [64, 64, 64]),
+	wall(309, "LargeBambooBlockWall", "Large Bamboo Block Wall", [64, 64, 64]),
+	wall(310, "AmberStoneWallEcho", "Amber Stone Wall (Echo)", [52, 52, 52]),
+	wall(311, "BambooFence", "Bamboo Fence", [73, 51, 36]),
+	wall(312, "AshWood", "Ash Wood Wall", [73, 51, 36]),
+	wall(313, "VioletMossBrickWall", "Violet Moss Brick Wall", [120, 40, 160]),
+	wall(314, "RainbowMossBrickWall", "Rainbow Moss Brick Wall", [110, 70, 120]),
+	wall(315, "ArgonMossBrickWall", "Argon Moss Brick Wall", [150, 40, 110]),
+	wall(316, "KryptonMossBrickWall", "Krypton Moss Brick Wall", [90, 130, 20]),
+	wall(317, "XenonMossBrickWall", "Xenon Moss Brick Wall", [20, 110, 140]),
+	wall(318, "LavaMossBrickWall", "Lava Moss Brick Wall", [150, 60, 20]),
+	wall(319, "ReefWall", "Reef Wall", [90, 70, 80]),
+	wall(320, "ShimmerBlockWall", "Aetherium Wall", [90, 70, 130]),
+	wall(321, "PoopWall", "Poo Wall", [72, 48, 30]),
+	wall(322, "ShimmerBrickWall", "Aetherium Brick Wall", [100, 80, 140]),
+	wall(323, "LunarRustBrickWall", "Lunar Rust Brick Wall", [80, 60, 60]),
+	wall(324, "DarkCelestialBrickWall", "Dark Celestial Brick Wall", [50, 40, 80]),
+	wall(325, "AstraBrickWall", "Astra Brick Wall", [40, 60, 90]),
+	wall(326, "CosmicEmberBrickWall", "Cosmic Ember Brick Wall", [90, 40, 40]),
+	wall(327, "CryocoreBrickWall", "Cryocore Brick Wall", [40, 80, 100]),
+	wall(328, "MercuryBrickWall", "Mercury Brick Wall", [80, 80, 90]),
+	wall(329, "StarRoyaleBrickWall", "Star Royale Brick Wall", [90, 70, 30]),
+	wall(330, "HeavenforgeBrickWall", "Heavenforge Brick Wall", [80, 80, 60]),
+	wall(331, "AncientBlueBrickWall", "Ancient Blue Brick Wall", [27, 31, 42]),
+	wall(332, "AncientGreenBrickWall", "Ancient Green Brick Wall", [31, 39, 26]),
+	wall(333, "AncientPinkBrickWall", "Ancient Pink Brick Wall", [41, 28, 36]),
+	wall(334, "AncientGoldBrickWall", "Ancient Gold Brick Wall", [74, 62, 12]),
+	wall(335, "AncientSilverBrickWall", "Ancient Silver Brick Wall", [46, 56, 59]),
+	wall(336, "AncientCopperBrickWall", "Ancient Copper Brick Wall", [75, 33, 0]),
+	wall(337, "AncientCobaltBrickWall", "Ancient Cobalt Brick Wall", [10, 49, 71]),
+	wall(338, "AncientMythrilBrickWall", "Ancient Mythril Brick Wall", [40, 62, 44]),
+	wall(339, "AncientObsidianBrickWall", "Ancient Obsidian Brick Wall", [15, 15, 20]),
+	wall(340, "AncientHellstoneBrickWall", "Ancient Hellstone Brick Wall", [73, 15, 10]),
+	wall(341, "LavaMossBlockWall", "Lava Moss Block Wall", [120, 50, 20]),
+	wall(342, "ArgonMossBlockWall", "Argon Moss Block Wall", [120, 30, 90]),
+	wall(343, "KryptonMossBlockWall", "Krypton Moss Block Wall", [70, 100, 15]),
+	wall(344, "XenonMossBlockWall", "Xenon Moss Block Wall", [15, 90, 110]),
+	wall(345, "VioletMossBlockWall", "Neon Moss Block Wall", [100, 30, 130]),
+	wall(346, "RainbowMossBlockWall", "Rainbow Moss Block Wall", [90, 60, 100])
+];
\ No newline at end of file
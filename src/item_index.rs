@@ -0,0 +1,122 @@
+use hashbrown::{HashMap, HashSet};
+
+use crate::{structs::Item, wld::Wld};
+
+// What holds an item, by index into `Wld::chests` or `Wld::tile_entities`
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Container {
+	Chest(usize),
+	TileEntity(usize)
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct ItemLocation {
+	pub container: Container,
+	pub slot: usize,
+	pub x: u32,
+	pub y: u32,
+	pub item: Item
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct ItemTotal {
+	pub id: u32,
+	// Items summed over all stacks
+	pub count: u64,
+	pub stacks: usize,
+	// Every container holding the item, once each
+	pub positions: Vec<(u32, u32)>
+}
+
+// A snapshot of every item in chests, item frames, weapon racks, mannequins, hat racks and food platters.
+// Changing the world afterwards doesn't update it
+#[derive(Debug, Clone, Default)]
+pub struct ItemIndex {
+	locations: Vec<ItemLocation>,
+	chest_names: Vec<String>,
+	by_item: HashMap<u32, Vec<usize>>,
+	by_prefix: HashMap<u8, Vec<usize>>
+}
+
+impl ItemIndex {
+	pub fn new(wld: &Wld) -> Self {
+		let mut locations = vec![];
+		for (i, chest) in wld.chests.iter().enumerate() {
+			for (slot, item) in chest.items.iter().enumerate() {
+				if let Some(item) = *item {
+					locations.push(ItemLocation { container: Container::Chest(i), slot, x: chest.x, y: chest.y, item });
+				}
+			}
+		}
+		for (i, entity) in wld.tile_entities.iter().enumerate() {
+			for (slot, item) in entity.info.slots().iter().enumerate() {
+				if let Some(item) = *item {
+					locations.push(ItemLocation { container: Container::TileEntity(i), slot, x: entity.x as u32, y: entity.y as u32, item });
+				}
+			}
+		}
+		
+		let mut by_item: HashMap<u32, Vec<usize>> = HashMap::new();
+		let mut by_prefix: HashMap<u8, Vec<usize>> = HashMap::new();
+		for (i, location) in locations.iter().enumerate() {
+			by_item.entry(location.item.id).or_default().push(i);
+			by_prefix.entry(location.item.prefix).or_default().push(i);
+		}
+		let chest_names = wld.chests.iter().map(|chest| chest.name.to_lowercase()).collect();
+		ItemIndex { locations, chest_names, by_item, by_prefix }
+	}
+	
+	pub fn locations(&self) -> &[ItemLocation] {
+		&self.locations
+	}
+	
+	fn get(&self, indices: Option<&Vec<usize>>) -> Vec<&ItemLocation> {
+		indices.map(|indices| indices.iter().map(|&i| &self.locations[i]).collect()).unwrap_or_default()
+	}
+	
+	pub fn with_item(&self, id: u32) -> Vec<&ItemLocation> {
+		self.get(self.by_item.get(&id))
+	}
+	
+	pub fn with_prefix(&self, prefix: u8) -> Vec<&ItemLocation> {
+		self.get(self.by_prefix.get(&prefix))
+	}
+	
+	// Items in chests whose name contains `name`, ignoring case
+	pub fn in_chests_named(&self, name: &str) -> Vec<&ItemLocation> {
+		let name = name.to_lowercase();
+		self.locations.iter().filter(|location| match location.container {
+			Container::Chest(i) => self.chest_names[i].contains(&name),
+			Container::TileEntity(_) => false
+		}).collect()
+	}
+	
+	// Every container holding the item, once each and in index order
+	pub fn containers_with(&self, id: u32) -> Vec<Container> {
+		let mut seen = HashSet::new();
+		self.with_item(id).into_iter().map(|location| location.container).filter(|&container| seen.insert(container)).collect()
+	}
+	
+	pub fn total(&self, id: u32) -> Option<ItemTotal> {
+		let locations = self.with_item(id);
+		if locations.is_empty() {
+			return None;
+		}
+		let mut seen = HashSet::new();
+		let positions = locations.iter().map(|location| (location.x, location.y)).filter(|&position| seen.insert(position)).collect();
+		Some(ItemTotal { id, count: locations.iter().map(|l| l.item.count as u64).sum(), stacks: locations.len(), positions })
+	}
+	
+	// One entry per item id found, sorted by id
+	pub fn totals(&self) -> Vec<ItemTotal> {
+		let mut ids: Vec<u32> = self.by_item.keys().copied().collect();
+		ids.sort_unstable();
+		ids.into_iter().filter_map(|id| self.total(id)).collect()
+	}
+}
+
+impl Wld {
+	pub fn item_index(&self) -> ItemIndex {
+		ItemIndex::new(self)
+	}
+}
@@ -0,0 +1,4263 @@
+use std::{borrow::Cow, fmt, iter::FromIterator, sync::OnceLock};
+
+use hashbrown::HashMap;
+
+use crate::{ids::only, structs::{Item, TileEntityInfo}};
+
+// Which prefixes an item can roll, weapons that don't fit a class (spears, flails, drills...) only take the shared ones
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum PrefixCategory {
+	None,
+	Weapon,
+	Melee,
+	Ranged,
+	Magic,
+	Accessory
+}
+
+impl PrefixCategory {
+	// Whether an item of this category can hold a prefix of `prefix`'s category
+	pub fn accepts(self, prefix: PrefixCategory) -> bool {
+		match (self, prefix) {
+			(PrefixCategory::None, _) | (_, PrefixCategory::None) => false,
+			(PrefixCategory::Accessory, p) => p == PrefixCategory::Accessory,
+			(_, PrefixCategory::Weapon) => true,
+			(item, p) => item == p
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ItemInfo {
+	pub id: u32,
+	pub name: Cow<'static, str>,
+	pub display_name: Cow<'static, str>,
+	pub max_stack: u16,
+	pub prefixes: PrefixCategory
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PrefixInfo {
+	pub id: u8,
+	pub name: &'static str,
+	pub category: PrefixCategory
+}
+
+// `builtin` holds every item in ITEMS, callers can start from an empty one and fill it from their own copy of the game's item list
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ItemCatalogue {
+	items: HashMap<u32, ItemInfo>
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ItemProblem {
+	UnknownItem(u32),
+	UnknownPrefix(u8),
+	EmptyStack,
+	ExceedsMaxStack { count: u16, max: u16 },
+	InvalidPrefix { prefix: u8, item: u32 }
+}
+
+impl fmt::Display for ItemProblem {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			ItemProblem::UnknownItem(id) => write!(f, "unknown item id {}", id),
+			ItemProblem::UnknownPrefix(id) => write!(f, "unknown prefix id {}", id),
+			ItemProblem::EmptyStack => write!(f, "stack is empty"),
+			ItemProblem::ExceedsMaxStack { count, max } => write!(f, "count {} exceeds max stack of {}", count, max),
+			ItemProblem::InvalidPrefix { prefix, item } => write!(f, "prefix {} is invalid for item #{}", prefix_name(prefix), item)
+		}
+	}
+}
+
+impl ItemInfo {
+	pub fn new(id: u32, name: &str, display_name: &str, max_stack: u16, prefixes: PrefixCategory) -> Self {
+		ItemInfo { id, name: Cow::Owned(name.to_string()), display_name: Cow::Owned(display_name.to_string()), max_stack, prefixes }
+	}
+}
+
+impl ItemCatalogue {
+	pub fn new() -> Self {
+		ItemCatalogue::default()
+	}
+	pub fn builtin() -> &'static ItemCatalogue {
+		static BUILTIN: OnceLock<ItemCatalogue> = OnceLock::new();
+		BUILTIN.get_or_init(|| ITEMS.iter().cloned().collect())
+	}
+	
+	// Id 0 is the empty slot and never gets an entry
+	pub fn insert(&mut self, info: ItemInfo) {
+		if info.id != 0 {
+			self.items.insert(info.id, info);
+		}
+	}
+	pub fn get(&self, id: u32) -> Option<&ItemInfo> {
+		self.items.get(&id)
+	}
+	// Accepts the internal name ("NightsEdge") or the display name ("Night's Edge"), the latter ignoring case.
+	// A display name shared by several items matches none of them
+	pub fn by_name(&self, name: &str) -> Option<&ItemInfo> {
+		only(self.items.values().filter(|i| i.name == name))
+			.or_else(|| only(self.items.values().filter(|i| i.display_name.eq_ignore_ascii_case(name))))
+	}
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+	
+	// Same as the item's Display, with the names coming from this catalogue instead of ITEMS
+	pub fn describe(&self, item: &Item) -> String {
+		let name = match self.get(item.id) {
+			Some(info) => info.display_name.to_string(),
+			None => format!("item #{}", item.id)
+		};
+		let mut text = match item.prefix {
+			0 => name,
+			prefix => format!("{} {}", prefix_name(prefix), name)
+		};
+		if item.count != 1 {
+			text += &format!(" ({})", item.count);
+		}
+		text
+	}
+}
+
+impl FromIterator<ItemInfo> for ItemCatalogue {
+	fn from_iter<I: IntoIterator<Item = ItemInfo>>(iter: I) -> Self {
+		let mut catalogue = ItemCatalogue::new();
+		for info in iter {
+			catalogue.insert(info);
+		}
+		catalogue
+	}
+}
+
+// Items added after ITEMS was last extended have no entry
+pub fn item_info(id: u32) -> Option<&'static ItemInfo> {
+	ITEMS.get(id as usize).filter(|_| id != 0)
+}
+
+pub fn prefix_info(id: u8) -> Option<&'static PrefixInfo> {
+	PREFIXES.get(id as usize).filter(|_| id != 0)
+}
+// Some names exist twice (Deadly, Quick, Hasty, Legendary), this returns the first one
+pub fn prefix_by_name(name: &str) -> Option<&'static PrefixInfo> {
+	PREFIXES[1..].iter().find(|p| p.name.eq_ignore_ascii_case(name))
+}
+
+fn prefix_name(id: u8) -> String {
+	match prefix_info(id) {
+		Some(info) => info.name.to_string(),
+		None => format!("prefix #{}", id)
+	}
+}
+
+impl Item {
+	pub fn prefix_info(&self) -> Option<&'static PrefixInfo> {
+		prefix_info(self.prefix)
+	}
+	
+	// Items missing from the catalogue only get their count and prefix id checked
+	pub fn problems(&self, catalogue: &ItemCatalogue) -> Vec<ItemProblem> {
+		let mut problems = vec![];
+		let info = catalogue.get(self.id);
+		let prefix = self.prefix_info();
+		
+		if info.is_none() {
+			problems.push(ItemProblem::UnknownItem(self.id));
+		}
+		if self.prefix != 0 && prefix.is_none() {
+			problems.push(ItemProblem::UnknownPrefix(self.prefix));
+		}
+		if self.count == 0 {
+			problems.push(ItemProblem::EmptyStack);
+		}
+		if let Some(info) = info {
+			if self.count > info.max_stack {
+				problems.push(ItemProblem::ExceedsMaxStack { count: self.count, max: info.max_stack });
+			}
+			if let Some(prefix) = prefix {
+				if !info.prefixes.accepts(prefix.category) {
+					problems.push(ItemProblem::InvalidPrefix { prefix: self.prefix, item: self.id });
+				}
+			}
+		}
+		problems
+	}
+	pub fn is_valid(&self, catalogue: &ItemCatalogue) -> bool {
+		self.problems(catalogue).is_empty()
+	}
+}
+
+impl fmt::Display for Item {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.prefix != 0 {
+			write!(f, "{} ", prefix_name(self.prefix))?;
+		}
+		match item_info(self.id) {
+			Some(info) => write!(f, "{}", info.display_name)?,
+			None => write!(f, "item #{}", self.id)?
+		}
+		if self.count != 1 {
+			write!(f, " ({})", self.count)?;
+		}
+		Ok(())
+	}
+}
+
+impl TileEntityInfo {
+	// Every item held by the entity, in slot order
+	pub fn items(&self) -> Vec<Item> {
+		self.slots().iter().flatten().copied().collect()
+	}
+	
+	// Slots in the order the file stores them, empty ones included so slot numbers stay meaningful
+	pub fn slots(&self) -> &[Option<Item>] {
+		match self {
+			TileEntityInfo::ItemFrame(item) | TileEntityInfo::WeaponRack(item) | TileEntityInfo::FoodPlatter(item) => std::slice::from_ref(item),
+			TileEntityInfo::Mannequin(slots) => slots,
+			TileEntityInfo::HatRack(slots) => slots,
+			TileEntityInfo::TargetDummy(_) | TileEntityInfo::LogicSensor(..) | TileEntityInfo::Pylon => &[]
+		}
+	}
+	pub fn slots_mut(&mut self) -> &mut [Option<Item>] {
+		match self {
+			TileEntityInfo::ItemFrame(item) | TileEntityInfo::WeaponRack(item) | TileEntityInfo::FoodPlatter(item) => std::slice::from_mut(item),
+			TileEntityInfo::Mannequin(slots) => slots,
+			TileEntityInfo::HatRack(slots) => slots,
+			TileEntityInfo::TargetDummy(_) | TileEntityInfo::LogicSensor(..) | TileEntityInfo::Pylon => &mut []
+		}
+	}
+}
+
+const fn item(id: u32, name: &'static str, display_name: &'static str, max_stack: u16, prefixes: PrefixCategory) -> ItemInfo {
+	ItemInfo { id, name: Cow::Borrowed(name), display_name: Cow::Borrowed(display_name), max_stack, prefixes }
+}
+
+const fn prefix(id: u8, name: &'static str, category: PrefixCategory) -> PrefixInfo {
+	PrefixInfo { id, name, category }
+}
+
+pub static PREFIXES: &[PrefixInfo] = &[
+	prefix(0, "None", PrefixCategory::None),
+	prefix(1, "Large", PrefixCategory::Melee),
+	prefix(2, "Massive", PrefixCategory::Melee),
+	prefix(3, "Dangerous", PrefixCategory::Melee),
+	prefix(4, "Savage", PrefixCategory::Melee),
+	prefix(5, "Sharp", PrefixCategory::Melee),
+	prefix(6, "Pointy", PrefixCategory::Melee),
+	prefix(7, "Tiny", PrefixCategory::Melee),
+	prefix(8, "Terrible", PrefixCategory::Melee),
+	prefix(9, "Small", PrefixCategory::Melee),
+	prefix(10, "Dull", PrefixCategory::Melee),
+	prefix(11, "Unhappy", PrefixCategory::Melee),
+	prefix(12, "Bulky", PrefixCategory::Melee),
+	prefix(13, "Shameful", PrefixCategory::Melee),
+	prefix(14, "Heavy", PrefixCategory::Melee),
+	prefix(15, "Light", PrefixCategory::Melee),
+	prefix(16, "Sighted", PrefixCategory::Ranged),
+	prefix(17, "Rapid", PrefixCategory::Ranged),
+	prefix(18, "Hasty", PrefixCategory::Ranged),
+	prefix(19, "Intimidating", PrefixCategory::Ranged),
+	prefix(20, "Deadly", PrefixCategory::Ranged),
+	prefix(21, "Staunch", PrefixCategory::Ranged),
+	prefix(22, "Awful", PrefixCategory::Ranged),
+	prefix(23, "Lethargic", PrefixCategory::Ranged),
+	prefix(24, "Awkward", PrefixCategory::Ranged),
+	prefix(25, "Powerful", PrefixCategory::Ranged),
+	prefix(26, "Mystic", PrefixCategory::Magic),
+	prefix(27, "Adept", PrefixCategory::Magic),
+	prefix(28, "Masterful", PrefixCategory::Magic),
+	prefix(29, "Inept", PrefixCategory::Magic),
+	prefix(30, "Ignorant", PrefixCategory::Magic),
+	prefix(31, "Deranged", PrefixCategory::Magic),
+	prefix(32, "Intense", PrefixCategory::Magic),
+	prefix(33, "Taboo", PrefixCategory::Magic),
+	prefix(34, "Celestial", PrefixCategory::Magic),
+	prefix(35, "Furious", PrefixCategory::Magic),
+	prefix(36, "Keen", PrefixCategory::Weapon),
+	prefix(37, "Superior", PrefixCategory::Weapon),
+	prefix(38, "Forceful", PrefixCategory::Weapon),
+	prefix(39, "Broken", PrefixCategory::Weapon),
+	prefix(40, "Damaged", PrefixCategory::Weapon),
+	prefix(41, "Shoddy", PrefixCategory::Weapon),
+	prefix(42, "Quick", PrefixCategory::Weapon),
+	prefix(43, "Deadly", PrefixCategory::Weapon),
+	prefix(44, "Agile", PrefixCategory::Weapon),
+	prefix(45, "Nimble", PrefixCategory::Weapon),
+	prefix(46, "Murderous", PrefixCategory::Weapon),
+	prefix(47, "Slow", PrefixCategory::Weapon),
+	prefix(48, "Sluggish", PrefixCategory::Weapon),
+	prefix(49, "Lazy", PrefixCategory::Weapon),
+	prefix(50, "Annoying", PrefixCategory::Weapon),
+	prefix(51, "Nasty", PrefixCategory::Weapon),
+	prefix(52, "Manic", PrefixCategory::Magic),
+	prefix(53, "Hurtful", PrefixCategory::Weapon),
+	prefix(54, "Strong", PrefixCategory::Weapon),
+	prefix(55, "Unpleasant", PrefixCategory::Weapon),
+	prefix(56, "Weak", PrefixCategory::Weapon),
+	prefix(57, "Ruthless", PrefixCategory::Weapon),
+	prefix(58, "Frenzying", PrefixCategory::Ranged),
+	prefix(59, "Godly", PrefixCategory::Weapon),
+	prefix(60, "Demonic", PrefixCategory::Weapon),
+	prefix(61, "Zealous", PrefixCategory::Weapon),
+	prefix(62, "Hard", PrefixCategory::Accessory),
+	prefix(63, "Guarding", PrefixCategory::Accessory),
+	prefix(64, "Armored", PrefixCategory::Accessory),
+	prefix(65, "Warding", PrefixCategory::Accessory),
+	prefix(66, "Arcane", PrefixCategory::Accessory),
+	prefix(67, "Precise", PrefixCategory::Accessory),
+	prefix(68, "Lucky", PrefixCategory::Accessory),
+	prefix(69, "Jagged", PrefixCategory::Accessory),
+	prefix(70, "Spiked", PrefixCategory::Accessory),
+	prefix(71, "Angry", PrefixCategory::Accessory),
+	prefix(72, "Menacing", PrefixCategory::Accessory),
+	prefix(73, "Brisk", PrefixCategory::Accessory),
+	prefix(74, "Fleeting", PrefixCategory::Accessory),
+	prefix(75, "Hasty", PrefixCategory::Accessory),
+	prefix(76, "Quick", PrefixCategory::Accessory),
+	prefix(77, "Wild", PrefixCategory::Accessory),
+	prefix(78, "Rash", PrefixCategory::Accessory),
+	prefix(79, "Intrepid", PrefixCategory::Accessory),
+	prefix(80, "Violent", PrefixCategory::Accessory),
+	prefix(81, "Legendary", PrefixCategory::Melee),
+	prefix(82, "Unreal", PrefixCategory::Ranged),
+	prefix(83, "Mythical", PrefixCategory::Magic),
+	prefix(84, "Legendary", PrefixCategory::Melee)
+];
+
+pub static ITEMS: &[ItemInfo] = &[
+	item(0, "None", "", 0, PrefixCategory::None),
+	item(1, "IronPickaxe", "Iron Pickaxe", 1, PrefixCategory::Melee),
+	item(2, "DirtBlock", "Dirt Block", 9999, PrefixCategory::None),
+	item(3, "StoneBlock", "Stone Block", 9999, PrefixCategory::None),
+	item(4, "IronBroadsword", "Iron Broadsword", 1, PrefixCategory::Melee),
+	item(5, "Mushroom", "Mushroom", 9999, PrefixCategory::None),
+	item(6, "IronShortsword", "Iron Shortsword", 1, PrefixCategory::Melee),
+	item(7, "IronHammer", "Iron Hammer", 1, PrefixCategory::Melee),
+	item(8, "Torch", "Torch", 9999, PrefixCategory::None),
+	item(9, "Wood", "Wood", 9999, PrefixCategory::None),
+	item(10, "IronAxe", "Iron Axe", 1, PrefixCategory::Melee),
+	item(11, "IronOre", "Iron Ore", 9999, PrefixCategory::None),
+	item(12, "CopperOre", "Copper Ore", 9999, PrefixCategory::None),
+	item(13, "GoldOre", "Gold Ore", 9999, PrefixCategory::None),
+	item(14, "SilverOre", "Silver Ore", 9999, PrefixCategory::None),
+	item(15, "CopperWatch", "Copper Watch", 1, PrefixCategory::Accessory),
+	item(16, "SilverWatch", "Silver Watch", 1, PrefixCategory::Accessory),
+	item(17, "GoldWatch", "Gold Watch", 1, PrefixCategory::Accessory),
+	item(18, "DepthMeter", "Depth Meter", 1, PrefixCategory::Accessory),
+	item(19, "GoldBar", "Gold Bar", 9999, PrefixCategory::None),
+	item(20, "CopperBar", "Copper Bar", 9999, PrefixCategory::None),
+	item(21, "SilverBar", "Silver Bar", 9999, PrefixCategory::None),
+	item(22, "IronBar", "Iron Bar", 9999, PrefixCategory::None),
+	item(23, "Gel", "Gel", 9999, PrefixCategory::None),
+	item(24, "WoodenSword", "Wooden Sword", 1, PrefixCategory::Melee),
+	item(25, "WoodenDoor", "Wooden Door", 9999, PrefixCategory::None),
+	item(26, "StoneWall", "Stone Wall", 9999, PrefixCategory::None),
+	item(27, "Acorn", "Acorn", 9999, PrefixCategory::None),
+	item(28, "LesserHealingPotion", "Lesser Healing Potion", 9999, PrefixCategory::None),
+	item(29, "LifeCrystal", "Life Crystal", 9999, PrefixCategory::None),
+	item(30, "DirtWall", "Dirt Wall", 9999, PrefixCategory::None),
+	item(31, "Bottle", "Bottle", 9999, PrefixCategory::None),
+	item(32, "WoodenTable", "Wooden Table", 9999, PrefixCategory::None),
+	item(33, "Furnace", "Furnace", 9999, PrefixCategory::None),
+	item(34, "WoodenChair", "Wooden Chair", 9999, PrefixCategory::None),
+	item(35, "IronAnvil", "Iron Anvil", 9999, PrefixCategory::None),
+	item(36, "WorkBench", "Work Bench", 9999, PrefixCategory::None),
+	item(37, "Goggles", "Goggles", 1, PrefixCategory::None),
+	item(38, "Lens", "Lens", 9999, PrefixCategory::None),
+	item(39, "WoodenBow", "Wooden Bow", 1, PrefixCategory::Ranged),
+	item(40, "WoodenArrow", "Wooden Arrow", 9999, PrefixCategory::None),
+	item(41, "FlamingArrow", "Flaming Arrow", 9999, PrefixCategory::None),
+	item(42, "Shuriken", "Shuriken", 9999, PrefixCategory::None),
+	item(43, "SuspiciousLookingEye", "Suspicious Looking Eye", 9999, PrefixCategory::None),
+	item(44, "DemonBow", "Demon Bow", 1, PrefixCategory::Ranged),
+	item(45, "WarAxeoftheNight", "War Axe of the Night", 1, PrefixCategory::Melee),
+	item(46, "LightsBane", "Light's Bane", 1, PrefixCategory::Melee),
+	item(47, "UnholyArrow", "Unholy Arrow", 9999, PrefixCategory::None),
+	item(48, "Chest", "Chest", 9999, PrefixCategory::None),
+	item(49, "BandofRegeneration", "Band of Regeneration", 1, PrefixCategory::Accessory),
+	item(50, "MagicMirror", "Magic Mirror", 1, PrefixCategory::None),
+	item(51, "JestersArrow", "Jester's Arrow", 9999, PrefixCategory::None),
+	item(52, "AngelStatue", "Angel Statue", 9999, PrefixCategory::None),
+	item(53, "CloudinaBottle", "Cloud in a Bottle", 1, PrefixCategory::Accessory),
+	item(54, "HermesBoots", "Hermes Boots", 1, PrefixCategory::Accessory),
+	item(55, "EnchantedBoomerang", "Enchanted Boomerang", 1, PrefixCategory::Weapon),
+	item(56, "DemoniteOre", "Demonite Ore", 9999, PrefixCategory::None),
+	item(57, "DemoniteBar", "Demonite Bar", 9999, PrefixCategory::None),
+	item(58, "Heart", "Heart", 9999, PrefixCategory::None),
+	item(59, "CorruptSeeds", "Corrupt Seeds", 9999, PrefixCategory::None),
+	item(60, "VileMushroom", "Vile Mushroom", 9999, PrefixCategory::None),
+	item(61, "EbonstoneBlock", "Ebonstone Block", 9999, PrefixCategory::None),
+	item(62, "GrassSeeds", "Grass Seeds", 9999, PrefixCategory::None),
+	item(63, "Sunflower", "Sunflower", 9999, PrefixCategory::None),
+	item(64, "Vilethorn", "Vilethorn", 1, PrefixCategory::Magic),
+	item(65, "Starfury", "Starfury", 1, PrefixCategory::Melee),
+	item(66, "PurificationPowder", "Purification Powder", 9999, PrefixCategory::None),
+	item(67, "VilePowder", "Vile Powder", 9999, PrefixCategory::None),
+	item(68, "RottenChunk", "Rotten Chunk", 9999, PrefixCategory::None),
+	item(69, "WormTooth", "Worm Tooth", 9999, PrefixCategory::None),
+	item(70, "WormFood", "Worm Food", 9999, PrefixCategory::None),
+	item(71, "CopperCoin", "Copper Coin", 100, PrefixCategory::None),
+	item(72, "SilverCoin", "Silver Coin", 100, PrefixCategory::None),
+	item(73, "GoldCoin", "Gold Coin", 100, PrefixCategory::None),
+	item(74, "PlatinumCoin", "Platinum Coin", 9999, PrefixCategory::None),
+	item(75, "FallenStar", "Fallen Star", 9999, PrefixCategory::None),
+	item(76, "CopperGreaves", "Copper Greaves", 1, PrefixCategory::None),
+	item(77, "IronGreaves", "Iron Greaves", 1, PrefixCategory::None),
+	item(78, "SilverGreaves", "Silver Greaves", 1, PrefixCategory::None),
+	item(79, "GoldGreaves", "Gold Greaves", 1, PrefixCategory::None),
+	item(80, "CopperChainmail", "Copper Chainmail", 1, PrefixCategory::None),
+	item(81, "IronChainmail", "Iron Chainmail", 1, PrefixCategory::None),
+	item(82, "SilverChainmail", "Silver Chainmail", 1, PrefixCategory::None),
+	item(83, "GoldChainmail", "Gold Chainmail", 1, PrefixCategory::None),
+	item(84, "GrapplingHook", "Grappling Hook", 1, PrefixCategory::None),
+	item(85, "Chain", "Chain", 9999, PrefixCategory::None),
+	item(86, "ShadowScale", "Shadow Scale", 9999, PrefixCategory::None),
+	item(87, "PiggyBank", "Piggy Bank", 9999, PrefixCategory::None),
+	item(88, "MiningHelmet", "Mining Helmet", 1, PrefixCategory::None),
+	item(89, "CopperHelmet", "Copper Helmet", 1, PrefixCategory::None),
+	item(90, "IronHelmet", "Iron Helmet", 1, PrefixCategory::None),
+	item(91, "SilverHelmet", "Silver Helmet", 1, PrefixCategory::None),
+	item(92, "GoldHelmet", "Gold Helmet", 1, PrefixCategory::None),
+	item(93, "WoodWall", "Wood Wall", 9999, PrefixCategory::None),
+	item(94, "WoodPlatform", "Wood Platform", 9999, PrefixCategory::None),
+	item(95, "FlintlockPistol", "Flintlock Pistol", 1, PrefixCategory::Ranged),
+	item(96, "Musket", "Musket", 1, PrefixCategory::Ranged),
+	item(97, "MusketBall", "Musket Ball", 9999, PrefixCategory::None),
+	item(98, "Minishark", "Minishark", 1, PrefixCategory::Ranged),
+	item(99, "IronBow", "Iron Bow", 1, PrefixCategory::Ranged),
+	item(100, "ShadowGreaves", "Shadow Greaves", 1, PrefixCategory::None),
+	item(101, "ShadowScalemail", "Shadow Scalemail", 1, PrefixCategory::None),
+	item(102, "ShadowHelmet", "Shadow Helmet", 1, PrefixCategory::None),
+	item(103, "NightmarePickaxe", "Nightmare Pickaxe", 1, PrefixCategory::Melee),
+	item(104, "TheBreaker", "The Breaker", 1, PrefixCategory::Melee),
+	item(105, "Candle", "Candle", 9999, PrefixCategory::None),
+	item(106, "CopperChandelier", "Copper Chandelier", 9999, PrefixCategory::None),
+	item(107, "SilverChandelier", "Silver Chandelier", 9999, PrefixCategory::None),
+	item(108, "GoldChandelier", "Gold Chandelier", 9999, PrefixCategory::None),
+	item(109, "ManaCrystal", "Mana Crystal", 9999, PrefixCategory::None),
+	item(110, "LesserManaPotion", "Lesser Mana Potion", 9999, PrefixCategory::None),
+	item(111, "BandofStarpower", "Band of Starpower", 1, PrefixCategory::Accessory),
+	item(112, "FlowerofFire", "Flower of Fire", 1, PrefixCategory::Magic),
+	item(113, "MagicMissile", "Magic Missile", 1, PrefixCategory::Magic),
+	item(114, "DirtRod", "Dirt Rod", 1, PrefixCategory::None),
+	item(115, "ShadowOrb", "Shadow Orb", 1, PrefixCategory::None),
+	item(116, "Meteorite", "Meteorite", 9999, PrefixCategory::None),
+	item(117, "MeteoriteBar", "Meteorite Bar", 9999, PrefixCategory::None),
+	item(118, "Hook", "Hook", 9999, PrefixCategory::None),
+	item(119, "Flamarang", "Flamarang", 1, PrefixCategory::Weapon),
+	item(120, "MoltenFury", "Molten Fury", 1, PrefixCategory::Ranged),
+	item(121, "FieryGreatsword", "Fiery Greatsword", 1, PrefixCategory::Melee),
+	item(122, "MoltenPickaxe", "Molten Pickaxe", 1, PrefixCategory::Melee),
+	item(123, "MeteorHelmet", "Meteor Helmet", 1, PrefixCategory::None),
+	item(124, "MeteorSuit", "Meteor Suit", 1, PrefixCategory::None),
+	item(125, "MeteorLeggings", "Meteor Leggings", 1, PrefixCategory::None),
+	item(126, "BottledWater", "Bottled Water", 9999, PrefixCategory::None),
+	item(127, "SpaceGun", "Space Gun", 1, PrefixCategory::Magic),
+	item(128, "RocketBoots", "Rocket Boots", 1, PrefixCategory::Accessory),
+	item(129, "GrayBrick", "Gray Brick", 9999, PrefixCategory::None),
+	item(130, "GrayBrickWall", "Gray Brick Wall", 9999, PrefixCategory::None),
+	item(131, "RedBrick", "Red Brick", 9999, PrefixCategory::None),
+	item(132, "RedBrickWall", "Red Brick Wall", 9999, PrefixCategory::None),
+	item(133, "ClayBlock", "Clay Block", 9999, PrefixCategory::None),
+	item(134, "BlueBrick", "Blue Brick", 9999, PrefixCategory::None),
+	item(135, "BlueBrickWall", "Blue Brick Wall", 9999, PrefixCategory::None),
+	item(136, "ChainLantern", "Chain Lantern", 9999, PrefixCategory::None),
+	item(137, "GreenBrick", "Green Brick", 9999, PrefixCategory::None),
+	item(138, "GreenBrickWall", "Green Brick Wall", 9999, PrefixCategory::None),
+	item(139, "PinkBrick", "Pink Brick", 9999, PrefixCategory::None),
+	item(140, "PinkBrickWall", "Pink Brick Wall", 9999, PrefixCategory::None),
+	item(141, "GoldBrick", "Gold Brick", 9999, PrefixCategory::None),
+	item(142, "GoldBrickWall", "Gold Brick Wall", 9999, PrefixCategory::None),
+	item(143, "SilverBrick", "Silver Brick", 9999, PrefixCategory::None),
+	item(144, "SilverBrickWall", "Silver Brick Wall", 9999, PrefixCategory::None),
+	item(145, "CopperBrick", "Copper Brick", 9999, PrefixCategory::None),
+	item(146, "CopperBrickWall", "Copper Brick Wall", 9999, PrefixCategory::None),
+	item(147, "Spike", "Spike", 9999, PrefixCategory::None),
+	item(148, "WaterCandle", "Water Candle", 9999, PrefixCategory::None),
+	item(149, "Book", "Book", 9999, PrefixCategory::None),
+	item(150, "Cobweb", "Cobweb", 9999, PrefixCategory::None),
+	item(151, "NecroHelmet", "Necro Helmet", 1, PrefixCategory::None),
+	item(152, "NecroBreastplate", "Necro Breastplate", 1, PrefixCategory::None),
+	item(153, "NecroGreaves", "Necro Greaves", 1, PrefixCategory::None),
+	item(154, "Bone", "Bone", 9999, PrefixCategory::None),
+	item(155, "Muramasa", "Muramasa", 1, PrefixCategory::Melee),
+	item(156, "CobaltShield", "Cobalt Shield", 1, PrefixCategory::Accessory),
+	item(157, "AquaScepter", "Aqua Scepter", 1, PrefixCategory::Magic),
+	item(158, "LuckyHorseshoe", "Lucky Horseshoe", 1, PrefixCategory::Accessory),
+	item(159, "ShinyRedBalloon", "Shiny Red Balloon", 1, PrefixCategory::Accessory),
+	item(160, "Harpoon", "Harpoon", 1, PrefixCategory::Ranged),
+	item(161, "SpikyBall", "Spiky Ball", 9999, PrefixCategory::None),
+	item(162, "BallOHurt", "Ball O' Hurt", 1, PrefixCategory::Weapon),
+	item(163, "BlueMoon", "Blue Moon", 1, PrefixCategory::Weapon),
+	item(164, "Handgun", "Handgun", 1, PrefixCategory::Ranged),
+	item(165, "WaterBolt", "Water Bolt", 1, PrefixCategory::Magic),
+	item(166, "Bomb", "Bomb", 9999, PrefixCategory::None),
+	item(167, "Dynamite", "Dynamite", 9999, PrefixCategory::None),
+	item(168, "Grenade", "Grenade", 9999, PrefixCategory::None),
+	item(169, "SandBlock", "Sand Block", 9999, PrefixCategory::None),
+	item(170, "Glass", "Glass", 9999, PrefixCategory::None),
+	item(171, "Sign", "Sign", 9999, PrefixCategory::None),
+	item(172, "AshBlock", "Ash Block", 9999, PrefixCategory::None),
+	item(173, "Obsidian", "Obsidian", 9999, PrefixCategory::None),
+	item(174, "Hellstone", "Hellstone", 9999, PrefixCategory::None),
+	item(175, "HellstoneBar", "Hellstone Bar", 9999, PrefixCategory::None),
+	item(176, "MudBlock", "Mud Block", 9999, PrefixCategory::None),
+	item(177, "Sapphire", "Sapphire", 9999, PrefixCategory::None),
+	item(178, "Ruby", "Ruby", 9999, PrefixCategory::None),
+	item(179, "Emerald", "Emerald", 9999, PrefixCategory::None),
+	item(180, "Topaz", "Topaz", 9999, PrefixCategory::None),
+	item(181, "Amethyst", "Amethyst", 9999, PrefixCategory::None),
+	item(182, "Diamond", "Diamond", 9999, PrefixCategory::None),
+	item(183, "GlowingMushroom", "Glowing Mushroom", 9999, PrefixCategory::None),
+	item(184, "Star", "Star", 9999, PrefixCategory::None),
+	item(185, "IvyWhip", "Ivy Whip", 1, PrefixCategory::None),
+	item(186, "BreathingReed", "Breathing Reed", 1, PrefixCategory::None),
+	item(187, "Flipper", "Flipper", 1, PrefixCategory::Accessory),
+	item(188, "HealingPotion", "Healing Potion", 9999, PrefixCategory::None),
+	item(189, "ManaPotion", "Mana Potion", 9999, PrefixCategory::None),
+	item(190, "BladeofGrass", "Blade of Grass", 1, PrefixCategory::Melee),
+	item(191, "ThornChakram", "Thorn Chakram", 1, PrefixCategory::Weapon),
+	item(192, "ObsidianBrick", "Obsidian Brick", 9999, PrefixCategory::None),
+	item(193, "ObsidianSkull", "Obsidian Skull", 1, PrefixCategory::Accessory),
+	item(194, "MushroomGrassSeeds", "Mushroom Grass Seeds", 9999, PrefixCategory::None),
+	item(195, "JungleGrassSeeds", "Jungle Grass Seeds", 9999, PrefixCategory::None),
+	item(196, "WoodenHammer", "Wooden Hammer", 1, PrefixCategory::Melee),
+	item(197, "StarCannon", "Star Cannon", 1, PrefixCategory::Ranged),
+	item(198, "BluePhaseblade", "Blue Phaseblade", 1, PrefixCategory::Melee),
+	item(199, "RedPhaseblade", "Red Phaseblade", 1, PrefixCategory::Melee),
+	item(200, "GreenPhaseblade", "Green Phaseblade", 1, PrefixCategory::Melee),
+	item(201, "PurplePhaseblade", "Purple Phaseblade", 1, PrefixCategory::Melee),
+	item(202, "WhitePhaseblade", "White Phaseblade", 1, PrefixCategory::Melee),
+	item(203, "YellowPhaseblade", "Yellow Phaseblade", 1, PrefixCategory::Melee),
+	item(204, "MeteorHamaxe", "Meteor Hamaxe", 1, PrefixCategory::Melee),
+	item(205, "EmptyBucket", "Empty Bucket", 9999, PrefixCategory::None),
+	item(206, "WaterBucket", "Water Bucket", 9999, PrefixCategory::None),
+	item(207, "LavaBucket", "Lava Bucket", 9999, PrefixCategory::None),
+	item(208, "JungleRose", "Jungle Rose", 1, PrefixCategory::None),
+	item(209, "Stinger", "Stinger", 9999, PrefixCategory::None),
+	item(210, "Vine", "Vine", 9999, PrefixCategory::None),
+	item(211, "FeralClaws", "Feral Claws", 1, PrefixCategory::Accessory),
+	item(212, "AnkletoftheWind", "Anklet of the Wind", 1, PrefixCategory::Accessory),
+	item(213, "StaffofRegrowth", "Staff of Regrowth", 1, PrefixCategory::None),
+	item(214, "HellstoneBrick", "Hellstone Brick", 9999, PrefixCategory::None),
+	item(215, "WhoopieCushion", "Whoopie Cushion", 1, PrefixCategory::None),
+	item(216, "Shackle", "Shackle", 1, PrefixCategory::Accessory),
+	item(217, "MoltenHamaxe", "Molten Hamaxe", 1, PrefixCategory::Melee),
+	item(218, "Flamelash", "Flamelash", 1, PrefixCategory::Magic),
+	item(219, "PhoenixBlaster", "Phoenix Blaster", 1, PrefixCategory::Ranged),
+	item(220, "Sunfury", "Sunfury", 1, PrefixCategory::Weapon),
+	item(221, "Hellforge", "Hellforge", 9999, PrefixCategory::None),
+	item(222, "ClayPot", "Clay Pot", 9999, PrefixCategory::None),
+	item(223, "NaturesGift", "Nature's Gift", 1, PrefixCategory::Accessory),
+	item(224, "Bed", "Bed", 9999, PrefixCategory::None),
+	item(225, "Silk", "Silk", 9999, PrefixCategory::None),
+	item(226, "LesserRestorationPotion", "Lesser Restoration Potion", 9999, PrefixCategory::None),
+	item(227, "RestorationPotion", "Restoration Potion", 9999, PrefixCategory::None),
+	item(228, "JungleHat", "Jungle Hat", 1, PrefixCategory::None),
+	item(229, "JungleShirt", "Jungle Shirt", 1, PrefixCategory::None),
+	item(230, "JunglePants", "Jungle Pants", 1, PrefixCategory::None),
+	item(231, "MoltenHelmet", "Molten Helmet", 1, PrefixCategory::None),
+	item(232, "MoltenBreastplate", "Molten Breastplate", 1, PrefixCategory::None),
+	item(233, "MoltenGreaves", "Molten Greaves", 1, PrefixCategory::None),
+	item(234, "MeteorShot", "Meteor Shot", 9999, PrefixCategory::None),
+	item(235, "StickyBomb", "Sticky Bomb", 9999, PrefixCategory::None),
+	item(236, "BlackLens", "Black Lens", 9999, PrefixCategory::None),
+	item(237, "Sunglasses", "Sunglasses", 1, PrefixCategory::None),
+	item(238, "WizardHat", "Wizard Hat", 1, PrefixCategory::None),
+	item(239, "TopHat", "Top Hat", 1, PrefixCategory::None),
+	item(240, "TuxedoShirt", "Tuxedo Shirt", 1, PrefixCategory::None),
+	item(241, "TuxedoPants", "Tuxedo Pants", 1, PrefixCategory::None),
+	item(242, "SummerHat", "Summer Hat", 1, PrefixCategory::None),
+	item(243, "BunnyHood", "Bunny Hood", 1, PrefixCategory::None),
+	item(244, "PlumbersHat", "Plumber's Hat", 1, PrefixCategory::None),
+	item(245, "PlumbersShirt", "Plumber's Shirt", 1, PrefixCategory::None),
+	item(246, "PlumbersPants", "Plumber's Pants", 1, PrefixCategory::None),
+	item(247, "HerosHat", "Hero's Hat", 1, PrefixCategory::None),
+	item(248, "HerosShirt", "Hero's Shirt", 1, PrefixCategory::None),
+	item(249, "HerosPants", "Hero's Pants", 1, PrefixCategory::None),
+	item(250, "FishBowl", "Fish Bowl", 1, PrefixCategory::None),
+	item(251, "ArchaeologistsHat", "Archaeologist's Hat", 1, PrefixCategory::None),
+	item(252, "ArchaeologistsJacket", "Archaeologist's Jacket", 1, PrefixCategory::None),
+	item(253, "ArchaeologistsPants", "Archaeologist's Pants", 1, PrefixCategory::None),
+	item(254, "BlackThread", "Black Thread", 9999, PrefixCategory::None),
+	item(255, "GreenThread", "Green Thread", 9999, PrefixCategory::None),
+	item(256, "NinjaHood", "Ninja Hood", 1, PrefixCategory::None),
+	item(257, "NinjaShirt", "Ninja Shirt", 1, PrefixCategory::None),
+	item(258, "NinjaPants", "Ninja Pants", 1, PrefixCategory::None),
+	item(259, "Leather", "Leather", 9999, PrefixCategory::None),
+	item(260, "RedHat", "Red Hat", 1, PrefixCategory::None),
+	item(261, "Goldfish", "Goldfish", 9999, PrefixCategory::None),
+	item(262, "Robe", "Robe", 1, PrefixCategory::None),
+	item(263, "RobotHat", "Robot Hat", 1, PrefixCategory::None),
+	item(264, "GoldCrown", "Gold Crown", 1, PrefixCategory::None),
+	item(265, "HellfireArrow", "Hellfire Arrow", 9999, PrefixCategory::None),
+	item(266, "Sandgun", "Sandgun", 1, PrefixCategory::Ranged),
+	item(267, "GuideVoodooDoll", "Guide Voodoo Doll", 1, PrefixCategory::None),
+	item(268, "DivingHelmet", "Diving Helmet", 1, PrefixCategory::Accessory),
+	item(269, "FamiliarShirt", "Familiar Shirt", 1, PrefixCategory::None),
+	item(270, "FamiliarPants", "Familiar Pants", 1, PrefixCategory::None),
+	item(271, "FamiliarWig", "Familiar Wig", 1, PrefixCategory::None),
+	item(272, "DemonScythe", "Demon Scythe", 1, PrefixCategory::Magic),
+	item(273, "NightsEdge", "Night's Edge", 1, PrefixCategory::Melee),
+	item(274, "DarkLance", "Dark Lance", 1, PrefixCategory::Weapon),
+	item(275, "Coral", "Coral", 9999, PrefixCategory::None),
+	item(276, "Cactus", "Cactus", 9999, PrefixCategory::None),
+	item(277, "Trident", "Trident", 1, PrefixCategory::Weapon),
+	item(278, "SilverBullet", "Silver Bullet", 9999, PrefixCategory::None),
+	item(279, "ThrowingKnife", "Throwing Knife", 9999, PrefixCategory::None),
+	item(280, "Spear", "Spear", 1, PrefixCategory::Weapon),
+	item(281, "Blowpipe", "Blowpipe", 1, PrefixCategory::Ranged),
+	item(282, "Glowstick", "Glowstick", 9999, PrefixCategory::None),
+	item(283, "Seed", "Seed", 9999, PrefixCategory::None),
+	item(284, "WoodenBoomerang", "Wooden Boomerang", 1, PrefixCategory::Weapon),
+	item(285, "Aglet", "Aglet", 1, PrefixCategory::Accessory),
+	item(286, "StickyGlowstick", "Sticky Glowstick", 9999, PrefixCategory::None),
+	item(287, "PoisonedKnife", "Poisoned Knife", 9999, PrefixCategory::None),
+	item(288, "ObsidianSkinPotion", "Obsidian Skin Potion", 9999, PrefixCategory::None),
+	item(289, "RegenerationPotion", "Regeneration Potion", 9999, PrefixCategory::None),
+	item(290, "SwiftnessPotion", "Swiftness Potion", 9999, PrefixCategory::None),
+	item(291, "GillsPotion", "Gills Potion", 9999, PrefixCategory::None),
+	item(292, "IronskinPotion", "Ironskin Potion", 9999, PrefixCategory::None),
+	item(293, "ManaRegenerationPotion", "Mana Regeneration Potion", 9999, PrefixCategory::None),
+	item(294, "MagicPowerPotion", "Magic Power Potion", 9999, PrefixCategory::None),
+	item(295, "FeatherfallPotion", "Featherfall Potion", 9999, PrefixCategory::None),
+	item(296, "SpelunkerPotion", "Spelunker Potion", 9999, PrefixCategory::None),
+	item(297, "InvisibilityPotion", "Invisibility Potion", 9999, PrefixCategory::None),
+	item(298, "ShinePotion", "Shine Potion", 9999, PrefixCategory::None),
+	item(299, "NightOwlPotion", "Night Owl Potion", 9999, PrefixCategory::None),
+	item(300, "BattlePotion", "Battle Potion", 9999, PrefixCategory::None),
+	item(301, "ThornsPotion", "Thorns Potion", 9999, PrefixCategory::None),
+	item(302, "WaterWalkingPotion", "Water Walking Potion", 9999, PrefixCategory::None),
+	item(303, "ArcheryPotion", "Archery Potion", 9999, PrefixCategory::None),
+	item(304, "HunterPotion", "Hunter Potion", 9999, PrefixCategory::None),
+	item(305, "GravitationPotion", "Gravitation Potion", 9999, PrefixCategory::None),
+	item(306, "GoldChest", "Gold Chest", 9999, PrefixCategory::None),
+	item(307, "DaybloomSeeds", "Daybloom Seeds", 9999, PrefixCategory::None),
+	item(308, "MoonglowSeeds", "Moonglow Seeds", 9999, PrefixCategory::None),
+	item(309, "BlinkrootSeeds", "Blinkroot Seeds", 9999, PrefixCategory::None),
+	item(310, "DeathweedSeeds", "Deathweed Seeds", 9999, PrefixCategory::None),
+	item(311, "WaterleafSeeds", "Waterleaf Seeds", 9999, PrefixCategory::None),
+	item(312, "FireblossomSeeds", "Fireblossom Seeds", 9999, PrefixCategory::None),
+	item(313, "Daybloom", "Daybloom", 9999, PrefixCategory::None),
+	item(314, "Moonglow", "Moonglow", 9999, PrefixCategory::None),
+	item(315, "Blinkroot", "Blinkroot", 9999, PrefixCategory::None),
+	item(316, "Deathweed", "Deathweed", 9999, PrefixCategory::None),
+	item(317, "Waterleaf", "Waterleaf", 9999, PrefixCategory::None),
+	item(318, "Fireblossom", "Fireblossom", 9999, PrefixCategory::None),
+	item(319, "SharkFin", "Shark Fin", 9999, PrefixCategory::None),
+	item(320, "Feather", "Feather", 9999, PrefixCategory::None),
+	item(321, "Tombstone", "Tombstone", 9999, PrefixCategory::None),
+	item(322, "MimeMask", "Mime Mask", 1, PrefixCategory::None),
+	item(323, "AntlionMandible", "Antlion Mandible", 9999, PrefixCategory::None),
+	item(324, "IllegalGunParts", "Illegal Gun Parts", 9999, PrefixCategory::None),
+	item(325, "TheDoctorsShirt", "The Doctor's Shirt", 1, PrefixCategory::None),
+	item(326, "TheDoctorsPants", "The Doctor's Pants", 1, PrefixCategory::None),
+	item(327, "GoldenKey", "Golden Key", 9999, PrefixCategory::None),
+	item(328, "ShadowChest", "Shadow Chest", 9999, PrefixCategory::None),
+	item(329, "ShadowKey", "Shadow Key", 1, PrefixCategory::None),
+	item(330, "ObsidianBrickWall", "Obsidian Brick Wall", 9999, PrefixCategory::None),
+	item(331, "JungleSpores", "Jungle Spores", 9999, PrefixCategory::None),
+	item(332, "Loom", "Loom", 9999, PrefixCategory::None),
+	item(333, "Piano", "Piano", 9999, PrefixCategory::None),
+	item(334, "Dresser", "Dresser", 9999, PrefixCategory::None),
+	item(335, "Bench", "Bench", 9999, PrefixCategory::None),
+	item(336, "Bathtub", "Bathtub", 9999, PrefixCategory::None),
+	item(337, "RedBanner", "Red Banner", 9999, PrefixCategory::None),
+	item(338, "GreenBanner", "Green Banner", 9999, PrefixCategory::None),
+	item(339, "BlueBanner", "Blue Banner", 9999, PrefixCategory::None),
+	item(340, "YellowBanner", "Yellow Banner", 9999, PrefixCategory::None),
+	item(341, "LampPost", "Lamp Post", 9999, PrefixCategory::None),
+	item(342, "TikiTorch", "Tiki Torch", 9999, PrefixCategory::None),
+	item(343, "Barrel", "Barrel", 9999, PrefixCategory::None),
+	item(344, "ChineseLantern", "Chinese Lantern", 9999, PrefixCategory::None),
+	item(345, "CookingPot", "Cooking Pot", 9999, PrefixCategory::None),
+	item(346, "Safe", "Safe", 9999, PrefixCategory::None),
+	item(347, "SkullLantern", "Skull Lantern", 9999, PrefixCategory::None),
+	item(348, "TrashCan", "Trash Can", 9999, PrefixCategory::None),
+	item(349, "Candelabra", "Candelabra", 9999, PrefixCategory::None),
+	item(350, "PinkVase", "Pink Vase", 9999, PrefixCategory::None),
+	item(351, "Mug", "Mug", 9999, PrefixCategory::None),
+	item(352, "Keg", "Keg", 9999, PrefixCategory::None),
+	item(353, "Ale", "Ale", 9999, PrefixCategory::None),
+	item(354, "Bookcase", "Bookcase", 9999, PrefixCategory::None),
+	item(355, "Throne", "Throne", 9999, PrefixCategory::None),
+	item(356, "Bowl", "Bowl", 9999, PrefixCategory::None),
+	item(357, "BowlofSoup", "Bowl of Soup", 9999, PrefixCategory::None),
+	item(358, "Toilet", "Toilet", 9999, PrefixCategory::None),
+	item(359, "GrandfatherClock", "Grandfather Clock", 9999, PrefixCategory::None),
+	item(360, "ArmorStatue", "Armor Statue", 9999, PrefixCategory::None),
+	item(361, "GoblinBattleStandard", "Goblin Battle Standard", 9999, PrefixCategory::None),
+	item(362, "TatteredCloth", "Tattered Cloth", 9999, PrefixCategory::None),
+	item(363, "Sawmill", "Sawmill", 9999, PrefixCategory::None),
+	item(364, "CobaltOre", "Cobalt Ore", 9999, PrefixCategory::None),
+	item(365, "MythrilOre", "Mythril Ore", 9999, PrefixCategory::None),
+	item(366, "AdamantiteOre", "Adamantite Ore", 9999, PrefixCategory::None),
+	item(367, "Pwnhammer", "Pwnhammer", 1, PrefixCategory::Melee),
+	item(368, "Excalibur", "Excalibur", 1, PrefixCategory::Melee),
+	item(369, "HallowedSeeds", "Hallowed Seeds", 9999, PrefixCategory::None),
+	item(370, "EbonsandBlock", "Ebonsand Block", 9999, PrefixCategory::None),
+	item(371, "CobaltHat", "Cobalt Hat", 1, PrefixCategory::None),
+	item(372, "CobaltHelmet", "Cobalt Helmet", 1, PrefixCategory::None),
+	item(373, "CobaltMask", "Cobalt Mask", 1, PrefixCategory::None),
+	item(374, "CobaltBreastplate", "Cobalt Breastplate", 1, PrefixCategory::None),
+	item(375, "CobaltLeggings", "Cobalt Leggings", 1, PrefixCategory::None),
+	item(376, "MythrilHood", "Mythril Hood", 1, PrefixCategory::None),
+	item(377, "MythrilHelmet", "Mythril Helmet", 1, PrefixCategory::None),
+	item(378, "MythrilHat", "Mythril Hat", 1, PrefixCategory::None),
+	item(379, "MythrilChainmail", "Mythril Chainmail", 1, PrefixCategory::None),
+	item(380, "MythrilGreaves", "Mythril Greaves", 1, PrefixCategory::None),
+	item(381, "CobaltBar", "Cobalt Bar", 9999, PrefixCategory::None),
+	item(382, "MythrilBar", "Mythril Bar", 9999, PrefixCategory::None),
+	item(383, "CobaltChainsaw", "Cobalt Chainsaw", 1, PrefixCategory::Weapon),
+	item(384, "MythrilChainsaw", "Mythril Chainsaw", 1, PrefixCategory::Weapon),
+	item(385, "CobaltDrill", "Cobalt Drill", 1, PrefixCategory::Weapon),
+	item(386, "MythrilDrill", "Mythril Drill", 1, PrefixCategory::Weapon),
+	item(387, "AdamantiteChainsaw", "Adamantite Chainsaw", 1, PrefixCategory::Weapon),
+	item(388, "AdamantiteDrill", "Adamantite Drill", 1, PrefixCategory::Weapon),
+	item(389, "DaoofPow", "Dao of Pow", 1, PrefixCategory::Weapon),
+	item(390, "MythrilHalberd", "Mythril Halberd", 1, PrefixCategory::Weapon),
+	item(391, "AdamantiteBar", "Adamantite Bar", 9999, PrefixCategory::None),
+	item(392, "GlassWall", "Glass Wall", 9999, PrefixCategory::None),
+	item(393, "Compass", "Compass", 1, PrefixCategory::Accessory),
+	item(394, "DivingGear", "Diving Gear", 1, PrefixCategory::Accessory),
+	item(395, "GPS", "GPS", 1, PrefixCategory::Accessory),
+	item(396, "ObsidianHorseshoe", "Obsidian Horseshoe", 1, PrefixCategory::Accessory),
+	item(397, "ObsidianShield", "Obsidian Shield", 1, PrefixCategory::Accessory),
+	item(398, "TinkerersWorkshop", "Tinkerer's Workshop", 9999, PrefixCategory::None),
+	item(399, "CloudinaBalloon", "Cloud in a Balloon", 1, PrefixCategory::Accessory),
+	item(400, "AdamantiteHeadgear", "Adamantite Headgear", 1, PrefixCategory::None),
+	item(401, "AdamantiteHelmet", "Adamantite Helmet", 1, PrefixCategory::None),
+	item(402, "AdamantiteMask", "Adamantite Mask", 1, PrefixCategory::None),
+	item(403, "AdamantiteBreastplate", "Adamantite Breastplate", 1, PrefixCategory::None),
+	item(404, "AdamantiteLeggings", "Adamantite Leggings", 1, PrefixCategory::None),
+	item(405, "SpectreBoots", "Spectre Boots", 1, PrefixCategory::Accessory),
+	item(406, "AdamantiteGlaive", "Adamantite Glaive", 1, PrefixCategory::Weapon),
+	item(407, "Toolbelt", "Toolbelt", 1, PrefixCategory::Accessory),
+	item(408, "PearlsandBlock", "Pearlsand Block", 9999, PrefixCategory::None),
+	item(409, "PearlstoneBlock", "Pearlstone Block", 9999, PrefixCategory::None),
+	item(410, "MiningShirt", "Mining Shirt", 1, PrefixCategory::None),
+	item(411, "MiningPants", "Mining Pants", 1, PrefixCategory::None),
+	item(412, "PearlstoneBrick", "Pearlstone Brick", 9999, PrefixCategory::None),
+	item(413, "IridescentBrick", "Iridescent Brick", 9999, PrefixCategory::None),
+	item(414, "MudstoneBrick", "Mudstone Brick", 9999, PrefixCategory::None),
+	item(415, "CobaltBrick", "Cobalt Brick", 9999, PrefixCategory::None),
+	item(416, "MythrilBrick", "Mythril Brick", 9999, PrefixCategory::None),
+	item(417, "PearlstoneBrickWall", "Pearlstone Brick Wall", 9999, PrefixCategory::None),
+	item(418, "IridescentBrickWall", "Iridescent Brick Wall", 9999, PrefixCategory::None),
+	item(419, "MudstoneBrickWall", "Mudstone Brick Wall", 9999, PrefixCategory::None),
+	item(420, "CobaltBrickWall", "Cobalt Brick Wall", 9999, PrefixCategory::None),
+	item(421, "MythrilBrickWall", "Mythril Brick Wall", 9999, PrefixCategory::None),
+	item(422, "HolyWater", "Holy Water", 9999, PrefixCategory::None),
+	item(423, "UnholyWater", "Unholy Water", 9999, PrefixCategory::None),
+	item(424, "SiltBlock", "Silt Block", 9999, PrefixCategory::None),
+	item(425, "FairyBell", "Fairy Bell", 1, PrefixCategory::None),
+	item(426, "BreakerBlade", "Breaker Blade", 1, PrefixCategory::Melee),
+	item(427, "BlueTorch", "Blue Torch", 9999, PrefixCategory::None),
+	item(428, "RedTorch", "Red Torch", 9999, PrefixCategory::None),
+	item(429, "GreenTorch", "Green Torch", 9999, PrefixCategory::None),
+	item(430, "PurpleTorch", "Purple Torch", 9999, PrefixCategory::None),
+	item(431, "WhiteTorch", "White Torch", 9999, PrefixCategory::None),
+	item(432, "YellowTorch", "Yellow Torch", 9999, PrefixCategory::None),
+	item(433, "DemonTorch", "Demon Torch", 9999, PrefixCategory::None),
+	item(434, "ClockworkAssaultRifle", "Clockwork Assault Rifle", 1, PrefixCategory::Ranged),
+	item(435, "CobaltRepeater", "Cobalt Repeater", 1, PrefixCategory::Ranged),
+	item(436, "MythrilRepeater", "Mythril Repeater", 1, PrefixCategory::Ranged),
+	item(437, "DualHook", "Dual Hook", 1, PrefixCategory::None),
+	item(438, "StarStatue", "Star Statue", 9999, PrefixCategory::None),
+	item(439, "SwordStatue", "Sword Statue", 9999, PrefixCategory::None),
+	item(440, "SlimeStatue", "Slime Statue", 9999, PrefixCategory::None),
+	item(441, "GoblinStatue", "Goblin Statue", 9999, PrefixCategory::None),
+	item(442, "ShieldStatue", "Shield Statue", 9999, PrefixCategory::None),
+	item(443, "BatStatue", "Bat Statue", 9999, PrefixCategory::None),
+	item(444, "FishStatue", "Fish Statue", 9999, PrefixCategory::None),
+	item(445, "BunnyStatue", "Bunny Statue", 9999, PrefixCategory::None),
+	item(446, "SkeletonStatue", "Skeleton Statue", 9999, PrefixCategory::None),
+	item(447, "ReaperStatue", "Reaper Statue", 9999, PrefixCategory::None),
+	item(448, "WomanStatue", "Woman Statue", 9999, PrefixCategory::None),
+	item(449, "ImpStatue", "Imp Statue", 9999, PrefixCategory::None),
+	item(450, "GargoyleStatue", "Gargoyle Statue", 9999, PrefixCategory::None),
+	item(451, "GloomStatue", "Gloom Statue", 9999, PrefixCategory::None),
+	item(452, "HornetStatue", "Hornet Statue", 9999, PrefixCategory::None),
+	item(453, "BombStatue", "Bomb Statue", 9999, PrefixCategory::None),
+	item(454, "CrabStatue", "Crab Statue", 9999, PrefixCategory::None),
+	item(455, "HammerStatue", "Hammer Statue", 9999, PrefixCategory::None),
+	item(456, "PotionStatue", "Potion Statue", 9999, PrefixCategory::None),
+	item(457, "SpearStatue", "Spear Statue", 9999, PrefixCategory::None),
+	item(458, "CrossStatue", "Cross Statue", 9999, PrefixCategory::None),
+	item(459, "JellyfishStatue", "Jellyfish Statue", 9999, PrefixCategory::None),
+	item(460, "BowStatue", "Bow Statue", 9999, PrefixCategory::None),
+	item(461, "BoomerangStatue", "Boomerang Statue", 9999, PrefixCategory::None),
+	item(462, "BootStatue", "Boot Statue", 9999, PrefixCategory::None),
+	item(463, "ChestStatue", "Chest Statue", 9999, PrefixCategory::None),
+	item(464, "BirdStatue", "Bird Statue", 9999, PrefixCategory::None),
+	item(465, "AxeStatue", "Axe Statue", 9999, PrefixCategory::None),
+	item(466, "CorruptStatue", "Corrupt Statue", 9999, PrefixCategory::None),
+	item(467, "TreeStatue", "Tree Statue", 9999, PrefixCategory::None),
+	item(468, "AnvilStatue", "Anvil Statue", 9999, PrefixCategory::None),
+	item(469, "PickaxeStatue", "Pickaxe Statue", 9999, PrefixCategory::None),
+	item(470, "MushroomStatue", "Mushroom Statue", 9999, PrefixCategory::None),
+	item(471, "EyeballStatue", "Eyeball Statue", 9999, PrefixCategory::None),
+	item(472, "PillarStatue", "Pillar Statue", 9999, PrefixCategory::None),
+	item(473, "HeartStatue", "Heart Statue", 9999, PrefixCategory::None),
+	item(474, "PotStatue", "Pot Statue", 9999, PrefixCategory::None),
+	item(475, "SunflowerStatue", "Sunflower Statue", 9999, PrefixCategory::None),
+	item(476, "KingStatue", "King Statue", 9999, PrefixCategory::None),
+	item(477, "QueenStatue", "Queen Statue", 9999, PrefixCategory::None),
+	item(478, "PiranhaStatue", "Piranha Statue", 9999, PrefixCategory::None),
+	item(479, "PlankedWall", "Planked Wall", 9999, PrefixCategory::None),
+	item(480, "WoodenBeam", "Wooden Beam", 9999, PrefixCategory::None),
+	item(481, "AdamantiteRepeater", "Adamantite Repeater", 1, PrefixCategory::Ranged),
+	item(482, "AdamantiteSword", "Adamantite Sword", 1, PrefixCategory::Melee),
+	item(483, "CobaltSword", "Cobalt Sword", 1, PrefixCategory::Melee),
+	item(484, "MythrilSword", "Mythril Sword", 1, PrefixCategory::Melee),
+	item(485, "MoonCharm", "Moon Charm", 1, PrefixCategory::Accessory),
+	item(486, "Ruler", "Ruler", 1, PrefixCategory::Accessory),
+	item(487, "CrystalBall", "Crystal Ball", 9999, PrefixCategory::None),
+	item(488, "DiscoBall", "Disco Ball", 9999, PrefixCategory::None),
+	item(489, "SorcererEmblem", "Sorcerer Emblem", 1, PrefixCategory::Accessory),
+	item(490, "WarriorEmblem", "Warrior Emblem", 1, PrefixCategory::Accessory),
+	item(491, "RangerEmblem", "Ranger Emblem", 1, PrefixCategory::Accessory),
+	item(492, "DemonWings", "Demon Wings", 1, PrefixCategory::Accessory),
+	item(493, "AngelWings", "Angel Wings", 1, PrefixCategory::Accessory),
+	item(494, "MagicalHarp", "Magical Harp", 1, PrefixCategory::Magic),
+	item(495, "RainbowRod", "Rainbow Rod", 1, PrefixCategory::Magic),
+	item(496, "IceRod", "Ice Rod", 1, PrefixCategory::Magic),
+	item(497, "NeptunesShell", "Neptune's Shell", 1, PrefixCategory::Accessory),
+	item(498, "Mannequin", "Mannequin", 9999, PrefixCategory::None),
+	item(499, "GreaterHealingPotion", "Greater Healing Potion", 9999, PrefixCategory::None),
+	item(500, "GreaterManaPotion", "Greater Mana Potion", 9999, PrefixCategory::None),
+	item(501, "PixieDust", "Pixie Dust", 9999, PrefixCategory::None),
+	item(502, "CrystalShard", "Crystal Shard", 9999, PrefixCategory::None),
+	item(503, "ClownHat", "Clown Hat", 1, PrefixCategory::None),
+	item(504, "ClownShirt", "Clown Shirt", 1, PrefixCategory::None),
+	item(505, "ClownPants", "Clown Pants", 1, PrefixCategory::None),
+	item(506, "Flamethrower", "Flamethrower", 1, PrefixCategory::Ranged),
+	item(507, "Bell", "Bell", 1, PrefixCategory::None),
+	item(508, "Harp", "Harp", 1, PrefixCategory::None),
+	item(509, "Wrench", "Red Wrench", 1, PrefixCategory::None),
+	item(510, "WireCutter", "Wire Cutter", 1, PrefixCategory::None),
+	item(511, "ActiveStoneBlock", "Active Stone Block", 9999, PrefixCategory::None),
+	item(512, "InactiveStoneBlock", "Inactive Stone Block", 9999, PrefixCategory::None),
+	item(513, "Lever", "Lever", 9999, PrefixCategory::None),
+	item(514, "LaserRifle", "Laser Rifle", 1, PrefixCategory::Magic),
+	item(515, "CrystalBullet", "Crystal Bullet", 9999, PrefixCategory::None),
+	item(516, "HolyArrow", "Holy Arrow", 9999, PrefixCategory::None),
+	item(517, "MagicDagger", "Magic Dagger", 1, PrefixCategory::Magic),
+	item(518, "CrystalStorm", "Crystal Storm", 1, PrefixCategory::Magic),
+	item(519, "CursedFlames", "Cursed Flames", 1, PrefixCategory::Magic),
+	item(520, "SoulofLight", "Soul of Light", 9999, PrefixCategory::None),
+	item(521, "SoulofNight", "Soul of Night", 9999, PrefixCategory::None),
+	item(522, "CursedFlame", "Cursed Flame", 9999, PrefixCategory::None),
+	item(523, "CursedTorch", "Cursed Torch", 9999, PrefixCategory::None),
+	item(524, "AdamantiteForge", "Adamantite Forge", 9999, PrefixCategory::None),
+	item(525, "MythrilAnvil", "Mythril Anvil", 9999, PrefixCategory::None),
+	item(526, "UnicornHorn", "Unicorn Horn", 9999, PrefixCategory::None),
+	item(527, "DarkShard", "Dark Shard", 9999, PrefixCategory::None),
+	item(528, "LightShard", "Light Shard", 9999, PrefixCategory::None),
+	item(529, "RedPressurePlate", "Red Pressure Plate", 1, PrefixCategory::None),
+	item(530, "Wire", "Wire", 9999, PrefixCategory::None),
+	item(531, "SpellTome", "Spell Tome", 9999, PrefixCategory::None),
+	item(532, "StarCloak", "Star Cloak", 1, PrefixCategory::Accessory),
+	item(533, "Megashark", "Megashark", 1, PrefixCategory::Ranged),
+	item(534, "Shotgun", "Shotgun", 1, PrefixCategory::Ranged),
+	item(535, "PhilosophersStone", "Philosopher's Stone", 1, PrefixCategory::Accessory),
+	item(536, "TitanGlove", "Titan Glove", 1, PrefixCategory::Accessory),
+	item(537, "CobaltNaginata", "Cobalt Naginata", 1, PrefixCategory::Melee),
+	item(538, "Switch", "Switch", 9999, PrefixCategory::None),
+	item(539, "DartTrap", "Dart Trap", 9999, PrefixCategory::None),
+	item(540, "Boulder", "Boulder", 9999, PrefixCategory::None),
+	item(541, "GreenPressurePlate", "Green Pressure Plate", 1, PrefixCategory::None),
+	item(542, "GrayPressurePlate", "Gray Pressure Plate", 1, PrefixCategory::None),
+	item(543, "BrownPressurePlate", "Brown Pressure Plate", 1, PrefixCategory::None),
+	item(544, "MechanicalEye", "Mechanical Eye", 20, PrefixCategory::None),
+	item(545, "CursedArrow", "Cursed Arrow", 9999, PrefixCategory::None),
+	item(546, "CursedBullet", "Cursed Bullet", 9999, PrefixCategory::None),
+	item(547, "SoulofFright", "Soul of Fright", 9999, PrefixCategory::None),
+	item(548, "SoulofMight", "Soul of Might", 9999, PrefixCategory::None),
+	item(549, "SoulofSight", "Soul of Sight", 9999, PrefixCategory::None),
+	item(550, "Gungnir", "Gungnir", 1, PrefixCategory::Melee),
+	item(551, "HallowedPlateMail", "Hallowed Plate Mail", 1, PrefixCategory::None),
+	item(552, "HallowedGreaves", "Hallowed Greaves", 1, PrefixCategory::None),
+	item(553, "HallowedHelmet", "Hallowed Helmet", 1, PrefixCategory::None),
+	item(554, "CrossNecklace", "Cross Necklace", 1, PrefixCategory::Accessory),
+	item(555, "ManaFlower", "Mana Flower", 1, PrefixCategory::Accessory),
+	item(556, "MechanicalWorm", "Mechanical Worm", 20, PrefixCategory::None),
+	item(557, "MechanicalSkull", "Mechanical Skull", 20, PrefixCategory::None),
+	item(558, "HallowedHeadgear", "Hallowed Headgear", 1, PrefixCategory::None),
+	item(559, "HallowedMask", "Hallowed Mask", 1, PrefixCategory::None),
+	item(560, "SlimeCrown", "Slime Crown", 20, PrefixCategory::None),
+	item(561, "LightDisc", "Light Disc", 1, PrefixCategory::Melee),
+	item(562, "MusicBoxOverworldDay", "Music Box (Overworld Day)", 1, PrefixCategory::None),
+	item(563, "MusicBoxEerie", "Music Box (Eerie)", 1, PrefixCategory::None),
+	item(564, "MusicBoxNight", "Music Box (Night)", 1, PrefixCategory::None),
+	item(565, "MusicBoxTitle", "Music Box (Title)", 1, PrefixCategory::None),
+	item(566, "MusicBoxUnderground", "Music Box (Underground)", 1, PrefixCategory::None),
+	item(567, "MusicBoxBoss1", "Music Box (Boss 1)", 1, PrefixCategory::None),
+	item(568, "MusicBoxJungle", "Music Box (Jungle)", 1, PrefixCategory::None),
+	item(569, "MusicBoxCorruption", "Music Box (Corruption)", 1, PrefixCategory::None),
+	item(570, "MusicBoxUndergroundCorruption", "Music Box (Underground Corruption)", 1, PrefixCategory::None),
+	item(571, "MusicBoxTheHallow", "Music Box (The Hallow)", 1, PrefixCategory::None),
+	item(572, "MusicBoxBoss2", "Music Box (Boss 2)", 1, PrefixCategory::None),
+	item(573, "MusicBoxUndergroundHallow", "Music Box (Underground Hallow)", 1, PrefixCategory::None),
+	item(574, "MusicBoxBoss3", "Music Box (Boss 3)", 1, PrefixCategory::None),
+	item(575, "SoulofFlight", "Soul of Flight", 9999, PrefixCategory::None),
+	item(576, "MusicBox", "Music Box", 1, PrefixCategory::None),
+	item(577, "DemoniteBrick", "Demonite Brick", 9999, PrefixCategory::None),
+	item(578, "HallowedRepeater", "Hallowed Repeater", 1, PrefixCategory::Ranged),
+	item(579, "Drax", "Drax", 1, PrefixCategory::Weapon),
+	item(580, "Explosives", "Explosives", 9999, PrefixCategory::None),
+	item(581, "InletPump", "Inlet Pump", 9999, PrefixCategory::None),
+	item(582, "OutletPump", "Outlet Pump", 9999, PrefixCategory::None),
+	item(583, "Timer1Second", "1 Second Timer", 9999, PrefixCategory::None),
+	item(584, "Timer3Second", "3 Second Timer", 9999, PrefixCategory::None),
+	item(585, "Timer5Second", "5 Second Timer", 9999, PrefixCategory::None),
+	item(586, "CandyCaneBlock", "Candy Cane Block", 9999, PrefixCategory::None),
+	item(587, "CandyCaneWall", "Candy Cane Wall", 9999, PrefixCategory::None),
+	item(588, "SantaHat", "Santa Hat", 1, PrefixCategory::None),
+	item(589, "SantaShirt", "Santa Shirt", 1, PrefixCategory::None),
+	item(590, "SantaPants", "Santa Pants", 1, PrefixCategory::None),
+	item(591, "GreenCandyCaneBlock", "Green Candy Cane Block", 9999, PrefixCategory::None),
+	item(592, "GreenCandyCaneWall", "Green Candy Cane Wall", 9999, PrefixCategory::None),
+	item(593, "SnowBlock", "Snow Block", 9999, PrefixCategory::None),
+	item(594, "SnowBrick", "Snow Brick", 9999, PrefixCategory::None),
+	item(595, "SnowBrickWall", "Snow Brick Wall", 9999, PrefixCategory::None),
+	item(596, "BlueLight", "Blue Light", 9999, PrefixCategory::None),
+	item(597, "RedLight", "Red Light", 9999, PrefixCategory::None),
+	item(598, "GreenLight", "Green Light", 9999, PrefixCategory::None),
+	item(599, "BluePresent", "Blue Present", 9999, PrefixCategory::None),
+	item(600, "GreenPresent", "Green Present", 9999, PrefixCategory::None),
+	item(601, "YellowPresent", "Yellow Present", 9999, PrefixCategory::None),
+	item(602, "SnowGlobe", "Snow Globe", 20, PrefixCategory::None),
+	item(603, "Carrot", "Carrot", 1, PrefixCategory::None),
+	item(604, "AdamantiteBeam", "Adamantite Beam", 9999, PrefixCategory::None),
+	item(605, "AdamantiteBeamWall", "Adamantite Beam Wall", 9999, PrefixCategory::None),
+	item(606, "DemoniteBrickWall", "Demonite Brick Wall", 9999, PrefixCategory::None),
+	item(607, "SandstoneBrick", "Sandstone Brick", 9999, PrefixCategory::None),
+	item(608, "SandstoneBrickWall", "Sandstone Brick Wall", 9999, PrefixCategory::None),
+	item(609, "EbonstoneBrick", "Ebonstone Brick", 9999, PrefixCategory::None),
+	item(610, "EbonstoneBrickWall", "Ebonstone Brick Wall", 9999, PrefixCategory::None),
+	item(611, "RedStucco", "Red Stucco", 9999, PrefixCategory::None),
+	item(612, "YellowStucco", "Yellow Stucco", 9999, PrefixCategory::None),
+	item(613, "GreenStucco", "Green Stucco", 9999, PrefixCategory::None),
+	item(614, "GrayStucco", "Gray Stucco", 9999, PrefixCategory::None),
+	item(615, "RedStuccoWall", "Red Stucco Wall", 9999, PrefixCategory::None),
+	item(616, "YellowStuccoWall", "Yellow Stucco Wall", 9999, PrefixCategory::None),
+	item(617, "GreenStuccoWall", "Green Stucco Wall", 9999, PrefixCategory::None),
+	item(618, "GrayStuccoWall", "Gray Stucco Wall", 9999, PrefixCategory::None),
+	item(619, "Ebonwood", "Ebonwood", 9999, PrefixCategory::None),
+	item(620, "RichMahogany", "Rich Mahogany", 9999, PrefixCategory::None),
+	item(621, "Pearlwood", "Pearlwood", 9999, PrefixCategory::None),
+	item(622, "EbonwoodWall", "Ebonwood Wall", 9999, PrefixCategory::None),
+	item(623, "RichMahoganyWall", "Rich Mahogany Wall", 9999, PrefixCategory::None),
+	item(624, "PearlwoodWall", "Pearlwood Wall", 9999, PrefixCategory::None),
+	item(625, "EbonwoodChest", "Ebonwood Chest", 9999, PrefixCategory::None),
+	item(626, "RichMahoganyChest", "Rich Mahogany Chest", 9999, PrefixCategory::None),
+	item(627, "PearlwoodChest", "Pearlwood Chest", 9999, PrefixCategory::None),
+	item(628, "EbonwoodChair", "Ebonwood Chair", 9999, PrefixCategory::None),
+	item(629, "RichMahoganyChair", "Rich Mahogany Chair", 9999, PrefixCategory::None),
+	item(630, "PearlwoodChair", "Pearlwood Chair", 9999, PrefixCategory::None),
+	item(631, "EbonwoodPlatform", "Ebonwood Platform", 9999, PrefixCategory::None),
+	item(632, "RichMahoganyPlatform", "Rich Mahogany Platform", 9999, PrefixCategory::None),
+	item(633, "PearlwoodPlatform", "Pearlwood Platform", 9999, PrefixCategory::None),
+	item(634, "BonePlatform", "Bone Platform", 9999, PrefixCategory::None),
+	item(635, "EbonwoodWorkBench", "Ebonwood Work Bench", 9999, PrefixCategory::None),
+	item(636, "RichMahoganyWorkBench", "Rich Mahogany Work Bench", 9999, PrefixCategory::None),
+	item(637, "PearlwoodWorkBench", "Pearlwood Work Bench", 9999, PrefixCategory::None),
+	item(638, "EbonwoodTable", "Ebonwood Table", 9999, PrefixCategory::None),
+	item(639, "RichMahoganyTable", "Rich Mahogany Table", 9999, PrefixCategory::None),
+	item(640, "PearlwoodTable", "Pearlwood Table", 9999, PrefixCategory::None),
+	item(641, "EbonwoodPiano", "Ebonwood Piano", 9999, PrefixCategory::None),
+	item(642, "RichMahoganyPiano", "Rich Mahogany Piano", 9999, PrefixCategory::None),
+	item(643, "PearlwoodPiano", "Pearlwood Piano", 9999, PrefixCategory::None),
+	item(644, "EbonwoodBed", "Ebonwood Bed", 9999, PrefixCategory::None),
+	item(645, "RichMahoganyBed", "Rich Mahogany Bed", 9999, PrefixCategory::None),
+	item(646, "PearlwoodBed", "Pearlwood Bed", 9999, PrefixCategory::None),
+	item(647, "EbonwoodDresser", "Ebonwood Dresser", 9999, PrefixCategory::None),
+	item(648, "RichMahoganyDresser", "Rich Mahogany Dresser", 9999, PrefixCategory::None),
+	item(649, "PearlwoodDresser", "Pearlwood Dresser", 9999, PrefixCategory::None),
+	item(650, "EbonwoodDoor", "Ebonwood Door", 9999, PrefixCategory::None),
+	item(651, "RichMahoganyDoor", "Rich Mahogany Door", 9999, PrefixCategory::None),
+	item(652, "PearlwoodDoor", "Pearlwood Door", 9999, PrefixCategory::None),
+	item(653, "EbonwoodSword", "Ebonwood Sword", 1, PrefixCategory::Melee),
+	item(654, "EbonwoodHammer", "Ebonwood Hammer", 1, PrefixCategory::Melee),
+	item(655, "EbonwoodBow", "Ebonwood Bow", 1, PrefixCategory::Ranged),
+	item(656, "RichMahoganySword", "Rich Mahogany Sword", 1, PrefixCategory::Melee),
+	item(657, "RichMahoganyHammer", "Rich Mahogany Hammer", 1, PrefixCategory::Melee),
+	item(658, "RichMahoganyBow", "Rich Mahogany Bow", 1, PrefixCategory::Ranged),
+	item(659, "PearlwoodSword", "Pearlwood Sword", 1, PrefixCategory::Melee),
+	item(660, "PearlwoodHammer", "Pearlwood Hammer", 1, PrefixCategory::Melee),
+	item(661, "PearlwoodBow", "Pearlwood Bow", 1, PrefixCategory::Ranged),
+	item(662, "RainbowBrick", "Rainbow Brick", 9999, PrefixCategory::None),
+	item(663, "RainbowBrickWall", "Rainbow Brick Wall", 9999, PrefixCategory::None),
+	item(664, "IceBlock", "Ice Block", 9999, PrefixCategory::None),
+	item(665, "RedsWings", "Red's Wings", 1, PrefixCategory::Accessory),
+	item(666, "RedsHelmet", "Red's Helmet", 1, PrefixCategory::None),
+	item(667, "RedsBreastplate", "Red's Breastplate", 1, PrefixCategory::None),
+	item(668, "RedsLeggings", "Red's Leggings", 1, PrefixCategory::None),
+	item(669, "Fish", "Fish", 1, PrefixCategory::None),
+	item(670, "IceBoomerang", "Ice Boomerang", 1, PrefixCategory::Melee),
+	item(671, "Keybrand", "Keybrand", 1, PrefixCategory::Melee),
+	item(672, "Cutlass", "Cutlass", 1, PrefixCategory::Melee),
+	item(673, "BorealWoodWorkBench", "Boreal Wood Work Bench", 9999, PrefixCategory::None),
+	item(674, "TrueExcalibur", "True Excalibur", 1, PrefixCategory::Melee),
+	item(675, "TrueNightsEdge", "True Night's Edge", 1, PrefixCategory::Melee),
+	item(676, "Frostbrand", "Frostbrand", 1, PrefixCategory::Melee),
+	item(677, "BorealWoodTable", "Boreal Wood Table", 9999, PrefixCategory::None),
+	item(678, "RedPotion", "Red Potion", 9999, PrefixCategory::None),
+	item(679, "TacticalShotgun", "Tactical Shotgun", 1, PrefixCategory::Ranged),
+	item(680, "IvyChest", "Ivy Chest", 9999, PrefixCategory::None),
+	item(681, "IceChest", "Ice Chest", 9999, PrefixCategory::None),
+	item(682, "Marrow", "Marrow", 1, PrefixCategory::Ranged),
+	item(683, "UnholyTrident", "Unholy Trident", 1, PrefixCategory::Magic),
+	item(684, "FrostHelmet", "Frost Helmet", 1, PrefixCategory::None),
+	item(685, "FrostBreastplate", "Frost Breastplate", 1, PrefixCategory::None),
+	item(686, "FrostLeggings", "Frost Leggings", 1, PrefixCategory::None),
+	item(687, "TinHelmet", "Tin Helmet", 1, PrefixCategory::None),
+	item(688, "TinChainmail", "Tin Chainmail", 1, PrefixCategory::None),
+	item(689, "TinGreaves", "Tin Greaves", 1, PrefixCategory::None),
+	item(690, "LeadHelmet", "Lead Helmet", 1, PrefixCategory::None),
+	item(691, "LeadChainmail", "Lead Chainmail", 1, PrefixCategory::None),
+	item(692, "LeadGreaves", "Lead Greaves", 1, PrefixCategory::None),
+	item(693, "TungstenHelmet", "Tungsten Helmet", 1, PrefixCategory::None),
+	item(694, "TungstenChainmail", "Tungsten Chainmail", 1, PrefixCategory::None),
+	item(695, "TungstenGreaves", "Tungsten Greaves", 1, PrefixCategory::None),
+	item(696, "PlatinumHelmet", "Platinum Helmet", 1, PrefixCategory::None),
+	item(697, "PlatinumChainmail", "Platinum Chainmail", 1, PrefixCategory::None),
+	item(698, "PlatinumGreaves", "Platinum Greaves", 1, PrefixCategory::None),
+	item(699, "TinOre", "Tin Ore", 9999, PrefixCategory::None),
+	item(700, "LeadOre", "Lead Ore", 9999, PrefixCategory::None),
+	item(701, "TungstenOre", "Tungsten Ore", 9999, PrefixCategory::None),
+	item(702, "PlatinumOre", "Platinum Ore", 9999, PrefixCategory::None),
+	item(703, "TinBar", "Tin Bar", 9999, PrefixCategory::None),
+	item(704, "LeadBar", "Lead Bar", 9999, PrefixCategory::None),
+	item(705, "TungstenBar", "Tungsten Bar", 9999, PrefixCategory::None),
+	item(706, "PlatinumBar", "Platinum Bar", 9999, PrefixCategory::None),
+	item(707, "TinWatch", "Tin Watch", 1, PrefixCategory::Accessory),
+	item(708, "TungstenWatch", "Tungsten Watch", 1, PrefixCategory::Accessory),
+	item(709, "PlatinumWatch", "Platinum Watch", 1, PrefixCategory::Accessory),
+	item(710, "TinChandelier", "Tin Chandelier", 9999, PrefixCategory::None),
+	item(711, "TungstenChandelier", "Tungsten Chandelier", 9999, PrefixCategory::None),
+	item(712, "PlatinumChandelier", "Platinum Chandelier", 9999, PrefixCategory::None),
+	item(713, "PlatinumCandle", "Platinum Candle", 9999, PrefixCategory::None),
+	item(714, "PlatinumCandelabra", "Platinum Candelabra", 9999, PrefixCategory::None),
+	item(715, "PlatinumCrown", "Platinum Crown", 1, PrefixCategory::None),
+	item(716, "LeadAnvil", "Lead Anvil", 9999, PrefixCategory::None),
+	item(717, "TinBrick", "Tin Brick", 9999, PrefixCategory::None),
+	item(718, "TungstenBrick", "Tungsten Brick", 9999, PrefixCategory::None),
+	item(719, "PlatinumBrick", "Platinum Brick", 9999, PrefixCategory::None),
+	item(720, "TinBrickWall", "Tin Brick Wall", 9999, PrefixCategory::None),
+	item(721, "TungstenBrickWall", "Tungsten Brick Wall", 9999, PrefixCategory::None),
+	item(722, "PlatinumBrickWall", "Platinum Brick Wall", 9999, PrefixCategory::None),
+	item(723, "BeamSword", "Beam Sword", 1, PrefixCategory::Melee),
+	item(724, "IceBlade", "Ice Blade", 1, PrefixCategory::Melee),
+	item(725, "IceBow", "Ice Bow", 1, PrefixCategory::Ranged),
+	item(726, "FrostStaff", "Frost Staff", 1, PrefixCategory::Magic),
+	item(727, "WoodHelmet", "Wood Helmet", 1, PrefixCategory::None),
+	item(728, "WoodBreastplate", "Wood Breastplate", 1, PrefixCategory::None),
+	item(729, "WoodGreaves", "Wood Greaves", 1, PrefixCategory::None),
+	item(730, "EbonwoodHelmet", "Ebonwood Helmet", 1, PrefixCategory::None),
+	item(731, "EbonwoodBreastplate", "Ebonwood Breastplate", 1, PrefixCategory::None),
+	item(732, "EbonwoodGreaves", "Ebonwood Greaves", 1, PrefixCategory::None),
+	item(733, "RichMahoganyHelmet", "Rich Mahogany Helmet", 1, PrefixCategory::None),
+	item(734, "RichMahoganyBreastplate", "Rich Mahogany Breastplate", 1, PrefixCategory::None),
+	item(735, "RichMahoganyGreaves", "Rich Mahogany Greaves", 1, PrefixCategory::None),
+	item(736, "PearlwoodHelmet", "Pearlwood Helmet", 1, PrefixCategory::None),
+	item(737, "PearlwoodBreastplate", "Pearlwood Breastplate", 1, PrefixCategory::None),
+	item(738, "PearlwoodGreaves", "Pearlwood Greaves", 1, PrefixCategory::None),
+	item(739, "AmethystStaff", "Amethyst Staff", 1, PrefixCategory::Magic),
+	item(740, "TopazStaff", "Topaz Staff", 1, PrefixCategory::Magic),
+	item(741, "SapphireStaff", "Sapphire Staff", 1, PrefixCategory::Magic),
+	item(742, "EmeraldStaff", "Emerald Staff", 1, PrefixCategory::Magic),
+	item(743, "RubyStaff", "Ruby Staff", 1, PrefixCategory::Magic),
+	item(744, "DiamondStaff", "Diamond Staff", 1, PrefixCategory::Magic),
+	item(745, "GrassWall", "Grass Wall", 9999, PrefixCategory::None),
+	item(746, "JungleWall", "Jungle Wall", 9999, PrefixCategory::None),
+	item(747, "FlowerWall", "Flower Wall", 9999, PrefixCategory::None),
+	item(748, "Jetpack", "Jetpack", 1, PrefixCategory::Accessory),
+	item(749, "ButterflyWings", "Butterfly Wings", 1, PrefixCategory::Accessory),
+	item(750, "CactusWall", "Cactus Wall", 9999, PrefixCategory::None),
+	item(751, "Cloud", "Cloud", 9999, PrefixCategory::None),
+	item(752, "CloudWall", "Cloud Wall", 9999, PrefixCategory::None),
+	item(753, "Seaweed", "Seaweed", 9999, PrefixCategory::None),
+	item(754, "RuneHat", "Rune Hat", 1, PrefixCategory::None),
+	item(755, "RuneRobe", "Rune Robe", 1, PrefixCategory::None),
+	item(756, "MushroomSpear", "Mushroom Spear", 1, PrefixCategory::Melee),
+	item(757, "TerraBlade", "Terra Blade", 1, PrefixCategory::Melee),
+	item(758, "GrenadeLauncher", "Grenade Launcher", 1, PrefixCategory::Ranged),
+	item(759, "RocketLauncher", "Rocket Launcher", 1, PrefixCategory::Ranged),
+	item(760, "ProximityMineLauncher", "Proximity Mine Launcher", 1, PrefixCategory::Ranged),
+	item(761, "FairyWings", "Fairy Wings", 1, PrefixCategory::Accessory),
+	item(762, "SlimeBlock", "Slime Block", 9999, PrefixCategory::None),
+	item(763, "FleshBlock", "Flesh Block", 9999, PrefixCategory::None),
+	item(764, "MushroomWall", "Mushroom Wall", 9999, PrefixCategory::None),
+	item(765, "RainCloud", "Rain Cloud", 9999, PrefixCategory::None),
+	item(766, "BoneBlock", "Bone Block", 9999, PrefixCategory::None),
+	item(767, "FrozenSlimeBlock", "Frozen Slime Block", 9999, PrefixCategory::None),
+	item(768, "BoneBlockWall", "Bone Block Wall", 9999, PrefixCategory::None),
+	item(769, "SlimeBlockWall", "Slime Block Wall", 9999, PrefixCategory::None),
+	item(770, "FleshBlockWall", "Flesh Block Wall", 9999, PrefixCategory::None),
+	item(771, "RocketI", "Rocket I", 9999, PrefixCategory::None),
+	item(772, "RocketII", "Rocket II", 9999, PrefixCategory::None),
+	item(773, "RocketIII", "Rocket III", 9999, PrefixCategory::None),
+	item(774, "RocketIV", "Rocket IV", 9999, PrefixCategory::None),
+	item(775, "AsphaltBlock", "Asphalt Block", 9999, PrefixCategory::None),
+	item(776, "CobaltPickaxe", "Cobalt Pickaxe", 1, PrefixCategory::Melee),
+	item(777, "MythrilPickaxe", "Mythril Pickaxe", 1, PrefixCategory::Melee),
+	item(778, "AdamantitePickaxe", "Adamantite Pickaxe", 1, PrefixCategory::Melee),
+	item(779, "Clentaminator", "Clentaminator", 1, PrefixCategory::Ranged),
+	item(780, "GreenSolution", "Green Solution", 9999, PrefixCategory::None),
+	item(781, "BlueSolution", "Blue Solution", 9999, PrefixCategory::None),
+	item(782, "PurpleSolution", "Purple Solution", 9999, PrefixCategory::None),
+	item(783, "DarkBlueSolution", "Dark Blue Solution", 9999, PrefixCategory::None),
+	item(784, "RedSolution", "Red Solution", 9999, PrefixCategory::None),
+	item(785, "HarpyWings", "Harpy Wings", 1, PrefixCategory::Accessory),
+	item(786, "BoneWings", "Bone Wings", 1, PrefixCategory::Accessory),
+	item(787, "Hammush", "Hammush", 1, PrefixCategory::Melee),
+	item(788, "NettleBurst", "Nettle Burst", 1, PrefixCategory::Magic),
+	item(789, "AnkhBanner", "Ankh Banner", 9999, PrefixCategory::None),
+	item(790, "SnakeBanner", "Snake Banner", 9999, PrefixCategory::None),
+	item(791, "OmegaBanner", "Omega Banner", 9999, PrefixCategory::None),
+	item(792, "CrimsonHelmet", "Crimson Helmet", 1, PrefixCategory::None),
+	item(793, "CrimsonScalemail", "Crimson Scalemail", 1, PrefixCategory::None),
+	item(794, "CrimsonGreaves", "Crimson Greaves", 1, PrefixCategory::None),
+	item(795, "BloodButcherer", "Blood Butcherer", 1, PrefixCategory::Melee),
+	item(796, "TendonBow", "Tendon Bow", 1, PrefixCategory::Ranged),
+	item(797, "FleshGrinder", "Flesh Grinder", 1, PrefixCategory::Melee),
+	item(798, "DeathbringerPickaxe", "Deathbringer Pickaxe", 1, PrefixCategory::Melee),
+	item(799, "BloodLustCluster", "Blood Lust Cluster", 1, PrefixCategory::Melee),
+	item(800, "TheUndertaker", "The Undertaker", 1, PrefixCategory::Ranged),
+	item(801, "TheMeatball", "The Meatball", 1, PrefixCategory::Melee),
+	item(802, "TheRottedFork", "The Rotted Fork", 1, PrefixCategory::Melee),
+	item(803, "EskimoHood", "Eskimo Hood", 1, PrefixCategory::None),
+	item(804, "EskimoCoat", "Eskimo Coat", 1, PrefixCategory::None),
+	item(805, "EskimoPants", "Eskimo Pants", 1, PrefixCategory::None),
+	item(806, "LivingWoodChair", "Living Wood Chair", 9999, PrefixCategory::None),
+	item(807, "CactusChair", "Cactus Chair", 9999, PrefixCategory::None),
+	item(808, "BoneChair", "Bone Chair", 9999, PrefixCategory::None),
+	item(809, "FleshChair", "Flesh Chair", 9999, PrefixCategory::None),
+	item(810, "MushroomChair", "Mushroom Chair", 9999, PrefixCategory::None),
+	item(811, "BoneWorkBench", "Bone Work Bench", 9999, PrefixCategory::None),
+	item(812, "CactusWorkBench", "Cactus Work Bench", 9999, PrefixCategory::None),
+	item(813, "FleshWorkBench", "Flesh Work Bench", 9999, PrefixCategory::None),
+	item(814, "MushroomWorkBench", "Mushroom Work Bench", 9999, PrefixCategory::None),
+	item(815, "SlimeWorkBench", "Slime Work Bench", 9999, PrefixCategory::None),
+	item(816, "CactusDoor", "Cactus Door", 9999, PrefixCategory::None),
+	item(817, "FleshDoor", "Flesh Door", 9999, PrefixCategory::None),
+	item(818, "MushroomDoor", "Mushroom Door", 9999, PrefixCategory::None),
+	item(819, "LivingWoodDoor", "Living Wood Door", 9999, PrefixCategory::None),
+	item(820, "BoneDoor", "Bone Door", 9999, PrefixCategory::None),
+	item(821, "FlameWings", "Flame Wings", 1, PrefixCategory::Accessory),
+	item(822, "FrozenWings", "Frozen Wings", 1, PrefixCategory::Accessory),
+	item(823, "GhostWings", "Spectre Wings", 1, PrefixCategory::Accessory),
+	item(824, "SunplateBlock", "Sunplate Block", 9999, PrefixCategory::None),
+	item(825, "DiscWall", "Disc Wall", 9999, PrefixCategory::None),
+	item(826, "SkywareChair", "Skyware Chair", 9999, PrefixCategory::None),
+	item(827, "BoneTable", "Bone Table", 9999, PrefixCategory::None),
+	item(828, "FleshTable", "Flesh Table", 9999, PrefixCategory::None),
+	item(829, "LivingWoodTable", "Living Wood Table", 9999, PrefixCategory::None),
+	item(830, "SkywareTable", "Skyware Table", 9999, PrefixCategory::None),
+	item(831, "LivingWoodChest", "Living Wood Chest", 9999, PrefixCategory::None),
+	item(832, "LivingWoodWand", "Living Wood Wand", 1, PrefixCategory::None),
+	item(833, "PurpleIceBlock", "Purple Ice Block", 9999, PrefixCategory::None),
+	item(834, "PinkIceBlock", "Pink Ice Block", 9999, PrefixCategory::None),
+	item(835, "RedIceBlock", "Red Ice Block", 9999, PrefixCategory::None),
+	item(836, "CrimstoneBlock", "Crimstone Block", 9999, PrefixCategory::None),
+	item(837, "SkywareDoor", "Skyware Door", 9999, PrefixCategory::None),
+	item(838, "SkywareChest", "Skyware Chest", 9999, PrefixCategory::None),
+	item(839, "SteampunkHat", "Steampunk Hat", 1, PrefixCategory::None),
+	item(840, "SteampunkShirt", "Steampunk Shirt", 1, PrefixCategory::None),
+	item(841, "SteampunkPants", "Steampunk Pants", 1, PrefixCategory::None),
+	item(842, "BeeHat", "Bee Hat", 1, PrefixCategory::None),
+	item(843, "BeeShirt", "Bee Shirt", 1, PrefixCategory::None),
+	item(844, "BeePants", "Bee Pants", 1, PrefixCategory::None),
+	item(845, "WorldBanner", "World Banner", 9999, PrefixCategory::None),
+	item(846, "SunBanner", "Sun Banner", 9999, PrefixCategory::None),
+	item(847, "GravityBanner", "Gravity Banner", 9999, PrefixCategory::None),
+	item(848, "PharaohsMask", "Pharaoh's Mask", 1, PrefixCategory::None),
+	item(849, "Actuator", "Actuator", 9999, PrefixCategory::None),
+	item(850, "BlueWrench", "Blue Wrench", 1, PrefixCategory::None),
+	item(851, "GreenWrench", "Green Wrench", 1, PrefixCategory::None),
+	item(852, "BluePressurePlate", "Blue Pressure Plate", 1, PrefixCategory::None),
+	item(853, "YellowPressurePlate", "Yellow Pressure Plate", 1, PrefixCategory::None),
+	item(854, "DiscountCard", "Discount Card", 1, PrefixCategory::Accessory),
+	item(855, "LuckyCoin", "Lucky Coin", 1, PrefixCategory::Accessory),
+	item(856, "UnicornonaStick", "Unicorn on a Stick", 1, PrefixCategory::None),
+	item(857, "SandstorminaBottle", "Sandstorm in a Bottle", 1, PrefixCategory::Accessory),
+	item(858, "BorealWoodSofa", "Boreal Wood Sofa", 9999, PrefixCategory::None),
+	item(859, "BeachBall", "Beach Ball", 1, PrefixCategory::None),
+	item(860, "CharmofMyths", "Charm of Myths", 1, PrefixCategory::Accessory),
+	item(861, "MoonShell", "Moon Shell", 1, PrefixCategory::Accessory),
+	item(862, "StarVeil", "Star Veil", 1, PrefixCategory::Accessory),
+	item(863, "WaterWalkingBoots", "Water Walking Boots", 1, PrefixCategory::Accessory),
+	item(864, "Tiara", "Tiara", 1, PrefixCategory::None),
+	item(865, "PrincessDress", "Princess Dress", 1, PrefixCategory::None),
+	item(866, "PharaohsRobe", "Pharaoh's Robe", 1, PrefixCategory::None),
+	item(867, "GreenCap", "Green Cap", 1, PrefixCategory::None),
+	item(868, "MushroomCap", "Mushroom Cap", 1, PrefixCategory::None),
+	item(869, "TamOShanter", "Tam O' Shanter", 9999, PrefixCategory::None),
+	item(870, "MummyMask", "Mummy Mask", 1, PrefixCategory::None),
+	item(871, "MummyShirt", "Mummy Shirt", 1, PrefixCategory::None),
+	item(872, "MummyPants", "Mummy Pants", 1, PrefixCategory::None),
+	item(873, "CowboyHat", "Cowboy Hat", 1, PrefixCategory::None),
+	item(874, "CowboyJacket", "Cowboy Jacket", 1, PrefixCategory::None),
+	item(875, "CowboyPants", "Cowboy Pants", 1, PrefixCategory::None),
+	item(876, "PirateHat", "Pirate Hat", 1, PrefixCategory::None),
+	item(877, "PirateShirt", "Pirate Shirt", 1, PrefixCategory::None),
+	item(878, "PiratePants", "Pirate Pants", 1, PrefixCategory::None),
+	item(879, "VikingHelmet", "Viking Helmet", 1, PrefixCategory::None),
+	item(880, "CrimtaneOre", "Crimtane Ore", 9999, PrefixCategory::None),
+	item(881, "CactusSword", "Cactus Sword", 1, PrefixCategory::Melee),
+	item(882, "CactusPickaxe", "Cactus Pickaxe", 1, PrefixCategory::Melee),
+	item(883, "IceBrick", "Ice Brick", 9999, PrefixCategory::None),
+	item(884, "IceBrickWall", "Ice Brick Wall", 9999, PrefixCategory::None),
+	item(885, "AdhesiveBandage", "Adhesive Bandage", 1, PrefixCategory::Accessory),
+	item(886, "ArmorPolish", "Armor Polish", 1, PrefixCategory::Accessory),
+	item(887, "Bezoar", "Bezoar", 1, PrefixCategory::Accessory),
+	item(888, "Blindfold", "Blindfold", 1, PrefixCategory::Accessory),
+	item(889, "FastClock", "Fast Clock", 1, PrefixCategory::Accessory),
+	item(890, "Megaphone", "Megaphone", 1, PrefixCategory::Accessory),
+	item(891, "Nazar", "Nazar", 1, PrefixCategory::Accessory),
+	item(892, "Vitamins", "Vitamins", 1, PrefixCategory::Accessory),
+	item(893, "TrifoldMap", "Trifold Map", 1, PrefixCategory::Accessory),
+	item(894, "CactusHelmet", "Cactus Helmet", 1, PrefixCategory::None),
+	item(895, "CactusBreastplate", "Cactus Breastplate", 1, PrefixCategory::None),
+	item(896, "CactusLeggings", "Cactus Leggings", 1, PrefixCategory::None),
+	item(897, "PowerGlove", "Power Glove", 1, PrefixCategory::Accessory),
+	item(898, "LightningBoots", "Lightning Boots", 1, PrefixCategory::Accessory),
+	item(899, "SunStone", "Sun Stone", 1, PrefixCategory::Accessory),
+	item(900, "MoonStone", "Moon Stone", 1, PrefixCategory::Accessory),
+	item(901, "ArmorBracing", "Armor Bracing", 1, PrefixCategory::Accessory),
+	item(902, "MedicatedBandage", "Medicated Bandage", 1, PrefixCategory::Accessory),
+	item(903, "ThePlan", "The Plan", 1, PrefixCategory::Accessory),
+	item(904, "CountercurseMantra", "Countercurse Mantra", 1, PrefixCategory::Accessory),
+	item(905, "CoinGun", "Coin Gun", 1, PrefixCategory::Ranged),
+	item(906, "LavaCharm", "Lava Charm", 1, PrefixCategory::Accessory),
+	item(907, "ObsidianWaterWalkingBoots", "Obsidian Water Walking Boots", 1, PrefixCategory::Accessory),
+	item(908, "LavaWaders", "Lava Waders", 1, PrefixCategory::Accessory),
+	item(909, "PureWaterFountain", "Pure Water Fountain", 9999, PrefixCategory::None),
+	item(910, "DesertWaterFountain", "Desert Water Fountain", 9999, PrefixCategory::None),
+	item(911, "Shadewood", "Shadewood", 9999, PrefixCategory::None),
+	item(912, "ShadewoodDoor", "Shadewood Door", 9999, PrefixCategory::None),
+	item(913, "ShadewoodPlatform", "Shadewood Platform", 9999, PrefixCategory::None),
+	item(914, "ShadewoodChest", "Shadewood Chest", 9999, PrefixCategory::None),
+	item(915, "ShadewoodChair", "Shadewood Chair", 9999, PrefixCategory::None),
+	item(916, "ShadewoodWorkBench", "Shadewood Work Bench", 9999, PrefixCategory::None),
+	item(917, "ShadewoodTable", "Shadewood Table", 9999, PrefixCategory::None),
+	item(918, "ShadewoodDresser", "Shadewood Dresser", 9999, PrefixCategory::None),
+	item(919, "ShadewoodPiano", "Shadewood Piano", 9999, PrefixCategory::None),
+	item(920, "ShadewoodBed", "Shadewood Bed", 9999, PrefixCategory::None),
+	item(921, "ShadewoodSword", "Shadewood Sword", 1, PrefixCategory::Melee),
+	item(922, "ShadewoodHammer", "Shadewood Hammer", 1, PrefixCategory::Melee),
+	item(923, "ShadewoodBow", "Shadewood Bow", 1, PrefixCategory::Ranged),
+	item(924, "ShadewoodHelmet", "Shadewood Helmet", 1, PrefixCategory::None),
+	item(925, "ShadewoodBreastplate", "Shadewood Breastplate", 1, PrefixCategory::None),
+	item(926, "ShadewoodGreaves", "Shadewood Greaves", 1, PrefixCategory::None),
+	item(927, "ShadewoodWall", "Shadewood Wall", 9999, PrefixCategory::None),
+	item(928, "Cannon", "Cannon", 9999, PrefixCategory::None),
+	item(929, "Cannonball", "Cannonball", 9999, PrefixCategory::None),
+	item(930, "FlareGun", "Flare Gun", 1, PrefixCategory::Ranged),
+	item(931, "Flare", "Flare", 9999, PrefixCategory::None),
+	item(932, "BoneWand", "Bone Wand", 1, PrefixCategory::None),
+	item(933, "LeafWand", "Leaf Wand", 1, PrefixCategory::None),
+	item(934, "FlyingCarpet", "Flying Carpet", 1, PrefixCategory::Accessory),
+	item(935, "AvengerEmblem", "Avenger Emblem", 1, PrefixCategory::Accessory),
+	item(936, "MechanicalGlove", "Mechanical Glove", 1, PrefixCategory::Accessory),
+	item(937, "LandMine", "Land Mine", 9999, PrefixCategory::None),
+	item(938, "PaladinsShield", "Paladin's Shield", 1, PrefixCategory::Accessory),
+	item(939, "WebSlinger", "Web Slinger", 9999, PrefixCategory::None),
+	item(940, "JungleWaterFountain", "Jungle Water Fountain", 9999, PrefixCategory::None),
+	item(941, "IcyWaterFountain", "Icy Water Fountain", 9999, PrefixCategory::None),
+	item(942, "CorruptWaterFountain", "Corrupt Water Fountain", 9999, PrefixCategory::None),
+	item(943, "CrimsonWaterFountain", "Crimson Water Fountain", 9999, PrefixCategory::None),
+	item(944, "HallowedWaterFountain", "Hallowed Water Fountain", 9999, PrefixCategory::None),
+	item(945, "BloodWaterFountain", "Blood Water Fountain", 9999, PrefixCategory::None),
+	item(946, "Umbrella", "Umbrella", 1, PrefixCategory::Melee),
+	item(947, "ChlorophyteOre", "Chlorophyte Ore", 9999, PrefixCategory::None),
+	item(948, "SteampunkWings", "Steampunk Wings", 1, PrefixCategory::Accessory),
+	item(949, "Snowball", "Snowball", 9999, PrefixCategory::None),
+	item(950, "IceSkates", "Ice Skates", 1, PrefixCategory::Accessory),
+	item(951, "SnowballLauncher", "Snowball Launcher", 1, PrefixCategory::Ranged),
+	item(952, "WebCoveredChest", "Web Covered Chest", 9999, PrefixCategory::None),
+	item(953, "ClimbingClaws", "Climbing Claws", 1, PrefixCategory::Accessory),
+	item(954, "AncientIronHelmet", "Ancient Iron Helmet", 1, PrefixCategory::None),
+	item(955, "AncientGoldHelmet", "Ancient Gold Helmet", 1, PrefixCategory::None),
+	item(956, "AncientShadowHelmet", "Ancient Shadow Helmet", 1, PrefixCategory::None),
+	item(957, "AncientShadowScalemail", "Ancient Shadow Scalemail", 1, PrefixCategory::None),
+	item(958, "AncientShadowGreaves", "Ancient Shadow Greaves", 1, PrefixCategory::None),
+	item(959, "AncientNecroHelmet", "Ancient Necro Helmet", 1, PrefixCategory::None),
+	item(960, "AncientCobaltHelmet", "Ancient Cobalt Helmet", 1, PrefixCategory::None),
+	item(961, "AncientCobaltBreastplate", "Ancient Cobalt Breastplate", 1, PrefixCategory::None),
+	item(962, "AncientCobaltLeggings", "Ancient Cobalt Leggings", 1, PrefixCategory::None),
+	item(963, "BlackBelt", "Black Belt", 1, PrefixCategory::Accessory),
+	item(964, "Boomstick", "Boomstick", 1, PrefixCategory::Ranged),
+	item(965, "Rope", "Rope", 9999, PrefixCategory::None),
+	item(966, "Campfire", "Campfire", 9999, PrefixCategory::None),
+	item(967, "Marshmallow", "Marshmallow", 9999, PrefixCategory::None),
+	item(968, "MarshmallowonaStick", "Marshmallow on a Stick", 1, PrefixCategory::None),
+	item(969, "CookedMarshmallow", "Cooked Marshmallow", 9999, PrefixCategory::None),
+	item(970, "RedRocket", "Red Rocket", 9999, PrefixCategory::None),
+	item(971, "GreenRocket", "Green Rocket", 9999, PrefixCategory::None),
+	item(972, "BlueRocket", "Blue Rocket", 9999, PrefixCategory::None),
+	item(973, "YellowRocket", "Yellow Rocket", 9999, PrefixCategory::None),
+	item(974, "IceTorch", "Ice Torch", 9999, PrefixCategory::None),
+	item(975, "ShoeSpikes", "Shoe Spikes", 1, PrefixCategory::Accessory),
+	item(976, "TigerClimbingGear", "Tiger Climbing Gear", 1, PrefixCategory::Accessory),
+	item(977, "Tabi", "Tabi", 1, PrefixCategory::Accessory),
+	item(978, "PinkEskimoHood", "Pink Eskimo Hood", 1, PrefixCategory::None),
+	item(979, "PinkEskimoCoat", "Pink Eskimo Coat", 1, PrefixCategory::None),
+	item(980, "PinkEskimoPants", "Pink Eskimo Pants", 1, PrefixCategory::None),
+	item(981, "PinkThread", "Pink Thread", 9999, PrefixCategory::None),
+	item(982, "ManaRegenerationBand", "Mana Regeneration Band", 1, PrefixCategory::Accessory),
+	item(983, "SandstorminaBalloon", "Sandstorm in a Balloon", 1, PrefixCategory::Accessory),
+	item(984, "MasterNinjaGear", "Master Ninja Gear", 1, PrefixCategory::Accessory),
+	item(985, "RopeCoil", "Rope Coil", 9999, PrefixCategory::None),
+	item(986, "Blowgun", "Blowgun", 1, PrefixCategory::Ranged),
+	item(987, "BlizzardinaBottle", "Blizzard in a Bottle", 1, PrefixCategory::Accessory),
+	item(988, "FrostburnArrow", "Frostburn Arrow", 9999, PrefixCategory::None),
+	item(989, "EnchantedSword", "Enchanted Sword", 1, PrefixCategory::Melee),
+	item(990, "PickaxeAxe", "Pickaxe Axe", 1, PrefixCategory::Melee),
+	item(991, "CobaltWaraxe", "Cobalt Waraxe", 1, PrefixCategory::Melee),
+	item(992, "MythrilWaraxe", "Mythril Waraxe", 1, PrefixCategory::Melee),
+	item(993, "AdamantiteWaraxe", "Adamantite Waraxe", 1, PrefixCategory::Melee),
+	item(994, "EatersBone", "Eater's Bone", 1, PrefixCategory::None),
+	item(995, "BlendOMatic", "Blend-O-Matic", 9999, PrefixCategory::None),
+	item(996, "MeatGrinder", "Meat Grinder", 9999, PrefixCategory::None),
+	item(997, "Extractinator", "Extractinator", 9999, PrefixCategory::None),
+	item(998, "Solidifier", "Solidifier", 9999, PrefixCategory::None),
+	item(999, "Amber", "Amber", 9999, PrefixCategory::None),
+	item(1000, "ConfettiGun", "Confetti Gun", 1, PrefixCategory::None),
+	item(1001, "ChlorophyteMask", "Chlorophyte Mask", 1, PrefixCategory::None),
+	item(1002, "ChlorophyteHelmet", "Chlorophyte Helmet", 1, PrefixCategory::None),
+	item(1003, "ChlorophyteHeadgear", "Chlorophyte Headgear", 1, PrefixCategory::None),
+	item(1004, "ChlorophytePlateMail", "Chlorophyte Plate Mail", 1, PrefixCategory::None),
+	item(1005, "ChlorophyteGreaves", "Chlorophyte Greaves", 1, PrefixCategory::None),
+	item(1006, "ChlorophyteBar", "Chlorophyte Bar", 9999, PrefixCategory::None),
+	item(1007, "RedDye", "Red Dye", 9999, PrefixCategory::None),
+	item(1008, "OrangeDye", "Orange Dye", 9999, PrefixCategory::None),
+	item(1009, "YellowDye", "Yellow Dye", 9999, PrefixCategory::None),
+	item(1010, "LimeDye", "Lime Dye", 9999, PrefixCategory::None),
+	item(1011, "GreenDye", "Green Dye", 9999, PrefixCategory::None),
+	item(1012, "TealDye", "Teal Dye", 9999, PrefixCategory::None),
+	item(1013, "CyanDye", "Cyan Dye", 9999, PrefixCategory::None),
+	item(1014, "SkyBlueDye", "Sky Blue Dye", 9999, PrefixCategory::None),
+	item(1015, "BlueDye", "Blue Dye", 9999, PrefixCategory::None),
+	item(1016, "PurpleDye", "Purple Dye", 9999, PrefixCategory::None),
+	item(1017, "VioletDye", "Violet Dye", 9999, PrefixCategory::None),
+	item(1018, "PinkDye", "Pink Dye", 9999, PrefixCategory::None),
+	item(1019, "RedandBlackDye", "Red and Black Dye", 9999, PrefixCategory::None),
+	item(1020, "OrangeandBlackDye", "Orange and Black Dye", 9999, PrefixCategory::None),
+	item(1021, "YellowandBlackDye", "Yellow and Black Dye", 9999, PrefixCategory::None),
+	item(1022, "LimeandBlackDye", "Lime and Black Dye", 9999, PrefixCategory::None),
+	item(1023, "GreenandBlackDye", "Green and Black Dye", 9999, PrefixCategory::None),
+	item(1024, "TealandBlackDye", "Teal and Black Dye", 9999, PrefixCategory::None),
+	item(1025, "CyanandBlackDye", "Cyan and Black Dye", 9999, PrefixCategory::None),
+	item(1026, "SkyBlueandBlackDye", "Sky Blue and Black Dye", 9999, PrefixCategory::None),
+	item(1027, "BlueandBlackDye", "Blue and Black Dye", 9999, PrefixCategory::None),
+	item(1028, "PurpleandBlackDye", "Purple and Black Dye", 9999, PrefixCategory::None),
+	item(1029, "VioletandBlackDye", "Violet and Black Dye", 9999, PrefixCategory::None),
+	item(1030, "PinkandBlackDye", "Pink and Black Dye", 9999, PrefixCategory::None),
+	item(1031, "FlameDye", "Flame Dye", 9999, PrefixCategory::None),
+	item(1032, "FlameAndBlackDye", "Flame and Black Dye", 9999, PrefixCategory::None),
+	item(1033, "GreenFlameDye", "Green Flame Dye", 9999, PrefixCategory::None),
+	item(1034, "GreenFlameAndBlackDye", "Green Flame and Black Dye", 9999, PrefixCategory::None),
+	item(1035, "BlueFlameDye", "Blue Flame Dye", 9999, PrefixCategory::None),
+	item(1036, "BlueFlameAndBlackDye", "Blue Flame and Black Dye", 9999, PrefixCategory::None),
+	item(1037, "SilverDye", "Silver Dye", 9999, PrefixCategory::None),
+	item(1038, "BrightRedDye", "Bright Red Dye", 9999, PrefixCategory::None),
+	item(1039, "BrightOrangeDye", "Bright Orange Dye", 9999, PrefixCategory::None),
+	item(1040, "BrightYellowDye", "Bright Yellow Dye", 9999, PrefixCategory::None),
+	item(1041, "BrightLimeDye", "Bright Lime Dye", 9999, PrefixCategory::None),
+	item(1042, "BrightGreenDye", "Bright Green Dye", 9999, PrefixCategory::None),
+	item(1043, "BrightTealDye", "Bright Teal Dye", 9999, PrefixCategory::None),
+	item(1044, "BrightCyanDye", "Bright Cyan Dye", 9999, PrefixCategory::None),
+	item(1045, "BrightSkyBlueDye", "Bright Sky Blue Dye", 9999, PrefixCategory::None),
+	item(1046, "BrightBlueDye", "Bright Blue Dye", 9999, PrefixCategory::None),
+	item(1047, "BrightPurpleDye", "Bright Purple Dye", 9999, PrefixCategory::None),
+	item(1048, "BrightVioletDye", "Bright Violet Dye", 9999, PrefixCategory::None),
+	item(1049, "BrightPinkDye", "Bright Pink Dye", 9999, PrefixCategory::None),
+	item(1050, "BlackDye", "Black Dye", 9999, PrefixCategory::None),
+	item(1051, "RedandSilverDye", "Red and Silver Dye", 9999, PrefixCategory::None),
+	item(1052, "OrangeandSilverDye", "Orange and Silver Dye", 9999, PrefixCategory::None),
+	item(1053, "YellowandSilverDye", "Yellow and Silver Dye", 9999, PrefixCategory::None),
+	item(1054, "LimeandSilverDye", "Lime and Silver Dye", 9999, PrefixCategory::None),
+	item(1055, "GreenandSilverDye", "Green and Silver Dye", 9999, PrefixCategory::None),
+	item(1056, "TealandSilverDye", "Teal and Silver Dye", 9999, PrefixCategory::None),
+	item(1057, "CyanandSilverDye", "Cyan and Silver Dye", 9999, PrefixCategory::None),
+	item(1058, "SkyBlueandSilverDye", "Sky Blue and Silver Dye", 9999, PrefixCategory::None),
+	item(1059, "BlueandSilverDye", "Blue and Silver Dye", 9999, PrefixCategory::None),
+	item(1060, "PurpleandSilverDye", "Purple and Silver Dye", 9999, PrefixCategory::None),
+	item(1061, "VioletandSilverDye", "Violet and Silver Dye", 9999, PrefixCategory::None),
+	item(1062, "PinkandSilverDye", "Pink and Silver Dye", 9999, PrefixCategory::None),
+	item(1063, "IntenseFlameDye", "Intense Flame Dye", 9999, PrefixCategory::None),
+	item(1064, "IntenseGreenFlameDye", "Intense Green Flame Dye", 9999, PrefixCategory::None),
+	item(1065, "IntenseBlueFlameDye", "Intense Blue Flame Dye", 9999, PrefixCategory::None),
+	item(1066, "RainbowDye", "Rainbow Dye", 9999, PrefixCategory::None),
+	item(1067, "IntenseRainbowDye", "Intense Rainbow Dye", 9999, PrefixCategory::None),
+	item(1068, "YellowGradientDye", "Yellow Gradient Dye", 9999, PrefixCategory::None),
+	item(1069, "CyanGradientDye", "Cyan Gradient Dye", 9999, PrefixCategory::None),
+	item(1070, "VioletGradientDye", "Violet Gradient Dye", 9999, PrefixCategory::None),
+	item(1071, "Paintbrush", "Paintbrush", 1, PrefixCategory::None),
+	item(1072, "PaintRoller", "Paint Roller", 1, PrefixCategory::None),
+	item(1073, "RedPaint", "Red Paint", 9999, PrefixCategory::None),
+	item(1074, "OrangePaint", "Orange Paint", 9999, PrefixCategory::None),
+	item(1075, "YellowPaint", "Yellow Paint", 9999, PrefixCategory::None),
+	item(1076, "LimePaint", "Lime Paint", 9999, PrefixCategory::None),
+	item(1077, "GreenPaint", "Green Paint", 9999, PrefixCategory::None),
+	item(1078, "TealPaint", "Teal Paint", 9999, PrefixCategory::None),
+	item(1079, "CyanPaint", "Cyan Paint", 9999, PrefixCategory::None),
+	item(1080, "SkyBluePaint", "Sky Blue Paint", 9999, PrefixCategory::None),
+	item(1081, "BluePaint", "Blue Paint", 9999, PrefixCategory::None),
+	item(1082, "PurplePaint", "Purple Paint", 9999, PrefixCategory::None),
+	item(1083, "VioletPaint", "Violet Paint", 9999, PrefixCategory::None),
+	item(1084, "PinkPaint", "Pink Paint", 9999, PrefixCategory::None),
+	item(1085, "DeepRedPaint", "Deep Red Paint", 9999, PrefixCategory::None),
+	item(1086, "DeepOrangePaint", "Deep Orange Paint", 9999, PrefixCategory::None),
+	item(1087, "DeepYellowPaint", "Deep Yellow Paint", 9999, PrefixCategory::None),
+	item(1088, "DeepLimePaint", "Deep Lime Paint", 9999, PrefixCategory::None),
+	item(1089, "DeepGreenPaint", "Deep Green Paint", 9999, PrefixCategory::None),
+	item(1090, "DeepTealPaint", "Deep Teal Paint", 9999, PrefixCategory::None),
+	item(1091, "DeepCyanPaint", "Deep Cyan Paint", 9999, PrefixCategory::None),
+	item(1092, "DeepSkyBluePaint", "Deep Sky Blue Paint", 9999, PrefixCategory::None),
+	item(1093, "DeepBluePaint", "Deep Blue Paint", 9999, PrefixCategory::None),
+	item(1094, "DeepPurplePaint", "Deep Purple Paint", 9999, PrefixCategory::None),
+	item(1095, "DeepVioletPaint", "Deep Violet Paint", 9999, PrefixCategory::None),
+	item(1096, "DeepPinkPaint", "Deep Pink Paint", 9999, PrefixCategory::None),
+	item(1097, "BlackPaint", "Black Paint", 9999, PrefixCategory::None),
+	item(1098, "WhitePaint", "White Paint", 9999, PrefixCategory::None),
+	item(1099, "GrayPaint", "Gray Paint", 9999, PrefixCategory::None),
+	item(1100, "PaintScraper", "Paint Scraper", 1, PrefixCategory::None),
+	item(1101, "LihzahrdBrick", "Lihzahrd Brick", 9999, PrefixCategory::None),
+	item(1102, "LihzahrdBrickWall", "Lihzahrd Brick Wall", 9999, PrefixCategory::None),
+	item(1103, "SlushBlock", "Slush Block", 9999, PrefixCategory::None),
+	item(1104, "PalladiumOre", "Palladium Ore", 9999, PrefixCategory::None),
+	item(1105, "OrichalcumOre", "Orichalcum Ore", 9999, PrefixCategory::None),
+	item(1106, "TitaniumOre", "Titanium Ore", 9999, PrefixCategory::None),
+	item(1107, "TealMushroom", "Teal Mushroom", 9999, PrefixCategory::None),
+	item(1108, "GreenMushroom", "Green Mushroom", 9999, PrefixCategory::None),
+	item(1109, "SkyBlueFlower", "Sky Blue Flower", 9999, PrefixCategory::None),
+	item(1110, "YellowMarigold", "Yellow Marigold", 9999, PrefixCategory::None),
+	item(1111, "BlueBerries", "Blue Berries", 9999, PrefixCategory::None),
+	item(1112, "LimeKelp", "Lime Kelp", 9999, PrefixCategory::None),
+	item(1113, "PinkPricklyPear", "Pink Prickly Pear", 9999, PrefixCategory::None),
+	item(1114, "OrangeBloodroot", "Orange Bloodroot", 9999, PrefixCategory::None),
+	item(1115, "RedHusk", "Red Husk", 9999, PrefixCategory::None),
+	item(1116, "CyanHusk", "Cyan Husk", 9999, PrefixCategory::None),
+	item(1117, "VioletHusk", "Violet Husk", 9999, PrefixCategory::None),
+	item(1118, "PurpleMucos", "Purple Mucos", 9999, PrefixCategory::None),
+	item(1119, "BlackInk", "Black Ink", 9999, PrefixCategory::None),
+	item(1120, "DyeVat", "Dye Vat", 9999, PrefixCategory::None),
+	item(1121, "BeeGun", "Bee Gun", 1, PrefixCategory::Magic),
+	item(1122, "PossessedHatchet", "Possessed Hatchet", 1, PrefixCategory::Melee),
+	item(1123, "BeeKeeper", "Bee Keeper", 1, PrefixCategory::Melee),
+	item(1124, "Hive", "Hive", 9999, PrefixCategory::None),
+	item(1125, "HoneyBlock", "Honey Block", 9999, PrefixCategory::None),
+	item(1126, "HiveWall", "Hive Wall", 9999, PrefixCategory::None),
+	item(1127, "CrispyHoneyBlock", "Crispy Honey Block", 9999, PrefixCategory::None),
+	item(1128, "HoneyBucket", "Honey Bucket", 9999, PrefixCategory::None),
+	item(1129, "HiveWand", "Hive Wand", 1, PrefixCategory::None),
+	item(1130, "Beenade", "Beenade", 9999, PrefixCategory::None),
+	item(1131, "GravityGlobe", "Gravity Globe", 1, PrefixCategory::Accessory),
+	item(1132, "HoneyComb", "Honey Comb", 1, PrefixCategory::Accessory),
+	item(1133, "Abeemination", "Abeemination", 20, PrefixCategory::None),
+	item(1134, "BottledHoney", "Bottled Honey", 9999, PrefixCategory::None),
+	item(1135, "RainHat", "Rain Hat", 1, PrefixCategory::None),
+	item(1136, "RainCoat", "Rain Coat", 1, PrefixCategory::None),
+	item(1137, "LihzahrdDoor", "Lihzahrd Door", 9999, PrefixCategory::None),
+	item(1138, "DungeonDoor", "Dungeon Door", 9999, PrefixCategory::None),
+	item(1139, "LeadDoor", "Lead Door", 9999, PrefixCategory::None),
+	item(1140, "IronDoor", "Iron Door", 9999, PrefixCategory::None),
+	item(1141, "TempleKey", "Temple Key", 9999, PrefixCategory::None),
+	item(1142, "LihzahrdChest", "Lihzahrd Chest", 9999, PrefixCategory::None),
+	item(1143, "LihzahrdChair", "Lihzahrd Chair", 9999, PrefixCategory::None),
+	item(1144, "LihzahrdTable", "Lihzahrd Table", 9999, PrefixCategory::None),
+	item(1145, "LihzahrdWorkBench", "Lihzahrd Work Bench", 9999, PrefixCategory::None),
+	item(1146, "SuperDartTrap", "Super Dart Trap", 9999, PrefixCategory::None),
+	item(1147, "FlameTrap", "Flame Trap", 9999, PrefixCategory::None),
+	item(1148, "SpikyBallTrap", "Spiky Ball Trap", 9999, PrefixCategory::None),
+	item(1149, "SpearTrap", "Spear Trap", 9999, PrefixCategory::None),
+	item(1150, "WoodenSpike", "Wooden Spike", 9999, PrefixCategory::None),
+	item(1151, "LihzahrdPressurePlate", "Lihzahrd Pressure Plate", 1, PrefixCategory::None),
+	item(1152, "LihzahrdStatue", "Lihzahrd Statue", 9999, PrefixCategory::None),
+	item(1153, "LihzahrdWatcherStatue", "Lihzahrd Watcher Statue", 9999, PrefixCategory::None),
+	item(1154, "LihzahrdGuardianStatue", "Lihzahrd Guardian Statue", 9999, PrefixCategory::None),
+	item(1155, "WaspGun", "Wasp Gun", 1, PrefixCategory::Magic),
+	item(1156, "PiranhaGun", "Piranha Gun", 1, PrefixCategory::Ranged),
+	item(1157, "PygmyStaff", "Pygmy Staff", 1, PrefixCategory::Magic),
+	item(1158, "PygmyNecklace", "Pygmy Necklace", 1, PrefixCategory::Accessory),
+	item(1159, "TikiMask", "Tiki Mask", 1, PrefixCategory::None),
+	item(1160, "TikiShirt", "Tiki Shirt", 1, PrefixCategory::None),
+	item(1161, "TikiPants", "Tiki Pants", 1, PrefixCategory::None),
+	item(1162, "LeafWings", "Leaf Wings", 1, PrefixCategory::Accessory),
+	item(1163, "BlizzardinaBalloon", "Blizzard in a Balloon", 1, PrefixCategory::Accessory),
+	item(1164, "BundleofBalloons", "Bundle of Balloons", 1, PrefixCategory::Accessory),
+	item(1165, "BatWings", "Bat Wings", 1, PrefixCategory::Accessory),
+	item(1166, "BoneSword", "Bone Sword", 1, PrefixCategory::Melee),
+	item(1167, "HerculesBeetle", "Hercules Beetle", 1, PrefixCategory::Accessory),
+	item(1168, "SmokeBomb", "Smoke Bomb", 9999, PrefixCategory::None),
+	item(1169, "BoneKey", "Bone Key", 1, PrefixCategory::None),
+	item(1170, "Nectar", "Nectar", 1, PrefixCategory::None),
+	item(1171, "TikiTotem", "Tiki Totem", 1, PrefixCategory::None),
+	item(1172, "LizardEgg", "Lizard Egg", 1, PrefixCategory::None),
+	item(1173, "GraveMarker", "Grave Marker", 9999, PrefixCategory::None),
+	item(1174, "CrossGraveMarker", "Cross Grave Marker", 9999, PrefixCategory::None),
+	item(1175, "Headstone", "Headstone", 9999, PrefixCategory::None),
+	item(1176, "Gravestone", "Gravestone", 9999, PrefixCategory::None),
+	item(1177, "Obelisk", "Obelisk", 9999, PrefixCategory::None),
+	item(1178, "LeafBlower", "Leaf Blower", 1, PrefixCategory::Magic),
+	item(1179, "ChlorophyteBullet", "Chlorophyte Bullet", 9999, PrefixCategory::None),
+	item(1180, "ParrotCracker", "Parrot Cracker", 1, PrefixCategory::None),
+	item(1181, "StrangeGlowingMushroom", "Strange Glowing Mushroom", 1, PrefixCategory::None),
+	item(1182, "Seedling", "Seedling", 1, PrefixCategory::None),
+	item(1183, "WispinaBottle", "Wisp in a Bottle", 1, PrefixCategory::None),
+	item(1184, "PalladiumBar", "Palladium Bar", 9999, PrefixCategory::None),
+	item(1185, "PalladiumSword", "Palladium Sword", 1, PrefixCategory::Melee),
+	item(1186, "PalladiumPike", "Palladium Pike", 1, PrefixCategory::Melee),
+	item(1187, "PalladiumRepeater", "Palladium Repeater", 1, PrefixCategory::Ranged),
+	item(1188, "PalladiumPickaxe", "Palladium Pickaxe", 1, PrefixCategory::Melee),
+	item(1189, "PalladiumDrill", "Palladium Drill", 1, PrefixCategory::Melee),
+	item(1190, "PalladiumChainsaw", "Palladium Chainsaw", 1, PrefixCategory::Melee),
+	item(1191, "OrichalcumBar", "Orichalcum Bar", 9999, PrefixCategory::None),
+	item(1192, "OrichalcumSword", "Orichalcum Sword", 1, PrefixCategory::Melee),
+	item(1193, "OrichalcumHalberd", "Orichalcum Halberd", 1, PrefixCategory::Melee),
+	item(1194, "OrichalcumRepeater", "Orichalcum Repeater", 1, PrefixCategory::Ranged),
+	item(1195, "OrichalcumPickaxe", "Orichalcum Pickaxe", 1, PrefixCategory::Melee),
+	item(1196, "OrichalcumDrill", "Orichalcum Drill", 1, PrefixCategory::Melee),
+	item(1197, "OrichalcumChainsaw", "Orichalcum Chainsaw", 1, PrefixCategory::Melee),
+	item(1198, "TitaniumBar", "Titanium Bar", 9999, PrefixCategory::None),
+	item(1199, "TitaniumSword", "Titanium Sword", 1, PrefixCategory::Melee),
+	item(1200, "TitaniumTrident", "Titanium Trident", 1, PrefixCategory::Melee),
+	item(1201, "TitaniumRepeater", "Titanium Repeater", 1, PrefixCategory::Ranged),
+	item(1202, "TitaniumPickaxe", "Titanium Pickaxe", 1, PrefixCategory::Melee),
+	item(1203, "TitaniumDrill", "Titanium Drill", 1, PrefixCategory::Melee),
+	item(1204, "TitaniumChainsaw", "Titanium Chainsaw", 1, PrefixCategory::Melee),
+	item(1205, "PalladiumMask", "Palladium Mask", 1, PrefixCategory::None),
+	item(1206, "PalladiumHelmet", "Palladium Helmet", 1, PrefixCategory::None),
+	item(1207, "PalladiumHeadgear", "Palladium Headgear", 1, PrefixCategory::None),
+	item(1208, "PalladiumBreastplate", "Palladium Breastplate", 1, PrefixCategory::None),
+	item(1209, "PalladiumLeggings", "Palladium Leggings", 1, PrefixCategory::None),
+	item(1210, "OrichalcumMask", "Orichalcum Mask", 1, PrefixCategory::None),
+	item(1211, "OrichalcumHelmet", "Orichalcum Helmet", 1, PrefixCategory::None),
+	item(1212, "OrichalcumHeadgear", "Orichalcum Headgear", 1, PrefixCategory::None),
+	item(1213, "OrichalcumBreastplate", "Orichalcum Breastplate", 1, PrefixCategory::None),
+	item(1214, "OrichalcumLeggings", "Orichalcum Leggings", 1, PrefixCategory::None),
+	item(1215, "TitaniumMask", "Titanium Mask", 1, PrefixCategory::None),
+	item(1216, "TitaniumHelmet", "Titanium Helmet", 1, PrefixCategory::None),
+	item(1217, "TitaniumHeadgear", "Titanium Headgear", 1, PrefixCategory::None),
+	item(1218, "TitaniumBreastplate", "Titanium Breastplate", 1, PrefixCategory::None),
+	item(1219, "TitaniumLeggings", "Titanium Leggings", 1, PrefixCategory::None),
+	item(1220, "OrichalcumAnvil", "Orichalcum Anvil", 9999, PrefixCategory::None),
+	item(1221, "TitaniumForge", "Titanium Forge", 9999, PrefixCategory::None),
+	item(1222, "PalladiumWaraxe", "Palladium Waraxe", 1, PrefixCategory::Melee),
+	item(1223, "OrichalcumWaraxe", "Orichalcum Waraxe", 1, PrefixCategory::Melee),
+	item(1224, "TitaniumWaraxe", "Titanium Waraxe", 1, PrefixCategory::Melee),
+	item(1225, "HallowedBar", "Hallowed Bar", 9999, PrefixCategory::None),
+	item(1226, "ChlorophyteClaymore", "Chlorophyte Claymore", 1, PrefixCategory::Melee),
+	item(1227, "ChlorophyteSaber", "Chlorophyte Saber", 1, PrefixCategory::Melee),
+	item(1228, "ChlorophytePartisan", "Chlorophyte Partisan", 1, PrefixCategory::Melee),
+	item(1229, "ChlorophyteShotbow", "Chlorophyte Shotbow", 1, PrefixCategory::Ranged),
+	item(1230, "ChlorophytePickaxe", "Chlorophyte Pickaxe", 1, PrefixCategory::Melee),
+	item(1231, "ChlorophyteDrill", "Chlorophyte Drill", 1, PrefixCategory::Melee),
+	item(1232, "ChlorophyteChainsaw", "Chlorophyte Chainsaw", 1, PrefixCategory::Melee),
+	item(1233, "ChlorophyteGreataxe", "Chlorophyte Greataxe", 1, PrefixCategory::Melee),
+	item(1234, "ChlorophyteWarhammer", "Chlorophyte Warhammer", 1, PrefixCategory::Melee),
+	item(1235, "ChlorophyteArrow", "Chlorophyte Arrow", 9999, PrefixCategory::None),
+	item(1236, "AmethystHook", "Amethyst Hook", 1, PrefixCategory::None),
+	item(1237, "TopazHook", "Topaz Hook", 1, PrefixCategory::None),
+	item(1238, "SapphireHook", "Sapphire Hook", 1, PrefixCategory::None),
+	item(1239, "EmeraldHook", "Emerald Hook", 1, PrefixCategory::None),
+	item(1240, "RubyHook", "Ruby Hook", 1, PrefixCategory::None),
+	item(1241, "DiamondHook", "Diamond Hook", 1, PrefixCategory::None),
+	item(1242, "AmberMosquito", "Amber Mosquito", 1, PrefixCategory::None),
+	item(1243, "UmbrellaHat", "Umbrella Hat", 1, PrefixCategory::None),
+	item(1244, "NimbusRod", "Nimbus Rod", 1, PrefixCategory::Magic),
+	item(1245, "OrangeTorch", "Orange Torch", 9999, PrefixCategory::None),
+	item(1246, "CrimsandBlock", "Crimsand Block", 9999, PrefixCategory::None),
+	item(1247, "BeeCloak", "Bee Cloak", 1, PrefixCategory::Accessory),
+	item(1248, "EyeoftheGolem", "Eye of the Golem", 1, PrefixCategory::Accessory),
+	item(1249, "HoneyBalloon", "Honey Balloon", 1, PrefixCategory::Accessory),
+	item(1250, "BlueHorseshoeBalloon", "Blue Horseshoe Balloon", 1, PrefixCategory::Accessory),
+	item(1251, "WhiteHorseshoeBalloon", "White Horseshoe Balloon", 1, PrefixCategory::Accessory),
+	item(1252, "YellowHorseshoeBalloon", "Yellow Horseshoe Balloon", 1, PrefixCategory::Accessory),
+	item(1253, "FrozenTurtleShell", "Frozen Turtle Shell", 1, PrefixCategory::Accessory),
+	item(1254, "SniperRifle", "Sniper Rifle", 1, PrefixCategory::Ranged),
+	item(1255, "VenusMagnum", "Venus Magnum", 1, PrefixCategory::Ranged),
+	item(1256, "CrimsonRod", "Crimson Rod", 1, PrefixCategory::Magic),
+	item(1257, "CrimtaneBar", "Crimtane Bar", 9999, PrefixCategory::None),
+	item(1258, "Stynger", "Stynger", 1, PrefixCategory::Ranged),
+	item(1259, "FlowerPow", "Flower Pow", 1, PrefixCategory::Melee),
+	item(1260, "RainbowGun", "Rainbow Gun", 1, PrefixCategory::Magic),
+	item(1261, "StyngerBolt", "Stynger Bolt", 9999, PrefixCategory::None),
+	item(1262, "ChlorophyteJackhammer", "Chlorophyte Jackhammer", 1, PrefixCategory::Melee),
+	item(1263, "Teleporter", "Teleporter", 9999, PrefixCategory::None),
+	item(1264, "FlowerofFrost", "Flower of Frost", 1, PrefixCategory::Magic),
+	item(1265, "Uzi", "Uzi", 1, PrefixCategory::Ranged),
+	item(1266, "MagnetSphere", "Magnet Sphere", 1, PrefixCategory::Magic),
+	item(1267, "PurpleStainedGlass", "Purple Stained Glass", 9999, PrefixCategory::None),
+	item(1268, "YellowStainedGlass", "Yellow Stained Glass", 9999, PrefixCategory::None),
+	item(1269, "BlueStainedGlass", "Blue Stained Glass", 9999, PrefixCategory::None),
+	item(1270, "GreenStainedGlass", "Green Stained Glass", 9999, PrefixCategory::None),
+	item(1271, "RedStainedGlass", "Red Stained Glass", 9999, PrefixCategory::None),
+	item(1272, "MulticoloredStainedGlass", "Multicolored Stained Glass", 9999, PrefixCategory::None),
+	item(1273, "SkeletronHand", "Skeletron Hand", 1, PrefixCategory::None),
+	item(1274, "Skull", "Skull", 1, PrefixCategory::None),
+	item(1275, "BallaHat", "Balla Hat", 1, PrefixCategory::None),
+	item(1276, "GangstaHat", "Gangsta Hat", 1, PrefixCategory::None),
+	item(1277, "SailorHat", "Sailor Hat", 1, PrefixCategory::None),
+	item(1278, "EyePatch", "Eye Patch", 9999, PrefixCategory::None),
+	item(1279, "SailorShirt", "Sailor Shirt", 1, PrefixCategory::None),
+	item(1280, "SailorPants", "Sailor Pants", 1, PrefixCategory::None),
+	item(1281, "SkeletronMask", "Skeletron Mask", 1, PrefixCategory::None),
+	item(1282, "AmethystRobe", "Amethyst Robe", 1, PrefixCategory::None),
+	item(1283, "TopazRobe", "Topaz Robe", 1, PrefixCategory::None),
+	item(1284, "SapphireRobe", "Sapphire Robe", 1, PrefixCategory::None),
+	item(1285, "EmeraldRobe", "Emerald Robe", 1, PrefixCategory::None),
+	item(1286, "RubyRobe", "Ruby Robe", 1, PrefixCategory::None),
+	item(1287, "DiamondRobe", "Diamond Robe", 1, PrefixCategory::None),
+	item(1288, "WhiteTuxedoShirt", "White Tuxedo Shirt", 1, PrefixCategory::None),
+	item(1289, "WhiteTuxedoPants", "White Tuxedo Pants", 1, PrefixCategory::None),
+	item(1290, "PanicNecklace", "Panic Necklace", 1, PrefixCategory::Accessory),
+	item(1291, "LifeFruit", "Life Fruit", 9999, PrefixCategory::None),
+	item(1292, "LihzahrdAltar", "Lihzahrd Altar", 9999, PrefixCategory::None),
+	item(1293, "LihzahrdPowerCell", "Lihzahrd Power Cell", 9999, PrefixCategory::None),
+	item(1294, "Picksaw", "Picksaw", 1, PrefixCategory::Melee),
+	item(1295, "HeatRay", "Heat Ray", 1, PrefixCategory::Magic),
+	item(1296, "StaffofEarth", "Staff of Earth", 1, PrefixCategory::Magic),
+	item(1297, "GolemFist", "Golem Fist", 1, PrefixCategory::Melee),
+	item(1298, "WaterChest", "Water Chest", 9999, PrefixCategory::None),
+	item(1299, "Binoculars", "Binoculars", 1, PrefixCategory::Ranged),
+	item(1300, "RifleScope", "Rifle Scope", 1, PrefixCategory::Accessory),
+	item(1301, "DestroyerEmblem", "Destroyer Emblem", 1, PrefixCategory::Accessory),
+	item(1302, "HighVelocityBullet", "High Velocity Bullet", 9999, PrefixCategory::None),
+	item(1303, "JellyfishNecklace", "Jellyfish Necklace", 1, PrefixCategory::Accessory),
+	item(1304, "ZombieArm", "Zombie Arm", 1, PrefixCategory::Melee),
+	item(1305, "TheAxe", "The Axe", 1, PrefixCategory::Melee),
+	item(1306, "IceSickle", "Ice Sickle", 1, PrefixCategory::Melee),
+	item(1307, "ClothierVoodooDoll", "Clothier Voodoo Doll", 1, PrefixCategory::None),
+	item(1308, "PoisonStaff", "Poison Staff", 1, PrefixCategory::Magic),
+	item(1309, "SlimeStaff", "Slime Staff", 1, PrefixCategory::Magic),
+	item(1310, "PoisonDart", "Poison Dart", 9999, PrefixCategory::None),
+	item(1311, "EyeSpring", "Eye Spring", 1, PrefixCategory::None),
+	item(1312, "ToySled", "Toy Sled", 1, PrefixCategory::None),
+	item(1313, "BookofSkulls", "Book of Skulls", 1, PrefixCategory::Magic),
+	item(1314, "KOCannon", "KO Cannon", 1, PrefixCategory::Melee),
+	item(1315, "PirateMap", "Pirate Map", 20, PrefixCategory::None),
+	item(1316, "TurtleHelmet", "Turtle Helmet", 1, PrefixCategory::None),
+	item(1317, "TurtleScaleMail", "Turtle Scale Mail", 9999, PrefixCategory::None),
+	item(1318, "TurtleLeggings", "Turtle Leggings", 1, PrefixCategory::None),
+	item(1319, "SnowballCannon", "Snowball Cannon", 1, PrefixCategory::Ranged),
+	item(1320, "BonePickaxe", "Bone Pickaxe", 1, PrefixCategory::Melee),
+	item(1321, "MagicQuiver", "Magic Quiver", 1, PrefixCategory::Accessory),
+	item(1322, "MagmaStone", "Magma Stone", 1, PrefixCategory::Accessory),
+	item(1323, "ObsidianRose", "Obsidian Rose", 1, PrefixCategory::Accessory),
+	item(1324, "Bananarang", "Bananarang", 1, PrefixCategory::Melee),
+	item(1325, "ChainKnife", "Chain Knife", 1, PrefixCategory::Melee),
+	item(1326, "RodofDiscord", "Rod of Discord", 1, PrefixCategory::None),
+	item(1327, "DeathSickle", "Death Sickle", 1, PrefixCategory::Melee),
+	item(1328, "TurtleShell", "Turtle Shell", 9999, PrefixCategory::None),
+	item(1329, "TissueSample", "Tissue Sample", 9999, PrefixCategory::None),
+	item(1330, "Vertebrae", "Vertebrae", 9999, PrefixCategory::None),
+	item(1331, "BloodySpine", "Bloody Spine", 20, PrefixCategory::None),
+	item(1332, "Ichor", "Ichor", 9999, PrefixCategory::None),
+	item(1333, "IchorTorch", "Ichor Torch", 9999, PrefixCategory::None),
+	item(1334, "IchorArrow", "Ichor Arrow", 9999, PrefixCategory::None),
+	item(1335, "IchorBullet", "Ichor Bullet", 9999, PrefixCategory::None),
+	item(1336, "GoldenShower", "Golden Shower", 1, PrefixCategory::Magic),
+	item(1337, "BunnyCannon", "Bunny Cannon", 1, PrefixCategory::Ranged),
+	item(1338, "ExplosiveBunny", "Explosive Bunny", 9999, PrefixCategory::None),
+	item(1339, "VialofVenom", "Vial of Venom", 9999, PrefixCategory::None),
+	item(1340, "FlaskofVenom", "Flask of Venom", 9999, PrefixCategory::None),
+	item(1341, "VenomArrow", "Venom Arrow", 9999, PrefixCategory::None),
+	item(1342, "VenomBullet", "Venom Bullet", 9999, PrefixCategory::None),
+	item(1343, "FireGauntlet", "Fire Gauntlet", 1, PrefixCategory::Accessory),
+	item(1344, "Cog", "Cog", 9999, PrefixCategory::None),
+	item(1345, "Confetti", "Confetti", 9999, PrefixCategory::None),
+	item(1346, "Nanites", "Nanites", 9999, PrefixCategory::None),
+	item(1347, "ExplosivePowder", "Explosive Powder", 9999, PrefixCategory::None),
+	item(1348, "GoldDust", "Gold Dust", 9999, PrefixCategory::None),
+	item(1349, "PartyBullet", "Party Bullet", 9999, PrefixCategory::None),
+	item(1350, "NanoBullet", "Nano Bullet", 9999, PrefixCategory::None),
+	item(1351, "ExplodingBullet", "Exploding Bullet", 9999, PrefixCategory::None),
+	item(1352, "GoldenBullet", "Golden Bullet", 9999, PrefixCategory::None),
+	item(1353, "FlaskofCursedFlames", "Flask of Cursed Flames", 9999, PrefixCategory::None),
+	item(1354, "FlaskofFire", "Flask of Fire", 9999, PrefixCategory::None),
+	item(1355, "FlaskofGold", "Flask of Gold", 9999, PrefixCategory::None),
+	item(1356, "FlaskofIchor", "Flask of Ichor", 9999, PrefixCategory::None),
+	item(1357, "FlaskofNanites", "Flask of Nanites", 9999, PrefixCategory::None),
+	item(1358, "FlaskofParty", "Flask of Party", 9999, PrefixCategory::None),
+	item(1359, "FlaskofPoison", "Flask of Poison", 9999, PrefixCategory::None),
+	item(1360, "EyeofCthulhuTrophy", "Eye of Cthulhu Trophy", 9999, PrefixCategory::None),
+	item(1361, "EaterofWorldsTrophy", "Eater of Worlds Trophy", 9999, PrefixCategory::None),
+	item(1362, "BrainofCthulhuTrophy", "Brain of Cthulhu Trophy", 9999, PrefixCategory::None),
+	item(1363, "SkeletronTrophy", "Skeletron Trophy", 9999, PrefixCategory::None),
+	item(1364, "QueenBeeTrophy", "Queen Bee Trophy", 9999, PrefixCategory::None),
+	item(1365, "WallofFleshTrophy", "Wall of Flesh Trophy", 9999, PrefixCategory::None),
+	item(1366, "DestroyerTrophy", "Destroyer Trophy", 9999, PrefixCategory::None),
+	item(1367, "SkeletronPrimeTrophy", "Skeletron Prime Trophy", 9999, PrefixCategory::None),
+	item(1368, "RetinazerTrophy", "Retinazer Trophy", 9999, PrefixCategory::None),
+	item(1369, "SpazmatismTrophy", "Spazmatism Trophy", 9999, PrefixCategory::None),
+	item(1370, "PlanteraTrophy", "Plantera Trophy", 9999, PrefixCategory::None),
+	item(1371, "GolemTrophy", "Golem Trophy", 9999, PrefixCategory::None),
+	item(1372, "BloodMoonRising", "Blood Moon Rising", 9999, PrefixCategory::None),
+	item(1373, "TheHangedMan", "The Hanged Man", 9999, PrefixCategory::None),
+	item(1374, "GloryoftheFire", "Glory of the Fire", 9999, PrefixCategory::None),
+	item(1375, "BoneWarp", "Bone Warp", 9999, PrefixCategory::None),
+	item(1376, "WallSkeleton", "Wall Skeleton", 9999, PrefixCategory::None),
+	item(1377, "HangingSkeleton", "Hanging Skeleton", 9999, PrefixCategory::None),
+	item(1378, "BlueSlabWall", "Blue Slab Wall", 9999, PrefixCategory::None),
+	item(1379, "BlueTiledWall", "Blue Tiled Wall", 9999, PrefixCategory::None),
+	item(1380, "PinkSlabWall", "Pink Slab Wall", 9999, PrefixCategory::None),
+	item(1381, "PinkTiledWall", "Pink Tiled Wall", 9999, PrefixCategory::None),
+	item(1382, "GreenSlabWall", "Green Slab Wall", 9999, PrefixCategory::None),
+	item(1383, "GreenTiledWall", "Green Tiled Wall", 9999, PrefixCategory::None),
+	item(1384, "BlueBrickPlatform", "Blue Brick Platform", 9999, PrefixCategory::None),
+	item(1385, "PinkBrickPlatform", "Pink Brick Platform", 9999, PrefixCategory::None),
+	item(1386, "GreenBrickPlatform", "Green Brick Platform", 9999, PrefixCategory::None),
+	item(1387, "MetalShelf", "Metal Shelf", 9999, PrefixCategory::None),
+	item(1388, "BrassShelf", "Brass Shelf", 9999, PrefixCategory::None),
+	item(1389, "WoodShelf", "Wood Shelf", 9999, PrefixCategory::None),
+	item(1390, "BrassLantern", "Brass Lantern", 9999, PrefixCategory::None),
+	item(1391, "CagedLantern", "Caged Lantern", 9999, PrefixCategory::None),
+	item(1392, "CarriageLantern", "Carriage Lantern", 9999, PrefixCategory::None),
+	item(1393, "AlchemyLantern", "Alchemy Lantern", 9999, PrefixCategory::None),
+	item(1394, "DiablostLamp", "Diabolist Lamp", 9999, PrefixCategory::None),
+	item(1395, "OilRagSconse", "Oil Rag Sconce", 9999, PrefixCategory::None),
+	item(1396, "BlueDungeonChair", "Blue Dungeon Chair", 9999, PrefixCategory::None),
+	item(1397, "BlueDungeonTable", "Blue Dungeon Table", 9999, PrefixCategory::None),
+	item(1398, "BlueDungeonWorkBench", "Blue Dungeon Work Bench", 9999, PrefixCategory::None),
+	item(1399, "GreenDungeonChair", "Green Dungeon Chair", 9999, PrefixCategory::None),
+	item(1400, "GreenDungeonTable", "Green Dungeon Table", 9999, PrefixCategory::None),
+	item(1401, "GreenDungeonWorkBench", "Green Dungeon Work Bench", 9999, PrefixCategory::None),
+	item(1402, "PinkDungeonChair", "Pink Dungeon Chair", 9999, PrefixCategory::None),
+	item(1403, "PinkDungeonTable", "Pink Dungeon Table", 9999, PrefixCategory::None),
+	item(1404, "PinkDungeonWorkBench", "Pink Dungeon Work Bench", 9999, PrefixCategory::None),
+	item(1405, "BlueDungeonCandle", "Blue Dungeon Candle", 9999, PrefixCategory::None),
+	item(1406, "GreenDungeonCandle", "Green Dungeon Candle", 9999, PrefixCategory::None),
+	item(1407, "PinkDungeonCandle", "Pink Dungeon Candle", 9999, PrefixCategory::None),
+	item(1408, "BlueDungeonVase", "Blue Dungeon Vase", 9999, PrefixCategory::None),
+	item(1409, "GreenDungeonVase", "Green Dungeon Vase", 9999, PrefixCategory::None),
+	item(1410, "PinkDungeonVase", "Pink Dungeon Vase", 9999, PrefixCategory::None),
+	item(1411, "BlueDungeonDoor", "Blue Dungeon Door", 9999, PrefixCategory::None),
+	item(1412, "GreenDungeonDoor", "Green Dungeon Door", 9999, PrefixCategory::None),
+	item(1413, "PinkDungeonDoor", "Pink Dungeon Door", 9999, PrefixCategory::None),
+	item(1414, "BlueDungeonBookcase", "Blue Dungeon Bookcase", 9999, PrefixCategory::None),
+	item(1415, "GreenDungeonBookcase", "Green Dungeon Bookcase", 9999, PrefixCategory::None),
+	item(1416, "PinkDungeonBookcase", "Pink Dungeon Bookcase", 9999, PrefixCategory::None),
+	item(1417, "Catacomb", "Catacomb", 9999, PrefixCategory::None),
+	item(1418, "DungeonShelf", "Dungeon Shelf", 9999, PrefixCategory::None),
+	item(1419, "SkellingtonJSkellingsworth", "Skellington J Skellingsworth", 9999, PrefixCategory::None),
+	item(1420, "TheCursedMan", "The Cursed Man", 9999, PrefixCategory::None),
+	item(1421, "TheEyeSeestheEnd", "The Eye Sees the End", 9999, PrefixCategory::None),
+	item(1422, "SomethingEvilisWatchingYou", "Something Evil is Watching You", 9999, PrefixCategory::None),
+	item(1423, "TheTwinsHaveAwoken", "The Twins Have Awoken", 9999, PrefixCategory::None),
+	item(1424, "TheScreamer", "The Screamer", 9999, PrefixCategory::None),
+	item(1425, "GoblinsPlayingPoker", "Goblins Playing Poker", 9999, PrefixCategory::None),
+	item(1426, "Dryadisque", "Dryadisque", 9999, PrefixCategory::None),
+	item(1427, "Sunflowers", "Sunflowers", 9999, PrefixCategory::None),
+	item(1428, "TerrarianGothic", "Terrarian Gothic", 9999, PrefixCategory::None),
+	item(1429, "Beanie", "Beanie", 9999, PrefixCategory::None),
+	item(1430, "ImbuingStation", "Imbuing Station", 9999, PrefixCategory::None),
+	item(1431, "StarinaBottle", "Star in a Bottle", 9999, PrefixCategory::None),
+	item(1432, "EmptyBullet", "Empty Bullet", 9999, PrefixCategory::None),
+	item(1433, "Impact", "Impact", 9999, PrefixCategory::None),
+	item(1434, "PoweredbyBirds", "Powered by Birds", 9999, PrefixCategory::None),
+	item(1435, "TheDestroyer", "The Destroyer", 9999, PrefixCategory::None),
+	item(1436, "ThePersistencyofEyes", "The Persistency of Eyes", 9999, PrefixCategory::None),
+	item(1437, "UnicornCrossingtheHallows", "Unicorn Crossing the Hallows", 9999, PrefixCategory::None),
+	item(1438, "GreatWave", "Great Wave", 9999, PrefixCategory::None),
+	item(1439, "StarryNight", "Starry Night", 9999, PrefixCategory::None),
+	item(1440, "GuidePicasso", "Guide Picasso", 9999, PrefixCategory::None),
+	item(1441, "TheGuardiansGaze", "The Guardian's Gaze", 9999, PrefixCategory::None),
+	item(1442, "FatherofSomeone", "Father of Someone", 9999, PrefixCategory::None),
+	item(1443, "NurseLisa", "Nurse Lisa", 9999, PrefixCategory::None),
+	item(1444, "ShadowbeamStaff", "Shadowbeam Staff", 1, PrefixCategory::Magic),
+	item(1445, "InfernoFork", "Inferno Fork", 1, PrefixCategory::Magic),
+	item(1446, "SpectreStaff", "Spectre Staff", 1, PrefixCategory::Magic),
+	item(1447, "WoodenFence", "Wooden Fence", 9999, PrefixCategory::None),
+	item(1448, "LeadFence", "Lead Fence", 9999, PrefixCategory::None),
+	item(1449, "BubbleMachine", "Bubble Machine", 9999, PrefixCategory::None),
+	item(1450, "BubbleWand", "Bubble Wand", 1, PrefixCategory::None),
+	item(1451, "MarchingBonesBanner", "Marching Bones Banner", 9999, PrefixCategory::None),
+	item(1452, "NecromanticSign", "Necromantic Sign", 9999, PrefixCategory::None),
+	item(1453, "RustedCompanyStandard", "Rusted Company Standard", 9999, PrefixCategory::None),
+	item(1454, "RaggedBrotherhoodSigil", "Ragged Brotherhood Sigil", 9999, PrefixCategory::None),
+	item(1455, "MoltenLegionFlag", "Molten Legion Flag", 9999, PrefixCategory::None),
+	item(1456, "DiabolicSigil", "Diabolic Sigil", 9999, PrefixCategory::None),
+	item(1457, "ObsidianPlatform", "Obsidian Platform", 9999, PrefixCategory::None),
+	item(1458, "ObsidianDoor", "Obsidian Door", 9999, PrefixCategory::None),
+	item(1459, "ObsidianChair", "Obsidian Chair", 9999, PrefixCategory::None),
+	item(1460, "ObsidianTable", "Obsidian Table", 9999, PrefixCategory::None),
+	item(1461, "ObsidianWorkBench", "Obsidian Work Bench", 9999, PrefixCategory::None),
+	item(1462, "ObsidianVase", "Obsidian Vase", 9999, PrefixCategory::None),
+	item(1463, "ObsidianBookcase", "Obsidian Bookcase", 9999, PrefixCategory::None),
+	item(1464, "HellboundBanner", "Hellbound Banner", 9999, PrefixCategory::None),
+	item(1465, "HellHammerBanner", "Hell Hammer Banner", 9999, PrefixCategory::None),
+	item(1466, "HelltowerBanner", "Helltower Banner", 9999, PrefixCategory::None),
+	item(1467, "LostHopesofManBanner", "Lost Hopes of Man Banner", 9999, PrefixCategory::None),
+	item(1468, "ObsidianWatcherBanner", "Obsidian Watcher Banner", 9999, PrefixCategory::None),
+	item(1469, "LavaEruptsBanner", "Lava Erupts Banner", 9999, PrefixCategory::None),
+	item(1470, "BlueDungeonBed", "Blue Dungeon Bed", 9999, PrefixCategory::None),
+	item(1471, "GreenDungeonBed", "Green Dungeon Bed", 9999, PrefixCategory::None),
+	item(1472, "PinkDungeonBed", "Pink Dungeon Bed", 9999, PrefixCategory::None),
+	item(1473, "ObsidianBed", "Obsidian Bed", 9999, PrefixCategory::None),
+	item(1474, "Waldo", "Waldo", 9999, PrefixCategory::None),
+	item(1475, "Darkness", "Darkness", 9999, PrefixCategory::None),
+	item(1476, "DarkSoulReaper", "Dark Soul Reaper", 9999, PrefixCategory::None),
+	item(1477, "Land", "Land", 9999, PrefixCategory::None),
+	item(1478, "TrappedGhost", "Trapped Ghost", 9999, PrefixCategory::None),
+	item(1479, "DemonsEye", "Demon's Eye", 9999, PrefixCategory::None),
+	item(1480, "FindingGold", "Finding Gold", 9999, PrefixCategory::None),
+	item(1481, "FirstEncounter", "First Encounter", 9999, PrefixCategory::None),
+	item(1482, "GoodMorning", "Good Morning", 9999, PrefixCategory::None),
+	item(1483, "UndergroundReward", "Underground Reward", 9999, PrefixCategory::None),
+	item(1484, "ThroughtheWindow", "Through the Window", 9999, PrefixCategory::None),
+	item(1485, "PlaceAbovetheClouds", "Place Above the Clouds", 9999, PrefixCategory::None),
+	item(1486, "DoNotStepontheGrass", "Do Not Step on the Grass", 9999, PrefixCategory::None),
+	item(1487, "ColdWatersintheWhiteLand", "Cold Waters in the White Land", 9999, PrefixCategory::None),
+	item(1488, "LightlessChasms", "Lightless Chasms", 9999, PrefixCategory::None),
+	item(1489, "TheLandofDeceivingLooks", "The Land of Deceiving Looks", 9999, PrefixCategory::None),
+	item(1490, "Daylight", "Daylight", 9999, PrefixCategory::None),
+	item(1491, "SecretoftheSands", "Secret of the Sands", 9999, PrefixCategory::None),
+	item(1492, "DeadlandComesAlive", "Deadland Comes Alive", 9999, PrefixCategory::None),
+	item(1493, "EvilPresence", "Evil Presence", 9999, PrefixCategory::None),
+	item(1494, "SkyGuardian", "Sky Guardian", 9999, PrefixCategory::None),
+	item(1495, "AmericanExplosive", "American Explosive", 9999, PrefixCategory::None),
+	item(1496, "Discover", "Discover", 9999, PrefixCategory::None),
+	item(1497, "HandEarth", "Hand Earth", 9999, PrefixCategory::None),
+	item(1498, "OldMiner", "Old Miner", 9999, PrefixCategory::None),
+	item(1499, "Skelehead", "Skelehead", 9999, PrefixCategory::None),
+	item(1500, "FacingtheCerebralMastermind", "Facing the Cerebral Mastermind", 9999, PrefixCategory::None),
+	item(1501, "LakeofFire", "Lake of Fire", 9999, PrefixCategory::None),
+	item(1502, "TrioSuperHeroes", "Trio Super Heroes", 9999, PrefixCategory::None),
+	item(1503, "SpectreHood", "Spectre Hood", 1, PrefixCategory::None),
+	item(1504, "SpectreRobe", "Spectre Robe", 1, PrefixCategory::None),
+	item(1505, "SpectrePants", "Spectre Pants", 1, PrefixCategory::None),
+	item(1506, "SpectrePickaxe", "Spectre Pickaxe", 1, PrefixCategory::Melee),
+	item(1507, "SpectreHamaxe", "Spectre Hamaxe", 1, PrefixCategory::Melee),
+	item(1508, "Ectoplasm", "Ectoplasm", 9999, PrefixCategory::None),
+	item(1509, "GothicChair", "Gothic Chair", 9999, PrefixCategory::None),
+	item(1510, "GothicTable", "Gothic Table", 9999, PrefixCategory::None),
+	item(1511, "GothicWorkBench", "Gothic Work Bench", 9999, PrefixCategory::None),
+	item(1512, "GothicBookcase", "Gothic Bookcase", 9999, PrefixCategory::None),
+	item(1513, "PaladinsHammer", "Paladin's Hammer", 1, PrefixCategory::Melee),
+	item(1514, "SWATHelmet", "SWAT Helmet", 1, PrefixCategory::None),
+	item(1515, "BeeWings", "Bee Wings", 1, PrefixCategory::Accessory),
+	item(1516, "GiantHarpyFeather", "Giant Harpy Feather", 9999, PrefixCategory::None),
+	item(1517, "BoneFeather", "Bone Feather", 9999, PrefixCategory::None),
+	item(1518, "FireFeather", "Fire Feather", 9999, PrefixCategory::None),
+	item(1519, "IceFeather", "Ice Feather", 9999, PrefixCategory::None),
+	item(1520, "BrokenBatWing", "Broken Bat Wing", 9999, PrefixCategory::None),
+	item(1521, "TatteredBeeWing", "Tattered Bee Wing", 9999, PrefixCategory::None),
+	item(1522, "LargeAmethyst", "Large Amethyst", 9999, PrefixCategory::None),
+	item(1523, "LargeTopaz", "Large Topaz", 9999, PrefixCategory::None),
+	item(1524, "LargeSapphire", "Large Sapphire", 9999, PrefixCategory::None),
+	item(1525, "LargeEmerald", "Large Emerald", 9999, PrefixCategory::None),
+	item(1526, "LargeRuby", "Large Ruby", 9999, PrefixCategory::None),
+	item(1527, "LargeDiamond", "Large Diamond", 9999, PrefixCategory::None),
+	item(1528, "JungleChest", "Jungle Chest", 9999, PrefixCategory::None),
+	item(1529, "CorruptionChest", "Corruption Chest", 9999, PrefixCategory::None),
+	item(1530, "CrimsonChest", "Crimson Chest", 9999, PrefixCategory::None),
+	item(1531, "HallowedChest", "Hallowed Chest", 9999, PrefixCategory::None),
+	item(1532, "FrozenChest", "Frozen Chest", 9999, PrefixCategory::None),
+	item(1533, "JungleKey", "Jungle Key", 1, PrefixCategory::None),
+	item(1534, "CorruptionKey", "Corruption Key", 1, PrefixCategory::None),
+	item(1535, "CrimsonKey", "Crimson Key", 1, PrefixCategory::None),
+	item(1536, "HallowedKey", "Hallowed Key", 1, PrefixCategory::None),
+	item(1537, "FrozenKey", "Frozen Key", 1, PrefixCategory::None),
+	item(1538, "ImpFace", "Imp Face", 9999, PrefixCategory::None),
+	item(1539, "OminousPresence", "Ominous Presence", 9999, PrefixCategory::None),
+	item(1540, "ShiningMoon", "Shining Moon", 9999, PrefixCategory::None),
+	item(1541, "LivingGore", "Living Gore", 9999, PrefixCategory::None),
+	item(1542, "FlowingMagma", "Flowing Magma", 9999, PrefixCategory::None),
+	item(1543, "SpectrePaintbrush", "Spectre Paintbrush", 1, PrefixCategory::None),
+	item(1544, "SpectrePaintRoller", "Spectre Paint Roller", 1, PrefixCategory::None),
+	item(1545, "SpectrePaintScraper", "Spectre Paint Scraper", 1, PrefixCategory::None),
+	item(1546, "ShroomiteHeadgear", "Shroomite Headgear", 1, PrefixCategory::None),
+	item(1547, "ShroomiteMask", "Shroomite Mask", 1, PrefixCategory::None),
+	item(1548, "ShroomiteHelmet", "Shroomite Helmet", 1, PrefixCategory::None),
+	item(1549, "ShroomiteBreastplate", "Shroomite Breastplate", 1, PrefixCategory::None),
+	item(1550, "ShroomiteLeggings", "Shroomite Leggings", 1, PrefixCategory::None),
+	item(1551, "Autohammer", "Autohammer", 9999, PrefixCategory::None),
+	item(1552, "ShroomiteBar", "Shroomite Bar", 9999, PrefixCategory::None),
+	item(1553, "SDMG", "S.D.M.G.", 1, PrefixCategory::Ranged),
+	item(1554, "CenxsTiara", "Cenx's Tiara", 1, PrefixCategory::None),
+	item(1555, "CenxsBreastplate", "Cenx's Breastplate", 1, PrefixCategory::None),
+	item(1556, "CenxsLeggings", "Cenx's Leggings", 1, PrefixCategory::None),
+	item(1557, "CrownosMask", "Crowno's Mask", 1, PrefixCategory::None),
+	item(1558, "CrownosBreastplate", "Crowno's Breastplate", 1, PrefixCategory::None),
+	item(1559, "CrownosLeggings", "Crowno's Leggings", 1, PrefixCategory::None),
+	item(1560, "WillsHelmet", "Will's Helmet", 1, PrefixCategory::None),
+	item(1561, "WillsBreastplate", "Will's Breastplate", 1, PrefixCategory::None),
+	item(1562, "WillsLeggings", "Will's Leggings", 1, PrefixCategory::None),
+	item(1563, "JimsHelmet", "Jim's Helmet", 1, PrefixCategory::None),
+	item(1564, "JimsBreastplate", "Jim's Breastplate", 1, PrefixCategory::None),
+	item(1565, "JimsLeggings", "Jim's Leggings", 1, PrefixCategory::None),
+	item(1566, "AaronsHelmet", "Aaron's Helmet", 1, PrefixCategory::None),
+	item(1567, "AaronsBreastplate", "Aaron's Breastplate", 1, PrefixCategory::None),
+	item(1568, "AaronsLeggings", "Aaron's Leggings", 1, PrefixCategory::None),
+	item(1569, "VampireKnives", "Vampire Knives", 1, PrefixCategory::Melee),
+	item(1570, "BrokenHeroSword", "Broken Hero Sword", 9999, PrefixCategory::None),
+	item(1571, "ScourgeoftheCorruptor", "Scourge of the Corruptor", 1, PrefixCategory::Melee),
+	item(1572, "StaffoftheFrostHydra", "Staff of the Frost Hydra", 1, PrefixCategory::Magic),
+	item(1573, "TheCreationoftheGuide", "The Creation of the Guide", 9999, PrefixCategory::None),
+	item(1574, "TheMerchant", "The Merchant", 9999, PrefixCategory::None),
+	item(1575, "CrownoDevoursHisLunch", "Crowno Devours His Lunch", 9999, PrefixCategory::None),
+	item(1576, "RareEnchantment", "Rare Enchantment", 9999, PrefixCategory::None),
+	item(1577, "GloriousNight", "Glorious Night", 9999, PrefixCategory::None),
+	item(1578, "SweetheartNecklace", "Sweetheart Necklace", 1, PrefixCategory::Accessory),
+	item(1579, "FlurryBoots", "Flurry Boots", 1, PrefixCategory::Accessory),
+	item(1580, "DTownsHelmet", "D-Town's Helmet", 1, PrefixCategory::None),
+	item(1581, "DTownsBreastplate", "D-Town's Breastplate", 1, PrefixCategory::None),
+	item(1582, "DTownsLeggings", "D-Town's Leggings", 1, PrefixCategory::None),
+	item(1583, "DTownsWings", "D-Town's Wings", 1, PrefixCategory::Accessory),
+	item(1584, "WillsWings", "Will's Wings", 1, PrefixCategory::Accessory),
+	item(1585, "CrownosWings", "Crowno's Wings", 1, PrefixCategory::Accessory),
+	item(1586, "CenxsWings", "Cenx's Wings", 1, PrefixCategory::Accessory),
+	item(1587, "CenxsDress", "Cenx's Dress", 1, PrefixCategory::None),
+	item(1588, "CenxsDressPants", "Cenx's Dress Pants", 1, PrefixCategory::None),
+	item(1589, "PalladiumColumn", "Palladium Column", 9999, PrefixCategory::None),
+	item(1590, "PalladiumColumnWall", "Palladium Column Wall", 9999, PrefixCategory::None),
+	item(1591, "BubblegumBlock", "Bubblegum Block", 9999, PrefixCategory::None),
+	item(1592, "BubblegumBlockWall", "Bubblegum Block Wall", 9999, PrefixCategory::None),
+	item(1593, "TitanstoneBlock", "Titanstone Block", 9999, PrefixCategory::None),
+	item(1594, "TitanstoneBlockWall", "Titanstone Block Wall", 9999, PrefixCategory::None),
+	item(1595, "MagicCuffs", "Magic Cuffs", 1, PrefixCategory::Accessory),
+	item(1596, "MusicBoxSnow", "Music Box (Snow)", 1, PrefixCategory::None),
+	item(1597, "MusicBoxSpace", "Music Box (Space)", 1, PrefixCategory::None),
+	item(1598, "MusicBoxCrimson", "Music Box (Crimson)", 1, PrefixCategory::None),
+	item(1599, "MusicBoxBoss4", "Music Box (Boss 4)", 1, PrefixCategory::None),
+	item(1600, "MusicBoxAltOverworldDay", "Music Box (Alt Overworld Day)", 1, PrefixCategory::None),
+	item(1601, "MusicBoxRain", "Music Box (Rain)", 1, PrefixCategory::None),
+	item(1602, "MusicBoxIce", "Music Box (Ice)", 1, PrefixCategory::None),
+	item(1603, "MusicBoxDesert", "Music Box (Desert)", 1, PrefixCategory::None),
+	item(1604, "MusicBoxOcean", "Music Box (Ocean)", 1, PrefixCategory::None),
+	item(1605, "MusicBoxDungeon", "Music Box (Dungeon)", 1, PrefixCategory::None),
+	item(1606, "MusicBoxPlantera", "Music Box (Plantera)", 1, PrefixCategory::None),
+	item(1607, "MusicBoxBoss5", "Music Box (Boss 5)", 1, PrefixCategory::None),
+	item(1608, "MusicBoxTemple", "Music Box (Temple)", 1, PrefixCategory::None),
+	item(1609, "MusicBoxEclipse", "Music Box (Eclipse)", 1, PrefixCategory::None),
+	item(1610, "MusicBoxMushrooms", "Music Box (Mushrooms)", 1, PrefixCategory::None),
+	item(1611, "ButterflyDust", "Butterfly Dust", 9999, PrefixCategory::None),
+	item(1612, "AnkhCharm", "Ankh Charm", 1, PrefixCategory::Accessory),
+	item(1613, "AnkhShield", "Ankh Shield", 1, PrefixCategory::Accessory),
+	item(1614, "BlueFlare", "Blue Flare", 9999, PrefixCategory::None),
+	item(1615, "AnglerFishBanner", "Angler Fish Banner", 9999, PrefixCategory::None),
+	item(1616, "AngryNimbusBanner", "Angry Nimbus Banner", 9999, PrefixCategory::None),
+	item(1617, "AnomuraFungusBanner", "Anomura Fungus Banner", 9999, PrefixCategory::None),
+	item(1618, "AntlionBanner", "Antlion Banner", 9999, PrefixCategory::None),
+	item(1619, "ArapaimaBanner", "Arapaima Banner", 9999, PrefixCategory::None),
+	item(1620, "ArmoredSkeletonBanner", "Armored Skeleton Banner", 9999, PrefixCategory::None),
+	item(1621, "BatBanner", "Cave Bat Banner", 9999, PrefixCategory::None),
+	item(1622, "BirdBanner", "Bird Banner", 9999, PrefixCategory::None),
+	item(1623, "BlackRecluseBanner", "Black Recluse Banner", 9999, PrefixCategory::None),
+	item(1624, "BloodFeederBanner", "Blood Feeder Banner", 9999, PrefixCategory::None),
+	item(1625, "BloodJellyBanner", "Blood Jelly Banner", 9999, PrefixCategory::None),
+	item(1626, "BloodCrawlerBanner", "Blood Crawler Banner", 9999, PrefixCategory::None),
+	item(1627, "BoneSerpentBanner", "Bone Serpent Banner", 9999, PrefixCategory::None),
+	item(1628, "BunnyBanner", "Bunny Banner", 9999, PrefixCategory::None),
+	item(1629, "ChaosElementalBanner", "Chaos Elemental Banner", 9999, PrefixCategory::None),
+	item(1630, "MimicBanner", "Mimic Banner", 9999, PrefixCategory::None),
+	item(1631, "ClownBanner", "Clown Banner", 9999, PrefixCategory::None),
+	item(1632, "CorruptBunnyBanner", "Corrupt Bunny Banner", 9999, PrefixCategory::None),
+	item(1633, "CorruptGoldfishBanner", "Corrupt Goldfish Banner", 9999, PrefixCategory::None),
+	item(1634, "CrabBanner", "Crab Banner", 9999, PrefixCategory::None),
+	item(1635, "CrimeraBanner", "Crimera Banner", 9999, PrefixCategory::None),
+	item(1636, "CrimsonAxeBanner", "Crimson Axe Banner", 9999, PrefixCategory::None),
+	item(1637, "CursedHammerBanner", "Cursed Hammer Banner", 9999, PrefixCategory::None),
+	item(1638, "DemonBanner", "Demon Banner", 9999, PrefixCategory::None),
+	item(1639, "DemonEyeBanner", "Demon Eye Banner", 9999, PrefixCategory::None),
+	item(1640, "DerplingBanner", "Derpling Banner", 9999, PrefixCategory::None),
+	item(1641, "EaterofSoulsBanner", "Eater of Souls Banner", 9999, PrefixCategory::None),
+	item(1642, "EnchantedSwordBanner", "Enchanted Sword Banner", 9999, PrefixCategory::None),
+	item(1643, "ZombieEskimoBanner", "Frozen Zombie Banner", 9999, PrefixCategory::None),
+	item(1644, "FaceMonsterBanner", "Face Monster Banner", 9999, PrefixCategory::None),
+	item(1645, "FloatyGrossBanner", "Floaty Gross Banner", 9999, PrefixCategory::None),
+	item(1646, "FlyingFishBanner", "Flying Fish Banner", 9999, PrefixCategory::None),
+	item(1647, "FlyingSnakeBanner", "Flying Snake Banner", 9999, PrefixCategory::None),
+	item(1648, "FrankensteinBanner", "Frankenstein Banner", 9999, PrefixCategory::None),
+	item(1649, "FungiBulbBanner", "Fungi Bulb Banner", 9999, PrefixCategory::None),
+	item(1650, "FungoFishBanner", "Fungo Fish Banner", 9999, PrefixCategory::None),
+	item(1651, "GastropodBanner", "Gastropod Banner", 9999, PrefixCategory::None),
+	item(1652, "GoblinThiefBanner", "Goblin Thief Banner", 9999, PrefixCategory::None),
+	item(1653, "GoblinSorcererBanner", "Goblin Sorcerer Banner", 9999, PrefixCategory::None),
+	item(1654, "GoblinPeonBanner", "Goblin Peon Banner", 9999, PrefixCategory::None),
+	item(1655, "GoblinScoutBanner", "Goblin Scout Banner", 9999, PrefixCategory::None),
+	item(1656, "GoblinWarriorBanner", "Goblin Warrior Banner", 9999, PrefixCategory::None),
+	item(1657, "GoldfishBanner", "Goldfish Banner", 9999, PrefixCategory::None),
+	item(1658, "HarpyBanner", "Harpy Banner", 9999, PrefixCategory::None),
+	item(1659, "HellbatBanner", "Hellbat Banner", 9999, PrefixCategory::None),
+	item(1660, "HerplingBanner", "Herpling Banner", 9999, PrefixCategory::None),
+	item(1661, "HornetBanner", "Hornet Banner", 9999, PrefixCategory::None),
+	item(1662, "IceElementalBanner", "Ice Elemental Banner", 9999, PrefixCategory::None),
+	item(1663, "IcyMermanBanner", "Icy Merman Banner", 9999, PrefixCategory::None),
+	item(1664, "FireImpBanner", "Fire Imp Banner", 9999, PrefixCategory::None),
+	item(1665, "JellyfishBanner", "Blue Jellyfish Banner", 9999, PrefixCategory::None),
+	item(1666, "JungleCreeperBanner", "Jungle Creeper Banner", 9999, PrefixCategory::None),
+	item(1667, "LihzahrdBanner", "Lihzahrd Banner", 9999, PrefixCategory::None),
+	item(1668, "ManEaterBanner", "Man Eater Banner", 9999, PrefixCategory::None),
+	item(1669, "MeteorHeadBanner", "Meteor Head Banner", 9999, PrefixCategory::None),
+	item(1670, "MothBanner", "Moth Banner", 9999, PrefixCategory::None),
+	item(1671, "MummyBanner", "Mummy Banner", 9999, PrefixCategory::None),
+	item(1672, "MushiLadybugBanner", "Mushi Ladybug Banner", 9999, PrefixCategory::None),
+	item(1673, "ParrotBanner", "Parrot Banner", 9999, PrefixCategory::None),
+	item(1674, "PigronBanner", "Pigron Banner", 9999, PrefixCategory::None),
+	item(1675, "PiranhaBanner", "Piranha Banner", 9999, PrefixCategory::None),
+	item(1676, "PirateBanner", "Pirate Deckhand Banner", 9999, PrefixCategory::None),
+	item(1677, "PixieBanner", "Pixie Banner", 9999, PrefixCategory::None),
+	item(1678, "RaincoatZombieBanner", "Raincoat Zombie Banner", 9999, PrefixCategory::None),
+	item(1679, "ReaperBanner", "Reaper Banner", 9999, PrefixCategory::None),
+	item(1680, "SharkBanner", "Shark Banner", 9999, PrefixCategory::None),
+	item(1681, "SkeletonBanner", "Skeleton Banner", 9999, PrefixCategory::None),
+	item(1682, "SkeletonMageBanner", "Dark Caster Banner", 9999, PrefixCategory::None),
+	item(1683, "SlimeBanner", "Blue Slime Banner", 9999, PrefixCategory::None),
+	item(1684, "SnowFlinxBanner", "Snow Flinx Banner", 9999, PrefixCategory::None),
+	item(1685, "SpiderBanner", "Wall Creeper Banner", 9999, PrefixCategory::None),
+	item(1686, "SporeZombieBanner", "Spore Zombie Banner", 9999, PrefixCategory::None),
+	item(1687, "SwampThingBanner", "Swamp Thing Banner", 9999, PrefixCategory::None),
+	item(1688, "TortoiseBanner", "Giant Tortoise Banner", 9999, PrefixCategory::None),
+	item(1689, "ToxicSludgeBanner", "Toxic Sludge Banner", 9999, PrefixCategory::None),
+	item(1690, "UmbrellaSlimeBanner", "Umbrella Slime Banner", 9999, PrefixCategory::None),
+	item(1691, "UnicornBanner", "Unicorn Banner", 9999, PrefixCategory::None),
+	item(1692, "VampireBanner", "Vampire Banner", 9999, PrefixCategory::None),
+	item(1693, "VultureBanner", "Vulture Banner", 9999, PrefixCategory::None),
+	item(1694, "NypmhBanner", "Nymph Banner", 9999, PrefixCategory::None),
+	item(1695, "WerewolfBanner", "Werewolf Banner", 9999, PrefixCategory::None),
+	item(1696, "WolfBanner", "Wolf Banner", 9999, PrefixCategory::None),
+	item(1697, "WorldFeederBanner", "World Feeder Banner", 9999, PrefixCategory::None),
+	item(1698, "WormBanner", "Giant Worm Banner", 9999, PrefixCategory::None),
+	item(1699, "WraithBanner", "Wraith Banner", 9999, PrefixCategory::None),
+	item(1700, "WyvernBanner", "Wyvern Banner", 9999, PrefixCategory::None),
+	item(1701, "ZombieBanner", "Zombie Banner", 9999, PrefixCategory::None),
+	item(1702, "GlassPlatform", "Glass Platform", 9999, PrefixCategory::None),
+	item(1703, "GlassChair", "Glass Chair", 9999, PrefixCategory::None),
+	item(1704, "GoldenChair", "Golden Chair", 9999, PrefixCategory::None),
+	item(1705, "GoldenToilet", "Golden Toilet", 9999, PrefixCategory::None),
+	item(1706, "BarStool", "Bar Stool", 9999, PrefixCategory::None),
+	item(1707, "HoneyChair", "Honey Chair", 9999, PrefixCategory::None),
+	item(1708, "SteampunkChair", "Steampunk Chair", 9999, PrefixCategory::None),
+	item(1709, "GlassDoor", "Glass Door", 9999, PrefixCategory::None),
+	item(1710, "GoldenDoor", "Golden Door", 9999, PrefixCategory::None),
+	item(1711, "HoneyDoor", "Honey Door", 9999, PrefixCategory::None),
+	item(1712, "SteampunkDoor", "Steampunk Door", 9999, PrefixCategory::None),
+	item(1713, "GlassTable", "Glass Table", 9999, PrefixCategory::None),
+	item(1714, "BanquetTable", "Banquet Table", 9999, PrefixCategory::None),
+	item(1715, "Bar", "Bar", 9999, PrefixCategory::None),
+	item(1716, "GoldenTable", "Golden Table", 9999, PrefixCategory::None),
+	item(1717, "HoneyTable", "Honey Table", 9999, PrefixCategory::None),
+	item(1718, "SteampunkTable", "Steampunk Table", 9999, PrefixCategory::None),
+	item(1719, "GlassBed", "Glass Bed", 9999, PrefixCategory::None),
+	item(1720, "GoldenBed", "Golden Bed", 9999, PrefixCategory::None),
+	item(1721, "HoneyBed", "Honey Bed", 9999, PrefixCategory::None),
+	item(1722, "SteampunkBed", "Steampunk Bed", 9999, PrefixCategory::None),
+	item(1723, "LivingWoodWall", "Living Wood Wall", 9999, PrefixCategory::None),
+	item(1724, "FartinaJar", "Fart in a Jar", 1, PrefixCategory::Accessory),
+	item(1725, "Pumpkin", "Pumpkin", 9999, PrefixCategory::None),
+	item(1726, "PumpkinWall", "Pumpkin Wall", 9999, PrefixCategory::None),
+	item(1727, "Hay", "Hay", 9999, PrefixCategory::None),
+	item(1728, "HayWall", "Hay Wall", 9999, PrefixCategory::None),
+	item(1729, "SpookyWood", "Spooky Wood", 9999, PrefixCategory::None),
+	item(1730, "SpookyWoodWall", "Spooky Wood Wall", 9999, PrefixCategory::None),
+	item(1731, "PumpkinHelmet", "Pumpkin Helmet", 1, PrefixCategory::None),
+	item(1732, "PumpkinBreastplate", "Pumpkin Breastplate", 1, PrefixCategory::None),
+	item(1733, "PumpkinLeggings", "Pumpkin Leggings", 1, PrefixCategory::None),
+	item(1734, "CandyApple", "Candy Apple", 9999, PrefixCategory::None),
+	item(1735, "SoulCake", "Soul Cake", 9999, PrefixCategory::None),
+	item(1736, "NurseHat", "Nurse Hat", 1, PrefixCategory::None),
+	item(1737, "NurseShirt", "Nurse Shirt", 1, PrefixCategory::None),
+	item(1738, "NursePants", "Nurse Pants", 1, PrefixCategory::None),
+	item(1739, "WizardsHat", "Wizard's Hat", 1, PrefixCategory::None),
+	item(1740, "GuyFawkesMask", "Guy Fawkes Mask", 1, PrefixCategory::None),
+	item(1741, "DyeTraderRobe", "Dye Trader Robe", 1, PrefixCategory::None),
+	item(1742, "SteampunkGoggles", "Steampunk Goggles", 1, PrefixCategory::None),
+	item(1743, "CyborgHelmet", "Cyborg Helmet", 1, PrefixCategory::None),
+	item(1744, "CyborgShirt", "Cyborg Shirt", 1, PrefixCategory::None),
+	item(1745, "CyborgPants", "Cyborg Pants", 1, PrefixCategory::None),
+	item(1746, "CreeperMask", "Creeper Mask", 1, PrefixCategory::None),
+	item(1747, "CreeperShirt", "Creeper Shirt", 1, PrefixCategory::None),
+	item(1748, "CreeperPants", "Creeper Pants", 1, PrefixCategory::None),
+	item(1749, "CatMask", "Cat Mask", 1, PrefixCategory::None),
+	item(1750, "CatShirt", "Cat Shirt", 1, PrefixCategory::None),
+	item(1751, "CatPants", "Cat Pants", 1, PrefixCategory::None),
+	item(1752, "GhostMask", "Ghost Mask", 1, PrefixCategory::None),
+	item(1753, "GhostShirt", "Ghost Shirt", 1, PrefixCategory::None),
+	item(1754, "PumpkinMask", "Pumpkin Mask", 1, PrefixCategory::None),
+	item(1755, "PumpkinShirt", "Pumpkin Shirt", 1, PrefixCategory::None),
+	item(1756, "PumpkinPants", "Pumpkin Pants", 1, PrefixCategory::None),
+	item(1757, "RobotMask", "Robot Mask", 1, PrefixCategory::None),
+	item(1758, "RobotShirt", "Robot Shirt", 1, PrefixCategory::None),
+	item(1759, "RobotPants", "Robot Pants", 1, PrefixCategory::None),
+	item(1760, "UnicornMask", "Unicorn Mask", 1, PrefixCategory::None),
+	item(1761, "UnicornShirt", "Unicorn Shirt", 1, PrefixCategory::None),
+	item(1762, "UnicornPants", "Unicorn Pants", 1, PrefixCategory::None),
+	item(1763, "VampireMask", "Vampire Mask", 1, PrefixCategory::None),
+	item(1764, "VampireShirt", "Vampire Shirt", 1, PrefixCategory::None),
+	item(1765, "VampirePants", "Vampire Pants", 1, PrefixCategory::None),
+	item(1766, "WitchHat", "Witch Hat", 1, PrefixCategory::None),
+	item(1767, "LeprechaunHat", "Leprechaun Hat", 1, PrefixCategory::None),
+	item(1768, "LeprechaunShirt", "Leprechaun Shirt", 1, PrefixCategory::None),
+	item(1769, "LeprechaunPants", "Leprechaun Pants", 1, PrefixCategory::None),
+	item(1770, "PixieShirt", "Pixie Shirt", 1, PrefixCategory::None),
+	item(1771, "PixiePants", "Pixie Pants", 1, PrefixCategory::None),
+	item(1772, "PrincessHat", "Princess Hat", 1, PrefixCategory::None),
+	item(1773, "PrincessDressNew", "Princess Dress", 1, PrefixCategory::None),
+	item(1774, "GoodieBag", "Goodie Bag", 9999, PrefixCategory::None),
+	item(1775, "WitchDress", "Witch Dress", 1, PrefixCategory::None),
+	item(1776, "WitchBoots", "Witch Boots", 1, PrefixCategory::None),
+	item(1777, "BrideofFrankensteinMask", "Bride of Frankenstein Mask", 1, PrefixCategory::None),
+	item(1778, "BrideofFrankensteinDress", "Bride of Frankenstein Dress", 1, PrefixCategory::None),
+	item(1779, "KarateTortoiseMask", "Karate Tortoise Mask", 1, PrefixCategory::None),
+	item(1780, "KarateTortoiseShirt", "Karate Tortoise Shirt", 1, PrefixCategory::None),
+	item(1781, "KarateTortoisePants", "Karate Tortoise Pants", 1, PrefixCategory::None),
+	item(1782, "CandyCornRifle", "Candy Corn Rifle", 1, PrefixCategory::Ranged),
+	item(1783, "CandyCorn", "Candy Corn", 9999, PrefixCategory::None),
+	item(1784, "JackOLanternLauncher", "Jack 'O Lantern Launcher", 1, PrefixCategory::Ranged),
+	item(1785, "ExplosiveJackOLantern", "Explosive Jack 'O Lantern", 9999, PrefixCategory::None),
+	item(1786, "Sickle", "Sickle", 1, PrefixCategory::Melee),
+	item(1787, "PumpkinPie", "Pumpkin Pie", 9999, PrefixCategory::None),
+	item(1788, "ScarecrowHat", "Scarecrow Hat", 1, PrefixCategory::None),
+	item(1789, "ScarecrowShirt", "Scarecrow Shirt", 1, PrefixCategory::None),
+	item(1790, "ScarecrowPants", "Scarecrow Pants", 1, PrefixCategory::None),
+	item(1791, "Cauldron", "Cauldron", 9999, PrefixCategory::None),
+	item(1792, "PumpkinChair", "Pumpkin Chair", 9999, PrefixCategory::None),
+	item(1793, "PumpkinDoor", "Pumpkin Door", 9999, PrefixCategory::None),
+	item(1794, "PumpkinTable", "Pumpkin Table", 9999, PrefixCategory::None),
+	item(1795, "PumpkinWorkBench", "Pumpkin Work Bench", 9999, PrefixCategory::None),
+	item(1796, "PumpkinPlatform", "Pumpkin Platform", 9999, PrefixCategory::None),
+	item(1797, "TatteredFairyWings", "Tattered Fairy Wings", 1, PrefixCategory::Accessory),
+	item(1798, "SpiderEgg", "Spider Egg", 1, PrefixCategory::None),
+	item(1799, "MagicalPumpkinSeed", "Magical Pumpkin Seed", 1, PrefixCategory::None),
+	item(1800, "BatHook", "Bat Hook", 1, PrefixCategory::None),
+	item(1801, "BatScepter", "Bat Scepter", 1, PrefixCategory::Magic),
+	item(1802, "RavenStaff", "Raven Staff", 1, PrefixCategory::Magic),
+	item(1803, "JungleKeyMold", "Jungle Key Mold", 9999, PrefixCategory::None),
+	item(1804, "CorruptionKeyMold", "Corruption Key Mold", 9999, PrefixCategory::None),
+	item(1805, "CrimsonKeyMold", "Crimson Key Mold", 9999, PrefixCategory::None),
+	item(1806, "HallowedKeyMold", "Hallowed Key Mold", 9999, PrefixCategory::None),
+	item(1807, "FrozenKeyMold", "Frozen Key Mold", 9999, PrefixCategory::None),
+	item(1808, "HangingJackOLantern", "Hanging Jack 'O Lantern", 9999, PrefixCategory::None),
+	item(1809, "RottenEgg", "Rotten Egg", 9999, PrefixCategory::None),
+	item(1810, "UnluckyYarn", "Unlucky Yarn", 1, PrefixCategory::None),
+	item(1811, "BlackFairyDust", "Black Fairy Dust", 9999, PrefixCategory::None),
+	item(1812, "Jackelier", "Jackelier", 9999, PrefixCategory::None),
+	item(1813, "JackOLantern", "Jack 'O Lantern", 9999, PrefixCategory::None),
+	item(1814, "SpookyChair", "Spooky Chair", 9999, PrefixCategory::None),
+	item(1815, "SpookyDoor", "Spooky Door", 9999, PrefixCategory::None),
+	item(1816, "SpookyTable", "Spooky Table", 9999, PrefixCategory::None),
+	item(1817, "SpookyWorkBench", "Spooky Work Bench", 9999, PrefixCategory::None),
+	item(1818, "SpookyPlatform", "Spooky Platform", 9999, PrefixCategory::None),
+	item(1819, "ReaperHood", "Reaper Hood", 1, PrefixCategory::None),
+	item(1820, "ReaperRobe", "Reaper Robe", 1, PrefixCategory::None),
+	item(1821, "FoxMask", "Fox Mask", 1, PrefixCategory::None),
+	item(1822, "FoxShirt", "Fox Shirt", 1, PrefixCategory::None),
+	item(1823, "FoxPants", "Fox Pants", 1, PrefixCategory::None),
+	item(1824, "CatEars", "Cat Ears", 9999, PrefixCategory::None),
+	item(1825, "BloodyMachete", "Bloody Machete", 1, PrefixCategory::Melee),
+	item(1826, "TheHorsemansBlade", "The Horseman's Blade", 1, PrefixCategory::Melee),
+	item(1827, "BladedGlove", "Bladed Glove", 1, PrefixCategory::Melee),
+	item(1828, "PumpkinSeed", "Pumpkin Seed", 9999, PrefixCategory::None),
+	item(1829, "SpookyHook", "Spooky Hook", 1, PrefixCategory::None),
+	item(1830, "SpookyWings", "Spooky Wings", 1, PrefixCategory::Accessory),
+	item(1831, "SpookyTwig", "Spooky Twig", 1, PrefixCategory::None),
+	item(1832, "SpookyHelmet", "Spooky Helmet", 1, PrefixCategory::None),
+	item(1833, "SpookyBreastplate", "Spooky Breastplate", 1, PrefixCategory::None),
+	item(1834, "SpookyLeggings", "Spooky Leggings", 1, PrefixCategory::None),
+	item(1835, "StakeLauncher", "Stake Launcher", 1, PrefixCategory::Ranged),
+	item(1836, "Stake", "Stake", 9999, PrefixCategory::None),
+	item(1837, "CursedSapling", "Cursed Sapling", 1, PrefixCategory::None),
+	item(1838, "SpaceCreatureMask", "Space Creature Mask", 1, PrefixCategory::None),
+	item(1839, "SpaceCreatureShirt", "Space Creature Shirt", 1, PrefixCategory::None),
+	item(1840, "SpaceCreaturePants", "Space Creature Pants", 1, PrefixCategory::None),
+	item(1841, "WolfMask", "Wolf Mask", 1, PrefixCategory::None),
+	item(1842, "WolfShirt", "Wolf Shirt", 1, PrefixCategory::None),
+	item(1843, "WolfPants", "Wolf Pants", 1, PrefixCategory::None),
+	item(1844, "PumpkinMoonMedallion", "Pumpkin Moon Medallion", 20, PrefixCategory::None),
+	item(1845, "NecromanticScroll", "Necromantic Scroll", 1, PrefixCategory::Accessory),
+	item(1846, "JackingSkeletron", "Jacking Skeletron", 9999, PrefixCategory::None),
+	item(1847, "BitterHarvest", "Bitter Harvest", 9999, PrefixCategory::None),
+	item(1848, "BloodMoonCountess", "Blood Moon Countess", 9999, PrefixCategory::None),
+	item(1849, "HallowsEve", "Hallow's Eve", 9999, PrefixCategory::None),
+	item(1850, "MorbidCuriosity", "Morbid Curiosity", 9999, PrefixCategory::None),
+	item(1851, "TreasureHunterShirt", "Treasure Hunter Shirt", 1, PrefixCategory::None),
+	item(1852, "TreasureHunterPants", "Treasure Hunter Pants", 1, PrefixCategory::None),
+	item(1853, "DryadCoverings", "Dryad Coverings", 9999, PrefixCategory::None),
+	item(1854, "DryadLoincloth", "Dryad Loincloth", 9999, PrefixCategory::None),
+	item(1855, "MourningWoodTrophy", "Mourning Wood Trophy", 9999, PrefixCategory::None),
+	item(1856, "PumpkingTrophy", "Pumpking Trophy", 9999, PrefixCategory::None),
+	item(1857, "JackOLanternMask", "Jack 'O Lantern Mask", 1, PrefixCategory::None),
+	item(1858, "SniperScope", "Sniper Scope", 1, PrefixCategory::Accessory),
+	item(1859, "HeartLantern", "Heart Lantern", 9999, PrefixCategory::None),
+	item(1860, "JellyfishDivingGear", "Jellyfish Diving Gear", 1, PrefixCategory::Accessory),
+	item(1861, "ArcticDivingGear", "Arctic Diving Gear", 1, PrefixCategory::Accessory),
+	item(1862, "FrostsparkBoots", "Frostspark Boots", 1, PrefixCategory::Accessory),
+	item(1863, "FartInABalloon", "Fart in a Balloon", 1, PrefixCategory::Accessory),
+	item(1864, "PapyrusScarab", "Papyrus Scarab", 1, PrefixCategory::Accessory),
+	item(1865, "CelestialStone", "Celestial Stone", 1, PrefixCategory::Accessory),
+	item(1866, "Hoverboard", "Hoverboard", 1, PrefixCategory::Accessory),
+	item(1867, "CandyCane", "Candy Cane", 9999, PrefixCategory::None),
+	item(1868, "SugarPlum", "Sugar Plum", 9999, PrefixCategory::None),
+	item(1869, "Present", "Present", 9999, PrefixCategory::None),
+	item(1870, "RedRyder", "Red Ryder", 1, PrefixCategory::Ranged),
+	item(1871, "FestiveWings", "Festive Wings", 1, PrefixCategory::Accessory),
+	item(1872, "PineTreeBlock", "Pine Tree Block", 9999, PrefixCategory::None),
+	item(1873, "ChristmasTree", "Christmas Tree", 9999, PrefixCategory::None),
+	item(1874, "StarTopper1", "Star Topper 1", 9999, PrefixCategory::None),
+	item(1875, "StarTopper2", "Star Topper 2", 9999, PrefixCategory::None),
+	item(1876, "StarTopper3", "Star Topper 3", 9999, PrefixCategory::None),
+	item(1877, "BowTopper", "Bow Topper", 9999, PrefixCategory::None),
+	item(1878, "WhiteGarland", "White Garland", 9999, PrefixCategory::None),
+	item(1879, "WhiteAndRedGarland", "White and Red Garland", 9999, PrefixCategory::None),
+	item(1880, "RedGardland", "Red Garland", 9999, PrefixCategory::None),
+	item(1881, "RedAndGreenGardland", "Red and Green Garland", 9999, PrefixCategory::None),
+	item(1882, "GreenGardland", "Green Garland", 9999, PrefixCategory::None),
+	item(1883, "GreenAndWhiteGarland", "Green and White Garland", 9999, PrefixCategory::None),
+	item(1884, "MulticoloredBulb", "Multicolored Bulb", 9999, PrefixCategory::None),
+	item(1885, "RedBulb", "Red Bulb", 9999, PrefixCategory::None),
+	item(1886, "YellowBulb", "Yellow Bulb", 9999, PrefixCategory::None),
+	item(1887, "GreenBulb", "Green Bulb", 9999, PrefixCategory::None),
+	item(1888, "RedAndGreenBulb", "Red and Green Bulb", 9999, PrefixCategory::None),
+	item(1889, "YellowAndGreenBulb", "Yellow and Green Bulb", 9999, PrefixCategory::None),
+	item(1890, "RedAndYellowBulb", "Red and Yellow Bulb", 9999, PrefixCategory::None),
+	item(1891, "WhiteBulb", "White Bulb", 9999, PrefixCategory::None),
+	item(1892, "WhiteAndRedBulb", "White and Red Bulb", 9999, PrefixCategory::None),
+	item(1893, "WhiteAndYellowBulb", "White and Yellow Bulb", 9999, PrefixCategory::None),
+	item(1894, "WhiteAndGreenBulb", "White and Green Bulb", 9999, PrefixCategory::None),
+	item(1895, "MulticoloredLights", "Multicolored Lights", 9999, PrefixCategory::None),
+	item(1896, "RedLights", "Red Lights", 9999, PrefixCategory::None),
+	item(1897, "GreenLights", "Green Lights", 9999, PrefixCategory::None),
+	item(1898, "BlueLights", "Blue Lights", 9999, PrefixCategory::None),
+	item(1899, "YellowLights", "Yellow Lights", 9999, PrefixCategory::None),
+	item(1900, "RedAndYellowLights", "Red and Yellow Lights", 9999, PrefixCategory::None),
+	item(1901, "RedAndGreenLights", "Red and Green Lights", 9999, PrefixCategory::None),
+	item(1902, "YellowAndGreenLights", "Yellow and Green Lights", 9999, PrefixCategory::None),
+	item(1903, "BlueAndGreenLights", "Blue and Green Lights", 9999, PrefixCategory::None),
+	item(1904, "RedAndBlueLights", "Red and Blue Lights", 9999, PrefixCategory::None),
+	item(1905, "BlueAndYellowLights", "Blue and Yellow Lights", 9999, PrefixCategory::None),
+	item(1906, "GiantBow", "Giant Bow", 9999, PrefixCategory::None),
+	item(1907, "ReindeerAntlers", "Reindeer Antlers", 9999, PrefixCategory::None),
+	item(1908, "Holly", "Holly", 9999, PrefixCategory::None),
+	item(1909, "CandyCaneSword", "Candy Cane Sword", 1, PrefixCategory::Melee),
+	item(1910, "EldMelter", "Elf Melter", 1, PrefixCategory::Ranged),
+	item(1911, "ChristmasPudding", "Christmas Pudding", 9999, PrefixCategory::None),
+	item(1912, "Eggnog", "Eggnog", 9999, PrefixCategory::None),
+	item(1913, "StarAnise", "Star Anise", 9999, PrefixCategory::None),
+	item(1914, "ReindeerBells", "Reindeer Bells", 1, PrefixCategory::None),
+	item(1915, "CandyCaneHook", "Candy Cane Hook", 1, PrefixCategory::None),
+	item(1916, "ChristmasHook", "Christmas Hook", 1, PrefixCategory::None),
+	item(1917, "CnadyCanePickaxe", "Candy Cane Pickaxe", 1, PrefixCategory::Melee),
+	item(1918, "FruitcakeChakram", "Fruitcake Chakram", 1, PrefixCategory::Melee),
+	item(1919, "SugarCookie", "Sugar Cookie", 9999, PrefixCategory::None),
+	item(1920, "GingerbreadCookie", "Gingerbread Cookie", 9999, PrefixCategory::None),
+	item(1921, "HandWarmer", "Hand Warmer", 1, PrefixCategory::Accessory),
+	item(1922, "Coal", "Coal", 1, PrefixCategory::None),
+	item(1923, "Toolbox", "Toolbox", 1, PrefixCategory::Accessory),
+	item(1924, "PineDoor", "Pine Door", 9999, PrefixCategory::None),
+	item(1925, "PineChair", "Pine Chair", 9999, PrefixCategory::None),
+	item(1926, "PineTable", "Pine Table", 9999, PrefixCategory::None),
+	item(1927, "DogWhistle", "Dog Whistle", 1, PrefixCategory::None),
+	item(1928, "ChristmasTreeSword", "Christmas Tree Sword", 1, PrefixCategory::Melee),
+	item(1929, "ChainGun", "Chain Gun", 1, PrefixCategory::Ranged),
+	item(1930, "Razorpine", "Razorpine", 1, PrefixCategory::Magic),
+	item(1931, "BlizzardStaff", "Blizzard Staff", 1, PrefixCategory::Magic),
+	item(1932, "MrsClauseHat", "Mrs. Claus Hat", 1, PrefixCategory::None),
+	item(1933, "MrsClauseShirt", "Mrs. Claus Shirt", 1, PrefixCategory::None),
+	item(1934, "MrsClauseHeels", "Mrs. Claus Heels", 9999, PrefixCategory::None),
+	item(1935, "ParkaHood", "Parka Hood", 1, PrefixCategory::None),
+	item(1936, "ParkaCoat", "Parka Coat", 1, PrefixCategory::None),
+	item(1937, "ParkaPants", "Parka Pants", 1, PrefixCategory::None),
+	item(1938, "SnowHat", "Snow Hat", 1, PrefixCategory::None),
+	item(1939, "UglySweater", "Ugly Sweater", 9999, PrefixCategory::None),
+	item(1940, "TreeMask", "Tree Mask", 1, PrefixCategory::None),
+	item(1941, "TreeShirt", "Tree Shirt", 1, PrefixCategory::None),
+	item(1942, "TreeTrunks", "Tree Trunks", 9999, PrefixCategory::None),
+	item(1943, "ElfHat", "Elf Hat", 1, PrefixCategory::None),
+	item(1944, "ElfShirt", "Elf Shirt", 1, PrefixCategory::None),
+	item(1945, "ElfPants", "Elf Pants", 1, PrefixCategory::None),
+	item(1946, "SnowmanCannon", "Snowman Cannon", 1, PrefixCategory::Ranged),
+	item(1947, "NorthPole", "North Pole", 1, PrefixCategory::Melee),
+	item(1948, "ChristmasTreeWallpaper", "Christmas Tree Wallpaper", 9999, PrefixCategory::None),
+	item(1949, "OrnamentWallpaper", "Ornament Wallpaper", 9999, PrefixCategory::None),
+	item(1950, "CandyCaneWallpaper", "Candy Cane Wallpaper", 9999, PrefixCategory::None),
+	item(1951, "FestiveWallpaper", "Festive Wallpaper", 9999, PrefixCategory::None),
+	item(1952, "StarsWallpaper", "Stars Wallpaper", 9999, PrefixCategory::None),
+	item(1953, "SquigglesWallpaper", "Squiggles Wallpaper", 9999, PrefixCategory::None),
+	item(1954, "SnowflakeWallpaper", "Snowflake Wallpaper", 9999, PrefixCategory::None),
+	item(1955, "KrampusHornWallpaper", "Krampus Horn Wallpaper", 9999, PrefixCategory::None),
+	item(1956, "BluegreenWallpaper", "Bluegreen Wallpaper", 9999, PrefixCategory::None),
+	item(1957, "GrinchFingerWallpaper", "Grinch Finger Wallpaper", 9999, PrefixCategory::None),
+	item(1958, "NaughtyPresent", "Naughty Present", 20, PrefixCategory::None),
+	item(1959, "BabyGrinchMischiefWhistle", "Baby Grinch's Mischief Whistle", 1, PrefixCategory::None),
+	item(1960, "IceQueenTrophy", "Ice Queen Trophy", 9999, PrefixCategory::None),
+	item(1961, "SantaNK1Trophy", "Santa-NK1 Trophy", 9999, PrefixCategory::None),
+	item(1962, "EverscreamTrophy", "Everscream Trophy", 9999, PrefixCategory::None),
+	item(1963, "MusicBoxPumpkinMoon", "Music Box (Pumpkin Moon)", 1, PrefixCategory::None),
+	item(1964, "MusicBoxAltUnderground", "Music Box (Alt Underground)", 1, PrefixCategory::None),
+	item(1965, "MusicBoxFrostMoon", "Music Box (Frost Moon)", 1, PrefixCategory::None),
+	item(1966, "BrownPaint", "Brown Paint", 9999, PrefixCategory::None),
+	item(1967, "ShadowPaint", "Shadow Paint", 9999, PrefixCategory::None),
+	item(1968, "NegativePaint", "Negative Paint", 9999, PrefixCategory::None),
+	item(1969, "TeamDye", "Team Dye", 9999, PrefixCategory::None),
+	item(1970, "AmethystGemsparkBlock", "Amethyst Gemspark Block", 9999, PrefixCategory::None),
+	item(1971, "TopazGemsparkBlock", "Topaz Gemspark Block", 9999, PrefixCategory::None),
+	item(1972, "SapphireGemsparkBlock", "Sapphire Gemspark Block", 9999, PrefixCategory::None),
+	item(1973, "EmeraldGemsparkBlock", "Emerald Gemspark Block", 9999, PrefixCategory::None),
+	item(1974, "RubyGemsparkBlock", "Ruby Gemspark Block", 9999, PrefixCategory::None),
+	item(1975, "DiamondGemsparkBlock", "Diamond Gemspark Block", 9999, PrefixCategory::None),
+	item(1976, "AmberGemsparkBlock", "Amber Gemspark Block", 9999, PrefixCategory::None),
+	item(1977, "LifeHairDye", "Life Hair Dye", 9999, PrefixCategory::None),
+	item(1978, "ManaHairDye", "Mana Hair Dye", 9999, PrefixCategory::None),
+	item(1979, "DepthHairDye", "Depth Hair Dye", 9999, PrefixCategory::None),
+	item(1980, "MoneyHairDye", "Money Hair Dye", 9999, PrefixCategory::None),
+	item(1981, "TimeHairDye", "Time Hair Dye", 9999, PrefixCategory::None),
+	item(1982, "TeamHairDye", "Team Hair Dye", 9999, PrefixCategory::None),
+	item(1983, "BiomeHairDye", "Biome Hair Dye", 9999, PrefixCategory::None),
+	item(1984, "PartyHairDye", "Party Hair Dye", 9999, PrefixCategory::None),
+	item(1985, "RainbowHairDye", "Rainbow Hair Dye", 9999, PrefixCategory::None),
+	item(1986, "SpeedHairDye", "Speed Hair Dye", 9999, PrefixCategory::None),
+	item(1987, "AngelHalo", "Angel Halo", 9999, PrefixCategory::None),
+	item(1988, "Fez", "Fez", 9999, PrefixCategory::None),
+	item(1989, "Womannquin", "Womannquin", 9999, PrefixCategory::None),
+	item(1990, "HairDyeRemover", "Hair Dye Remover", 9999, PrefixCategory::None),
+	item(1991, "BugNet", "Bug Net", 1, PrefixCategory::None),
+	item(1992, "Firefly", "Firefly", 9999, PrefixCategory::None),
+	item(1993, "FireflyinaBottle", "Firefly in a Bottle", 9999, PrefixCategory::None),
+	item(1994, "MonarchButterfly", "Monarch Butterfly", 9999, PrefixCategory::None),
+	item(1995, "PurpleEmperorButterfly", "Purple Emperor Butterfly", 9999, PrefixCategory::None),
+	item(1996, "RedAdmiralButterfly", "Red Admiral Butterfly", 9999, PrefixCategory::None),
+	item(1997, "UlyssesButterfly", "Ulysses Butterfly", 9999, PrefixCategory::None),
+	item(1998, "SulphurButterfly", "Sulphur Butterfly", 9999, PrefixCategory::None),
+	item(1999, "TreeNymphButterfly", "Tree Nymph Butterfly", 9999, PrefixCategory::None),
+	item(2000, "ZebraSwallowtailButterfly", "Zebra Swallowtail Butterfly", 9999, PrefixCategory::None),
+	item(2001, "JuliaButterfly", "Julia Butterfly", 9999, PrefixCategory::None),
+	item(2002, "Worm", "Worm", 9999, PrefixCategory::None),
+	item(2003, "Mouse", "Mouse", 9999, PrefixCategory::None),
+	item(2004, "LightningBug", "Lightning Bug", 9999, PrefixCategory::None),
+	item(2005, "LightningBuginaBottle", "Lightning Bug in a Bottle", 9999, PrefixCategory::None),
+	item(2006, "Snail", "Snail", 9999, PrefixCategory::None),
+	item(2007, "GlowingSnail", "Glowing Snail", 9999, PrefixCategory::None),
+	item(2008, "FancyGreyWallpaper", "Fancy Grey Wallpaper", 9999, PrefixCategory::None),
+	item(2009, "IceFloeWallpaper", "Ice Floe Wallpaper", 9999, PrefixCategory::None),
+	item(2010, "MusicWallpaper", "Music Wallpaper", 9999, PrefixCategory::None),
+	item(2011, "PurpleRainWallpaper", "Purple Rain Wallpaper", 9999, PrefixCategory::None),
+	item(2012, "RainbowWallpaper", "Rainbow Wallpaper", 9999, PrefixCategory::None),
+	item(2013, "SparkleStoneWallpaper", "Sparkle Stone Wallpaper", 9999, PrefixCategory::None),
+	item(2014, "StarlitHeavenWallpaper", "Starlit Heaven Wallpaper", 9999, PrefixCategory::None),
+	item(2015, "Bird", "Bird", 9999, PrefixCategory::None),
+	item(2016, "BlueJay", "Blue Jay", 9999, PrefixCategory::None),
+	item(2017, "Cardinal", "Cardinal", 9999, PrefixCategory::None),
+	item(2018, "Squirrel", "Squirrel", 9999, PrefixCategory::None),
+	item(2019, "Bunny", "Bunny", 9999, PrefixCategory::None),
+	item(2020, "CactusBookcase", "Cactus Bookcase", 9999, PrefixCategory::None),
+	item(2021, "EbonwoodBookcase", "Ebonwood Bookcase", 9999, PrefixCategory::None),
+	item(2022, "FleshBookcase", "Flesh Bookcase", 9999, PrefixCategory::None),
+	item(2023, "HoneyBookcase", "Honey Bookcase", 9999, PrefixCategory::None),
+	item(2024, "SteampunkBookcase", "Steampunk Bookcase", 9999, PrefixCategory::None),
+	item(2025, "GlassBookcase", "Glass Bookcase", 9999, PrefixCategory::None),
+	item(2026, "RichMahoganyBookcase", "Rich Mahogany Bookcase", 9999, PrefixCategory::None),
+	item(2027, "PearlwoodBookcase", "Pearlwood Bookcase", 9999, PrefixCategory::None),
+	item(2028, "SpookyBookcase", "Spooky Bookcase", 9999, PrefixCategory::None),
+	item(2029, "SkywareBookcase", "Skyware Bookcase", 9999, PrefixCategory::None),
+	item(2030, "LihzahrdBookcase", "Lihzahrd Bookcase", 9999, PrefixCategory::None),
+	item(2031, "FrozenBookcase", "Frozen Bookcase", 9999, PrefixCategory::None),
+	item(2032, "CactusLantern", "Cactus Lantern", 9999, PrefixCategory::None),
+	item(2033, "EbonwoodLantern", "Ebonwood Lantern", 9999, PrefixCategory::None),
+	item(2034, "FleshLantern", "Flesh Lantern", 9999, PrefixCategory::None),
+	item(2035, "HoneyLantern", "Honey Lantern", 9999, PrefixCategory::None),
+	item(2036, "SteampunkLantern", "Steampunk Lantern", 9999, PrefixCategory::None),
+	item(2037, "GlassLantern", "Glass Lantern", 9999, PrefixCategory::None),
+	item(2038, "RichMahoganyLantern", "Rich Mahogany Lantern", 9999, PrefixCategory::None),
+	item(2039, "PearlwoodLantern", "Pearlwood Lantern", 9999, PrefixCategory::None),
+	item(2040, "FrozenLantern", "Frozen Lantern", 9999, PrefixCategory::None),
+	item(2041, "LihzahrdLantern", "Lihzahrd Lantern", 9999, PrefixCategory::None),
+	item(2042, "SkywareLantern", "Skyware Lantern", 9999, PrefixCategory::None),
+	item(2043, "SpookyLantern", "Spooky Lantern", 9999, PrefixCategory::None),
+	item(2044, "FrozenDoor", "Frozen Door", 9999, PrefixCategory::None),
+	item(2045, "CactusCandle", "Cactus Candle", 9999, PrefixCategory::None),
+	item(2046, "EbonwoodCandle", "Ebonwood Candle", 9999, PrefixCategory::None),
+	item(2047, "FleshCandle", "Flesh Candle", 9999, PrefixCategory::None),
+	item(2048, "GlassCandle", "Glass Candle", 9999, PrefixCategory::None),
+	item(2049, "FrozenCandle", "Frozen Candle", 9999, PrefixCategory::None),
+	item(2050, "RichMahoganyCandle", "Rich Mahogany Candle", 9999, PrefixCategory::None),
+	item(2051, "PearlwoodCandle", "Pearlwood Candle", 9999, PrefixCategory::None),
+	item(2052, "LihzahrdCandle", "Lihzahrd Candle", 9999, PrefixCategory::None),
+	item(2053, "SkywareCandle", "Skyware Candle", 9999, PrefixCategory::None),
+	item(2054, "PumpkinCandle", "Pumpkin Candle", 9999, PrefixCategory::None),
+	item(2055, "CactusChandelier", "Cactus Chandelier", 9999, PrefixCategory::None),
+	item(2056, "EbonwoodChandelier", "Ebonwood Chandelier", 9999, PrefixCategory::None),
+	item(2057, "FleshChandelier", "Flesh Chandelier", 9999, PrefixCategory::None),
+	item(2058, "HoneyChandelier", "Honey Chandelier", 9999, PrefixCategory::None),
+	item(2059, "FrozenChandelier", "Frozen Chandelier", 9999, PrefixCategory::None),
+	item(2060, "RichMahoganyChandelier", "Rich Mahogany Chandelier", 9999, PrefixCategory::None),
+	item(2061, "PearlwoodChandelier", "Pearlwood Chandelier", 9999, PrefixCategory::None),
+	item(2062, "LihzahrdChandelier", "Lihzahrd Chandelier", 9999, PrefixCategory::None),
+	item(2063, "SkywareChandelier", "Skyware Chandelier", 9999, PrefixCategory::None),
+	item(2064, "SpookyChandelier", "Spooky Chandelier", 9999, PrefixCategory::None),
+	item(2065, "GlassChandelier", "Glass Chandelier", 9999, PrefixCategory::None),
+	item(2066, "CactusBed", "Cactus Bed", 9999, PrefixCategory::None),
+	item(2067, "FleshBed", "Flesh Bed", 9999, PrefixCategory::None),
+	item(2068, "FrozenBed", "Frozen Bed", 9999, PrefixCategory::None),
+	item(2069, "LihzahrdBed", "Lihzahrd Bed", 9999, PrefixCategory::None),
+	item(2070, "SkywareBed", "Skyware Bed", 9999, PrefixCategory::None),
+	item(2071, "SpookyBed", "Spooky Bed", 9999, PrefixCategory::None),
+	item(2072, "CactusBathtub", "Cactus Bathtub", 9999, PrefixCategory::None),
+	item(2073, "EbonwoodBathtub", "Ebonwood Bathtub", 9999, PrefixCategory::None),
+	item(2074, "FleshBathtub", "Flesh Bathtub", 9999, PrefixCategory::None),
+	item(2075, "GlassBathtub", "Glass Bathtub", 9999, PrefixCategory::None),
+	item(2076, "FrozenBathtub", "Frozen Bathtub", 9999, PrefixCategory::None),
+	item(2077, "RichMahoganyBathtub", "Rich Mahogany Bathtub", 9999, PrefixCategory::None),
+	item(2078, "PearlwoodBathtub", "Pearlwood Bathtub", 9999, PrefixCategory::None),
+	item(2079, "LihzahrdBathtub", "Lihzahrd Bathtub", 9999, PrefixCategory::None),
+	item(2080, "SkywareBathtub", "Skyware Bathtub", 9999, PrefixCategory::None),
+	item(2081, "SpookyBathtub", "Spooky Bathtub", 9999, PrefixCategory::None),
+	item(2082, "CactusLamp", "Cactus Lamp", 9999, PrefixCategory::None),
+	item(2083, "EbonwoodLamp", "Ebonwood Lamp", 9999, PrefixCategory::None),
+	item(2084, "FleshLamp", "Flesh Lamp", 9999, PrefixCategory::None),
+	item(2085, "GlassLamp", "Glass Lamp", 9999, PrefixCategory::None),
+	item(2086, "FrozenLamp", "Frozen Lamp", 9999, PrefixCategory::None),
+	item(2087, "RichMahoganyLamp", "Rich Mahogany Lamp", 9999, PrefixCategory::None),
+	item(2088, "PearlwoodLamp", "Pearlwood Lamp", 9999, PrefixCategory::None),
+	item(2089, "LihzahrdLamp", "Lihzahrd Lamp", 9999, PrefixCategory::None),
+	item(2090, "SkywareLamp", "Skyware Lamp", 9999, PrefixCategory::None),
+	item(2091, "SpookyLamp", "Spooky Lamp", 9999, PrefixCategory::None),
+	item(2092, "CactusCandelabra", "Cactus Candelabra", 9999, PrefixCategory::None),
+	item(2093, "EbonwoodCandelabra", "Ebonwood Candelabra", 9999, PrefixCategory::None),
+	item(2094, "FleshCandelabra", "Flesh Candelabra", 9999, PrefixCategory::None),
+	item(2095, "HoneyCandelabra", "Honey Candelabra", 9999, PrefixCategory::None),
+	item(2096, "SteampunkCandelabra", "Steampunk Candelabra", 9999, PrefixCategory::None),
+	item(2097, "GlassCandelabra", "Glass Candelabra", 9999, PrefixCategory::None),
+	item(2098, "RichMahoganyCandelabra", "Rich Mahogany Candelabra", 9999, PrefixCategory::None),
+	item(2099, "PearlwoodCandelabra", "Pearlwood Candelabra", 9999, PrefixCategory::None),
+	item(2100, "FrozenCandelabra", "Frozen Candelabra", 9999, PrefixCategory::None),
+	item(2101, "LihzahrdCandelabra", "Lihzahrd Candelabra", 9999, PrefixCategory::None),
+	item(2102, "SkywareCandelabra", "Skyware Candelabra", 9999, PrefixCategory::None),
+	item(2103, "SpookyCandelabra", "Spooky Candelabra", 9999, PrefixCategory::None),
+	item(2104, "BrainMask", "Brain of Cthulhu Mask", 1, PrefixCategory::None),
+	item(2105, "FleshMask", "Wall of Flesh Mask", 1, PrefixCategory::None),
+	item(2106, "TwinMask", "Twin Mask", 1, PrefixCategory::None),
+	item(2107, "SkeletronPrimeMask", "Skeletron Prime Mask", 1, PrefixCategory::None),
+	item(2108, "BeeMask", "Queen Bee Mask", 1, PrefixCategory::None),
+	item(2109, "PlanteraMask", "Plantera Mask", 1, PrefixCategory::None),
+	item(2110, "GolemMask", "Golem Mask", 1, PrefixCategory::None),
+	item(2111, "EaterMask", "Eater of Worlds Mask", 1, PrefixCategory::None),
+	item(2112, "EyeMask", "Eye of Cthulhu Mask", 1, PrefixCategory::None),
+	item(2113, "DestroyerMask", "Destroyer Mask", 1, PrefixCategory::None),
+	item(2114, "BlacksmithRack", "Blacksmith Rack", 9999, PrefixCategory::None),
+	item(2115, "CarpentryRack", "Carpentry Rack", 9999, PrefixCategory::None),
+	item(2116, "HelmetRack", "Helmet Rack", 9999, PrefixCategory::None),
+	item(2117, "SpearRack", "Spear Rack", 9999, PrefixCategory::None),
+	item(2118, "SwordRack", "Sword Rack", 9999, PrefixCategory::None),
+	item(2119, "StoneSlab", "Stone Slab", 9999, PrefixCategory::None),
+	item(2120, "SandstoneSlab", "Sandstone Slab", 9999, PrefixCategory::None),
+	item(2121, "Frog", "Frog", 9999, PrefixCategory::None),
+	item(2122, "MallardDuck", "Mallard Duck", 9999, PrefixCategory::None),
+	item(2123, "Duck", "Duck", 9999, PrefixCategory::None),
+	item(2124, "HoneyBathtub", "Honey Bathtub", 9999, PrefixCategory::None),
+	item(2125, "SteampunkBathtub", "Steampunk Bathtub", 9999, PrefixCategory::None),
+	item(2126, "LivingWoodBathtub", "Living Wood Bathtub", 9999, PrefixCategory::None),
+	item(2127, "ShadewoodBathtub", "Shadewood Bathtub", 9999, PrefixCategory::None),
+	item(2128, "BoneBathtub", "Bone Bathtub", 9999, PrefixCategory::None),
+	item(2129, "HoneyLamp", "Honey Lamp", 9999, PrefixCategory::None),
+	item(2130, "SteampunkLamp", "Steampunk Lamp", 9999, PrefixCategory::None),
+	item(2131, "LivingWoodLamp", "Living Wood Lamp", 9999, PrefixCategory::None),
+	item(2132, "ShadewoodLamp", "Shadewood Lamp", 9999, PrefixCategory::None),
+	item(2133, "GoldenLamp", "Golden Lamp", 9999, PrefixCategory::None),
+	item(2134, "BoneLamp", "Bone Lamp", 9999, PrefixCategory::None),
+	item(2135, "LivingWoodBookcase", "Living Wood Bookcase", 9999, PrefixCategory::None),
+	item(2136, "ShadewoodBookcase", "Shadewood Bookcase", 9999, PrefixCategory::None),
+	item(2137, "GoldenBookcase", "Golden Bookcase", 9999, PrefixCategory::None),
+	item(2138, "BoneBookcase", "Bone Bookcase", 9999, PrefixCategory::None),
+	item(2139, "LivingWoodBed", "Living Wood Bed", 9999, PrefixCategory::None),
+	item(2140, "BoneBed", "Bone Bed", 9999, PrefixCategory::None),
+	item(2141, "LivingWoodChandelier", "Living Wood Chandelier", 9999, PrefixCategory::None),
+	item(2142, "ShadewoodChandelier", "Shadewood Chandelier", 9999, PrefixCategory::None),
+	item(2143, "GoldenChandelier", "Golden Chandelier", 9999, PrefixCategory::None),
+	item(2144, "BoneChandelier", "Bone Chandelier", 9999, PrefixCategory::None),
+	item(2145, "LivingWoodLantern", "Living Wood Lantern", 9999, PrefixCategory::None),
+	item(2146, "ShadewoodLantern", "Shadewood Lantern", 9999, PrefixCategory::None),
+	item(2147, "GoldenLantern", "Golden Lantern", 9999, PrefixCategory::None),
+	item(2148, "BoneLantern", "Bone Lantern", 9999, PrefixCategory::None),
+	item(2149, "LivingWoodCandelabra", "Living Wood Candelabra", 9999, PrefixCategory::None),
+	item(2150, "ShadewoodCandelabra", "Shadewood Candelabra", 9999, PrefixCategory::None),
+	item(2151, "GoldenCandelabra", "Golden Candelabra", 9999, PrefixCategory::None),
+	item(2152, "BoneCandelabra", "Bone Candelabra", 9999, PrefixCategory::None),
+	item(2153, "LivingWoodCandle", "Living Wood Candle", 9999, PrefixCategory::None),
+	item(2154, "ShadewoodCandle", "Shadewood Candle", 9999, PrefixCategory::None),
+	item(2155, "GoldenCandle", "Golden Candle", 9999, PrefixCategory::None),
+	item(2156, "BlackScorpion", "Black Scorpion", 9999, PrefixCategory::None),
+	item(2157, "Scorpion", "Scorpion", 9999, PrefixCategory::None),
+	item(2158, "BubbleWallpaper", "Bubble Wallpaper", 9999, PrefixCategory::None),
+	item(2159, "CopperPipeWallpaper", "Copper Pipe Wallpaper", 9999, PrefixCategory::None),
+	item(2160, "DuckyWallpaper", "Ducky Wallpaper", 9999, PrefixCategory::None),
+	item(2161, "FrostCore", "Frost Core", 9999, PrefixCategory::None),
+	item(2162, "BunnyCage", "Bunny Cage", 9999, PrefixCategory::None),
+	item(2163, "SquirrelCage", "Squirrel Cage", 9999, PrefixCategory::None),
+	item(2164, "MallardDuckCage", "Mallard Duck Cage", 9999, PrefixCategory::None),
+	item(2165, "DuckCage", "Duck Cage", 9999, PrefixCategory::None),
+	item(2166, "BirdCage", "Bird Cage", 9999, PrefixCategory::None),
+	item(2167, "BlueJayCage", "Blue Jay Cage", 9999, PrefixCategory::None),
+	item(2168, "CardinalCage", "Cardinal Cage", 9999, PrefixCategory::None),
+	item(2169, "WaterfallWall", "Waterfall Wall", 9999, PrefixCategory::None),
+	item(2170, "LavafallWall", "Lavafall Wall", 9999, PrefixCategory::None),
+	item(2171, "CrimsonSeeds", "Crimson Seeds", 9999, PrefixCategory::None),
+	item(2172, "HeavyWorkBench", "Heavy Work Bench", 9999, PrefixCategory::None),
+	item(2173, "CopperPlating", "Copper Plating", 9999, PrefixCategory::None),
+	item(2174, "SnailCage", "Snail Cage", 9999, PrefixCategory::None),
+	item(2175, "GlowingSnailCage", "Glowing Snail Cage", 9999, PrefixCategory::None),
+	item(2176, "ShroomiteDiggingClaw", "Shroomite Digging Claw", 1, PrefixCategory::Melee),
+	item(2177, "AmmoBox", "Ammo Box", 9999, PrefixCategory::None),
+	item(2178, "MonarchButterflyJar", "Monarch Butterfly Jar", 9999, PrefixCategory::None),
+	item(2179, "PurpleEmperorButterflyJar", "Purple Emperor Butterfly Jar", 9999, PrefixCategory::None),
+	item(2180, "RedAdmiralButterflyJar", "Red Admiral Butterfly Jar", 9999, PrefixCategory::None),
+	item(2181, "UlyssesButterflyJar", "Ulysses Butterfly Jar", 9999, PrefixCategory::None),
+	item(2182, "SulphurButterflyJar", "Sulphur Butterfly Jar", 9999, PrefixCategory::None),
+	item(2183, "TreeNymphButterflyJar", "Tree Nymph Butterfly Jar", 9999, PrefixCategory::None),
+	item(2184, "ZebraSwallowtailButterflyJar", "Zebra Swallowtail Butterfly Jar", 9999, PrefixCategory::None),
+	item(2185, "JuliaButterflyJar", "Julia Butterfly Jar", 9999, PrefixCategory::None),
+	item(2186, "ScorpionCage", "Scorpion Cage", 9999, PrefixCategory::None),
+	item(2187, "BlackScorpionCage", "Black Scorpion Cage", 9999, PrefixCategory::None),
+	item(2188, "VenomStaff", "Venom Staff", 1, PrefixCategory::Magic),
+	item(2189, "SpectreMask", "Spectre Mask", 1, PrefixCategory::None),
+	item(2190, "FrogCage", "Frog Cage", 9999, PrefixCategory::None),
+	item(2191, "MouseCage", "Mouse Cage", 9999, PrefixCategory::None),
+	item(2192, "BoneWelder", "Bone Welder", 9999, PrefixCategory::None),
+	item(2193, "FleshCloningVaat", "Flesh Cloning Vat", 9999, PrefixCategory::None),
+	item(2194, "GlassKiln", "Glass Kiln", 9999, PrefixCategory::None),
+	item(2195, "LihzahrdFurnace", "Lihzahrd Furnace", 9999, PrefixCategory::None),
+	item(2196, "LivingLoom", "Living Loom", 9999, PrefixCategory::None),
+	item(2197, "SkyMill", "Sky Mill", 9999, PrefixCategory::None),
+	item(2198, "IceMachine", "Ice Machine", 9999, PrefixCategory::None),
+	item(2199, "BeetleHelmet", "Beetle Helmet", 1, PrefixCategory::None),
+	item(2200, "BeetleScaleMail", "Beetle Scale Mail", 9999, PrefixCategory::None),
+	item(2201, "BeetleShell", "Beetle Shell", 9999, PrefixCategory::None),
+	item(2202, "BeetleLeggings", "Beetle Leggings", 1, PrefixCategory::None),
+	item(2203, "SteampunkBoiler", "Steampunk Boiler", 9999, PrefixCategory::None),
+	item(2204, "HoneyDispenser", "Honey Dispenser", 9999, PrefixCategory::None),
+	item(2205, "Penguin", "Penguin", 9999, PrefixCategory::None),
+	item(2206, "PenguinCage", "Penguin Cage", 9999, PrefixCategory::None),
+	item(2207, "WormCage", "Worm Cage", 9999, PrefixCategory::None),
+	item(2208, "Terrarium", "Terrarium", 9999, PrefixCategory::None),
+	item(2209, "SuperManaPotion", "Super Mana Potion", 9999, PrefixCategory::None),
+	item(2210, "EbonwoodFence", "Ebonwood Fence", 9999, PrefixCategory::None),
+	item(2211, "RichMahoganyFence", "Rich Mahogany Fence", 9999, PrefixCategory::None),
+	item(2212, "PearlwoodFence", "Pearlwood Fence", 9999, PrefixCategory::None),
+	item(2213, "ShadewoodFence", "Shadewood Fence", 9999, PrefixCategory::None),
+	item(2214, "BrickLayer", "Brick Layer", 1, PrefixCategory::Accessory),
+	item(2215, "ExtendoGrip", "Extendo Grip", 1, PrefixCategory::Accessory),
+	item(2216, "PaintSprayer", "Paint Sprayer", 1, PrefixCategory::Accessory),
+	item(2217, "PortableCementMixer", "Portable Cement Mixer", 1, PrefixCategory::Accessory),
+	item(2218, "BeetleHusk", "Beetle Husk", 9999, PrefixCategory::None),
+	item(2219, "CelestialMagnet", "Celestial Magnet", 1, PrefixCategory::Accessory),
+	item(2220, "CelestialEmblem", "Celestial Emblem", 1, PrefixCategory::Accessory),
+	item(2221, "CelestialCuffs", "Celestial Cuffs", 1, PrefixCategory::Accessory),
+	item(2222, "PeddlersHat", "Peddler's Hat", 1, PrefixCategory::None),
+	item(2223, "PulseBow", "Pulse Bow", 1, PrefixCategory::Ranged),
+	item(2224, "DynastyChandelier", "Large Dynasty Lantern", 9999, PrefixCategory::None),
+	item(2225, "DynastyLamp", "Dynasty Lamp", 9999, PrefixCategory::None),
+	item(2226, "DynastyLantern", "Dynasty Lantern", 9999, PrefixCategory::None),
+	item(2227, "DynastyCandelabra", "Large Dynasty Candle", 9999, PrefixCategory::None),
+	item(2228, "DynastyChair", "Dynasty Chair", 9999, PrefixCategory::None),
+	item(2229, "DynastyWorkBench", "Dynasty Work Bench", 9999, PrefixCategory::None),
+	item(2230, "DynastyChest", "Dynasty Chest", 9999, PrefixCategory::None),
+	item(2231, "DynastyBed", "Dynasty Bed", 9999, PrefixCategory::None),
+	item(2232, "DynastyBathtub", "Dynasty Bathtub", 9999, PrefixCategory::None),
+	item(2233, "DynastyBookcase", "Dynasty Bookcase", 9999, PrefixCategory::None),
+	item(2234, "DynastyCup", "Dynasty Cup", 9999, PrefixCategory::None),
+	item(2235, "DynastyBowl", "Dynasty Bowl", 9999, PrefixCategory::None),
+	item(2236, "DynastyCandle", "Dynasty Candle", 9999, PrefixCategory::None),
+	item(2237, "DynastyClock", "Dynasty Clock", 9999, PrefixCategory::None),
+	item(2238, "GoldenClock", "Golden Clock", 9999, PrefixCategory::None),
+	item(2239, "GlassClock", "Glass Clock", 9999, PrefixCategory::None),
+	item(2240, "HoneyClock", "Honey Clock", 9999, PrefixCategory::None),
+	item(2241, "SteampunkClock", "Steampunk Clock", 9999, PrefixCategory::None),
+	item(2242, "FancyDishes", "Fancy Dishes", 9999, PrefixCategory::None),
+	item(2243, "GlassBowl", "Glass Bowl", 9999, PrefixCategory::None),
+	item(2244, "WineGlass", "Wine Glass", 9999, PrefixCategory::None),
+	item(2245, "LivingWoodPiano", "Living Wood Piano", 9999, PrefixCategory::None),
+	item(2246, "FleshPiano", "Flesh Piano", 9999, PrefixCategory::None),
+	item(2247, "FrozenPiano", "Frozen Piano", 9999, PrefixCategory::None),
+	item(2248, "FrozenTable", "Frozen Table", 9999, PrefixCategory::None),
+	item(2249, "HoneyChest", "Honey Chest", 9999, PrefixCategory::None),
+	item(2250, "SteampunkChest", "Steampunk Chest", 9999, PrefixCategory::None),
+	item(2251, "HoneyWorkBench", "Honey Work Bench", 9999, PrefixCategory::None),
+	item(2252, "FrozenWorkBench", "Frozen Work Bench", 9999, PrefixCategory::None),
+	item(2253, "SteampunkWorkBench", "Steampunk Work Bench", 9999, PrefixCategory::None),
+	item(2254, "GlassPiano", "Glass Piano", 9999, PrefixCategory::None),
+	item(2255, "HoneyPiano", "Honey Piano", 9999, PrefixCategory::None),
+	item(2256, "SteampunkPiano", "Steampunk Piano", 9999, PrefixCategory::None),
+	item(2257, "HoneyCup", "Honey Cup", 9999, PrefixCategory::None),
+	item(2258, "SteampunkCup", "Chalice", 9999, PrefixCategory::None),
+	item(2259, "DynastyTable", "Dynasty Table", 9999, PrefixCategory::None),
+	item(2260, "DynastyWood", "Dynasty Wood", 9999, PrefixCategory::None),
+	item(2261, "RedDynastyShingles", "Red Dynasty Shingles", 9999, PrefixCategory::None),
+	item(2262, "BlueDynastyShingles", "Blue Dynasty Shingles", 9999, PrefixCategory::None),
+	item(2263, "WhiteDynastyWall", "White Dynasty Wall", 9999, PrefixCategory::None),
+	item(2264, "BlueDynastyWall", "Blue Dynasty Wall", 9999, PrefixCategory::None),
+	item(2265, "DynastyDoor", "Dynasty Door", 9999, PrefixCategory::None),
+	item(2266, "Sake", "Sake", 9999, PrefixCategory::None),
+	item(2267, "PadThai", "Pad Thai", 9999, PrefixCategory::None),
+	item(2268, "Pho", "Pho", 9999, PrefixCategory::None),
+	item(2269, "Revolver", "Revolver", 1, PrefixCategory::Ranged),
+	item(2270, "Gatligator", "Gatligator", 1, PrefixCategory::Ranged),
+	item(2271, "ArcaneRuneWall", "Arcane Rune Wall", 9999, PrefixCategory::None),
+	item(2272, "WaterGun", "Water Gun", 1, PrefixCategory::Ranged),
+	item(2273, "Katana", "Katana", 1, PrefixCategory::Melee),
+	item(2274, "UltrabrightTorch", "Ultrabright Torch", 9999, PrefixCategory::None),
+	item(2275, "MagicHat", "Magic Hat", 1, PrefixCategory::None),
+	item(2276, "DiamondRing", "Diamond Ring", 1, PrefixCategory::Accessory),
+	item(2277, "Gi", "Gi", 9999, PrefixCategory::None),
+	item(2278, "Kimono", "Kimono", 9999, PrefixCategory::None),
+	item(2279, "GypsyRobe", "Gypsy Robe", 1, PrefixCategory::None),
+	item(2280, "BeetleWings", "Beetle Wings", 1, PrefixCategory::Accessory),
+	item(2281, "TigerSkin", "Tiger Skin", 1, PrefixCategory::None),
+	item(2282, "LeopardSkin", "Leopard Skin", 1, PrefixCategory::None),
+	item(2283, "ZebraSkin", "Zebra Skin", 1, PrefixCategory::None),
+	item(2284, "CrimsonCloak", "Crimson Cloak", 1, PrefixCategory::Accessory),
+	item(2285, "MysteriousCape", "Mysterious Cape", 1, PrefixCategory::Accessory),
+	item(2286, "RedCape", "Red Cape", 1, PrefixCategory::Accessory),
+	item(2287, "WinterCape", "Winter Cape", 1, PrefixCategory::Accessory),
+	item(2288, "FrozenChair", "Frozen Chair", 9999, PrefixCategory::None),
+	item(2289, "WoodFishingPole", "Wood Fishing Pole", 1, PrefixCategory::None),
+	item(2290, "Bass", "Bass", 9999, PrefixCategory::None),
+	item(2291, "ReinforcedFishingPole", "Reinforced Fishing Pole", 1, PrefixCategory::None),
+	item(2292, "FiberglassFishingPole", "Fiberglass Fishing Pole", 1, PrefixCategory::None),
+	item(2293, "FisherofSouls", "Fisher of Souls", 1, PrefixCategory::None),
+	item(2294, "GoldenFishingRod", "Golden Fishing Rod", 1, PrefixCategory::None),
+	item(2295, "MechanicsRod", "Mechanic's Rod", 1, PrefixCategory::None),
+	item(2296, "SittingDucksFishingPole", "Sitting Duck's Fishing Pole", 1, PrefixCategory::None),
+	item(2297, "Trout", "Trout", 9999, PrefixCategory::None),
+	item(2298, "Salmon", "Salmon", 9999, PrefixCategory::None),
+	item(2299, "AtlanticCod", "Atlantic Cod", 9999, PrefixCategory::None),
+	item(2300, "Tuna", "Tuna", 9999, PrefixCategory::None),
+	item(2301, "RedSnapper", "Red Snapper", 9999, PrefixCategory::None),
+	item(2302, "NeonTetra", "Neon Tetra", 9999, PrefixCategory::None),
+	item(2303, "ArmoredCavefish", "Armored Cavefish", 9999, PrefixCategory::None),
+	item(2304, "Damselfish", "Damselfish", 9999, PrefixCategory::None),
+	item(2305, "CrimsonTigerfish", "Crimson Tigerfish", 9999, PrefixCategory::None),
+	item(2306, "FrostMinnow", "Frost Minnow", 9999, PrefixCategory::None),
+	item(2307, "PrincessFish", "Princess Fish", 9999, PrefixCategory::None),
+	item(2308, "GoldenCarp", "Golden Carp", 9999, PrefixCategory::None),
+	item(2309, "SpecularFish", "Specular Fish", 9999, PrefixCategory::None),
+	item(2310, "Prismite", "Prismite", 9999, PrefixCategory::None),
+	item(2311, "VariegatedLardfish", "Variegated Lardfish", 9999, PrefixCategory::None),
+	item(2312, "FlarefinKoi", "Flarefin Koi", 9999, PrefixCategory::None),
+	item(2313, "DoubleCod", "Double Cod", 9999, PrefixCategory::None),
+	item(2314, "Honeyfin", "Honeyfin", 9999, PrefixCategory::None),
+	item(2315, "Obsidifish", "Obsidifish", 9999, PrefixCategory::None),
+	item(2316, "Shrimp", "Shrimp", 9999, PrefixCategory::None),
+	item(2317, "ChaosFish", "Chaos Fish", 9999, PrefixCategory::None),
+	item(2318, "Ebonkoi", "Ebonkoi", 9999, PrefixCategory::None),
+	item(2319, "Hemopiranha", "Hemopiranha", 9999, PrefixCategory::None),
+	item(2320, "Rockfish", "Rockfish", 1, PrefixCategory::Melee),
+	item(2321, "Stinkfish", "Stinkfish", 9999, PrefixCategory::None),
+	item(2322, "MiningPotion", "Mining Potion", 9999, PrefixCategory::None),
+	item(2323, "HeartreachPotion", "Heartreach Potion", 9999, PrefixCategory::None),
+	item(2324, "CalmingPotion", "Calming Potion", 9999, PrefixCategory::None),
+	item(2325, "BuilderPotion", "Builder Potion", 9999, PrefixCategory::None),
+	item(2326, "TitanPotion", "Titan Potion", 9999, PrefixCategory::None),
+	item(2327, "FlipperPotion", "Flipper Potion", 9999, PrefixCategory::None),
+	item(2328, "SummoningPotion", "Summoning Potion", 9999, PrefixCategory::None),
+	item(2329, "TrapsightPotion", "Dangersense Potion", 9999, PrefixCategory::None),
+	item(2330, "PurpleClubberfish", "Purple Clubberfish", 1, PrefixCategory::Melee),
+	item(2331, "ObsidianSwordfish", "Obsidian Swordfish", 1, PrefixCategory::Melee),
+	item(2332, "Swordfish", "Swordfish", 1, PrefixCategory::Melee),
+	item(2333, "IronFence", "Iron Fence", 9999, PrefixCategory::None),
+	item(2334, "WoodenCrate", "Wooden Crate", 9999, PrefixCategory::None),
+	item(2335, "IronCrate", "Iron Crate", 9999, PrefixCategory::None),
+	item(2336, "GoldenCrate", "Golden Crate", 9999, PrefixCategory::None),
+	item(2337, "OldShoe", "Old Shoe", 9999, PrefixCategory::None),
+	item(2338, "FishingSeaweed", "Seaweed", 9999, PrefixCategory::None),
+	item(2339, "TinCan", "Tin Can", 9999, PrefixCategory::None),
+	item(2340, "MinecartTrack", "Minecart Track", 9999, PrefixCategory::None),
+	item(2341, "ReaverShark", "Reaver Shark", 1, PrefixCategory::Melee),
+	item(2342, "SawtoothShark", "Sawtooth Shark", 1, PrefixCategory::Melee),
+	item(2343, "Minecart", "Minecart", 1, PrefixCategory::None),
+	item(2344, "AmmoReservationPotion", "Ammo Reservation Potion", 9999, PrefixCategory::None),
+	item(2345, "LifeforcePotion", "Lifeforce Potion", 9999, PrefixCategory::None),
+	item(2346, "EndurancePotion", "Endurance Potion", 9999, PrefixCategory::None),
+	item(2347, "RagePotion", "Rage Potion", 9999, PrefixCategory::None),
+	item(2348, "InfernoPotion", "Inferno Potion", 9999, PrefixCategory::None),
+	item(2349, "WrathPotion", "Wrath Potion", 9999, PrefixCategory::None),
+	item(2350, "RecallPotion", "Recall Potion", 9999, PrefixCategory::None),
+	item(2351, "TeleportationPotion", "Teleportation Potion", 9999, PrefixCategory::None),
+	item(2352, "LovePotion", "Love Potion", 9999, PrefixCategory::None),
+	item(2353, "StinkPotion", "Stink Potion", 9999, PrefixCategory::None),
+	item(2354, "FishingPotion", "Fishing Potion", 9999, PrefixCategory::None),
+	item(2355, "SonarPotion", "Sonar Potion", 9999, PrefixCategory::None),
+	item(2356, "CratePotion", "Crate Potion", 9999, PrefixCategory::None),
+	item(2357, "ShiverthornSeeds", "Shiverthorn Seeds", 9999, PrefixCategory::None),
+	item(2358, "Shiverthorn", "Shiverthorn", 9999, PrefixCategory::None),
+	item(2359, "WarmthPotion", "Warmth Potion", 9999, PrefixCategory::None),
+	item(2360, "FishHook", "Fish Hook", 1, PrefixCategory::None),
+	item(2361, "BeeHeadgear", "Bee Headgear", 1, PrefixCategory::None),
+	item(2362, "BeeBreastplate", "Bee Breastplate", 1, PrefixCategory::None),
+	item(2363, "BeeGreaves", "Bee Greaves", 1, PrefixCategory::None),
+	item(2364, "HornetStaff", "Hornet Staff", 1, PrefixCategory::Magic),
+	item(2365, "ImpStaff", "Imp Staff", 1, PrefixCategory::Magic),
+	item(2366, "QueenSpiderStaff", "Queen Spider Staff", 1, PrefixCategory::Magic),
+	item(2367, "AnglerHat", "Angler Hat", 1, PrefixCategory::None),
+	item(2368, "AnglerVest", "Angler Vest", 1, PrefixCategory::None),
+	item(2369, "AnglerPants", "Angler Pants", 1, PrefixCategory::None),
+	item(2370, "SpiderMask", "Spider Mask", 1, PrefixCategory::None),
+	item(2371, "SpiderBreastplate", "Spider Breastplate", 1, PrefixCategory::None),
+	item(2372, "SpiderGreaves", "Spider Greaves", 1, PrefixCategory::None),
+	item(2373, "HighTestFishingLine", "High Test Fishing Line", 1, PrefixCategory::Accessory),
+	item(2374, "AnglerEarring", "Angler Earring", 1, PrefixCategory::Accessory),
+	item(2375, "TackleBox", "Tackle Box", 1, PrefixCategory::Accessory),
+	item(2376, "BlueDungeonPiano", "Blue Dungeon Piano", 9999, PrefixCategory::None),
+	item(2377, "GreenDungeonPiano", "Green Dungeon Piano", 9999, PrefixCategory::None),
+	item(2378, "PinkDungeonPiano", "Pink Dungeon Piano", 9999, PrefixCategory::None),
+	item(2379, "GoldenPiano", "Golden Piano", 9999, PrefixCategory::None),
+	item(2380, "ObsidianPiano", "Obsidian Piano", 9999, PrefixCategory::None),
+	item(2381, "BonePiano", "Bone Piano", 9999, PrefixCategory::None),
+	item(2382, "CactusPiano", "Cactus Piano", 9999, PrefixCategory::None),
+	item(2383, "SpookyPiano", "Spooky Piano", 9999, PrefixCategory::None),
+	item(2384, "SkywarePiano", "Skyware Piano", 9999, PrefixCategory::None),
+	item(2385, "LihzahrdPiano", "Lihzahrd Piano", 9999, PrefixCategory::None),
+	item(2386, "BlueDungeonDresser", "Blue Dungeon Dresser", 9999, PrefixCategory::None),
+	item(2387, "GreenDungeonDresser", "Green Dungeon Dresser", 9999, PrefixCategory::None),
+	item(2388, "PinkDungeonDresser", "Pink Dungeon Dresser", 9999, PrefixCategory::None),
+	item(2389, "GoldenDresser", "Golden Dresser", 9999, PrefixCategory::None),
+	item(2390, "ObsidianDresser", "Obsidian Dresser", 9999, PrefixCategory::None),
+	item(2391, "BoneDresser", "Bone Dresser", 9999, PrefixCategory::None),
+	item(2392, "CactusDresser", "Cactus Dresser", 9999, PrefixCategory::None),
+	item(2393, "SpookyDresser", "Spooky Dresser", 9999, PrefixCategory::None),
+	item(2394, "SkywareDresser", "Skyware Dresser", 9999, PrefixCategory::None),
+	item(2395, "HoneyDresser", "Honey Dresser", 9999, PrefixCategory::None),
+	item(2396, "LihzahrdDresser", "Lihzahrd Dresser", 9999, PrefixCategory::None),
+	item(2397, "Sofa", "Sofa", 9999, PrefixCategory::None),
+	item(2398, "EbonwoodSofa", "Ebonwood Sofa", 9999, PrefixCategory::None),
+	item(2399, "RichMahoganySofa", "Rich Mahogany Sofa", 9999, PrefixCategory::None),
+	item(2400, "PearlwoodSofa", "Pearlwood Sofa", 9999, PrefixCategory::None),
+	item(2401, "ShadewoodSofa", "Shadewood Sofa", 9999, PrefixCategory::None),
+	item(2402, "BlueDungeonSofa", "Blue Dungeon Sofa", 9999, PrefixCategory::None),
+	item(2403, "GreenDungeonSofa", "Green Dungeon Sofa", 9999, PrefixCategory::None),
+	item(2404, "PinkDungeonSofa", "Pink Dungeon Sofa", 9999, PrefixCategory::None),
+	item(2405, "GoldenSofa", "Golden Sofa", 9999, PrefixCategory::None),
+	item(2406, "ObsidianSofa", "Obsidian Sofa", 9999, PrefixCategory::None),
+	item(2407, "BoneSofa", "Bone Sofa", 9999, PrefixCategory::None),
+	item(2408, "CactusSofa", "Cactus Sofa", 9999, PrefixCategory::None),
+	item(2409, "SpookySofa", "Spooky Sofa", 9999, PrefixCategory::None),
+	item(2410, "SkywareSofa", "Skyware Sofa", 9999, PrefixCategory::None),
+	item(2411, "HoneySofa", "Honey Sofa", 9999, PrefixCategory::None),
+	item(2412, "SteampunkSofa", "Steampunk Sofa", 9999, PrefixCategory::None),
+	item(2413, "MushroomSofa", "Mushroom Sofa", 9999, PrefixCategory::None),
+	item(2414, "GlassSofa", "Glass Sofa", 9999, PrefixCategory::None),
+	item(2415, "PumpkinSofa", "Pumpkin Sofa", 9999, PrefixCategory::None),
+	item(2416, "LihzahrdSofa", "Lihzahrd Sofa", 9999, PrefixCategory::None),
+	item(2417, "SeashellHairpin", "Seashell Hairpin", 9999, PrefixCategory::None),
+	item(2418, "MermaidAdornment", "Mermaid Adornment", 9999, PrefixCategory::None),
+	item(2419, "MermaidTail", "Mermaid Tail", 9999, PrefixCategory::None),
+	item(2420, "ZephyrFish", "Zephyr Fish", 1, PrefixCategory::None),
+	item(2421, "Fleshcatcher", "Fleshcatcher", 1, PrefixCategory::None),
+	item(2422, "HotlineFishingHook", "Hotline Fishing Hook", 1, PrefixCategory::None),
+	item(2423, "FrogLeg", "Frog Leg", 1, PrefixCategory::Accessory),
+	item(2424, "Anchor", "Anchor", 1, PrefixCategory::Melee),
+	item(2425, "CookedFish", "Cooked Fish", 9999, PrefixCategory::None),
+	item(2426, "CookedShrimp", "Cooked Shrimp", 9999, PrefixCategory::None),
+	item(2427, "Sashimi", "Sashimi", 9999, PrefixCategory::None),
+	item(2428, "FuzzyCarrot", "Fuzzy Carrot", 1, PrefixCategory::None),
+	item(2429, "ScalyTruffle", "Scaly Truffle", 1, PrefixCategory::None),
+	item(2430, "SlimySaddle", "Slimy Saddle", 1, PrefixCategory::None),
+	item(2431, "BeeWax", "Bee Wax", 9999, PrefixCategory::None),
+	item(2432, "CopperPlatingWall", "Copper Plating Wall", 9999, PrefixCategory::None),
+	item(2433, "StoneSlabWall", "Stone Slab Wall", 9999, PrefixCategory::None),
+	item(2434, "Sail", "Sail", 9999, PrefixCategory::None),
+	item(2435, "CoralstoneBlock", "Coralstone Block", 9999, PrefixCategory::None),
+	item(2436, "BlueJellyfish", "Blue Jellyfish", 9999, PrefixCategory::None),
+	item(2437, "GreenJellyfish", "Green Jellyfish", 9999, PrefixCategory::None),
+	item(2438, "PinkJellyfish", "Pink Jellyfish", 9999, PrefixCategory::None),
+	item(2439, "BlueJellyfishJar", "Blue Jellyfish Jar", 9999, PrefixCategory::None),
+	item(2440, "GreenJellyfishJar", "Green Jellyfish Jar", 9999, PrefixCategory::None),
+	item(2441, "PinkJellyfishJar", "Pink Jellyfish Jar", 9999, PrefixCategory::None),
+	item(2442, "LifePreserver", "Life Preserver", 9999, PrefixCategory::None),
+	item(2443, "ShipsWheel", "Ship's Wheel", 9999, PrefixCategory::None),
+	item(2444, "CompassRose", "Compass Rose", 9999, PrefixCategory::None),
+	item(2445, "WallAnchor", "Wall Anchor", 9999, PrefixCategory::None),
+	item(2446, "GoldfishTrophy", "Goldfish Trophy", 9999, PrefixCategory::None),
+	item(2447, "BunnyfishTrophy", "Bunnyfish Trophy", 9999, PrefixCategory::None),
+	item(2448, "SwordfishTrophy", "Swordfish Trophy", 9999, PrefixCategory::None),
+	item(2449, "SharkteethTrophy", "Sharkteeth Trophy", 9999, PrefixCategory::None),
+	item(2450, "Batfish", "Batfish", 9999, PrefixCategory::None),
+	item(2451, "BumblebeeTuna", "Bumblebee Tuna", 9999, PrefixCategory::None),
+	item(2452, "Catfish", "Catfish", 9999, PrefixCategory::None),
+	item(2453, "Cloudfish", "Cloudfish", 9999, PrefixCategory::None),
+	item(2454, "Cursedfish", "Cursedfish", 9999, PrefixCategory::None),
+	item(2455, "Dirtfish", "Dirtfish", 9999, PrefixCategory::None),
+	item(2456, "DynamiteFish", "Dynamite Fish", 9999, PrefixCategory::None),
+	item(2457, "EaterofPlankton", "Eater of Plankton", 9999, PrefixCategory::None),
+	item(2458, "FallenStarfish", "Fallen Starfish", 9999, PrefixCategory::None),
+	item(2459, "TheFishofCthulu", "The Fish of Cthulhu", 9999, PrefixCategory::None),
+	item(2460, "Fishotron", "Fishotron", 9999, PrefixCategory::None),
+	item(2461, "Harpyfish", "Harpyfish", 9999, PrefixCategory::None),
+	item(2462, "Hungerfish", "Hungerfish", 9999, PrefixCategory::None),
+	item(2463, "Ichorfish", "Ichorfish", 9999, PrefixCategory::None),
+	item(2464, "Jewelfish", "Jewelfish", 9999, PrefixCategory::None),
+	item(2465, "MirageFish", "Mirage Fish", 9999, PrefixCategory::None),
+	item(2466, "MutantFlinxfin", "Mutant Flinxfin", 9999, PrefixCategory::None),
+	item(2467, "Pengfish", "Pengfish", 9999, PrefixCategory::None),
+	item(2468, "Pixiefish", "Pixiefish", 9999, PrefixCategory::None),
+	item(2469, "Spiderfish", "Spiderfish", 9999, PrefixCategory::None),
+	item(2470, "TundraTrout", "Tundra Trout", 9999, PrefixCategory::None),
+	item(2471, "UnicornFish", "Unicorn Fish", 9999, PrefixCategory::None),
+	item(2472, "GuideVoodooFish", "Guide Voodoo Fish", 9999, PrefixCategory::None),
+	item(2473, "Wyverntail", "Wyverntail", 9999, PrefixCategory::None),
+	item(2474, "ZombieFish", "Zombie Fish", 9999, PrefixCategory::None),
+	item(2475, "AmanitiaFungifin", "Amanita Fungifin", 9999, PrefixCategory::None),
+	item(2476, "Angelfish", "Angelfish", 9999, PrefixCategory::None),
+	item(2477, "BloodyManowar", "Bloody Manowar", 9999, PrefixCategory::None),
+	item(2478, "Bonefish", "Bonefish", 9999, PrefixCategory::None),
+	item(2479, "Bunnyfish", "Bunnyfish", 9999, PrefixCategory::None),
+	item(2480, "CapnTunabeard", "Cap'n Tunabeard", 9999, PrefixCategory::None),
+	item(2481, "Clownfish", "Clownfish", 9999, PrefixCategory::None),
+	item(2482, "DemonicHellfish", "Demonic Hellfish", 9999, PrefixCategory::None),
+	item(2483, "Derpfish", "Derpfish", 9999, PrefixCategory::None),
+	item(2484, "Fishron", "Fishron", 9999, PrefixCategory::None),
+	item(2485, "InfectedScabbardfish", "Infected Scabbardfish", 9999, PrefixCategory::None),
+	item(2486, "Mudfish", "Mudfish", 9999, PrefixCategory::None),
+	item(2487, "Slimefish", "Slimefish", 9999, PrefixCategory::None),
+	item(2488, "TropicalBarracuda", "Tropical Barracuda", 9999, PrefixCategory::None),
+	item(2489, "KingSlimeTrophy", "King Slime Trophy", 9999, PrefixCategory::None),
+	item(2490, "ShipInABottle", "Ship in a Bottle", 9999, PrefixCategory::None),
+	item(2491, "HardySaddle", "Hardy Saddle", 1, PrefixCategory::None),
+	item(2492, "PressureTrack", "Pressure Plate Track", 9999, PrefixCategory::None),
+	item(2493, "KingSlimeMask", "King Slime Mask", 1, PrefixCategory::None),
+	item(2494, "FinWings", "Fin Wings", 1, PrefixCategory::Accessory),
+	item(2495, "TreasureMap", "Treasure Map", 9999, PrefixCategory::None),
+	item(2496, "SeaweedPlanter", "Seaweed Planter", 9999, PrefixCategory::None),
+	item(2497, "PillaginMePixels", "Pillagin Me Pixels", 9999, PrefixCategory::None),
+	item(2498, "FishCostumeMask", "Fish Costume Mask", 1, PrefixCategory::None),
+	item(2499, "FishCostumeShirt", "Fish Costume Shirt", 1, PrefixCategory::None),
+	item(2500, "FishCostumeFinskirt", "Fish Costume Finskirt", 9999, PrefixCategory::None),
+	item(2501, "GingerBeard", "Ginger Beard", 1, PrefixCategory::None),
+	item(2502, "HoneyedGoggles", "Honeyed Goggles", 1, PrefixCategory::None),
+	item(2503, "BorealWood", "Boreal Wood", 9999, PrefixCategory::None),
+	item(2504, "PalmWood", "Palm Wood", 9999, PrefixCategory::None),
+	item(2505, "BorealWoodWall", "Boreal Wood Wall", 9999, PrefixCategory::None),
+	item(2506, "PalmWoodWall", "Palm Wood Wall", 9999, PrefixCategory::None),
+	item(2507, "BorealWoodFence", "Boreal Wood Fence", 9999, PrefixCategory::None),
+	item(2508, "PalmWoodFence", "Palm Wood Fence", 9999, PrefixCategory::None),
+	item(2509, "BorealWoodHelmet", "Boreal Wood Helmet", 1, PrefixCategory::None),
+	item(2510, "BorealWoodBreastplate", "Boreal Wood Breastplate", 1, PrefixCategory::None),
+	item(2511, "BorealWoodGreaves", "Boreal Wood Greaves", 1, PrefixCategory::None),
+	item(2512, "PalmWoodHelmet", "Palm Wood Helmet", 1, PrefixCategory::None),
+	item(2513, "PalmWoodBreastplate", "Palm Wood Breastplate", 1, PrefixCategory::None),
+	item(2514, "PalmWoodGreaves", "Palm Wood Greaves", 1, PrefixCategory::None),
+	item(2515, "PalmWoodBow", "Palm Wood Bow", 1, PrefixCategory::Ranged),
+	item(2516, "PalmWoodHammer", "Palm Wood Hammer", 1, PrefixCategory::Melee),
+	item(2517, "PalmWoodSword", "Palm Wood Sword", 1, PrefixCategory::Melee),
+	item(2518, "PalmWoodPlatform", "Palm Wood Platform", 9999, PrefixCategory::None),
+	item(2519, "PalmWoodBathtub", "Palm Wood Bathtub", 9999, PrefixCategory::None),
+	item(2520, "PalmWoodBed", "Palm Wood Bed", 9999, PrefixCategory::None),
+	item(2521, "PalmWoodBench", "Palm Wood Bench", 9999, PrefixCategory::None),
+	item(2522, "PalmWoodCandelabra", "Palm Wood Candelabra", 9999, PrefixCategory::None),
+	item(2523, "PalmWoodCandle", "Palm Wood Candle", 9999, PrefixCategory::None),
+	item(2524, "PalmWoodChair", "Palm Wood Chair", 9999, PrefixCategory::None),
+	item(2525, "PalmWoodChandelier", "Palm Wood Chandelier", 9999, PrefixCategory::None),
+	item(2526, "PalmWoodChest", "Palm Wood Chest", 9999, PrefixCategory::None),
+	item(2527, "PalmWoodSofa", "Palm Wood Sofa", 9999, PrefixCategory::None),
+	item(2528, "PalmWoodDoor", "Palm Wood Door", 9999, PrefixCategory::None),
+	item(2529, "PalmWoodDresser", "Palm Wood Dresser", 9999, PrefixCategory::None),
+	item(2530, "PalmWoodLantern", "Palm Wood Lantern", 9999, PrefixCategory::None),
+	item(2531, "PalmWoodPiano", "Palm Wood Piano", 9999, PrefixCategory::None),
+	item(2532, "PalmWoodTable", "Palm Wood Table", 9999, PrefixCategory::None),
+	item(2533, "PalmWoodLamp", "Palm Wood Lamp", 9999, PrefixCategory::None),
+	item(2534, "PalmWoodWorkBench", "Palm Wood Work Bench", 9999, PrefixCategory::None),
+	item(2535, "OpticStaff", "Optic Staff", 1, PrefixCategory::Magic),
+	item(2536, "PalmWoodBookcase", "Palm Wood Bookcase", 9999, PrefixCategory::None),
+	item(2537, "MushroomBathtub", "Mushroom Bathtub", 9999, PrefixCategory::None),
+	item(2538, "MushroomBed", "Mushroom Bed", 9999, PrefixCategory::None),
+	item(2539, "MushroomBench", "Mushroom Bench", 9999, PrefixCategory::None),
+	item(2540, "MushroomBookcase", "Mushroom Bookcase", 9999, PrefixCategory::None),
+	item(2541, "MushroomCandelabra", "Mushroom Candelabra", 9999, PrefixCategory::None),
+	item(2542, "MushroomCandle", "Mushroom Candle", 9999, PrefixCategory::None),
+	item(2543, "MushroomChandelier", "Mushroom Chandelier", 9999, PrefixCategory::None),
+	item(2544, "MushroomChest", "Mushroom Chest", 9999, PrefixCategory::None),
+	item(2545, "MushroomDresser", "Mushroom Dresser", 9999, PrefixCategory::None),
+	item(2546, "MushroomLantern", "Mushroom Lantern", 9999, PrefixCategory::None),
+	item(2547, "MushroomLamp", "Mushroom Lamp", 9999, PrefixCategory::None),
+	item(2548, "MushroomPiano", "Mushroom Piano", 9999, PrefixCategory::None),
+	item(2549, "MushroomPlatform", "Mushroom Platform", 9999, PrefixCategory::None),
+	item(2550, "MushroomTable", "Mushroom Table", 9999, PrefixCategory::None),
+	item(2551, "SpiderStaff", "Spider Staff", 1, PrefixCategory::Magic),
+	item(2552, "BorealWoodBathtub", "Boreal Wood Bathtub", 9999, PrefixCategory::None),
+	item(2553, "BorealWoodBed", "Boreal Wood Bed", 9999, PrefixCategory::None),
+	item(2554, "BorealWoodBookcase", "Boreal Wood Bookcase", 9999, PrefixCategory::None),
+	item(2555, "BorealWoodCandelabra", "Boreal Wood Candelabra", 9999, PrefixCategory::None),
+	item(2556, "BorealWoodCandle", "Boreal Wood Candle", 9999, PrefixCategory::None),
+	item(2557, "BorealWoodChair", "Boreal Wood Chair", 9999, PrefixCategory::None),
+	item(2558, "BorealWoodChandelier", "Boreal Wood Chandelier", 9999, PrefixCategory::None),
+	item(2559, "BorealWoodChest", "Boreal Wood Chest", 9999, PrefixCategory::None),
+	item(2560, "BorealWoodClock", "Boreal Wood Clock", 9999, PrefixCategory::None),
+	item(2561, "BorealWoodDoor", "Boreal Wood Door", 9999, PrefixCategory::None),
+	item(2562, "BorealWoodDresser", "Boreal Wood Dresser", 9999, PrefixCategory::None),
+	item(2563, "BorealWoodLamp", "Boreal Wood Lamp", 9999, PrefixCategory::None),
+	item(2564, "BorealWoodLantern", "Boreal Wood Lantern", 9999, PrefixCategory::None),
+	item(2565, "BorealWoodPiano", "Boreal Wood Piano", 9999, PrefixCategory::None),
+	item(2566, "BorealWoodPlatform", "Boreal Wood Platform", 9999, PrefixCategory::None),
+	item(2567, "SlimeBathtub", "Slime Bathtub", 9999, PrefixCategory::None),
+	item(2568, "SlimeBed", "Slime Bed", 9999, PrefixCategory::None),
+	item(2569, "SlimeBookcase", "Slime Bookcase", 9999, PrefixCategory::None),
+	item(2570, "SlimeCandelabra", "Slime Candelabra", 9999, PrefixCategory::None),
+	item(2571, "SlimeCandle", "Slime Candle", 9999, PrefixCategory::None),
+	item(2572, "SlimeChair", "Slime Chair", 9999, PrefixCategory::None),
+	item(2573, "SlimeChandelier", "Slime Chandelier", 9999, PrefixCategory::None),
+	item(2574, "SlimeChest", "Slime Chest", 9999, PrefixCategory::None),
+	item(2575, "SlimeClock", "Slime Clock", 9999, PrefixCategory::None),
+	item(2576, "SlimeDoor", "Slime Door", 9999, PrefixCategory::None),
+	item(2577, "SlimeDresser", "Slime Dresser", 9999, PrefixCategory::None),
+	item(2578, "SlimeLamp", "Slime Lamp", 9999, PrefixCategory::None),
+	item(2579, "SlimeLantern", "Slime Lantern", 9999, PrefixCategory::None),
+	item(2580, "SlimePiano", "Slime Piano", 9999, PrefixCategory::None),
+	item(2581, "SlimePlatform", "Slime Platform", 9999, PrefixCategory::None),
+	item(2582, "SlimeSofa", "Slime Sofa", 9999, PrefixCategory::None),
+	item(2583, "SlimeTable", "Slime Table", 9999, PrefixCategory::None),
+	item(2584, "PirateStaff", "Pirate Staff", 1, PrefixCategory::Magic),
+	item(2585, "SlimeHook", "Slime Hook", 1, PrefixCategory::None),
+	item(2586, "StickyGrenade", "Sticky Grenade", 9999, PrefixCategory::None),
+	item(2587, "TartarSauce", "Tartar Sauce", 1, PrefixCategory::None),
+	item(2588, "DukeFishronMask", "Duke Fishron Mask", 1, PrefixCategory::None),
+	item(2589, "DukeFishronTrophy", "Duke Fishron Trophy", 9999, PrefixCategory::None),
+	item(2590, "MolotovCocktail", "Molotov Cocktail", 9999, PrefixCategory::None),
+	item(2591, "BoneClock", "Bone Clock", 9999, PrefixCategory::None),
+	item(2592, "CactusClock", "Cactus Clock", 9999, PrefixCategory::None),
+	item(2593, "EbonwoodClock", "Ebonwood Clock", 9999, PrefixCategory::None),
+	item(2594, "FrozenClock", "Frozen Clock", 9999, PrefixCategory::None),
+	item(2595, "LihzahrdClock", "Lihzahrd Clock", 9999, PrefixCategory::None),
+	item(2596, "LivingWoodClock", "Living Wood Clock", 9999, PrefixCategory::None),
+	item(2597, "RichMahoganyClock", "Rich Mahogany Clock", 9999, PrefixCategory::None),
+	item(2598, "FleshClock", "Flesh Clock", 9999, PrefixCategory::None),
+	item(2599, "MushroomClock", "Mushroom Clock", 9999, PrefixCategory::None),
+	item(2600, "ObsidianClock", "Obsidian Clock", 9999, PrefixCategory::None),
+	item(2601, "PalmWoodClock", "Palm Wood Clock", 9999, PrefixCategory::None),
+	item(2602, "PearlwoodClock", "Pearlwood Clock", 9999, PrefixCategory::None),
+	item(2603, "PumpkinClock", "Pumpkin Clock", 9999, PrefixCategory::None),
+	item(2604, "ShadewoodClock", "Shadewood Clock", 9999, PrefixCategory::None),
+	item(2605, "SpookyClock", "Spooky Clock", 9999, PrefixCategory::None),
+	item(2606, "SkywareClock", "Skyware Clock", 9999, PrefixCategory::None),
+	item(2607, "SpiderFang", "Spider Fang", 9999, PrefixCategory::None),
+	item(2608, "FalconBlade", "Falcon Blade", 1, PrefixCategory::Melee),
+	item(2609, "FishronWings", "Fishron Wings", 1, PrefixCategory::Accessory),
+	item(2610, "SlimeGun", "Slime Gun", 1, PrefixCategory::Ranged),
+	item(2611, "Flairon", "Flairon", 1, PrefixCategory::Melee),
+	item(2612, "GreenDungeonChest", "Green Dungeon Chest", 9999, PrefixCategory::None),
+	item(2613, "PinkDungeonChest", "Pink Dungeon Chest", 9999, PrefixCategory::None),
+	item(2614, "BlueDungeonChest", "Blue Dungeon Chest", 9999, PrefixCategory::None),
+	item(2615, "BoneChest", "Bone Chest", 9999, PrefixCategory::None),
+	item(2616, "CactusChest", "Cactus Chest", 9999, PrefixCategory::None),
+	item(2617, "FleshChest", "Flesh Chest", 9999, PrefixCategory::None),
+	item(2618, "ObsidianChest", "Obsidian Chest", 9999, PrefixCategory::None),
+	item(2619, "PumpkinChest", "Pumpkin Chest", 9999, PrefixCategory::None),
+	item(2620, "SpookyChest", "Spooky Chest", 9999, PrefixCategory::None),
+	item(2621, "TempestStaff", "Tempest Staff", 1, PrefixCategory::Magic),
+	item(2622, "RazorbladeTyphoon", "Razorblade Typhoon", 1, PrefixCategory::Magic),
+	item(2623, "BubbleGun", "Bubble Gun", 1, PrefixCategory::Magic),
+	item(2624, "Tsunami", "Tsunami", 1, PrefixCategory::Ranged),
+	item(2625, "Seashell", "Seashell", 9999, PrefixCategory::None),
+	item(2626, "Starfish", "Starfish", 9999, PrefixCategory::None),
+	item(2627, "SteampunkPlatform", "Steampunk Platform", 9999, PrefixCategory::None),
+	item(2628, "SkywarePlatform", "Skyware Platform", 9999, PrefixCategory::None),
+	item(2629, "LivingWoodPlatform", "Living Wood Platform", 9999, PrefixCategory::None),
+	item(2630, "HoneyPlatform", "Honey Platform", 9999, PrefixCategory::None),
+	item(2631, "SkywareWorkbench", "Skyware Work Bench", 9999, PrefixCategory::None),
+	item(2632, "GlassWorkBench", "Glass Work Bench", 9999, PrefixCategory::None),
+	item(2633, "LivingWoodWorkBench", "Living Wood Work Bench", 9999, PrefixCategory::None),
+	item(2634, "FleshSofa", "Flesh Sofa", 9999, PrefixCategory::None),
+	item(2635, "FrozenSofa", "Frozen Sofa", 9999, PrefixCategory::None),
+	item(2636, "LivingWoodSofa", "Living Wood Sofa", 9999, PrefixCategory::None),
+	item(2637, "PumpkinDresser", "Pumpkin Dresser", 9999, PrefixCategory::None),
+	item(2638, "SteampunkDresser", "Steampunk Dresser", 9999, PrefixCategory::None),
+	item(2639, "GlassDresser", "Glass Dresser", 9999, PrefixCategory::None),
+	item(2640, "FleshDresser", "Flesh Dresser", 9999, PrefixCategory::None),
+	item(2641, "PumpkinLantern", "Pumpkin Lantern", 9999, PrefixCategory::None),
+	item(2642, "ObsidianLantern", "Obsidian Lantern", 9999, PrefixCategory::None),
+	item(2643, "PumpkinLamp", "Pumpkin Lamp", 9999, PrefixCategory::None),
+	item(2644, "ObsidianLamp", "Obsidian Lamp", 9999, PrefixCategory::None),
+	item(2645, "BlueDungeonLamp", "Blue Dungeon Lamp", 9999, PrefixCategory::None),
+	item(2646, "GreenDungeonLamp", "Green Dungeon Lamp", 9999, PrefixCategory::None),
+	item(2647, "PinkDungeonLamp", "Pink Dungeon Lamp", 9999, PrefixCategory::None),
+	item(2648, "HoneyCandle", "Honey Candle", 9999, PrefixCategory::None),
+	item(2649, "SteampunkCandle", "Steampunk Candle", 9999, PrefixCategory::None),
+	item(2650, "SpookyCandle", "Spooky Candle", 9999, PrefixCategory::None),
+	item(2651, "ObsidianCandle", "Obsidian Candle", 9999, PrefixCategory::None),
+	item(2652, "BlueDungeonChandelier", "Blue Dungeon Chandelier", 9999, PrefixCategory::None),
+	item(2653, "GreenDungeonChandelier", "Green Dungeon Chandelier", 9999, PrefixCategory::None),
+	item(2654, "PinkDungeonChandelier", "Pink Dungeon Chandelier", 9999, PrefixCategory::None),
+	item(2655, "SteampunkChandelier", "Steampunk Chandelier", 9999, PrefixCategory::None),
+	item(2656, "PumpkinChandelier", "Pumpkin Chandelier", 9999, PrefixCategory::None),
+	item(2657, "ObsidianChandelier", "Obsidian Chandelier", 9999, PrefixCategory::None),
+	item(2658, "BlueDungeonBathtub", "Blue Dungeon Bathtub", 9999, PrefixCategory::None),
+	item(2659, "GreenDungeonBathtub", "Green Dungeon Bathtub", 9999, PrefixCategory::None),
+	item(2660, "PinkDungeonBathtub", "Pink Dungeon Bathtub", 9999, PrefixCategory::None),
+	item(2661, "PumpkinBathtub", "Pumpkin Bathtub", 9999, PrefixCategory::None),
+	item(2662, "ObsidianBathtub", "Obsidian Bathtub", 9999, PrefixCategory::None),
+	item(2663, "GoldenBathtub", "Golden Bathtub", 9999, PrefixCategory::None),
+	item(2664, "BlueDungeonCandelabra", "Blue Dungeon Candelabra", 9999, PrefixCategory::None),
+	item(2665, "GreenDungeonCandelabra", "Green Dungeon Candelabra", 9999, PrefixCategory::None),
+	item(2666, "PinkDungeonCandelabra", "Pink Dungeon Candelabra", 9999, PrefixCategory::None),
+	item(2667, "ObsidianCandelabra", "Obsidian Candelabra", 9999, PrefixCategory::None),
+	item(2668, "PumpkinCandelabra", "Pumpkin Candelabra", 9999, PrefixCategory::None),
+	item(2669, "PumpkinBed", "Pumpkin Bed", 9999, PrefixCategory::None),
+	item(2670, "PumpkinBookcase", "Pumpkin Bookcase", 9999, PrefixCategory::None),
+	item(2671, "PumpkinPiano", "Pumpkin Piano", 9999, PrefixCategory::None),
+	item(2672, "SharkStatue", "Shark Statue", 9999, PrefixCategory::None),
+	item(2673, "TruffleWorm", "Truffle Worm", 9999, PrefixCategory::None),
+	item(2674, "ApprenticeBait", "Apprentice Bait", 9999, PrefixCategory::None),
+	item(2675, "JourneymanBait", "Journeyman Bait", 9999, PrefixCategory::None),
+	item(2676, "MasterBait", "Master Bait", 9999, PrefixCategory::None),
+	item(2677, "AmberGemsparkWall", "Amber Gemspark Wall", 9999, PrefixCategory::None),
+	item(2678, "AmberGemsparkWallOff", "Offline Amber Gemspark Wall", 9999, PrefixCategory::None),
+	item(2679, "AmethystGemsparkWall", "Amethyst Gemspark Wall", 9999, PrefixCategory::None),
+	item(2680, "AmethystGemsparkWallOff", "Offline Amethyst Gemspark Wall", 9999, PrefixCategory::None),
+	item(2681, "DiamondGemsparkWall", "Diamond Gemspark Wall", 9999, PrefixCategory::None),
+	item(2682, "DiamondGemsparkWallOff", "Offline Diamond Gemspark Wall", 9999, PrefixCategory::None),
+	item(2683, "EmeraldGemsparkWall", "Emerald Gemspark Wall", 9999, PrefixCategory::None),
+	item(2684, "EmeraldGemsparkWallOff", "Offline Emerald Gemspark Wall", 9999, PrefixCategory::None),
+	item(2685, "RubyGemsparkWall", "Ruby Gemspark Wall", 9999, PrefixCategory::None),
+	item(2686, "RubyGemsparkWallOff", "Offline Ruby Gemspark Wall", 9999, PrefixCategory::None),
+	item(2687, "SapphireGemsparkWall", "Sapphire Gemspark Wall", 9999, PrefixCategory::None),
+	item(2688, "SapphireGemsparkWallOff", "Offline Sapphire Gemspark Wall", 9999, PrefixCategory::None),
+	item(2689, "TopazGemsparkWall", "Topaz Gemspark Wall", 9999, PrefixCategory::None),
+	item(2690, "TopazGemsparkWallOff", "Offline Topaz Gemspark Wall", 9999, PrefixCategory::None),
+	item(2691, "TinPlatingWall", "Tin Plating Wall", 9999, PrefixCategory::None),
+	item(2692, "TinPlating", "Tin Plating", 9999, PrefixCategory::None),
+	item(2693, "WaterfallBlock", "Waterfall Block", 9999, PrefixCategory::None),
+	item(2694, "LavafallBlock", "Lavafall Block", 9999, PrefixCategory::None),
+	item(2695, "ConfettiBlock", "Confetti Block", 9999, PrefixCategory::None),
+	item(2696, "ConfettiWall", "Confetti Wall", 9999, PrefixCategory::None),
+	item(2697, "ConfettiBlockBlack", "Midnight Confetti Block", 9999, PrefixCategory::None),
+	item(2698, "ConfettiWallBlack", "Midnight Confetti Wall", 9999, PrefixCategory::None),
+	item(2699, "WeaponRack", "Weapon Rack", 9999, PrefixCategory::None),
+	item(2700, "FireworksBox", "Fireworks Box", 9999, PrefixCategory::None),
+	item(2701, "LivingFireBlock", "Living Fire Block", 9999, PrefixCategory::None),
+	item(2702, "AlphabetStatue0", "'0' Statue", 9999, PrefixCategory::None),
+	item(2703, "AlphabetStatue1", "'1' Statue", 9999, PrefixCategory::None),
+	item(2704, "AlphabetStatue2", "'2' Statue", 9999, PrefixCategory::None),
+	item(2705, "AlphabetStatue3", "'3' Statue", 9999, PrefixCategory::None),
+	item(2706, "AlphabetStatue4", "'4' Statue", 9999, PrefixCategory::None),
+	item(2707, "AlphabetStatue5", "'5' Statue", 9999, PrefixCategory::None),
+	item(2708, "AlphabetStatue6", "'6' Statue", 9999, PrefixCategory::None),
+	item(2709, "AlphabetStatue7", "'7' Statue", 9999, PrefixCategory::None),
+	item(2710, "AlphabetStatue8", "'8' Statue", 9999, PrefixCategory::None),
+	item(2711, "AlphabetStatue9", "'9' Statue", 9999, PrefixCategory::None),
+	item(2712, "AlphabetStatueA", "'A' Statue", 9999, PrefixCategory::None),
+	item(2713, "AlphabetStatueB", "'B' Statue", 9999, PrefixCategory::None),
+	item(2714, "AlphabetStatueC", "'C' Statue", 9999, PrefixCategory::None),
+	item(2715, "AlphabetStatueD", "'D' Statue", 9999, PrefixCategory::None),
+	item(2716, "AlphabetStatueE", "'E' Statue", 9999, PrefixCategory::None),
+	item(2717, "AlphabetStatueF", "'F' Statue", 9999, PrefixCategory::None),
+	item(2718, "AlphabetStatueG", "'G' Statue", 9999, PrefixCategory::None),
+	item(2719, "AlphabetStatueH", "'H' Statue", 9999, PrefixCategory::None),
+	item(2720, "AlphabetStatueI", "'I' Statue", 9999, PrefixCategory::None),
+	item(2721, "AlphabetStatueJ", "'J' Statue", 9999, PrefixCategory::None),
+	item(2722, "AlphabetStatueK", "'K' Statue", 9999, PrefixCategory::None),
+	item(2723, "AlphabetStatueL", "'L' Statue", 9999, PrefixCategory::None),
+	item(2724, "AlphabetStatueM", "'M' Statue", 9999, PrefixCategory::None),
+	item(2725, "AlphabetStatueN", "'N' Statue", 9999, PrefixCategory::None),
+	item(2726, "AlphabetStatueO", "'O' Statue", 9999, PrefixCategory::None),
+	item(2727, "AlphabetStatueP", "'P' Statue", 9999, PrefixCategory::None),
+	item(2728, "AlphabetStatueQ", "'Q' Statue", 9999, PrefixCategory::None),
+	item(2729, "AlphabetStatueR", "'R' Statue", 9999, PrefixCategory::None),
+	item(2730, "AlphabetStatueS", "'S' Statue", 9999, PrefixCategory::None),
+	item(2731, "AlphabetStatueT", "'T' Statue", 9999, PrefixCategory::None),
+	item(2732, "AlphabetStatueU", "'U' Statue", 9999, PrefixCategory::None),
+	item(2733, "AlphabetStatueV", "'V' Statue", 9999, PrefixCategory::None),
+	item(2734, "AlphabetStatueW", "'W' Statue", 9999, PrefixCategory::None),
+	item(2735, "AlphabetStatueX", "'X' Statue", 9999, PrefixCategory::None),
+	item(2736, "AlphabetStatueY", "'Y' Statue", 9999, PrefixCategory::None),
+	item(2737, "AlphabetStatueZ", "'Z' Statue", 9999, PrefixCategory::None),
+	item(2738, "FireworkFountain", "Firework Fountain", 9999, PrefixCategory::None),
+	item(2739, "BoosterTrack", "Booster Track", 9999, PrefixCategory::None),
+	item(2740, "Grasshopper", "Grasshopper", 9999, PrefixCategory::None),
+	item(2741, "GrasshopperCage", "Grasshopper Cage", 9999, PrefixCategory::None),
+	item(2742, "MusicBoxUndergroundCrimson", "Music Box (Underground Crimson)", 1, PrefixCategory::None),
+	item(2743, "CactusTable", "Cactus Table", 9999, PrefixCategory::None),
+	item(2744, "CactusPlatform", "Cactus Platform", 9999, PrefixCategory::None),
+	item(2745, "BorealWoodSword", "Boreal Wood Sword", 1, PrefixCategory::Melee),
+	item(2746, "BorealWoodHammer", "Boreal Wood Hammer", 1, PrefixCategory::Melee),
+	item(2747, "BorealWoodBow", "Boreal Wood Bow", 1, PrefixCategory::Ranged),
+	item(2748, "GlassChest", "Glass Chest", 9999, PrefixCategory::None),
+	item(2749, "XenoStaff", "Xeno Staff", 1, PrefixCategory::Magic),
+	item(2750, "MeteorStaff", "Meteor Staff", 1, PrefixCategory::Magic),
+	item(2751, "LivingCursedFireBlock", "Living Cursed Fire Block", 9999, PrefixCategory::None),
+	item(2752, "LivingDemonFireBlock", "Living Demon Fire Block", 9999, PrefixCategory::None),
+	item(2753, "LivingFrostFireBlock", "Living Frost Fire Block", 9999, PrefixCategory::None),
+	item(2754, "LivingIchorBlock", "Living Ichor Block", 9999, PrefixCategory::None),
+	item(2755, "LivingUltrabrightFireBlock", "Living Ultrabright Fire Block", 9999, PrefixCategory::None),
+	item(2756, "GenderChangePotion", "Gender Change Potion", 9999, PrefixCategory::None),
+	item(2757, "VortexHelmet", "Vortex Helmet", 1, PrefixCategory::None),
+	item(2758, "VortexBreastplate", "Vortex Breastplate", 1, PrefixCategory::None),
+	item(2759, "VortexLeggings", "Vortex Leggings", 1, PrefixCategory::None),
+	item(2760, "NebulaHelmet", "Nebula Helmet", 1, PrefixCategory::None),
+	item(2761, "NebulaBreastplate", "Nebula Breastplate", 1, PrefixCategory::None),
+	item(2762, "NebulaLeggings", "Nebula Leggings", 1, PrefixCategory::None),
+	item(2763, "SolarFlareHelmet", "Solar Flare Helmet", 1, PrefixCategory::None),
+	item(2764, "SolarFlareBreastplate", "Solar Flare Breastplate", 1, PrefixCategory::None),
+	item(2765, "SolarFlareLeggings", "Solar Flare Leggings", 1, PrefixCategory::None),
+	item(2766, "LunarTabletFragment", "Lunar Tablet Fragment", 9999, PrefixCategory::None),
+	item(2767, "SolarTablet", "Solar Tablet", 9999, PrefixCategory::None),
+	item(2768, "DrillContainmentUnit", "Drill Containment Unit", 1, PrefixCategory::None),
+	item(2769, "CosmicCarKey", "Cosmic Car Key", 1, PrefixCategory::None),
+	item(2770, "MothronWings", "Mothron Wings", 1, PrefixCategory::Accessory),
+	item(2771, "BrainScrambler", "Brain Scrambler", 1, PrefixCategory::None),
+	item(2772, "VortexAxe", "Vortex Axe", 1, PrefixCategory::Melee),
+	item(2773, "VortexChainsaw", "Vortex Chainsaw", 1, PrefixCategory::Melee),
+	item(2774, "VortexDrill", "Vortex Drill", 1, PrefixCategory::Melee),
+	item(2775, "VortexHammer", "Vortex Hammer", 1, PrefixCategory::Melee),
+	item(2776, "VortexPickaxe", "Vortex Pickaxe", 1, PrefixCategory::Melee),
+	item(2777, "NebulaAxe", "Nebula Axe", 1, PrefixCategory::Melee),
+	item(2778, "NebulaChainsaw", "Nebula Chainsaw", 1, PrefixCategory::Melee),
+	item(2779, "NebulaDrill", "Nebula Drill", 1, PrefixCategory::Melee),
+	item(2780, "NebulaHammer", "Nebula Hammer", 1, PrefixCategory::Melee),
+	item(2781, "NebulaPickaxe", "Nebula Pickaxe", 1, PrefixCategory::Melee),
+	item(2782, "SolarFlareAxe", "Solar Flare Axe", 1, PrefixCategory::Melee),
+	item(2783, "SolarFlareChainsaw", "Solar Flare Chainsaw", 1, PrefixCategory::Melee),
+	item(2784, "SolarFlareDrill", "Solar Flare Drill", 1, PrefixCategory::Melee),
+	item(2785, "SolarFlareHammer", "Solar Flare Hammer", 1, PrefixCategory::Melee),
+	item(2786, "SolarFlarePickaxe", "Solar Flare Pickaxe", 1, PrefixCategory::Melee),
+	item(2787, "HoneyfallBlock", "Honeyfall Block", 9999, PrefixCategory::None),
+	item(2788, "HoneyfallWall", "Honeyfall Wall", 9999, PrefixCategory::None),
+	item(2789, "ChlorophyteBrickWall", "Chlorophyte Brick Wall", 9999, PrefixCategory::None),
+	item(2790, "CrimtaneBrickWall", "Crimtane Brick Wall", 9999, PrefixCategory::None),
+	item(2791, "ShroomitePlatingWall", "Shroomite Plating Wall", 9999, PrefixCategory::None),
+	item(2792, "ChlorophyteBrick", "Chlorophyte Brick", 9999, PrefixCategory::None),
+	item(2793, "CrimtaneBrick", "Crimtane Brick", 9999, PrefixCategory::None),
+	item(2794, "ShroomitePlating", "Shroomite Plating", 9999, PrefixCategory::None),
+	item(2795, "LaserMachinegun", "Laser Machinegun", 1, PrefixCategory::Magic),
+	item(2796, "ElectrosphereLauncher", "Electrosphere Launcher", 1, PrefixCategory::Ranged),
+	item(2797, "Xenopopper", "Xenopopper", 1, PrefixCategory::Ranged),
+	item(2798, "LaserDrill", "Laser Drill", 1, PrefixCategory::Melee),
+	item(2799, "LaserRuler", "Laser Ruler", 1, PrefixCategory::None),
+	item(2800, "AntiGravityHook", "Anti Gravity Hook", 1, PrefixCategory::None),
+	item(2801, "MoonMask", "Moon Mask", 1, PrefixCategory::None),
+	item(2802, "SunMask", "Sun Mask", 1, PrefixCategory::None),
+	item(2803, "MartianCostumeMask", "Martian Costume Mask", 1, PrefixCategory::None),
+	item(2804, "MartianCostumeShirt", "Martian Costume Shirt", 1, PrefixCategory::None),
+	item(2805, "MartianCostumePants", "Martian Costume Pants", 1, PrefixCategory::None),
+	item(2806, "MartianUniformHelmet", "Martian Uniform Helmet", 1, PrefixCategory::None),
+	item(2807, "MartianUniformTorso", "Martian Uniform Torso", 9999, PrefixCategory::None),
+	item(2808, "MartianUniformPants", "Martian Uniform Pants", 1, PrefixCategory::None),
+	item(2809, "MartianAstroClock", "Martian Astro Clock", 9999, PrefixCategory::None),
+	item(2810, "MartianBathtub", "Martian Bathtub", 9999, PrefixCategory::None),
+	item(2811, "MartianBed", "Martian Bed", 9999, PrefixCategory::None),
+	item(2812, "MartianHoverChair", "Martian Hover Chair", 9999, PrefixCategory::None),
+	item(2813, "MartianChandelier", "Martian Chandelier", 9999, PrefixCategory::None),
+	item(2814, "MartianChest", "Martian Chest", 9999, PrefixCategory::None),
+	item(2815, "MartianDoor", "Martian Door", 9999, PrefixCategory::None),
+	item(2816, "MartianDresser", "Martian Dresser", 9999, PrefixCategory::None),
+	item(2817, "MartianHolobookcase", "Martian Holobookcase", 9999, PrefixCategory::None),
+	item(2818, "MartianHoverCandle", "Martian Hover Candle", 9999, PrefixCategory::None),
+	item(2819, "MartianLamppost", "Martian Lamppost", 9999, PrefixCategory::None),
+	item(2820, "MartianLantern", "Martian Lantern", 9999, PrefixCategory::None),
+	item(2821, "MartianPiano", "Martian Piano", 9999, PrefixCategory::None),
+	item(2822, "MartianPlatform", "Martian Platform", 9999, PrefixCategory::None),
+	item(2823, "MartianSofa", "Martian Sofa", 9999, PrefixCategory::None),
+	item(2824, "MartianTable", "Martian Table", 9999, PrefixCategory::None),
+	item(2825, "MartianTableLamp", "Martian Table Lamp", 9999, PrefixCategory::None),
+	item(2826, "MartianWorkBench", "Martian Work Bench", 9999, PrefixCategory::None),
+	item(2827, "WoodenSink", "Wooden Sink", 9999, PrefixCategory::None),
+	item(2828, "EbonwoodSink", "Ebonwood Sink", 9999, PrefixCategory::None),
+	item(2829, "RichMahoganySink", "Rich Mahogany Sink", 9999, PrefixCategory::None),
+	item(2830, "PearlwoodSink", "Pearlwood Sink", 9999, PrefixCategory::None),
+	item(2831, "BoneSink", "Bone Sink", 9999, PrefixCategory::None),
+	item(2832, "FleshSink", "Flesh Sink", 9999, PrefixCategory::None),
+	item(2833, "LivingWoodSink", "Living Wood Sink", 9999, PrefixCategory::None),
+	item(2834, "SkywareSink", "Skyware Sink", 9999, PrefixCategory::None),
+	item(2835, "ShadewoodSink", "Shadewood Sink", 9999, PrefixCategory::None),
+	item(2836, "LihzahrdSink", "Lihzahrd Sink", 9999, PrefixCategory::None),
+	item(2837, "BlueDungeonSink", "Blue Dungeon Sink", 9999, PrefixCategory::None),
+	item(2838, "GreenDungeonSink", "Green Dungeon Sink", 9999, PrefixCategory::None),
+	item(2839, "PinkDungeonSink", "Pink Dungeon Sink", 9999, PrefixCategory::None),
+	item(2840, "ObsidianSink", "Obsidian Sink", 9999, PrefixCategory::None),
+	item(2841, "MetalSink", "Metal Sink", 9999, PrefixCategory::None),
+	item(2842, "GlassSink", "Glass Sink", 9999, PrefixCategory::None),
+	item(2843, "GoldenSink", "Golden Sink", 9999, PrefixCategory::None),
+	item(2844, "HoneySink", "Honey Sink", 9999, PrefixCategory::None),
+	item(2845, "SteampunkSink", "Steampunk Sink", 9999, PrefixCategory::None),
+	item(2846, "PumpkinSink", "Pumpkin Sink", 9999, PrefixCategory::None),
+	item(2847, "SpookySink", "Spooky Sink", 9999, PrefixCategory::None),
+	item(2848, "FrozenSink", "Frozen Sink", 9999, PrefixCategory::None),
+	item(2849, "DynastySink", "Dynasty Sink", 9999, PrefixCategory::None),
+	item(2850, "PalmWoodSink", "Palm Wood Sink", 9999, PrefixCategory::None),
+	item(2851, "MushroomSink", "Mushroom Sink", 9999, PrefixCategory::None),
+	item(2852, "BorealWoodSink", "Boreal Wood Sink", 9999, PrefixCategory::None),
+	item(2853, "SlimeSink", "Slime Sink", 9999, PrefixCategory::None),
+	item(2854, "CactusSink", "Cactus Sink", 9999, PrefixCategory::None),
+	item(2855, "MartianSink", "Martian Sink", 9999, PrefixCategory::None),
+	item(2856, "SandFallBlock", "Sandfall Block", 9999, PrefixCategory::None),
+	item(2857, "SandFallWall", "Sandfall Wall", 9999, PrefixCategory::None),
+	item(2858, "SnowFallBlock", "Snowfall Block", 9999, PrefixCategory::None),
+	item(2859, "SnowFallWall", "Snowfall Wall", 9999, PrefixCategory::None),
+	item(2860, "MartianConduitPlating", "Martian Conduit Plating", 9999, PrefixCategory::None),
+	item(2861, "MartianConduitWall", "Martian Conduit Wall", 9999, PrefixCategory::None),
+	item(2862, "HiTekSunglasses", "HiTek Sunglasses", 1, PrefixCategory::None),
+	item(2863, "MartianHairDye", "Martian Hair Dye", 9999, PrefixCategory::None),
+	item(2864, "MartianArmorDye", "Martian Armor Dye", 9999, PrefixCategory::None),
+	item(2865, "PaintingCastleMarsberg", "Castle Marsberg", 9999, PrefixCategory::None),
+	item(2866, "PaintingMartiaLisa", "Martia Lisa", 9999, PrefixCategory::None),
+	item(2867, "PaintingTheTruthIsUpThere", "The Truth Is Up There", 9999, PrefixCategory::None),
+	item(2868, "SmokeBlock", "Smoke Block", 9999, PrefixCategory::None),
+	item(2869, "LivingFlameDye", "Living Flame Dye", 9999, PrefixCategory::None),
+	item(2870, "LivingRainbowDye", "Living Rainbow Dye", 9999, PrefixCategory::None),
+	item(2871, "ShadowDye", "Shadow Dye", 9999, PrefixCategory::None),
+	item(2872, "NegativeDye", "Negative Dye", 9999, PrefixCategory::None),
+	item(2873, "LivingOceanDye", "Living Ocean Dye", 9999, PrefixCategory::None),
+	item(2874, "BrownDye", "Brown Dye", 9999, PrefixCategory::None),
+	item(2875, "BrownAndBlackDye", "Brown and Black Dye", 9999, PrefixCategory::None),
+	item(2876, "BrightBrownDye", "Bright Brown Dye", 9999, PrefixCategory::None),
+	item(2877, "BrownAndSilverDye", "Brown and Silver Dye", 9999, PrefixCategory::None),
+	item(2878, "WispDye", "Wisp Dye", 9999, PrefixCategory::None),
+	item(2879, "PixieDye", "Pixie Dye", 9999, PrefixCategory::None),
+	item(2880, "InfluxWaver", "Influx Waver", 1, PrefixCategory::Melee),
+	item(2881, "PhasicWarpEjector", "Phasic Warp Ejector", 1, PrefixCategory::None),
+	item(2882, "ChargedBlasterCannon", "Charged Blaster Cannon", 1, PrefixCategory::Magic),
+	item(2883, "ChlorophyteDye", "Chlorophyte Dye", 9999, PrefixCategory::None),
+	item(2884, "UnicornWispDye", "Unicorn Wisp Dye", 9999, PrefixCategory::None),
+	item(2885, "InfernalWispDye", "Infernal Wisp Dye", 9999, PrefixCategory::None),
+	item(2886, "ViciousPowder", "Vicious Powder", 9999, PrefixCategory::None),
+	item(2887, "ViciousMushroom", "Vicious Mushroom", 9999, PrefixCategory::None),
+	item(2888, "BeesKnees", "The Bee's Knees", 1, PrefixCategory::Ranged),
+	item(2889, "GoldBird", "Gold Bird", 9999, PrefixCategory::None),
+	item(2890, "GoldBunny", "Gold Bunny", 9999, PrefixCategory::None),
+	item(2891, "GoldButterfly", "Gold Butterfly", 9999, PrefixCategory::None),
+	item(2892, "GoldFrog", "Gold Frog", 9999, PrefixCategory::None),
+	item(2893, "GoldGrasshopper", "Gold Grasshopper", 9999, PrefixCategory::None),
+	item(2894, "GoldMouse", "Gold Mouse", 9999, PrefixCategory::None),
+	item(2895, "GoldWorm", "Gold Worm", 9999, PrefixCategory::None),
+	item(2896, "StickyDynamite", "Sticky Dynamite", 9999, PrefixCategory::None),
+	item(2897, "AngryTrapperBanner", "Angry Trapper Banner", 9999, PrefixCategory::None),
+	item(2898, "ArmoredVikingBanner", "Armored Viking Banner", 9999, PrefixCategory::None),
+	item(2899, "BlackSlimeBanner", "Black Slime Banner", 9999, PrefixCategory::None),
+	item(2900, "BlueArmoredBonesBanner", "Blue Armored Bones Banner", 9999, PrefixCategory::None),
+	item(2901, "BlueCultistArcherBanner", "Blue Cultist Archer Banner", 9999, PrefixCategory::None),
+	item(2902, "BlueCultistCasterBanner", "Blue Cultist Caster Banner", 9999, PrefixCategory::None),
+	item(2903, "BlueCultistFighterBanner", "Blue Cultist Fighter Banner", 9999, PrefixCategory::None),
+	item(2904, "BoneLeeBanner", "Bone Lee Banner", 9999, PrefixCategory::None),
+	item(2905, "ClingerBanner", "Clinger Banner", 9999, PrefixCategory::None),
+	item(2906, "CochinealBeetleBanner", "Cochineal Beetle Banner", 9999, PrefixCategory::None),
+	item(2907, "CorruptPenguinBanner", "Corrupt Penguin Banner", 9999, PrefixCategory::None),
+	item(2908, "CorruptSlimeBanner", "Corrupt Slime Banner", 9999, PrefixCategory::None),
+	item(2909, "CorruptorBanner", "Corruptor Banner", 9999, PrefixCategory::None),
+	item(2910, "CrimslimeBanner", "Crimslime Banner", 9999, PrefixCategory::None),
+	item(2911, "CursedSkullBanner", "Cursed Skull Banner", 9999, PrefixCategory::None),
+	item(2912, "CyanBeetleBanner", "Cyan Beetle Banner", 9999, PrefixCategory::None),
+	item(2913, "DevourerBanner", "Devourer Banner", 9999, PrefixCategory::None),
+	item(2914, "DiablolistBanner", "Diabolist Banner", 9999, PrefixCategory::None),
+	item(2915, "DoctorBonesBanner", "Doctor Bones Banner", 9999, PrefixCategory::None),
+	item(2916, "DungeonSlimeBanner", "Dungeon Slime Banner", 9999, PrefixCategory::None),
+	item(2917, "DungeonSpiritBanner", "Dungeon Spirit Banner", 9999, PrefixCategory::None),
+	item(2918, "ElfArcherBanner", "Elf Archer Banner", 9999, PrefixCategory::None),
+	item(2919, "ElfCopterBanner", "Elf Copter Banner", 9999, PrefixCategory::None),
+	item(2920, "EyezorBanner", "Eyezor Banner", 9999, PrefixCategory::None),
+	item(2921, "FlockoBanner", "Flocko Banner", 9999, PrefixCategory::None),
+	item(2922, "GhostBanner", "Ghost Banner", 9999, PrefixCategory::None),
+	item(2923, "GiantBatBanner", "Giant Bat Banner", 9999, PrefixCategory::None),
+	item(2924, "GiantCursedSkullBanner", "Giant Cursed Skull Banner", 9999, PrefixCategory::None),
+	item(2925, "GiantFlyingFoxBanner", "Giant Flying Fox Banner", 9999, PrefixCategory::None),
+	item(2926, "GingerbreadManBanner", "Gingerbread Man Banner", 9999, PrefixCategory::None),
+	item(2927, "GoblinArcherBanner", "Goblin Archer Banner", 9999, PrefixCategory::None),
+	item(2928, "GreenSlimeBanner", "Green Slime Banner", 9999, PrefixCategory::None),
+	item(2929, "HeadlessHorsemanBanner", "Headless Horseman Banner", 9999, PrefixCategory::None),
+	item(2930, "HellArmoredBonesBanner", "Hell Armored Bones Banner", 9999, PrefixCategory::None),
+	item(2931, "HellhoundBanner", "Hellhound Banner", 9999, PrefixCategory::None),
+	item(2932, "HoppinJackBanner", "Hoppin Jack Banner", 9999, PrefixCategory::None),
+	item(2933, "IceBatBanner", "Ice Bat Banner", 9999, PrefixCategory::None),
+	item(2934, "IceGolemBanner", "Ice Golem Banner", 9999, PrefixCategory::None),
+	item(2935, "IceSlimeBanner", "Ice Slime Banner", 9999, PrefixCategory::None),
+	item(2936, "IchorStickerBanner", "Ichor Sticker Banner", 9999, PrefixCategory::None),
+	item(2937, "IlluminantBatBanner", "Illuminant Bat Banner", 9999, PrefixCategory::None),
+	item(2938, "IlluminantSlimeBanner", "Illuminant Slime Banner", 9999, PrefixCategory::None),
+	item(2939, "JungleBatBanner", "Jungle Bat Banner", 9999, PrefixCategory::None),
+	item(2940, "JungleSlimeBanner", "Jungle Slime Banner", 9999, PrefixCategory::None),
+	item(2941, "KrampusBanner", "Krampus Banner", 9999, PrefixCategory::None),
+	item(2942, "LacBeetleBanner", "Lac Beetle Banner", 9999, PrefixCategory::None),
+	item(2943, "LavaBatBanner", "Lava Bat Banner", 9999, PrefixCategory::None),
+	item(2944, "LavaSlimeBanner", "Lava Slime Banner", 9999, PrefixCategory::None),
+	item(2945, "MartianBrainscramblerBanner", "Martian Brainscrambler Banner", 9999, PrefixCategory::None),
+	item(2946, "MartianDroneBanner", "Martian Drone Banner", 9999, PrefixCategory::None),
+	item(2947, "MartianEngineerBanner", "Martian Engineer Banner", 9999, PrefixCategory::None),
+	item(2948, "MartianGigazapperBanner", "Martian Gigazapper Banner", 9999, PrefixCategory::None),
+	item(2949, "MartianGreyGruntBanner", "Martian Grey Grunt Banner", 9999, PrefixCategory::None),
+	item(2950, "MartianOfficerBanner", "Martian Officer Banner", 9999, PrefixCategory::None),
+	item(2951, "MartianRaygunnerBanner", "Martian Raygunner Banner", 9999, PrefixCategory::None),
+	item(2952, "MartianScutlixGunnerBanner", "Martian Scutlix Gunner Banner", 9999, PrefixCategory::None),
+	item(2953, "MartianTeslaTurretBanner", "Martian Tesla Turret Banner", 9999, PrefixCategory::None),
+	item(2954, "MisterStabbyBanner", "Mister Stabby Banner", 9999, PrefixCategory::None),
+	item(2955, "MotherSlimeBanner", "Mother Slime Banner", 9999, PrefixCategory::None),
+	item(2956, "NecromancerBanner", "Necromancer Banner", 9999, PrefixCategory::None),
+	item(2957, "NutcrackerBanner", "Nutcracker Banner", 9999, PrefixCategory::None),
+	item(2958, "PaladinBanner", "Paladin Banner", 9999, PrefixCategory::None),
+	item(2959, "PenguinBanner", "Penguin Banner", 9999, PrefixCategory::None),
+	item(2960, "PinkyBanner", "Pinky Banner", 9999, PrefixCategory::None),
+	item(2961, "PoltergeistBanner", "Poltergeist Banner", 9999, PrefixCategory::None),
+	item(2962, "PossessedArmorBanner", "Possessed Armor Banner", 9999, PrefixCategory::None),
+	item(2963, "PresentMimicBanner", "Present Mimic Banner", 9999, PrefixCategory::None),
+	item(2964, "PurpleSlimeBanner", "Purple Slime Banner", 9999, PrefixCategory::None),
+	item(2965, "RaggedCasterBanner", "Ragged Caster Banner", 9999, PrefixCategory::None),
+	item(2966, "RainbowSlimeBanner", "Rainbow Slime Banner", 9999, PrefixCategory::None),
+	item(2967, "RavenBanner", "Raven Banner", 9999, PrefixCategory::None),
+	item(2968, "RedSlimeBanner", "Red Slime Banner", 9999, PrefixCategory::None),
+	item(2969, "RuneWizardBanner", "Rune Wizard Banner", 9999, PrefixCategory::None),
+	item(2970, "RustyArmoredBonesBanner", "Rusty Armored Bones Banner", 9999, PrefixCategory::None),
+	item(2971, "ScarecrowBanner", "Scarecrow Banner", 9999, PrefixCategory::None),
+	item(2972, "ScutlixBanner", "Scutlix Banner", 9999, PrefixCategory::None),
+	item(2973, "SkeletonArcherBanner", "Skeleton Archer Banner", 9999, PrefixCategory::None),
+	item(2974, "SkeletonCommandoBanner", "Skeleton Commando Banner", 9999, PrefixCategory::None),
+	item(2975, "SkeletonSniperBanner", "Skeleton Sniper Banner", 9999, PrefixCategory::None),
+	item(2976, "SlimerBanner", "Slimer Banner", 9999, PrefixCategory::None),
+	item(2977, "SnatcherBanner", "Snatcher Banner", 9999, PrefixCategory::None),
+	item(2978, "SnowBallaBanner", "Snow Balla Banner", 9999, PrefixCategory::None),
+	item(2979, "SnowmanGangstaBanner", "Snowman Gangsta Banner", 9999, PrefixCategory::None),
+	item(2980, "SpikedIceSlimeBanner", "Spiked Ice Slime Banner", 9999, PrefixCategory::None),
+	item(2981, "SpikedJungleSlimeBanner", "Spiked Jungle Slime Banner", 9999, PrefixCategory::None),
+	item(2982, "SplinterlingBanner", "Splinterling Banner", 9999, PrefixCategory::None),
+	item(2983, "SquidBanner", "Squid Banner", 9999, PrefixCategory::None),
+	item(2984, "TacticalSkeletonBanner", "Tactical Skeleton Banner", 9999, PrefixCategory::None),
+	item(2985, "TheGroomBanner", "The Groom Banner", 9999, PrefixCategory::None),
+	item(2986, "TimBanner", "Tim Banner", 9999, PrefixCategory::None),
+	item(2987, "UndeadMinerBanner", "Undead Miner Banner", 9999, PrefixCategory::None),
+	item(2988, "UndeadVikingBanner", "Undead Viking Banner", 9999, PrefixCategory::None),
+	item(2989, "WhiteCultistArcherBanner", "White Cultist Archer Banner", 9999, PrefixCategory::None),
+	item(2990, "WhiteCultistCasterBanner", "White Cultist Caster Banner", 9999, PrefixCategory::None),
+	item(2991, "WhiteCultistFighterBanner", "White Cultist Fighter Banner", 9999, PrefixCategory::None),
+	item(2992, "YellowSlimeBanner", "Yellow Slime Banner", 9999, PrefixCategory::None),
+	item(2993, "YetiBanner", "Yeti Banner", 9999, PrefixCategory::None),
+	item(2994, "ZombieElfBanner", "Zombie Elf Banner", 9999, PrefixCategory::None),
+	item(2995, "SparkySlimeBanner", "Sparky Slime Banner", 9999, PrefixCategory::None),
+	item(2996, "DrManFlyBanner", "Dr. Man Fly Banner", 9999, PrefixCategory::None),
+	item(2997, "GoblinSummonerBanner", "Goblin Summoner Banner", 9999, PrefixCategory::None),
+	item(2998, "WanderingEyeBanner", "Wandering Eye Banner", 9999, PrefixCategory::None),
+	item(2999, "BewitchingTable", "Bewitching Table", 9999, PrefixCategory::None),
+	item(3000, "AlchemyTable", "Alchemy Table", 9999, PrefixCategory::None),
+	item(3001, "BrightSilverDye", "Bright Silver Dye", 9999, PrefixCategory::None),
+	item(3002, "SilverAndBlackDye", "Silver and Black Dye", 9999, PrefixCategory::None),
+	item(3003, "RedAcidDye", "Red Acid Dye", 9999, PrefixCategory::None),
+	item(3004, "GelDye", "Gel Dye", 9999, PrefixCategory::None),
+	item(3005, "PinkGelDye", "Pink Gel Dye", 9999, PrefixCategory::None),
+	item(3006, "SoulDrain", "Life Drain", 1, PrefixCategory::Magic),
+	item(3007, "DartPistol", "Dart Pistol", 1, PrefixCategory::Ranged),
+	item(3008, "DartRifle", "Dart Rifle", 1, PrefixCategory::Ranged),
+	item(3009, "CrystalDart", "Crystal Dart", 9999, PrefixCategory::None),
+	item(3010, "CursedDart", "Cursed Dart", 9999, PrefixCategory::None),
+	item(3011, "IchorDart", "Ichor Dart", 9999, PrefixCategory::None),
+	item(3012, "ChainGuillotines", "Chain Guillotines", 1, PrefixCategory::Melee),
+	item(3013, "FetidBaghnakhs", "Fetid Baghnakhs", 1, PrefixCategory::Melee),
+	item(3014, "ClingerStaff", "Clinger Staff", 1, PrefixCategory::Magic),
+	item(3015, "PutridScent", "Putrid Scent", 1, PrefixCategory::Accessory),
+	item(3016, "FleshKnuckles", "Flesh Knuckles", 1, PrefixCategory::Accessory),
+	item(3017, "FlowerBoots", "Flower Boots", 1, PrefixCategory::Accessory),
+	item(3018, "Seedler", "Seedler", 1, PrefixCategory::Melee),
+	item(3019, "HellwingBow", "Hellwing Bow", 1, PrefixCategory::Ranged),
+	item(3020, "TendonHook", "Tendon Hook", 1, PrefixCategory::None),
+	item(3021, "ThornHook", "Thorn Hook", 1, PrefixCategory::None),
+	item(3022, "IlluminantHook", "Illuminant Hook", 1, PrefixCategory::None),
+	item(3023, "WormHook", "Worm Hook", 1, PrefixCategory::None),
+	item(3024, "DevDye", "Dev Dye", 9999, PrefixCategory::None),
+	item(3025, "PurpleOozeDye", "Purple Ooze Dye", 9999, PrefixCategory::None),
+	item(3026, "ReflectiveSilverDye", "Reflective Silver Dye", 9999, PrefixCategory::None),
+	item(3027, "ReflectiveGoldDye", "Reflective Gold Dye", 9999, PrefixCategory::None),
+	item(3028, "BlueAcidDye", "Blue Acid Dye", 9999, PrefixCategory::None),
+	item(3029, "DaedalusStormbow", "Daedalus Stormbow", 1, PrefixCategory::Ranged),
+	item(3030, "FlyingKnife", "Flying Knife", 1, PrefixCategory::Melee),
+	item(3031, "BottomlessBucket", "Bottomless Bucket", 1, PrefixCategory::None),
+	item(3032, "SuperAbsorbantSponge", "Super Absorbant Sponge", 1, PrefixCategory::None),
+	item(3033, "GoldRing", "Gold Ring", 1, PrefixCategory::Accessory),
+	item(3034, "CoinRing", "Coin Ring", 1, PrefixCategory::Accessory),
+	item(3035, "GreedyRing", "Greedy Ring", 1, PrefixCategory::Accessory),
+	item(3036, "FishFinder", "Fish Finder", 1, PrefixCategory::Accessory),
+	item(3037, "WeatherRadio", "Weather Radio", 1, PrefixCategory::Accessory),
+	item(3038, "HadesDye", "Hades Dye", 9999, PrefixCategory::None),
+	item(3039, "TwilightDye", "Twilight Dye", 9999, PrefixCategory::None),
+	item(3040, "AcidDye", "Acid Dye", 9999, PrefixCategory::None),
+	item(3041, "MushroomDye", "Mushroom Dye", 9999, PrefixCategory::None),
+	item(3042, "PhaseDye", "Phase Dye", 9999, PrefixCategory::None),
+	item(3043, "MagicLantern", "Magic Lantern", 1, PrefixCategory::None),
+	item(3044, "MusicBoxLunarBoss", "Music Box (Lunar Boss)", 1, PrefixCategory::None),
+	item(3045, "RainbowTorch", "Rainbow Torch", 9999, PrefixCategory::None),
+	item(3046, "CursedCampfire", "Cursed Campfire", 9999, PrefixCategory::None),
+	item(3047, "DemonCampfire", "Demon Campfire", 9999, PrefixCategory::None),
+	item(3048, "FrozenCampfire", "Frozen Campfire", 9999, PrefixCategory::None),
+	item(3049, "IchorCampfire", "Ichor Campfire", 9999, PrefixCategory::None),
+	item(3050, "RainbowCampfire", "Rainbow Campfire", 9999, PrefixCategory::None),
+	item(3051, "CrystalVileShard", "Crystal Vile Shard", 1, PrefixCategory::Magic),
+	item(3052, "ShadowFlameBow", "Shadow Flame Bow", 1, PrefixCategory::Ranged),
+	item(3053, "ShadowFlameHexDoll", "Shadow Flame Hex Doll", 1, PrefixCategory::Magic),
+	item(3054, "ShadowFlameKnife", "Shadow Flame Knife", 1, PrefixCategory::Melee),
+	item(3055, "PaintingAcorns", "Acorns", 9999, PrefixCategory::None),
+	item(3056, "PaintingColdSnap", "Cold Snap", 9999, PrefixCategory::None),
+	item(3057, "PaintingCursedSaint", "Cursed Saint", 9999, PrefixCategory::None),
+	item(3058, "PaintingSnowfellas", "Snowfellas", 9999, PrefixCategory::None),
+	item(3059, "PaintingTheSeason", "The Season", 9999, PrefixCategory::None),
+	item(3060, "BoneRattle", "Bone Rattle", 1, PrefixCategory::None),
+	item(3061, "ArchitectGizmoPack", "Architect Gizmo Pack", 1, PrefixCategory::Accessory),
+	item(3062, "CrimsonHeart", "Crimson Heart", 1, PrefixCategory::None),
+	item(3063, "Meowmere", "Meowmere", 1, PrefixCategory::Melee),
+	item(3064, "Sundial", "Sundial", 9999, PrefixCategory::None),
+	item(3065, "StarWrath", "Star Wrath", 1, PrefixCategory::Melee),
+	item(3066, "SmoothMarbleBlock", "Smooth Marble Block", 9999, PrefixCategory::None),
+	item(3067, "HellstoneBrickWall", "Hellstone Brick Wall", 9999, PrefixCategory::None),
+	item(3068, "CordageGuide", "Guide to Plant Fiber Cordage", 9999, PrefixCategory::None),
+	item(3069, "WandofSparking", "Wand of Sparking", 1, PrefixCategory::Magic),
+	item(3070, "GoldBirdCage", "Gold Bird Cage", 9999, PrefixCategory::None),
+	item(3071, "GoldBunnyCage", "Gold Bunny Cage", 9999, PrefixCategory::None),
+	item(3072, "GoldButterflyCage", "Gold Butterfly Jar", 9999, PrefixCategory::None),
+	item(3073, "GoldFrogCage", "Gold Frog Cage", 9999, PrefixCategory::None),
+	item(3074, "GoldGrasshopperCage", "Gold Grasshopper Cage", 9999, PrefixCategory::None),
+	item(3075, "GoldMouseCage", "Gold Mouse Cage", 9999, PrefixCategory::None),
+	item(3076, "GoldWormCage", "Gold Worm Cage", 9999, PrefixCategory::None),
+	item(3077, "SilkRope", "Silk Rope", 9999, PrefixCategory::None),
+	item(3078, "WebRope", "Web Rope", 9999, PrefixCategory::None),
+	item(3079, "SilkRopeCoil", "Silk Rope Coil", 9999, PrefixCategory::None),
+	item(3080, "WebRopeCoil", "Web Rope Coil", 9999, PrefixCategory::None),
+	item(3081, "Marble", "Marble", 9999, PrefixCategory::None),
+	item(3082, "MarbleWall", "Marble Wall", 9999, PrefixCategory::None),
+	item(3083, "MarbleBlockWall", "Smooth Marble Wall", 9999, PrefixCategory::None),
+	item(3084, "Radar", "Radar", 1, PrefixCategory::Accessory),
+	item(3085, "LockBox", "Lock Box", 9999, PrefixCategory::None),
+	item(3086, "Granite", "Granite", 9999, PrefixCategory::None),
+	item(3087, "GraniteBlock", "Smooth Granite Block", 9999, PrefixCategory::None),
+	item(3088, "GraniteWall", "Granite Wall", 9999, PrefixCategory::None),
+	item(3089, "GraniteBlockWall", "Smooth Granite Wall", 9999, PrefixCategory::None),
+	item(3090, "RoyalGel", "Royal Gel", 1, PrefixCategory::Accessory),
+	item(3091, "NightKey", "Night Key", 9999, PrefixCategory::None),
+	item(3092, "LightKey", "Light Key", 9999, PrefixCategory::None),
+	item(3093, "HerbBag", "Herb Bag", 9999, PrefixCategory::None),
+	item(3094, "Javelin", "Javelin", 9999, PrefixCategory::None),
+	item(3095, "TallyCounter", "Tally Counter", 1, PrefixCategory::Accessory),
+	item(3096, "Sextant", "Sextant", 1, PrefixCategory::Accessory),
+	item(3097, "EoCShield", "Shield of Cthulhu", 1, PrefixCategory::Accessory),
+	item(3098, "ButchersChainsaw", "Butcher's Chainsaw", 1, PrefixCategory::Melee),
+	item(3099, "Stopwatch", "Stopwatch", 1, PrefixCategory::Accessory),
+	item(3100, "MeteoriteBrick", "Meteorite Brick", 9999, PrefixCategory::None),
+	item(3101, "MeteoriteBrickWall", "Meteorite Brick Wall", 9999, PrefixCategory::None),
+	item(3102, "MetalDetector", "Metal Detector", 1, PrefixCategory::Accessory),
+	item(3103, "EndlessQuiver", "Endless Quiver", 1, PrefixCategory::None),
+	item(3104, "EndlessMusketPouch", "Endless Musket Pouch", 1, PrefixCategory::None),
+	item(3105, "ToxicFlask", "Toxic Flask", 1, PrefixCategory::Magic),
+	item(3106, "PsychoKnife", "Psycho Knife", 1, PrefixCategory::Melee),
+	item(3107, "NailGun", "Nail Gun", 1, PrefixCategory::Ranged),
+	item(3108, "Nail", "Nail", 9999, PrefixCategory::None),
+	item(3109, "NightVisionHelmet", "Night Vision Helmet", 1, PrefixCategory::None),
+	item(3110, "CelestialShell", "Celestial Shell", 1, PrefixCategory::Accessory),
+	item(3111, "PinkGel", "Pink Gel", 9999, PrefixCategory::None),
+	item(3112, "BouncyGlowstick", "Bouncy Glowstick", 9999, PrefixCategory::None),
+	item(3113, "PinkSlimeBlock", "Pink Slime Block", 9999, PrefixCategory::None),
+	item(3114, "PinkTorch", "Pink Torch", 9999, PrefixCategory::None),
+	item(3115, "BouncyBomb", "Bouncy Bomb", 9999, PrefixCategory::None),
+	item(3116, "BouncyGrenade", "Bouncy Grenade", 9999, PrefixCategory::None),
+	item(3117, "PeaceCandle", "Peace Candle", 9999, PrefixCategory::None),
+	item(3118, "LifeformAnalyzer", "Lifeform Analyzer", 1, PrefixCategory::Accessory),
+	item(3119, "DPSMeter", "DPS Meter", 1, PrefixCategory::Accessory),
+	item(3120, "FishermansGuide", "Fisherman's Pocket Guide", 1, PrefixCategory::Accessory),
+	item(3121, "GoblinTech", "Goblin Tech", 1, PrefixCategory::Accessory),
+	item(3122, "REK", "R.E.K. 3000", 1, PrefixCategory::Accessory),
+	item(3123, "PDA", "PDA", 1, PrefixCategory::Accessory),
+	item(3124, "CellPhone", "Cell Phone", 1, PrefixCategory::None),
+	item(3125, "GraniteChest", "Granite Chest", 9999, PrefixCategory::None),
+	item(3126, "MeteoriteClock", "Meteorite Clock", 9999, PrefixCategory::None),
+	item(3127, "MarbleClock", "Marble Clock", 9999, PrefixCategory::None),
+	item(3128, "GraniteClock", "Granite Clock", 9999, PrefixCategory::None),
+	item(3129, "MeteoriteDoor", "Meteorite Door", 9999, PrefixCategory::None),
+	item(3130, "MarbleDoor", "Marble Door", 9999, PrefixCategory::None),
+	item(3131, "GraniteDoor", "Granite Door", 9999, PrefixCategory::None),
+	item(3132, "MeteoriteDresser", "Meteorite Dresser", 9999, PrefixCategory::None),
+	item(3133, "MarbleDresser", "Marble Dresser", 9999, PrefixCategory::None),
+	item(3134, "GraniteDresser", "Granite Dresser", 9999, PrefixCategory::None),
+	item(3135, "MeteoriteLamp", "Meteorite Lamp", 9999, PrefixCategory::None),
+	item(3136, "MarbleLamp", "Marble Lamp", 9999, PrefixCategory::None),
+	item(3137, "GraniteLamp", "Granite Lamp", 9999, PrefixCategory::None),
+	item(3138, "MeteoriteLantern", "Meteorite Lantern", 9999, PrefixCategory::None),
+	item(3139, "MarbleLantern", "Marble Lantern", 9999, PrefixCategory::None),
+	item(3140, "GraniteLantern", "Granite Lantern", 9999, PrefixCategory::None),
+	item(3141, "MeteoritePiano", "Meteorite Piano", 9999, PrefixCategory::None),
+	item(3142, "MarblePiano", "Marble Piano", 9999, PrefixCategory::None),
+	item(3143, "GranitePiano", "Granite Piano", 9999, PrefixCategory::None),
+	item(3144, "MeteoritePlatform", "Meteorite Platform", 9999, PrefixCategory::None),
+	item(3145, "MarblePlatform", "Marble Platform", 9999, PrefixCategory::None),
+	item(3146, "GranitePlatform", "Granite Platform", 9999, PrefixCategory::None),
+	item(3147, "MeteoriteSink", "Meteorite Sink", 9999, PrefixCategory::None),
+	item(3148, "MarbleSink", "Marble Sink", 9999, PrefixCategory::None),
+	item(3149, "GraniteSink", "Granite Sink", 9999, PrefixCategory::None),
+	item(3150, "MeteoriteSofa", "Meteorite Sofa", 9999, PrefixCategory::None),
+	item(3151, "MarbleSofa", "Marble Sofa", 9999, PrefixCategory::None),
+	item(3152, "GraniteSofa", "Granite Sofa", 9999, PrefixCategory::None),
+	item(3153, "MeteoriteTable", "Meteorite Table", 9999, PrefixCategory::None),
+	item(3154, "MarbleTable", "Marble Table", 9999, PrefixCategory::None),
+	item(3155, "GraniteTable", "Granite Table", 9999, PrefixCategory::None),
+	item(3156, "MeteoriteWorkBench", "Meteorite Work Bench", 9999, PrefixCategory::None),
+	item(3157, "MarbleWorkBench", "Marble Work Bench", 9999, PrefixCategory::None),
+	item(3158, "GraniteWorkBench", "Granite Work Bench", 9999, PrefixCategory::None),
+	item(3159, "MeteoriteBathtub", "Meteorite Bathtub", 9999, PrefixCategory::None),
+	item(3160, "MarbleBathtub", "Marble Bathtub", 9999, PrefixCategory::None),
+	item(3161, "GraniteBathtub", "Granite Bathtub", 9999, PrefixCategory::None),
+	item(3162, "MeteoriteBed", "Meteorite Bed", 9999, PrefixCategory::None),
+	item(3163, "MarbleBed", "Marble Bed", 9999, PrefixCategory::None),
+	item(3164, "GraniteBed", "Granite Bed", 9999, PrefixCategory::None),
+	item(3165, "MeteoriteBookcase", "Meteorite Bookcase", 9999, PrefixCategory::None),
+	item(3166, "MarbleBookcase", "Marble Bookcase", 9999, PrefixCategory::None),
+	item(3167, "GraniteBookcase", "Granite Bookcase", 9999, PrefixCategory::None),
+	item(3168, "MeteoriteCandelabra", "Meteorite Candelabra", 9999, PrefixCategory::None),
+	item(3169, "MarbleCandelabra", "Marble Candelabra", 9999, PrefixCategory::None),
+	item(3170, "GraniteCandelabra", "Granite Candelabra", 9999, PrefixCategory::None),
+	item(3171, "MeteoriteCandle", "Meteorite Candle", 9999, PrefixCategory::None),
+	item(3172, "MarbleCandle", "Marble Candle", 9999, PrefixCategory::None),
+	item(3173, "GraniteCandle", "Granite Candle", 9999, PrefixCategory::None),
+	item(3174, "MeteoriteChair", "Meteorite Chair", 9999, PrefixCategory::None),
+	item(3175, "MarbleChair", "Marble Chair", 9999, PrefixCategory::None),
+	item(3176, "GraniteChair", "Granite Chair", 9999, PrefixCategory::None),
+	item(3177, "MeteoriteChandelier", "Meteorite Chandelier", 9999, PrefixCategory::None),
+	item(3178, "MarbleChandelier", "Marble Chandelier", 9999, PrefixCategory::None),
+	item(3179, "GraniteChandelier", "Granite Chandelier", 9999, PrefixCategory::None),
+	item(3180, "MeteoriteChest", "Meteorite Chest", 9999, PrefixCategory::None),
+	item(3181, "MarbleChest", "Marble Chest", 9999, PrefixCategory::None),
+	item(3182, "MagicWaterDropper", "Magic Water Dropper", 9999, PrefixCategory::None),
+	item(3183, "GoldenBugNet", "Golden Bug Net", 1, PrefixCategory::None),
+	item(3184, "MagicLavaDropper", "Magic Lava Dropper", 9999, PrefixCategory::None),
+	item(3185, "MagicHoneyDropper", "Magic Honey Dropper", 9999, PrefixCategory::None),
+	item(3186, "EmptyDropper", "Empty Dropper", 9999, PrefixCategory::None),
+	item(3187, "GladiatorHelmet", "Gladiator Helmet", 1, PrefixCategory::None),
+	item(3188, "GladiatorBreastplate", "Gladiator Breastplate", 1, PrefixCategory::None),
+	item(3189, "GladiatorLeggings", "Gladiator Leggings", 1, PrefixCategory::None),
+	item(3190, "ReflectiveDye", "Reflective Dye", 9999, PrefixCategory::None),
+	item(3191, "EnchantedNightcrawler", "Enchanted Nightcrawler", 9999, PrefixCategory::None),
+	item(3192, "Grubby", "Grubby", 9999, PrefixCategory::None),
+	item(3193, "Sluggy", "Sluggy", 9999, PrefixCategory::None),
+	item(3194, "Buggy", "Buggy", 9999, PrefixCategory::None),
+	item(3195, "GrubSoup", "Grub Soup", 9999, PrefixCategory::None),
+	item(3196, "BombFish", "Bomb Fish", 9999, PrefixCategory::None),
+	item(3197, "FrostDaggerfish", "Frost Daggerfish", 9999, PrefixCategory::None),
+	item(3198, "SharpeningStation", "Sharpening Station", 9999, PrefixCategory::None),
+	item(3199, "ReflectiveObsidianDye", "Reflective Obsidian Dye", 9999, PrefixCategory::None),
+	item(3200, "ReflectiveMetalDye", "Reflective Metal Dye", 9999, PrefixCategory::None),
+	item(3201, "MidnightRainbowDye", "Midnight Rainbow Dye", 9999, PrefixCategory::None),
+	item(3202, "BlackAndWhiteDye", "Black and White Dye", 9999, PrefixCategory::None),
+	item(3203, "CorruptFishingCrate", "Corrupt Crate", 9999, PrefixCategory::None),
+	item(3204, "CrimsonFishingCrate", "Crimson Crate", 9999, PrefixCategory::None),
+	item(3205, "DungeonFishingCrate", "Dungeon Crate", 9999, PrefixCategory::None),
+	item(3206, "FloatingIslandFishingCrate", "Sky Crate", 9999, PrefixCategory::None),
+	item(3207, "HallowedFishingCrate", "Hallowed Crate", 9999, PrefixCategory::None),
+	item(3208, "JungleFishingCrate", "Jungle Crate", 9999, PrefixCategory::None),
+	item(3209, "CrystalSerpent", "Crystal Serpent", 1, PrefixCategory::Magic),
+	item(3210, "Toxikarp", "Toxikarp", 1, PrefixCategory::Magic),
+	item(3211, "Bladetongue", "Bladetongue", 1, PrefixCategory::Melee),
+	item(3212, "SharkToothNecklace", "Shark Tooth Necklace", 1, PrefixCategory::Accessory),
+	item(3213, "MoneyTrough", "Money Trough", 1, PrefixCategory::None),
+	item(3214, "Bubble", "Bubble", 9999, PrefixCategory::None),
+	item(3215, "DayBloomPlanterBox", "Daybloom Planter Box", 9999, PrefixCategory::None),
+	item(3216, "MoonglowPlanterBox", "Moonglow Planter Box", 9999, PrefixCategory::None),
+	item(3217, "CorruptPlanterBox", "Deathweed Planter Box", 9999, PrefixCategory::None),
+	item(3218, "CrimsonPlanterBox", "Crimson Deathweed Planter Box", 9999, PrefixCategory::None),
+	item(3219, "BlinkrootPlanterBox", "Blinkroot Planter Box", 9999, PrefixCategory::None),
+	item(3220, "WaterleafPlanterBox", "Waterleaf Planter Box", 9999, PrefixCategory::None),
+	item(3221, "ShiverthornPlanterBox", "Shiverthorn Planter Box", 9999, PrefixCategory::None),
+	item(3222, "FireBlossomPlanterBox", "Fireblossom Planter Box", 9999, PrefixCategory::None),
+	item(3223, "BrainOfConfusion", "Brain of Confusion", 1, PrefixCategory::Accessory),
+	item(3224, "WormScarf", "Worm Scarf", 1, PrefixCategory::Accessory),
+	item(3225, "BalloonPufferfish", "Balloon Pufferfish", 1, PrefixCategory::Accessory),
+	item(3226, "BejeweledValkyrieHead", "Lazure's Valkyrie Circlet", 1, PrefixCategory::None),
+	item(3227, "BejeweledValkyrieBody", "Lazure's Valkyrie Cloak", 1, PrefixCategory::None),
+	item(3228, "BejeweledValkyrieWing", "Lazure's Barrier Platform", 1, PrefixCategory::Accessory),
+	item(3229, "RichGravestone1", "Golden Cross Grave Marker", 9999, PrefixCategory::None),
+	item(3230, "RichGravestone2", "Golden Tombstone", 9999, PrefixCategory::None),
+	item(3231, "RichGravestone3", "Golden Grave Marker", 9999, PrefixCategory::None),
+	item(3232, "RichGravestone4", "Golden Gravestone", 9999, PrefixCategory::None),
+	item(3233, "RichGravestone5", "Golden Headstone", 9999, PrefixCategory::None),
+	item(3234, "CrystalBlock", "Crystal Block", 9999, PrefixCategory::None),
+	item(3235, "MusicBoxMartians", "Music Box (Martian Madness)", 1, PrefixCategory::None),
+	item(3236, "MusicBoxPirates", "Music Box (Pirate Invasion)", 1, PrefixCategory::None),
+	item(3237, "MusicBoxHell", "Music Box (Hell)", 1, PrefixCategory::None),
+	item(3238, "CrystalBlockWall", "Crystal Block Wall", 9999, PrefixCategory::None),
+	item(3239, "Trapdoor", "Trapdoor", 9999, PrefixCategory::None),
+	item(3240, "TallGate", "Tall Gate", 9999, PrefixCategory::None),
+	item(3241, "SharkronBalloon", "Sharkron Balloon", 1, PrefixCategory::Accessory),
+	item(3242, "TaxCollectorHat", "Tax Collector Hat", 1, PrefixCategory::None),
+	item(3243, "TaxCollectorSuit", "Tax Collector Suit", 9999, PrefixCategory::None),
+	item(3244, "TaxCollectorPants", "Tax Collector Pants", 1, PrefixCategory::None),
+	item(3245, "BoneGlove", "Bone Glove", 1, PrefixCategory::Ranged),
+	item(3246, "ClothierJacket", "Clothier Jacket", 1, PrefixCategory::None),
+	item(3247, "ClothierPants", "Clothier Pants", 1, PrefixCategory::None),
+	item(3248, "DyeTraderTurban", "Dye Trader Turban", 9999, PrefixCategory::None),
+	item(3249, "DeadlySphereStaff", "Deadly Sphere Staff", 1, PrefixCategory::Magic),
+	item(3250, "GreenHorseshoeBalloon", "Green Horseshoe Balloon", 1, PrefixCategory::Accessory),
+	item(3251, "AmberHorseshoeBalloon", "Amber Horseshoe Balloon", 1, PrefixCategory::Accessory),
+	item(3252, "PinkHorseshoeBalloon", "Pink Horseshoe Balloon", 1, PrefixCategory::Accessory),
+	item(3253, "LavaLamp", "Lava Lamp", 9999, PrefixCategory::None),
+	item(3254, "GraniteGolemBanner", "Granite Golem Banner", 9999, PrefixCategory::None),
+	item(3255, "GraniteFlyerBanner", "Granite Elemental Banner", 9999, PrefixCategory::None),
+	item(3256, "HopliteBanner", "Hoplite Banner", 9999, PrefixCategory::None),
+	item(3257, "MedusaBanner", "Medusa Banner", 9999, PrefixCategory::None),
+	item(3258, "GreekSkeletonBanner", "Greek Skeleton Banner", 9999, PrefixCategory::None),
+	item(3259, "BloodZombieBanner", "Blood Zombie Banner", 9999, PrefixCategory::None),
+	item(3260, "DripplerBanner", "Drippler Banner", 9999, PrefixCategory::None),
+	item(3261, "SpectreBar", "Spectre Bar", 9999, PrefixCategory::None),
+	item(3262, "Code1", "Code 1", 1, PrefixCategory::Melee),
+	item(3263, "PrinceUniform", "Prince Uniform", 9999, PrefixCategory::None),
+	item(3264, "PrincePants", "Prince Pants", 1, PrefixCategory::None),
+	item(3265, "PrinceCape", "Prince Cape", 9999, PrefixCategory::None),
+	item(3266, "SkiphsHelm", "Skiphs's Mask", 1, PrefixCategory::None),
+	item(3267, "SkiphsShirt", "Skiphs's Skin", 1, PrefixCategory::None),
+	item(3268, "SkiphsPants", "Skiphs's Bear Butt", 1, PrefixCategory::None),
+	item(3269, "SkiphsWings", "Skiphs's Paws", 1, PrefixCategory::Accessory),
+	item(3270, "LokisHelm", "Loki's Helmet", 1, PrefixCategory::None),
+	item(3271, "LokisShirt", "Loki's Breastplate", 1, PrefixCategory::None),
+	item(3272, "LokisPants", "Loki's Greaves", 1, PrefixCategory::None),
+	item(3273, "LokisWings", "Loki's Wings", 1, PrefixCategory::Accessory),
+	item(3274, "ArkhalisHat", "Arkhalis's Hood", 1, PrefixCategory::None),
+	item(3275, "ArkhalisShirt", "Arkhalis's Bodice", 1, PrefixCategory::None),
+	item(3276, "ArkhalisPants", "Arkhalis's Tights", 1, PrefixCategory::None),
+	item(3277, "ArkhalisWings", "Arkhalis's Lightwings", 1, PrefixCategory::Accessory),
+	item(3278, "WoodYoyo", "Wooden Yoyo", 1, PrefixCategory::Melee),
+	item(3279, "CorruptYoyo", "Malaise", 1, PrefixCategory::Melee),
+	item(3280, "CrimsonYoyo", "Artery", 1, PrefixCategory::Melee),
+	item(3281, "JungleYoyo", "Amazon", 1, PrefixCategory::Melee),
+	item(3282, "Cascade", "Cascade", 1, PrefixCategory::Melee),
+	item(3283, "Chik", "Chik", 1, PrefixCategory::Melee),
+	item(3284, "Code2", "Code 2", 1, PrefixCategory::Melee),
+	item(3285, "Rallypunch", "Rally", 1, PrefixCategory::Melee),
+	item(3286, "Yelets", "Yelets", 1, PrefixCategory::Melee),
+	item(3287, "RedsYoyo", "Red's Throw", 1, PrefixCategory::Melee),
+	item(3288, "ValkyrieYoyo", "Valkyrie Yoyo", 1, PrefixCategory::Melee),
+	item(3289, "Amarok", "Amarok", 1, PrefixCategory::Melee),
+	item(3290, "HelFire", "Hel-Fire", 1, PrefixCategory::Melee),
+	item(3291, "Kraken", "Kraken", 1, PrefixCategory::Melee),
+	item(3292, "TheEyeOfCthulhu", "The Eye of Cthulhu", 1, PrefixCategory::Melee),
+	item(3293, "RedString", "Red String", 1, PrefixCategory::Accessory),
+	item(3294, "OrangeString", "Orange String", 1, PrefixCategory::Accessory),
+	item(3295, "YellowString", "Yellow String", 1, PrefixCategory::Accessory),
+	item(3296, "LimeString", "Lime String", 1, PrefixCategory::Accessory),
+	item(3297, "GreenString", "Green String", 1, PrefixCategory::Accessory),
+	item(3298, "TealString", "Teal String", 1, PrefixCategory::Accessory),
+	item(3299, "CyanString", "Cyan String", 1, PrefixCategory::Accessory),
+	item(3300, "SkyBlueString", "Sky Blue String", 1, PrefixCategory::Accessory),
+	item(3301, "BlueString", "Blue String", 1, PrefixCategory::Accessory),
+	item(3302, "PurpleString", "Purple String", 1, PrefixCategory::Accessory),
+	item(3303, "VioletString", "Violet String", 1, PrefixCategory::Accessory),
+	item(3304, "PinkString", "Pink String", 1, PrefixCategory::Accessory),
+	item(3305, "BrownString", "Brown String", 1, PrefixCategory::Accessory),
+	item(3306, "WhiteString", "White String", 1, PrefixCategory::Accessory),
+	item(3307, "RainbowString", "Rainbow String", 1, PrefixCategory::Accessory),
+	item(3308, "BlackString", "Black String", 1, PrefixCategory::Accessory),
+	item(3309, "BlackCounterweight", "Black Counterweight", 1, PrefixCategory::Accessory),
+	item(3310, "BlueCounterweight", "Blue Counterweight", 1, PrefixCategory::Accessory),
+	item(3311, "GreenCounterweight", "Green Counterweight", 1, PrefixCategory::Accessory),
+	item(3312, "PurpleCounterweight", "Purple Counterweight", 1, PrefixCategory::Accessory),
+	item(3313, "RedCounterweight", "Red Counterweight", 1, PrefixCategory::Accessory),
+	item(3314, "YellowCounterweight", "Yellow Counterweight", 1, PrefixCategory::Accessory),
+	item(3315, "FormatC", "Format C", 1, PrefixCategory::Melee),
+	item(3316, "Gradient", "Gradient", 1, PrefixCategory::Melee),
+	item(3317, "Valor", "Valor", 1, PrefixCategory::Melee),
+	item(3318, "KingSlimeBossBag", "Treasure Bag (King Slime)", 9999, PrefixCategory::None),
+	item(3319, "EyeOfCthulhuBossBag", "Treasure Bag (Eye of Cthulhu)", 9999, PrefixCategory::None),
+	item(3320, "EaterOfWorldsBossBag", "Treasure Bag (Eater of Worlds)", 9999, PrefixCategory::None),
+	item(3321, "BrainOfCthulhuBossBag", "Treasure Bag (Brain of Cthulhu)", 9999, PrefixCategory::None),
+	item(3322, "QueenBeeBossBag", "Treasure Bag (Queen Bee)", 9999, PrefixCategory::None),
+	item(3323, "SkeletronBossBag", "Treasure Bag (Skeletron)", 9999, PrefixCategory::None),
+	item(3324, "WallOfFleshBossBag", "Treasure Bag (Wall of Flesh)", 9999, PrefixCategory::None),
+	item(3325, "DestroyerBossBag", "Treasure Bag (The Destroyer)", 9999, PrefixCategory::None),
+	item(3326, "TwinsBossBag", "Treasure Bag (The Twins)", 9999, PrefixCategory::None),
+	item(3327, "SkeletronPrimeBossBag", "Treasure Bag (Skeletron Prime)", 9999, PrefixCategory::None),
+	item(3328, "PlanteraBossBag", "Treasure Bag (Plantera)", 9999, PrefixCategory::None),
+	item(3329, "GolemBossBag", "Treasure Bag (Golem)", 9999, PrefixCategory::None),
+	item(3330, "FishronBossBag", "Treasure Bag (Duke Fishron)", 9999, PrefixCategory::None),
+	item(3331, "CultistBossBag", "Treasure Bag (Lunatic Cultist)", 9999, PrefixCategory::None),
+	item(3332, "MoonLordBossBag", "Treasure Bag (Moon Lord)", 9999, PrefixCategory::None),
+	item(3333, "HiveBackpack", "Hive Pack", 1, PrefixCategory::Accessory),
+	item(3334, "YoYoGlove", "Yoyo Glove", 1, PrefixCategory::Accessory),
+	item(3335, "DemonHeart", "Demon Heart", 1, PrefixCategory::None),
+	item(3336, "SporeSac", "Spore Sac", 1, PrefixCategory::Accessory),
+	item(3337, "ShinyStone", "Shiny Stone", 1, PrefixCategory::Accessory),
+	item(3338, "Yoraiz0rHead", "Yoraiz0r's Recolored Goggles", 1, PrefixCategory::None),
+	item(3339, "Yoraiz0rShirt", "Yoraiz0r's Uniform", 1, PrefixCategory::None),
+	item(3340, "Yoraiz0rPants", "Yoraiz0r's Skirt", 1, PrefixCategory::None),
+	item(3341, "Yoraiz0rWings", "Yoraiz0r's Spell", 1, PrefixCategory::Accessory),
+	item(3342, "Yoraiz0rDarkness", "Yoraiz0r's Scowl", 1, PrefixCategory::Accessory),
+	item(3343, "JimsWings", "Jim's Wings", 1, PrefixCategory::Accessory),
+	item(3344, "LeinforsHat", "Leinfors' Hair Protector", 1, PrefixCategory::None),
+	item(3345, "LeinforsShirt", "Leinfors' Excessive Style", 1, PrefixCategory::None),
+	item(3346, "LeinforsPants", "Leinfors' Fancypants", 1, PrefixCategory::None),
+	item(3347, "LeinforsWings", "Leinfors' Prehensile Cloak", 1, PrefixCategory::Accessory),
+	item(3348, "LeinforsAccessory", "Leinfors' Luxury Shampoo", 1, PrefixCategory::Accessory),
+	item(3349, "BossMaskCultist", "Ancient Cultist Mask", 9999, PrefixCategory::None),
+	item(3350, "BossMaskMoonlord", "Moon Lord Mask", 9999, PrefixCategory::None),
+	item(3351, "PedguinHat", "Pedguin's Hood", 1, PrefixCategory::None),
+	item(3352, "PedguinShirt", "Pedguin's Jacket", 1, PrefixCategory::None),
+	item(3353, "PedguinPants", "Pedguin's Trousers", 1, PrefixCategory::None),
+	item(3354, "AncientArmorHat", "Ancient Headdress", 1, PrefixCategory::None),
+	item(3355, "AncientArmorShirt", "Ancient Garments", 1, PrefixCategory::None),
+	item(3356, "AncientArmorPants", "Ancient Slacks", 1, PrefixCategory::None),
+	item(3357, "MartianSaucerTrophy", "Martian Saucer Trophy", 9999, PrefixCategory::None),
+	item(3358, "AncientCultistTrophy", "Ancient Cultist Trophy", 9999, PrefixCategory::None),
+	item(3359, "MoonLordTrophy", "Moon Lord Trophy", 9999, PrefixCategory::None),
+	item(3360, "LivingMahoganyWand", "Living Mahogany Wand", 1, PrefixCategory::None),
+	item(3361, "LivingMahoganyLeafWand", "Rich Mahogany Leaf Wand", 1, PrefixCategory::None),
+	item(3362, "VortexMonolith", "Vortex Monolith", 9999, PrefixCategory::None),
+	item(3363, "NebulaMonolith", "Nebula Monolith", 9999, PrefixCategory::None),
+	item(3364, "StardustMonolith", "Stardust Monolith", 9999, PrefixCategory::None),
+	item(3365, "SolarMonolith", "Solar Monolith", 9999, PrefixCategory::None),
+	item(3366, "SolarBrick", "Solar Fragment Block", 9999, PrefixCategory::None),
+	item(3367, "VortexBrick", "Vortex Fragment Block", 9999, PrefixCategory::None),
+	item(3368, "NebulaBrick", "Nebula Fragment Block", 9999, PrefixCategory::None),
+	item(3369, "StardustBrick", "Stardust Fragment Block", 9999, PrefixCategory::None),
+	item(3370, "SolarBrickWall", "Solar Fragment Wall", 9999, PrefixCategory::None),
+	item(3371, "VortexBrickWall", "Vortex Fragment Wall", 9999, PrefixCategory::None),
+	item(3372, "NebulaBrickWall", "Nebula Fragment Wall", 9999, PrefixCategory::None),
+	item(3373, "StardustBrickWall", "Stardust Fragment Wall", 9999, PrefixCategory::None),
+	item(3374, "SolarDye", "Solar Dye", 9999, PrefixCategory::None),
+	item(3375, "NebulaDye", "Nebula Dye", 9999, PrefixCategory::None),
+	item(3376, "VortexDye", "Vortex Dye", 9999, PrefixCategory::None),
+	item(3377, "StardustDye", "Stardust Dye", 9999, PrefixCategory::None),
+	item(3378, "VoidDye", "Void Dye", 9999, PrefixCategory::None),
+	item(3379, "ShadowflameHadesDye", "Shadowflame Hades Dye", 9999, PrefixCategory::None),
+	item(3380, "GrimDye", "Grim Dye", 9999, PrefixCategory::None),
+	item(3381, "StardustHelmet", "Stardust Helmet", 1, PrefixCategory::None),
+	item(3382, "StardustBreastplate", "Stardust Breastplate", 1, PrefixCategory::None),
+	item(3383, "StardustLeggings", "Stardust Leggings", 1, PrefixCategory::None),
+	item(3384, "PortalGun", "Portal Gun", 1, PrefixCategory::None),
+	item(3385, "StrangePlant1", "Strange Plant", 9999, PrefixCategory::None),
+	item(3386, "StrangePlant2", "Strange Plant", 9999, PrefixCategory::None),
+	item(3387, "StrangePlant3", "Strange Plant", 9999, PrefixCategory::None),
+	item(3388, "StrangePlant4", "Strange Plant", 9999, PrefixCategory::None),
+	item(3389, "Terrarian", "Terrarian", 1, PrefixCategory::Melee),
+	item(3390, "SolarCoriteBanner", "Corite Banner", 9999, PrefixCategory::None),
+	item(3391, "SolarCrawltipedeBanner", "Crawltipede Banner", 9999, PrefixCategory::None),
+	item(3392, "SolarDrakomireBanner", "Drakomire Banner", 9999, PrefixCategory::None),
+	item(3393, "SolarDrakomireRiderBanner", "Drakomire Rider Banner", 9999, PrefixCategory::None),
+	item(3394, "SolarSolenianBanner", "Selenian Banner", 9999, PrefixCategory::None),
+	item(3395, "SolarSrollerBanner", "Sroller Banner", 9999, PrefixCategory::None),
+	item(3396, "VortexHornetBanner", "Alien Larva Banner", 9999, PrefixCategory::None),
+	item(3397, "VortexHornetQueenBanner", "Alien Queen Banner", 9999, PrefixCategory::None),
+	item(3398, "VortexLarvaBanner", "Alien Hornet Banner", 9999, PrefixCategory::None),
+	item(3399, "VortexRiflemanBanner", "Storm Diver Banner", 9999, PrefixCategory::None),
+	item(3400, "VortexSoldierBanner", "Vortexian Banner", 9999, PrefixCategory::None),
+	item(3401, "NebulaBeastBanner", "Evolution Beast Banner", 9999, PrefixCategory::None),
+	item(3402, "NebulaBrainBanner", "Nebula Floater Banner", 9999, PrefixCategory::None),
+	item(3403, "NebulaHeadcrabBanner", "Brain Suckler Banner", 9999, PrefixCategory::None),
+	item(3404, "NebulaSoldierBanner", "Predictor Banner", 9999, PrefixCategory::None),
+	item(3405, "StardustJellyfishBanner", "Flow Invader Banner", 9999, PrefixCategory::None),
+	item(3406, "StardustLargeCellBanner", "Star Cell Banner", 9999, PrefixCategory::None),
+	item(3407, "StardustSoldierBanner", "Stargazer Banner", 9999, PrefixCategory::None),
+	item(3408, "StardustSpiderBanner", "Twinkle Popper Banner", 9999, PrefixCategory::None),
+	item(3409, "StardustWormBanner", "Milkyway Weaver Banner", 9999, PrefixCategory::None),
+	item(3410, "ButcherBanner", "Butcher Banner", 9999, PrefixCategory::None),
+	item(3411, "CreatureFromTheDeepBanner", "Creature from the Deep Banner", 9999, PrefixCategory::None),
+	item(3412, "FritzBanner", "Fritz Banner", 9999, PrefixCategory::None),
+	item(3413, "NailheadBanner", "Nailhead Banner", 9999, PrefixCategory::None),
+	item(3414, "CrimsonBunnyBanner", "Vicious Bunny Banner", 9999, PrefixCategory::None),
+	item(3415, "CrimsonGoldfishBanner", "Vicious Goldfish Banner", 9999, PrefixCategory::None),
+	item(3416, "PsychoBanner", "Psycho Banner", 9999, PrefixCategory::None),
+	item(3417, "DeadlySphereBanner", "Deadly Sphere Banner", 9999, PrefixCategory::None),
+	item(3418, "ThePossessedBanner", "The Possessed Banner", 9999, PrefixCategory::None),
+	item(3419, "CrimsonPenguinBanner", "Vicious Penguin Banner", 9999, PrefixCategory::None),
+	item(3420, "ShadowflameApparitionBanner", "Shadowflame Apparition Banner", 9999, PrefixCategory::None),
+	item(3421, "BigMimicCorruptionBanner", "Corrupt Mimic Banner", 9999, PrefixCategory::None),
+	item(3422, "BigMimicCrimsonBanner", "Crimson Mimic Banner", 9999, PrefixCategory::None),
+	item(3423, "BigMimicHallowBanner", "Hallowed Mimic Banner", 9999, PrefixCategory::None),
+	item(3424, "MothronBanner", "Mothron Banner", 9999, PrefixCategory::None),
+	item(3425, "SalamanderBanner", "Salamander Banner", 9999, PrefixCategory::None),
+	item(3426, "GiantShellyBanner", "Giant Shelly Banner", 9999, PrefixCategory::None),
+	item(3427, "CrawdadBanner", "Crawdad Banner", 9999, PrefixCategory::None),
+	item(3428, "PirateCaptainBanner", "Pirate Captain Banner", 9999, PrefixCategory::None),
+	item(3429, "PirateCorsairBanner", "Pirate Corsair Banner", 9999, PrefixCategory::None),
+	item(3430, "PirateCrossbowerBanner", "Pirate Crossbower Banner", 9999, PrefixCategory::None),
+	item(3431, "PirateDeadeyeBanner", "Pirate Deadeye Banner", 9999, PrefixCategory::None),
+	item(3432, "GreenJellyfishBanner", "Green Jellyfish Banner", 9999, PrefixCategory::None),
+	item(3433, "PinkJellyfishBanner", "Pink Jellyfish Banner", 9999, PrefixCategory::None),
+	item(3434, "ZombieMushroomBanner", "Zombie Mushroom Banner", 9999, PrefixCategory::None),
+	item(3435, "BurningHadesDye", "Burning Hades Dye", 9999, PrefixCategory::None),
+	item(3436, "FogboundDye", "Fogbound Dye", 9999, PrefixCategory::None),
+	item(3437, "BloodbathDye", "Bloodbath Dye", 9999, PrefixCategory::None),
+	item(3438, "ReflectiveCopperDye", "Reflective Copper Dye", 9999, PrefixCategory::None),
+	item(3439, "LokisDye", "Loki's Dye", 9999, PrefixCategory::None),
+	item(3440, "SkiphsBlood", "Skiphs's Blood", 9999, PrefixCategory::None),
+	item(3441, "TeamBlockRed", "Red Team Block", 9999, PrefixCategory::None),
+	item(3442, "TeamBlockRedPlatform", "Red Team Platform", 9999, PrefixCategory::None),
+	item(3443, "TeamBlockGreen", "Green Team Block", 9999, PrefixCategory::None),
+	item(3444, "TeamBlockGreenPlatform", "Green Team Platform", 9999, PrefixCategory::None),
+	item(3445, "TeamBlockBlue", "Blue Team Block", 9999, PrefixCategory::None),
+	item(3446, "TeamBlockBluePlatform", "Blue Team Platform", 9999, PrefixCategory::None),
+	item(3447, "TeamBlockYellow", "Yellow Team Block", 9999, PrefixCategory::None),
+	item(3448, "TeamBlockYellowPlatform", "Yellow Team Platform", 9999, PrefixCategory::None),
+	item(3449, "TeamBlockPink", "Pink Team Block", 9999, PrefixCategory::None),
+	item(3450, "TeamBlockPinkPlatform", "Pink Team Platform", 9999, PrefixCategory::None),
+	item(3451, "TeamBlockWhite", "White Team Block", 9999, PrefixCategory::None),
+	item(3452, "TeamBlockWhitePlatform", "White Team Platform", 9999, PrefixCategory::None),
+	item(3453, "NebulaPickup1", "Damage Booster", 9999, PrefixCategory::None),
+	item(3454, "NebulaPickup2", "Life Booster", 9999, PrefixCategory::None),
+	item(3455, "NebulaPickup3", "Mana Booster", 9999, PrefixCategory::None),
+	item(3456, "FragmentVortex", "Vortex Fragment", 9999, PrefixCategory::None),
+	item(3457, "FragmentNebula", "Nebula Fragment", 9999, PrefixCategory::None),
+	item(3458, "FragmentSolar", "Solar Fragment", 9999, PrefixCategory::None),
+	item(3459, "FragmentStardust", "Stardust Fragment", 9999, PrefixCategory::None),
+	item(3460, "LunarOre", "Luminite", 9999, PrefixCategory::None),
+	item(3461, "LunarBrick", "Luminite Brick", 9999, PrefixCategory::None),
+	item(3462, "StardustAxe", "Stardust Axe", 1, PrefixCategory::Melee),
+	item(3463, "StardustChainsaw", "Stardust Chainsaw", 1, PrefixCategory::Melee),
+	item(3464, "StardustDrill", "Stardust Drill", 1, PrefixCategory::Melee),
+	item(3465, "StardustHammer", "Stardust Hammer", 1, PrefixCategory::Melee),
+	item(3466, "StardustPickaxe", "Stardust Pickaxe", 1, PrefixCategory::Melee),
+	item(3467, "LunarBar", "Luminite Bar", 9999, PrefixCategory::None),
+	item(3468, "WingsSolar", "Solar Wings", 9999, PrefixCategory::None),
+	item(3469, "WingsVortex", "Vortex Booster", 9999, PrefixCategory::None),
+	item(3470, "WingsNebula", "Nebula Mantle", 9999, PrefixCategory::None),
+	item(3471, "WingsStardust", "Stardust Wings", 9999, PrefixCategory::None),
+	item(3472, "LunarBrickWall", "Luminite Brick Wall", 9999, PrefixCategory::None),
+	item(3473, "SolarEruption", "Solar Eruption", 1, PrefixCategory::Melee),
+	item(3474, "StardustCellStaff", "Stardust Cell Staff", 1, PrefixCategory::Magic),
+	item(3475, "VortexBeater", "Vortex Beater", 1, PrefixCategory::Ranged),
+	item(3476, "NebulaArcanum", "Nebula Arcanum", 1, PrefixCategory::Magic),
+	item(3477, "BloodWater", "Blood Water", 9999, PrefixCategory::None),
+	item(3478, "WeddingVeil", "Wedding Veil", 9999, PrefixCategory::None),
+	item(3479, "WeddingDress", "Wedding Dress", 1, PrefixCategory::None),
+	item(3480, "TinBow", "Tin Bow", 1, PrefixCategory::Ranged),
+	item(3481, "TinHammer", "Tin Hammer", 1, PrefixCategory::Melee),
+	item(3482, "TinAxe", "Tin Axe", 1, PrefixCategory::Melee),
+	item(3483, "TinShortsword", "Tin Shortsword", 1, PrefixCategory::Melee),
+	item(3484, "TinBroadsword", "Tin Broadsword", 1, PrefixCategory::Melee),
+	item(3485, "TinPickaxe", "Tin Pickaxe", 1, PrefixCategory::Melee),
+	item(3486, "LeadBow", "Lead Bow", 1, PrefixCategory::Ranged),
+	item(3487, "LeadHammer", "Lead Hammer", 1, PrefixCategory::Melee),
+	item(3488, "LeadAxe", "Lead Axe", 1, PrefixCategory::Melee),
+	item(3489, "LeadShortsword", "Lead Shortsword", 1, PrefixCategory::Melee),
+	item(3490, "LeadBroadsword", "Lead Broadsword", 1, PrefixCategory::Melee),
+	item(3491, "LeadPickaxe", "Lead Pickaxe", 1, PrefixCategory::Melee),
+	item(3492, "SilverBow", "Silver Bow", 1, PrefixCategory::Ranged),
+	item(3493, "SilverHammer", "Silver Hammer", 1, PrefixCategory::Melee),
+	item(3494, "SilverAxe", "Silver Axe", 1, PrefixCategory::Melee),
+	item(3495, "SilverShortsword", "Silver Shortsword", 1, PrefixCategory::Melee),
+	item(3496, "SilverBroadsword", "Silver Broadsword", 1, PrefixCategory::Melee),
+	item(3497, "SilverPickaxe", "Silver Pickaxe", 1, PrefixCategory::Melee),
+	item(3498, "TungstenBow", "Tungsten Bow", 1, PrefixCategory::Ranged),
+	item(3499, "TungstenHammer", "Tungsten Hammer", 1, PrefixCategory::Melee),
+	item(3500, "TungstenAxe", "Tungsten Axe", 1, PrefixCategory::Melee),
+	item(3501, "TungstenShortsword", "Tungsten Shortsword", 1, PrefixCategory::Melee),
+	item(3502, "TungstenBroadsword", "Tungsten Broadsword", 1, PrefixCategory::Melee),
+	item(3503, "TungstenPickaxe", "Tungsten Pickaxe", 1, PrefixCategory::Melee),
+	item(3504, "CopperBow", "Copper Bow", 1, PrefixCategory::Ranged),
+	item(3505, "CopperHammer", "Copper Hammer", 1, PrefixCategory::Melee),
+	item(3506, "CopperAxe", "Copper Axe", 1, PrefixCategory::Melee),
+	item(3507, "CopperShortsword", "Copper Shortsword", 1, PrefixCategory::Melee),
+	item(3508, "CopperBroadsword", "Copper Broadsword", 1, PrefixCategory::Melee),
+	item(3509, "CopperPickaxe", "Copper Pickaxe", 1, PrefixCategory::Melee),
+	item(3510, "GoldBow", "Gold Bow", 1, PrefixCategory::Ranged),
+	item(3511, "GoldHammer", "Gold Hammer", 1, PrefixCategory::Melee),
+	item(3512, "GoldAxe", "Gold Axe", 1, PrefixCategory::Melee),
+	item(3513, "GoldShortsword", "Gold Shortsword", 1, PrefixCategory::Melee),
+	item(3514, "GoldBroadsword", "Gold Broadsword", 1, PrefixCategory::Melee),
+	item(3515, "GoldPickaxe", "Gold Pickaxe", 1, PrefixCategory::Melee),
+	item(3516, "PlatinumBow", "Platinum Bow", 1, PrefixCategory::Ranged),
+	item(3517, "PlatinumHammer", "Platinum Hammer", 1, PrefixCategory::Melee),
+	item(3518, "PlatinumAxe", "Platinum Axe", 1, PrefixCategory::Melee),
+	item(3519, "PlatinumShortsword", "Platinum Shortsword", 1, PrefixCategory::Melee),
+	item(3520, "PlatinumBroadsword", "Platinum Broadsword", 1, PrefixCategory::Melee),
+	item(3521, "PlatinumPickaxe", "Platinum Pickaxe", 1, PrefixCategory::Melee),
+	item(3522, "LunarHamaxeSolar", "Solar Flare Hamaxe", 1, PrefixCategory::Melee),
+	item(3523, "LunarHamaxeVortex", "Vortex Hamaxe", 1, PrefixCategory::Melee),
+	item(3524, "LunarHamaxeNebula", "Nebula Hamaxe", 1, PrefixCategory::Melee),
+	item(3525, "LunarHamaxeStardust", "Stardust Hamaxe", 1, PrefixCategory::Melee),
+	item(3526, "MusicBoxTowers", "Music Box (Lunar Towers)", 1, PrefixCategory::None),
+	item(3527, "MusicBoxGoblins", "Music Box (Goblin Invasion)", 1, PrefixCategory::None),
+	item(3528, "ItemFrame", "Item Frame", 9999, PrefixCategory::None),
+	item(3529, "Fireplace", "Fireplace", 9999, PrefixCategory::None),
+	item(3530, "Chimney", "Chimney", 9999, PrefixCategory::None),
+	item(3531, "StardustDragonStaff", "Stardust Dragon Staff", 1, PrefixCategory::Magic),
+	item(3532, "ShiftingSandsDye", "Shifting Sands Dye", 9999, PrefixCategory::None),
+	item(3533, "ShiftingPearlSandsDye", "Shifting Pearl Sands Dye", 9999, PrefixCategory::None),
+	item(3534, "MirageDye", "Mirage Dye", 9999, PrefixCategory::None),
+	item(3535, "SquirrelHook", "Squirrel Hook", 1, PrefixCategory::None),
+	item(3536, "GraniteGolemStatue", "Granite Golem Statue", 9999, PrefixCategory::None),
+	item(3537, "MedusaStatue", "Medusa Statue", 9999, PrefixCategory::None),
+	item(3538, "HopliteStatue", "Hoplite Statue", 9999, PrefixCategory::None),
+	item(3539, "BloodZombieStatue", "Blood Zombie Statue", 9999, PrefixCategory::None),
+	item(3540, "Phantasm", "Phantasm", 1, PrefixCategory::Ranged),
+	item(3541, "LastPrism", "Last Prism", 1, PrefixCategory::Magic),
+	item(3542, "NebulaBlaze", "Nebula Blaze", 1, PrefixCategory::Magic),
+	item(3543, "DayBreak", "Daybreak", 1, PrefixCategory::Melee),
+	item(3544, "SuperHealingPotion", "Super Healing Potion", 9999, PrefixCategory::None),
+	item(3545, "Detonator", "Detonator", 9999, PrefixCategory::None),
+	item(3546, "FireworksLauncher", "Celebration", 1, PrefixCategory::Ranged),
+	item(3547, "BouncyDynamite", "Bouncy Dynamite", 9999, PrefixCategory::None),
+	item(3548, "PartyGirlGrenade", "Happy Grenade", 9999, PrefixCategory::None),
+	item(3549, "LunarCraftingStation", "Ancient Manipulator", 9999, PrefixCategory::None),
+	item(3550, "FlameAndSilverDye", "Flame and Silver Dye", 9999, PrefixCategory::None),
+	item(3551, "GreenFlameAndSilverDye", "Green Flame and Silver Dye", 9999, PrefixCategory::None),
+	item(3552, "BlueFlameAndSilverDye", "Blue Flame and Silver Dye", 9999, PrefixCategory::None),
+	item(3553, "Sandstone", "Sandstone Block", 9999, PrefixCategory::None),
+	item(3554, "HardenedSand", "Hardened Sand Block", 9999, PrefixCategory::None),
+	item(3555, "CorruptHardenedSand", "Hardened Ebonsand Block", 9999, PrefixCategory::None),
+	item(3556, "CrimsonHardenedSand", "Hardened Crimsand Block", 9999, PrefixCategory::None),
+	item(3557, "HallowHardenedSand", "Hardened Pearlsand Block", 9999, PrefixCategory::None),
+	item(3558, "CorruptSandstone", "Ebonsandstone Block", 9999, PrefixCategory::None),
+	item(3559, "CrimsonSandstone", "Crimsandstone Block", 9999, PrefixCategory::None),
+	item(3560, "HallowSandstone", "Pearlsandstone Block", 9999, PrefixCategory::None),
+	item(3561, "DesertFossil", "Desert Fossil", 9999, PrefixCategory::None),
+	item(3562, "FossilOre", "Sturdy Fossil", 9999, PrefixCategory::None),
+	item(3563, "SandstoneWall", "Sandstone Wall", 9999, PrefixCategory::None),
+	item(3564, "HardenedSandWall", "Hardened Sand Wall", 9999, PrefixCategory::None),
+	item(3565, "CorruptHardenedSandWall", "Hardened Ebonsand Wall", 9999, PrefixCategory::None),
+	item(3566, "CrimsonHardenedSandWall", "Hardened Crimsand Wall", 9999, PrefixCategory::None),
+	item(3567, "MoonlordArrow", "Luminite Arrow", 9999, PrefixCategory::None),
+	item(3568, "MoonlordBullet", "Luminite Bullet", 9999, PrefixCategory::None),
+	item(3569, "MoonlordTurretStaff", "Lunar Portal Staff", 1, PrefixCategory::Magic),
+	item(3570, "LunarFlareBook", "Lunar Flare", 1, PrefixCategory::Magic),
+	item(3571, "RainbowCrystalStaff", "Rainbow Crystal Staff", 1, PrefixCategory::Magic),
+	item(3572, "LunarHook", "Lunar Hook", 1, PrefixCategory::None),
+	item(3573, "LunarBlockSolar", "Solar Lunar Block", 9999, PrefixCategory::None),
+	item(3574, "LunarBlockVortex", "Vortex Lunar Block", 9999, PrefixCategory::None),
+	item(3575, "LunarBlockNebula", "Nebula Lunar Block", 9999, PrefixCategory::None),
+	item(3576, "LunarBlockStardust", "Stardust Lunar Block", 9999, PrefixCategory::None),
+	item(3577, "SuspiciousLookingTentacle", "Suspicious Looking Tentacle", 1, PrefixCategory::None),
+	item(3578, "ZombieArmStatue", "Zombie Arm Statue", 9999, PrefixCategory::None),
+	item(3579, "DripplerStatue", "Drippler Statue", 9999, PrefixCategory::None),
+	item(3580, "WraithStatue", "Wraith Statue", 9999, PrefixCategory::None),
+	item(3581, "BoneSkeletonStatue", "Bone Skeleton Statue", 9999, PrefixCategory::None),
+	item(3582, "UndeadVikingStatue", "Undead Viking Statue", 9999, PrefixCategory::None),
+	item(3583, "HarpyStatue", "Harpy Statue", 9999, PrefixCategory::None),
+	item(3584, "PigronStatue", "Pigron Statue", 9999, PrefixCategory::None),
+	item(3585, "ArmedZombieStatue", "Armed Zombie Statue", 9999, PrefixCategory::None),
+	item(3586, "WallCreeperStatue", "Wall Creeper Statue", 9999, PrefixCategory::None),
+	item(3587, "SillyBalloonPink", "Silly Pink Balloon", 9999, PrefixCategory::None),
+	item(3588, "SillyBalloonPurple", "Silly Purple Balloon", 9999, PrefixCategory::None),
+	item(3589, "SillyBalloonGreen", "Silly Green Balloon", 9999, PrefixCategory::None),
+	item(3590, "SillyStreamerBlue", "Blue Streamer", 9999, PrefixCategory::None),
+	item(3591, "SillyStreamerGreen", "Green Streamer", 9999, PrefixCategory::None),
+	item(3592, "SillyStreamerPink", "Pink Streamer", 9999, PrefixCategory::None),
+	item(3593, "SillyBalloonMachine", "Silly Balloon Machine", 9999, PrefixCategory::None),
+	item(3594, "SillyBalloonTiedPink", "Silly Tied Balloon (Pink)", 9999, PrefixCategory::None),
+	item(3595, "SillyBalloonTiedPurple", "Silly Tied Balloon (Purple)", 9999, PrefixCategory::None),
+	item(3596, "SillyBalloonTiedGreen", "Silly Tied Balloon (Green)", 9999, PrefixCategory::None),
+	item(3597, "Pigronata", "Pigronata", 9999, PrefixCategory::None),
+	item(3598, "PartyMonolith", "Party Monolith", 9999, PrefixCategory::None),
+	item(3599, "PartyBundleOfBalloonsAccessory", "Bundle of Party Balloons", 1, PrefixCategory::Accessory),
+	item(3600, "PartyBalloonAnimal", "Balloon Animal", 9999, PrefixCategory::None),
+	item(3601, "CelestialSigil", "Celestial Sigil", 20, PrefixCategory::None),
+	item(3602, "LogicGateLamp_Off", "Logic Gate Lamp (Off)", 9999, PrefixCategory::None),
+	item(3603, "LogicGate_AND", "Logic Gate (AND)", 9999, PrefixCategory::None),
+	item(3604, "LogicGate_OR", "Logic Gate (OR)", 9999, PrefixCategory::None),
+	item(3605, "LogicGate_NAND", "Logic Gate (NAND)", 9999, PrefixCategory::None),
+	item(3606, "LogicGate_NOR", "Logic Gate (NOR)", 9999, PrefixCategory::None),
+	item(3607, "LogicGate_XOR", "Logic Gate (XOR)", 9999, PrefixCategory::None),
+	item(3608, "LogicGate_NXOR", "Logic Gate (XNOR)", 9999, PrefixCategory::None),
+	item(3609, "LogicSensor_Day", "Day Sensor", 9999, PrefixCategory::None),
+	item(3610, "LogicSensor_Night", "Night Sensor", 9999, PrefixCategory::None),
+	item(3611, "LogicSensor_Above", "Player Above Sensor", 9999, PrefixCategory::None),
+	item(3612, "LogicSensor_Water", "Water Sensor", 9999, PrefixCategory::None),
+	item(3613, "LogicSensor_Lava", "Lava Sensor", 9999, PrefixCategory::None),
+	item(3614, "LogicSensor_Honey", "Honey Sensor", 9999, PrefixCategory::None),
+	item(3615, "LogicSensor_Liquid", "Liquid Sensor", 9999, PrefixCategory::None),
+	item(3616, "WireKite", "The Grand Design", 1, PrefixCategory::None),
+	item(3617, "YellowWrench", "Yellow Wrench", 1, PrefixCategory::None),
+	item(3618, "LogicGateLamp_Faulty", "Logic Gate Lamp (Faulty)", 9999, PrefixCategory::None),
+	item(3619, "ActuationAccessory", "Presserator", 1, PrefixCategory::Accessory),
+	item(3620, "MechanicalLens", "Mechanical Lens", 1, PrefixCategory::Accessory),
+	item(3621, "ActuationRod", "Actuation Rod", 1, PrefixCategory::None),
+	item(3622, "WireBulb", "Wire Bulb", 9999, PrefixCategory::None),
+	item(3623, "TealPressurePad", "Teal Pressure Pad", 9999, PrefixCategory::None),
+	item(3624, "ConveyorBeltLeft", "Conveyor Belt (Clockwise)", 9999, PrefixCategory::None),
+	item(3625, "ConveyorBeltRight", "Conveyor Belt (Counter Clockwise)", 9999, PrefixCategory::None),
+	item(3626, "MulticolorWrench", "Multicolor Wrench", 1, PrefixCategory::None),
+	item(3627, "CompanionCube", "Companion Cube", 1, PrefixCategory::None),
+	item(3628, "PixelBox", "Pixel Box", 9999, PrefixCategory::None),
+	item(3629, "PartyPresent", "Party Present", 9999, PrefixCategory::None),
+	item(3630, "SliceOfCake", "Slice of Cake", 9999, PrefixCategory::None),
+	item(3631, "CogWall", "Cog Wall", 9999, PrefixCategory::None),
+	item(3632, "WeightedPressurePlatePink", "Weighted Pressure Plate Pink", 9999, PrefixCategory::None),
+	item(3633, "WeightedPressurePlateOrange", "Weighted Pressure Plate Orange", 9999, PrefixCategory::None),
+	item(3634, "WeightedPressurePlateCyan", "Weighted Pressure Plate Cyan", 9999, PrefixCategory::None),
+	item(3635, "WeightedPressurePlatePurple", "Weighted Pressure Plate Purple", 9999, PrefixCategory::None),
+	item(3636, "ProjectilePressurePad", "Projectile Pressure Pad", 9999, PrefixCategory::None),
+	item(3637, "AnnouncementBox", "Announcement Box", 9999, PrefixCategory::None),
+	item(3638, "JunctionBox", "Junction Box", 9999, PrefixCategory::None),
+	item(3639, "DesertBasiliskBanner", "Basilisk Banner", 9999, PrefixCategory::None),
+	item(3640, "DesertDjinnBanner", "Desert Spirit Banner", 9999, PrefixCategory::None),
+	item(3641, "DesertGhoulBanner", "Ghoul Banner", 9999, PrefixCategory::None),
+	item(3642, "DesertLamiaBanner", "Lamia Banner", 9999, PrefixCategory::None),
+	item(3643, "SandElementalBanner", "Sand Elemental Banner", 9999, PrefixCategory::None),
+	item(3644, "SandsharkBanner", "Sandshark Banner", 9999, PrefixCategory::None),
+	item(3645, "SandsharkCorruptBanner", "Bone Biter Banner", 9999, PrefixCategory::None),
+	item(3646, "SandsharkCrimsonBanner", "Flesh Reaver Banner", 9999, PrefixCategory::None),
+	item(3647, "SandsharkHallowedBanner", "Crystal Thresher Banner", 9999, PrefixCategory::None),
+	item(3648, "SandSlimeBanner", "Sand Slime Banner", 9999, PrefixCategory::None),
+	item(3649, "TombCrawlerBanner", "Tomb Crawler Banner", 9999, PrefixCategory::None),
+	item(3650, "DuneSplicerBanner", "Dune Splicer Banner", 9999, PrefixCategory::None),
+	item(3651, "WalkingAntlionBanner", "Antlion Charger Banner", 9999, PrefixCategory::None),
+	item(3652, "FlyingAntlionBanner", "Antlion Swarmer Banner", 9999, PrefixCategory::None),
+	item(3653, "AntlionLarvaBanner", "Antlion Larva Banner", 9999, PrefixCategory::None),
+	item(3654, "GhoulCorruptBanner", "Vile Ghoul Banner", 9999, PrefixCategory::None),
+	item(3655, "GhoulCrimsonBanner", "Tainted Ghoul Banner", 9999, PrefixCategory::None),
+	item(3656, "GhoulHallowBanner", "Dreamer Ghoul Banner", 9999, PrefixCategory::None),
+	item(3657, "DjinnLamp", "Desert Spirit Lamp", 1, PrefixCategory::None),
+	item(3658, "DjinnsCurse", "Djinn's Curse", 1, PrefixCategory::Accessory),
+	item(3659, "SpiritFlame", "Spirit Flame", 1, PrefixCategory::Magic),
+	item(3660, "AncientBattleArmorMaterial", "Forbidden Fragment", 9999, PrefixCategory::None),
+	item(3661, "SkyFracture", "Sky Fracture", 1, PrefixCategory::Magic),
+	item(3662, "OnyxBlaster", "Onyx Blaster", 1, PrefixCategory::Ranged),
+	item(3663, "AncientHorn", "Ancient Horn", 1, PrefixCategory::None),
+	item(3664, "MagicSandDropper", "Magic Sand Dropper", 9999, PrefixCategory::None),
+	item(3665, "Fake_Chest", "Trapped Chest", 9999, PrefixCategory::None),
+	item(3666, "Fake_GoldChest", "Trapped Gold Chest", 9999, PrefixCategory::None),
+	item(3667, "Fake_ShadowChest", "Trapped Shadow Chest", 9999, PrefixCategory::None),
+	item(3668, "Fake_EbonwoodChest", "Trapped Ebonwood Chest", 9999, PrefixCategory::None),
+	item(3669, "Fake_RichMahoganyChest", "Trapped Rich Mahogany Chest", 9999, PrefixCategory::None),
+	item(3670, "Fake_PearlwoodChest", "Trapped Pearlwood Chest", 9999, PrefixCategory::None),
+	item(3671, "Fake_IvyChest", "Trapped Ivy Chest", 9999, PrefixCategory::None),
+	item(3672, "Fake_IceChest", "Trapped Ice Chest", 9999, PrefixCategory::None),
+	item(3673, "Fake_LivingWoodChest", "Trapped Living Wood Chest", 9999, PrefixCategory::None),
+	item(3674, "Fake_SkywareChest", "Trapped Skyware Chest", 9999, PrefixCategory::None),
+	item(3675, "Fake_ShadewoodChest", "Trapped Shadewood Chest", 9999, PrefixCategory::None),
+	item(3676, "Fake_WebCoveredChest", "Trapped Web Covered Chest", 9999, PrefixCategory::None),
+	item(3677, "Fake_LihzahrdChest", "Trapped Lihzahrd Chest", 9999, PrefixCategory::None),
+	item(3678, "Fake_WaterChest", "Trapped Water Chest", 9999, PrefixCategory::None),
+	item(3679, "Fake_JungleChest", "Trapped Jungle Chest", 9999, PrefixCategory::None),
+	item(3680, "Fake_CorruptionChest", "Trapped Corruption Chest", 9999, PrefixCategory::None),
+	item(3681, "Fake_CrimsonChest", "Trapped Crimson Chest", 9999, PrefixCategory::None),
+	item(3682, "Fake_HallowedChest", "Trapped Hallowed Chest", 9999, PrefixCategory::None),
+	item(3683, "Fake_FrozenChest", "Trapped Frozen Chest", 9999, PrefixCategory::None),
+	item(3684, "Fake_DynastyChest", "Trapped Dynasty Chest", 9999, PrefixCategory::None),
+	item(3685, "Fake_HoneyChest", "Trapped Honey Chest", 9999, PrefixCategory::None),
+	item(3686, "Fake_SteampunkChest", "Trapped Steampunk Chest", 9999, PrefixCategory::None),
+	item(3687, "Fake_PalmWoodChest", "Trapped Palm Wood Chest", 9999, PrefixCategory::None),
+	item(3688, "Fake_MushroomChest", "Trapped Mushroom Chest", 9999, PrefixCategory::None),
+	item(3689, "Fake_BorealWoodChest", "Trapped Boreal Wood Chest", 9999, PrefixCategory::None),
+	item(3690, "Fake_SlimeChest", "Trapped Slime Chest", 9999, PrefixCategory::None),
+	item(3691, "Fake_GreenDungeonChest", "Trapped Green Dungeon Chest", 9999, PrefixCategory::None),
+	item(3692, "Fake_PinkDungeonChest", "Trapped Pink Dungeon Chest", 9999, PrefixCategory::None),
+	item(3693, "Fake_BlueDungeonChest", "Trapped Blue Dungeon Chest", 9999, PrefixCategory::None),
+	item(3694, "Fake_BoneChest", "Trapped Bone Chest", 9999, PrefixCategory::None),
+	item(3695, "Fake_CactusChest", "Trapped Cactus Chest", 9999, PrefixCategory::None),
+	item(3696, "Fake_FleshChest", "Trapped Flesh Chest", 9999, PrefixCategory::None),
+	item(3697, "Fake_ObsidianChest", "Trapped Obsidian Chest", 9999, PrefixCategory::None),
+	item(3698, "Fake_PumpkinChest", "Trapped Pumpkin Chest", 9999, PrefixCategory::None),
+	item(3699, "Fake_SpookyChest", "Trapped Spooky Chest", 9999, PrefixCategory::None),
+	item(3700, "Fake_GlassChest", "Trapped Glass Chest", 9999, PrefixCategory::None),
+	item(3701, "Fake_MartianChest", "Trapped Martian Chest", 9999, PrefixCategory::None),
+	item(3702, "Fake_MeteoriteChest", "Trapped Meteorite Chest", 9999, PrefixCategory::None),
+	item(3703, "Fake_GraniteChest", "Trapped Granite Chest", 9999, PrefixCategory::None),
+	item(3704, "Fake_MarbleChest", "Trapped Marble Chest", 9999, PrefixCategory::None),
+	item(3705, "Fake_newchest1", "Trapped Crystal Chest", 9999, PrefixCategory::None),
+	item(3706, "Fake_newchest2", "Trapped Golden Chest", 9999, PrefixCategory::None),
+	item(3707, "FossilHelm", "Fossil Helmet", 1, PrefixCategory::None),
+	item(3708, "FossilShirt", "Fossil Plate", 1, PrefixCategory::None),
+	item(3709, "FossilPants", "Fossil Greaves", 1, PrefixCategory::None),
+	item(3710, "BoneJavelin", "Bone Javelin", 9999, PrefixCategory::None),
+	item(3711, "BoneDagger", "Bone Dagger", 9999, PrefixCategory::None),
+	item(3712, "AncientBattleArmorHat", "Forbidden Mask", 1, PrefixCategory::None),
+	item(3713, "AncientBattleArmorShirt", "Forbidden Robes", 1, PrefixCategory::None),
+	item(3714, "AncientBattleArmorPants", "Forbidden Treads", 1, PrefixCategory::None),
+	item(3715, "AncientCloth", "Ancient Cloth", 9999, PrefixCategory::None),
+	item(3716, "CorruptSandstoneWall", "Ebonsandstone Wall", 9999, PrefixCategory::None),
+	item(3717, "CrimsonSandstoneWall", "Crimsandstone Wall", 9999, PrefixCategory::None),
+	item(3718, "HallowSandstoneWall", "Pearlsandstone Wall", 9999, PrefixCategory::None),
+	item(3719, "HallowHardenedSandWall", "Hardened Pearlsand Wall", 9999, PrefixCategory::None),
+	item(3720, "DesertFossilWall", "Desert Fossil Wall", 9999, PrefixCategory::None),
+	item(3721, "MoonLordLegs", "Moon Lord Legs", 1, PrefixCategory::None),
+	item(3722, "MusicBoxSandstorm", "Music Box (Sandstorm)", 1, PrefixCategory::None),
+	item(3723, "SafemanSunHair", "Safeman's Sunny Day", 9999, PrefixCategory::None),
+	item(3724, "SafemanSunDress", "Safeman's Blanket Cape", 1, PrefixCategory::None),
+	item(3725, "SafemanWings", "Safeman's Blanket Cape Wings", 1, PrefixCategory::Accessory),
+	item(3726, "SafemanDressLeggings", "Safeman's Pink Leggings", 1, PrefixCategory::None),
+	item(3727, "TsunamiInABottle", "Tsunami in a Bottle", 1, PrefixCategory::Accessory),
+	item(3728, "DD2ElderCrystal", "Eternia Crystal", 9999, PrefixCategory::None),
+	item(3729, "DD2ElderCrystalStand", "Eternia Crystal Stand", 9999, PrefixCategory::None),
+	item(3730, "DefenderMedal", "Defender Medal", 9999, PrefixCategory::None),
+	item(3731, "DD2FlameburstTowerT1Popper", "Flameburst Rod", 1, PrefixCategory::Magic),
+	item(3732, "DD2FlameburstTowerT2Popper", "Flameburst Cane", 1, PrefixCategory::Magic),
+	item(3733, "DD2FlameburstTowerT3Popper", "Flameburst Staff", 1, PrefixCategory::Magic),
+	item(3734, "DD2BallistraTowerT1Popper", "Ballista Rod", 1, PrefixCategory::Magic),
+	item(3735, "DD2BallistraTowerT2Popper", "Ballista Cane", 1, PrefixCategory::Magic),
+	item(3736, "DD2BallistraTowerT3Popper", "Ballista Staff", 1, PrefixCategory::Magic),
+	item(3737, "DD2LightningAuraT1Popper", "Lightning Aura Rod", 1, PrefixCategory::Magic),
+	item(3738, "DD2LightningAuraT2Popper", "Lightning Aura Cane", 1, PrefixCategory::Magic),
+	item(3739, "DD2LightningAuraT3Popper", "Lightning Aura Staff", 1, PrefixCategory::Magic),
+	item(3740, "DD2ExplosiveTrapT1Popper", "Explosive Trap Rod", 1, PrefixCategory::Magic),
+	item(3741, "DD2ExplosiveTrapT2Popper", "Explosive Trap Cane", 1, PrefixCategory::Magic),
+	item(3742, "DD2ExplosiveTrapT3Popper", "Explosive Trap Staff", 1, PrefixCategory::Magic),
+	item(3743, "MonkStaffT1", "Sleepy Octopod", 1, PrefixCategory::Melee),
+	item(3744, "MonkStaffT2", "Ghastly Glaive", 1, PrefixCategory::Melee),
+	item(3745, "DD2GoblinBomberBanner", "Etherian Goblin Bomber Banner", 9999, PrefixCategory::None),
+	item(3746, "DD2GoblinBanner", "Etherian Goblin Banner", 9999, PrefixCategory::None),
+	item(3747, "DD2SkeletonBanner", "Old One's Skeleton Banner", 9999, PrefixCategory::None),
+	item(3748, "DD2DrakinBanner", "Drakin Banner", 9999, PrefixCategory::None),
+	item(3749, "DD2KoboldFlyerBanner", "Kobold Glider Banner", 9999, PrefixCategory::None),
+	item(3750, "DD2KoboldBanner", "Kobold Banner", 9999, PrefixCategory::None),
+	item(3751, "DD2WitherBeastBanner", "Wither Beast Banner", 9999, PrefixCategory::None),
+	item(3752, "DD2WyvernBanner", "Etherian Wyvern Banner", 9999, PrefixCategory::None),
+	item(3753, "DD2JavelinThrowerBanner", "Etherian Javelin Thrower Banner", 9999, PrefixCategory::None),
+	item(3754, "DD2LightningBugBanner", "Etherian Lightning Bug Banner", 9999, PrefixCategory::None),
+	item(3755, "OgreMask", "Ogre Mask", 1, PrefixCategory::None),
+	item(3756, "GoblinMask", "Goblin Mask", 1, PrefixCategory::None),
+	item(3757, "GoblinBomberCap", "Goblin Bomber Cap", 1, PrefixCategory::None),
+	item(3758, "EtherianJavelin", "Etherian Javelin", 9999, PrefixCategory::None),
+	item(3759, "KoboldDynamiteBackpack", "Kobold Dynamite Backpack", 9999, PrefixCategory::None),
+	item(3760, "BookStaff", "Tome of Infinite Wisdom", 1, PrefixCategory::Magic),
+	item(3761, "DD2PhoenixBow", "Phantom Phoenix", 1, PrefixCategory::Ranged),
+	item(3762, "DD2PetGato", "Gato Egg", 1, PrefixCategory::None),
+	item(3763, "DD2PetGhost", "Creepy Candle", 1, PrefixCategory::None),
+	item(3764, "DD2PetDragon", "Dragon Egg", 1, PrefixCategory::None),
+	item(3765, "MonkStaffT3", "Sky Dragon's Fury", 1, PrefixCategory::Melee),
+	item(3766, "DD2BetsyBow", "Aerial Bane", 1, PrefixCategory::Ranged),
+	item(3767, "DD2SquireDemonSword", "Brand of the Inferno", 1, PrefixCategory::Melee),
+	item(3768, "MonkBelt", "Monk's Belt", 1, PrefixCategory::Accessory),
+	item(3769, "DD2SquireBetsySword", "Flying Dragon", 1, PrefixCategory::Melee),
+	item(3770, "ApprenticeStaffT3", "Betsy's Wrath", 1, PrefixCategory::Magic),
+	item(3771, "SquireGreatHelm", "Squire Great Helm", 1, PrefixCategory::None),
+	item(3772, "SquirePlating", "Squire Plating", 9999, PrefixCategory::None),
+	item(3773, "SquireGreaves", "Squire Greaves", 1, PrefixCategory::None),
+	item(3774, "SquireAltHead", "Valhalla Knight's Helm", 1, PrefixCategory::None),
+	item(3775, "SquireAltShirt", "Valhalla Knight's Breastplate", 1, PrefixCategory::None),
+	item(3776, "SquireAltPants", "Valhalla Knight's Greaves", 1, PrefixCategory::None),
+	item(3777, "ApprenticeHat", "Apprentice's Hat", 1, PrefixCategory::None),
+	item(3778, "ApprenticeRobe", "Apprentice's Robe", 1, PrefixCategory::None),
+	item(3779, "ApprenticeTrousers", "Apprentice's Trousers", 1, PrefixCategory::None),
+	item(3780, "ApprenticeAltHead", "Dark Artist's Hat", 1, PrefixCategory::None),
+	item(3781, "ApprenticeAltShirt", "Dark Artist's Robes", 1, PrefixCategory::None),
+	item(3782, "ApprenticeAltPants", "Dark Artist's Leggings", 1, PrefixCategory::None),
+	item(3783, "HuntressWig", "Huntress's Wig", 1, PrefixCategory::None),
+	item(3784, "HuntressJerkin", "Huntress's Jerkin", 9999, PrefixCategory::None),
+	item(3785, "HuntressPants", "Huntress's Pants", 1, PrefixCategory::None),
+	item(3786, "HuntressAltHead", "Red Riding Hood", 1, PrefixCategory::None),
+	item(3787, "HuntressAltShirt", "Red Riding Dress", 1, PrefixCategory::None),
+	item(3788, "HuntressAltPants", "Red Riding Leggings", 1, PrefixCategory::None),
+	item(3789, "MonkBrows", "Monk's Bushy Brow Bald Cap", 9999, PrefixCategory::None),
+	item(3790, "MonkShirt", "Monk's Shirt", 1, PrefixCategory::None),
+	item(3791, "MonkPants", "Monk's Pants", 1, PrefixCategory::None),
+	item(3792, "MonkAltHead", "Shinobi Infiltrator's Helmet", 1, PrefixCategory::None),
+	item(3793, "MonkAltShirt", "Shinobi Infiltrator's Torso", 1, PrefixCategory::None),
+	item(3794, "MonkAltPants", "Shinobi Infiltrator's Pants", 1, PrefixCategory::None),
+	item(3795, "BetsyWings", "Betsy's Wings", 1, PrefixCategory::Accessory),
+	item(3796, "BossBagBetsy", "Treasure Bag (Betsy)", 9999, PrefixCategory::None),
+	item(3797, "BossBagOgre", "Treasure Bag (Ogre)", 9999, PrefixCategory::None),
+	item(3798, "BossBagDarkMage", "Treasure Bag (Dark Mage)", 9999, PrefixCategory::None),
+	item(3799, "BossMaskOgre", "Ogre Mask", 9999, PrefixCategory::None),
+	item(3800, "BossMaskBetsy", "Betsy Mask", 9999, PrefixCategory::None),
+	item(3801, "BossMaskDarkMage", "Dark Mage Mask", 9999, PrefixCategory::None),
+	item(3802, "BossTrophyBetsy", "Betsy Trophy", 9999, PrefixCategory::None),
+	item(3803, "BossTrophyOgre", "Ogre Trophy", 9999, PrefixCategory::None),
+	item(3804, "BossTrophyDarkmage", "Dark Mage Trophy", 9999, PrefixCategory::None),
+	item(3805, "MusicBoxDD2", "Music Box (Old One's Army)", 1, PrefixCategory::None),
+	item(3806, "ApprenticeScarf", "Apprentice's Scarf", 1, PrefixCategory::Accessory),
+	item(3807, "SquireShield", "Squire's Shield", 1, PrefixCategory::Accessory),
+	item(3808, "HuntressBuckler", "Huntress's Buckler", 1, PrefixCategory::Accessory),
+	item(3809, "DefendersForge", "Defender's Forge", 9999, PrefixCategory::None),
+	item(3810, "WarTable", "War Table", 9999, PrefixCategory::None),
+	item(3811, "WarTableBanner", "War Table Banner", 9999, PrefixCategory::None),
+	item(3812, "DD2EnergyCrystal", "Etherian Mana", 9999, PrefixCategory::None),
+	item(3813, "CrystalChest", "Crystal Chest", 9999, PrefixCategory::None),
+	item(3814, "GoldenChest", "Golden Chest", 9999, PrefixCategory::None),
+	item(3815, "GoldenPlatform", "Golden Platform", 9999, PrefixCategory::None),
+	item(3816, "GoldenWorkbench", "Golden Work Bench", 9999, PrefixCategory::None),
+	item(3817, "CrystalBathtub", "Crystal Bathtub", 9999, PrefixCategory::None),
+	item(3818, "CrystalBed", "Crystal Bed", 9999, PrefixCategory::None),
+	item(3819, "CrystalBookCase", "Crystal Bookcase", 9999, PrefixCategory::None),
+	item(3820, "CrystalCandelabra", "Crystal Candelabra", 9999, PrefixCategory::None),
+	item(3821, "CrystalCandle", "Crystal Candle", 9999, PrefixCategory::None),
+	item(3822, "CrystalChair", "Crystal Chair", 9999, PrefixCategory::None),
+	item(3823, "CrystalChandelier", "Crystal Chandelier", 9999, PrefixCategory::None),
+	item(3824, "CrystalDoor", "Crystal Door", 9999, PrefixCategory::None),
+	item(3825, "CrystalDresser", "Crystal Dresser", 9999, PrefixCategory::None),
+	item(3826, "CrystalLamp", "Crystal Lamp", 9999, PrefixCategory::None),
+	item(3827, "CrystalLantern", "Crystal Lantern", 9999, PrefixCategory::None),
+	item(3828, "CrystalPiano", "Crystal Piano", 9999, PrefixCategory::None),
+	item(3829, "CrystalPlatform", "Crystal Platform", 9999, PrefixCategory::None),
+	item(3830, "CrystalSink", "Crystal Sink", 9999, PrefixCategory::None),
+	item(3831, "CrystalSofaHowDoesThatEvenWork", "Crystal Sofa", 9999, PrefixCategory::None),
+	item(3832, "CrystalTable", "Crystal Table", 9999, PrefixCategory::None),
+	item(3833, "CrystalWorkbench", "Crystal Work Bench", 9999, PrefixCategory::None),
+	item(3834, "LesionBathtub", "Lesion Bathtub", 9999, PrefixCategory::None),
+	item(3835, "LesionBed", "Lesion Bed", 9999, PrefixCategory::None),
+	item(3836, "LesionBookcase", "Lesion Bookcase", 9999, PrefixCategory::None),
+	item(3837, "LesionCandelabra", "Lesion Candelabra", 9999, PrefixCategory::None),
+	item(3838, "LesionCandle", "Lesion Candle", 9999, PrefixCategory::None),
+	item(3839, "LesionChair", "Lesion Chair", 9999, PrefixCategory::None),
+	item(3840, "LesionChandelier", "Lesion Chandelier", 9999, PrefixCategory::None),
+	item(3841, "LesionChest", "Lesion Chest", 9999, PrefixCategory::None),
+	item(3842, "LesionClock", "Lesion Clock", 9999, PrefixCategory::None),
+	item(3843, "LesionDoor", "Lesion Door", 9999, PrefixCategory::None),
+	item(3844, "LesionDresser", "Lesion Dresser", 9999, PrefixCategory::None),
+	item(3845, "LesionLamp", "Lesion Lamp", 9999, PrefixCategory::None),
+	item(3846, "LesionLantern", "Lesion Lantern", 9999, PrefixCategory::None),
+	item(3847, "LesionPiano", "Lesion Piano", 9999, PrefixCategory::None),
+	item(3848, "LesionPlatform", "Lesion Platform", 9999, PrefixCategory::None),
+	item(3849, "LesionSink", "Lesion Sink", 9999, PrefixCategory::None),
+	item(3850, "LesionSofa", "Lesion Sofa", 9999, PrefixCategory::None),
+	item(3851, "LesionTable", "Lesion Table", 9999, PrefixCategory::None),
+	item(3852, "LesionWorkbench", "Lesion Work Bench", 9999, PrefixCategory::None),
+	item(3853, "SolarBathtub", "Solar Bathtub", 9999, PrefixCategory::None),
+	item(3854, "SolarBed", "Solar Bed", 9999, PrefixCategory::None),
+	item(3855, "SolarBookcase", "Solar Bookcase", 9999, PrefixCategory::None),
+	item(3856, "SolarCandelabra", "Solar Candelabra", 9999, PrefixCategory::None),
+	item(3857, "SolarCandle", "Solar Candle", 9999, PrefixCategory::None),
+	item(3858, "SolarChair", "Solar Chair", 9999, PrefixCategory::None),
+	item(3859, "SolarChandelier", "Solar Chandelier", 9999, PrefixCategory::None),
+	item(3860, "SolarChest", "Solar Chest", 9999, PrefixCategory::None),
+	item(3861, "SolarClock", "Solar Clock", 9999, PrefixCategory::None),
+	item(3862, "SolarDoor", "Solar Door", 9999, PrefixCategory::None),
+	item(3863, "SolarDresser", "Solar Dresser", 9999, PrefixCategory::None),
+	item(3864, "SolarLamp", "Solar Lamp", 9999, PrefixCategory::None),
+	item(3865, "SolarLantern", "Solar Lantern", 9999, PrefixCategory::None),
+	item(3866, "SolarPiano", "Solar Piano", 9999, PrefixCategory::None),
+	item(3867, "SolarPlatform", "Solar Platform", 9999, PrefixCategory::None),
+	item(3868, "SolarSink", "Solar Sink", 9999, PrefixCategory::None),
+	item(3869, "SolarSofa", "Solar Sofa", 9999, PrefixCategory::None),
+	item(3870, "SolarTable", "Solar Table", 9999, PrefixCategory::None),
+	item(3871, "SolarWorkbench", "Solar Work Bench", 9999, PrefixCategory::None),
+	item(3872, "VortexBathtub", "Vortex Bathtub", 9999, PrefixCategory::None),
+	item(3873, "VortexBed", "Vortex Bed", 9999, PrefixCategory::None),
+	item(3874, "VortexBookcase", "Vortex Bookcase", 9999, PrefixCategory::None),
+	item(3875, "VortexCandelabra", "Vortex Candelabra", 9999, PrefixCategory::None),
+	item(3876, "VortexCandle", "Vortex Candle", 9999, PrefixCategory::None),
+	item(3877, "VortexChair", "Vortex Chair", 9999, PrefixCategory::None),
+	item(3878, "VortexChandelier", "Vortex Chandelier", 9999, PrefixCategory::None),
+	item(3879, "VortexChest", "Vortex Chest", 9999, PrefixCategory::None),
+	item(3880, "VortexClock", "Vortex Clock", 9999, PrefixCategory::None),
+	item(3881, "VortexDoor", "Vortex Door", 9999, PrefixCategory::None),
+	item(3882, "VortexDresser", "Vortex Dresser", 9999, PrefixCategory::None),
+	item(3883, "VortexLamp", "Vortex Lamp", 9999, PrefixCategory::None),
+	item(3884, "VortexLantern", "Vortex Lantern", 9999, PrefixCategory::None),
+	item(3885, "VortexPiano", "Vortex Piano", 9999, PrefixCategory::None),
+	item(3886, "VortexPlatform", "Vortex Platform", 9999, PrefixCategory::None),
+	item(3887, "VortexSink", "Vortex Sink", 9999, PrefixCategory::None),
+	item(3888, "VortexSofa", "Vortex Sofa", 9999, PrefixCategory::None),
+	item(3889, "VortexTable", "Vortex Table", 9999, PrefixCategory::None),
+	item(3890, "VortexWorkbench", "Vortex Work Bench", 9999, PrefixCategory::None),
+	item(3891, "NebulaBathtub", "Nebula Bathtub", 9999, PrefixCategory::None),
+	item(3892, "NebulaBed", "Nebula Bed", 9999, PrefixCategory::None),
+	item(3893, "NebulaBookcase", "Nebula Bookcase", 9999, PrefixCategory::None),
+	item(3894, "NebulaCandelabra", "Nebula Candelabra", 9999, PrefixCategory::None),
+	item(3895, "NebulaCandle", "Nebula Candle", 9999, PrefixCategory::None),
+	item(3896, "NebulaChair", "Nebula Chair", 9999, PrefixCategory::None),
+	item(3897, "NebulaChandelier", "Nebula Chandelier", 9999, PrefixCategory::None),
+	item(3898, "NebulaChest", "Nebula Chest", 9999, PrefixCategory::None),
+	item(3899, "NebulaClock", "Nebula Clock", 9999, PrefixCategory::None),
+	item(3900, "NebulaDoor", "Nebula Door", 9999, PrefixCategory::None),
+	item(3901, "NebulaDresser", "Nebula Dresser", 9999, PrefixCategory::None),
+	item(3902, "NebulaLamp", "Nebula Lamp", 9999, PrefixCategory::None),
+	item(3903, "NebulaLantern", "Nebula Lantern", 9999, PrefixCategory::None),
+	item(3904, "NebulaPiano", "Nebula Piano", 9999, PrefixCategory::None),
+	item(3905, "NebulaPlatform", "Nebula Platform", 9999, PrefixCategory::None),
+	item(3906, "NebulaSink", "Nebula Sink", 9999, PrefixCategory::None),
+	item(3907, "NebulaSofa", "Nebula Sofa", 9999, PrefixCategory::None),
+	item(3908, "NebulaTable", "Nebula Table", 9999, PrefixCategory::None),
+	item(3909, "NebulaWorkbench", "Nebula Work Bench", 9999, PrefixCategory::None),
+	item(3910, "StardustBathtub", "Stardust Bathtub", 9999, PrefixCategory::None),
+	item(3911, "StardustBed", "Stardust Bed", 9999, PrefixCategory::None),
+	item(3912, "StardustBookcase", "Stardust Bookcase", 9999, PrefixCategory::None),
+	item(3913, "StardustCandelabra", "Stardust Candelabra", 9999, PrefixCategory::None),
+	item(3914, "StardustCandle", "Stardust Candle", 9999, PrefixCategory::None),
+	item(3915, "StardustChair", "Stardust Chair", 9999, PrefixCategory::None),
+	item(3916, "StardustChandelier", "Stardust Chandelier", 9999, PrefixCategory::None),
+	item(3917, "StardustChest", "Stardust Chest", 9999, PrefixCategory::None),
+	item(3918, "StardustClock", "Stardust Clock", 9999, PrefixCategory::None),
+	item(3919, "StardustDoor", "Stardust Door", 9999, PrefixCategory::None),
+	item(3920, "StardustDresser", "Stardust Dresser", 9999, PrefixCategory::None),
+	item(3921, "StardustLamp", "Stardust Lamp", 9999, PrefixCategory::None),
+	item(3922, "StardustLantern", "Stardust Lantern", 9999, PrefixCategory::None),
+	item(3923, "StardustPiano", "Stardust Piano", 9999, PrefixCategory::None),
+	item(3924, "StardustPlatform", "Stardust Platform", 9999, PrefixCategory::None),
+	item(3925, "StardustSink", "Stardust Sink", 9999, PrefixCategory::None),
+	item(3926, "StardustSofa", "Stardust Sofa", 9999, PrefixCategory::None),
+	item(3927, "StardustTable", "Stardust Table", 9999, PrefixCategory::None),
+	item(3928, "StardustWorkbench", "Stardust Work Bench", 9999, PrefixCategory::None),
+	item(3929, "SandstonePlatform", "Sandstone Platform", 9999, PrefixCategory::None),
+	item(3930, "Celeb2", "Celebration Mk2", 1, PrefixCategory::Ranged)
+];
@@ -0,0 +1,169 @@
+use std::fmt;
+
+use hashbrown::HashMap;
+
+use crate::{region::Region, structs::{Chest, NPCRoom, Sign, Tile, TileEntity, NPC}, tiles::TileGrid, wld::Wld};
+
+// Everything outside the tile grid, small enough to keep whole copies of
+#[derive(Clone, PartialEq)]
+struct Entities {
+	chests: Vec<Chest>,
+	signs: Vec<Sign>,
+	npcs: Vec<NPC>,
+	tile_entities: Vec<TileEntity>,
+	weighted_pressure_plates: Vec<(u32, u32)>,
+	npc_rooms: Vec<NPCRoom>
+}
+
+impl Entities {
+	fn take(wld: &Wld) -> Self {
+		Entities {
+			chests: wld.chests.clone(),
+			signs: wld.signs.clone(),
+			npcs: wld.npcs.clone(),
+			tile_entities: wld.tile_entities.clone(),
+			weighted_pressure_plates: wld.weighted_pressure_plates.clone(),
+			npc_rooms: wld.npc_rooms.clone()
+		}
+	}
+	
+	// Puts these into the world and keeps what was there
+	fn swap(&mut self, wld: &mut Wld) {
+		std::mem::swap(&mut self.chests, &mut wld.chests);
+		std::mem::swap(&mut self.signs, &mut wld.signs);
+		std::mem::swap(&mut self.npcs, &mut wld.npcs);
+		std::mem::swap(&mut self.tile_entities, &mut wld.tile_entities);
+		std::mem::swap(&mut self.weighted_pressure_plates, &mut wld.weighted_pressure_plates);
+		std::mem::swap(&mut self.npc_rooms, &mut wld.npc_rooms);
+	}
+}
+
+#[derive(Clone)]
+struct Transaction {
+	// The tile each cell had before its first change
+	tiles: HashMap<(u32, u32), Tile>,
+	entities: Option<Entities>
+}
+
+impl Transaction {
+	fn is_empty(&self) -> bool {
+		self.tiles.is_empty() && self.entities.is_none()
+	}
+	
+	// Restores the recorded state and turns self into the state it replaced, so applying it again redoes
+	fn swap(&mut self, wld: &mut Wld) {
+		for (&(x, y), tile) in self.tiles.iter_mut() {
+			let current = *wld.tiles.get(x, y);
+			wld.tiles.set(x, y, *tile);
+			*tile = current;
+		}
+		if let Some(entities) = self.entities.as_mut() {
+			entities.swap(wld);
+		}
+	}
+}
+
+// Undo history of a world. Tile changes are recorded by the `Wld` methods that make them, chests, signs, NPCs and
+// the other entities are compared against a copy taken by `begin`. Changing the world size clears it
+#[derive(Clone, Default)]
+pub struct Journal {
+	open: Option<Transaction>,
+	undo: Vec<Transaction>,
+	redo: Vec<Transaction>
+}
+
+impl Journal {
+	pub fn is_recording(&self) -> bool {
+		self.open.is_some()
+	}
+	pub fn can_undo(&self) -> bool {
+		!self.undo.is_empty()
+	}
+	pub fn can_redo(&self) -> bool {
+		!self.redo.is_empty()
+	}
+	pub fn clear(&mut self) {
+		*self = Journal::default();
+	}
+	
+	pub(crate) fn record(&mut self, grid: &TileGrid, x: u32, y: u32) {
+		if let Some(transaction) = self.open.as_mut() {
+			transaction.tiles.entry((x, y)).or_insert_with(|| *grid.get(x, y));
+		}
+	}
+	pub(crate) fn record_region(&mut self, grid: &TileGrid, region: Region) {
+		if self.open.is_none() { return }
+		for x in region.x..region.right() {
+			for y in region.y..region.bottom() {
+				self.record(grid, x, y);
+			}
+		}
+	}
+}
+
+// History isn't part of the world, two worlds with the same content are equal whatever was done to them
+impl PartialEq for Journal {
+	fn eq(&self, _: &Self) -> bool {
+		true
+	}
+}
+
+impl fmt::Debug for Journal {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Journal {{ recording: {}, undo: {}, redo: {} }}", self.open.is_some(), self.undo.len(), self.redo.len())
+	}
+}
+
+impl Wld {
+	// Starts recording changes, does nothing when already recording so nested edits end up in the outer transaction
+	pub fn begin(&mut self) {
+		if self.journal.open.is_none() {
+			self.journal.open = Some(Transaction { tiles: HashMap::new(), entities: Some(Entities::take(self)) });
+		}
+	}
+	
+	// Ends the transaction and makes it the next one to undo. Returns false when it didn't change anything
+	pub fn commit(&mut self) -> bool {
+		let mut transaction = match self.journal.open.take() {
+			Some(transaction) => transaction,
+			None => return false
+		};
+		if transaction.entities.as_ref().is_some_and(|entities| *entities == Entities::take(self)) {
+			transaction.entities = None;
+		}
+		transaction.tiles.retain(|&(x, y), tile| self.tiles.get(x, y) != tile);
+		if transaction.is_empty() {
+			return false;
+		}
+		self.journal.undo.push(transaction);
+		self.journal.redo.clear();
+		true
+	}
+	
+	// Reverts the last committed transaction, committing the open one first
+	pub fn undo(&mut self) -> bool {
+		self.commit();
+		match self.journal.undo.pop() {
+			Some(mut transaction) => {
+				transaction.swap(self);
+				self.journal.redo.push(transaction);
+				true
+			}
+			None => false
+		}
+	}
+	
+	pub fn redo(&mut self) -> bool {
+		if self.journal.open.is_some() {
+			return false;
+		}
+		match self.journal.redo.pop() {
+			Some(mut transaction) => {
+				transaction.swap(self);
+				self.journal.undo.push(transaction);
+				true
+			}
+			None => false
+		}
+	}
+}
@@ -0,0 +1,46 @@
+extern crate hashbrown;
+extern crate rand;
+
+mod wld;
+pub mod error;
+pub mod version;
+pub mod structs;
+pub mod tiles;
+pub mod read;
+pub mod write;
+pub mod save;
+pub mod ids;
+pub mod items;
+pub mod npcs;
+pub mod validate;
+pub mod region;
+pub mod schematic;
+pub mod transform;
+pub mod resize;
+pub mod stitch;
+pub mod journal;
+pub mod fill;
+pub mod query;
+pub mod item_index;
+pub mod loot;
+
+pub use wld::Wld;
+pub use error::{Section, WldError};
+pub use tiles::TileGrid;
+pub use read::WldFile;
+pub use save::SaveOptions;
+pub use ids::{TileId, WallId, TileInfo, WallInfo};
+pub use items::{ItemInfo, ItemCatalogue, PrefixInfo, PrefixCategory, ItemProblem};
+pub use npcs::TownNPC;
+pub use validate::Diagnostic;
+pub use region::{Region, Clipboard};
+pub use schematic::Schematic;
+pub use transform::{Transform, Rejected};
+pub use resize::Anchor;
+pub use stitch::Direction;
+pub use journal::Journal;
+pub use fill::{Layers, Connectivity};
+pub use query::{Query, QueryError};
+pub use item_index::{ItemIndex, ItemLocation, ItemTotal, Container};
+pub use loot::{LootTable, LootEntry, LootPrefix, LootMode, ChestFilter};
+pub use structs::{Tile, Block, Slope, Wall, Liquid, LiquidType, Chest, Item, Sign, NPC, TileEntity, TileEntityInfo, NPCRoom, CreativePower};
@@ -0,0 +1,183 @@
+use rand::{distributions::{Distribution, WeightedIndex}, seq::SliceRandom, Rng};
+
+use crate::{ids::TileId, items::{ItemCatalogue, ItemInfo, ItemProblem, PREFIXES}, region::Region, structs::Item, wld::Wld};
+
+const CHEST_SIZE: usize = 40;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum LootPrefix {
+	None,
+	OneOf(Vec<u8>),
+	// Any prefix the item can have according to the item catalogue
+	Random
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct LootEntry {
+	pub id: u32,
+	pub weight: u32,
+	// Both ends included, clamped to the item's max stack
+	pub count: (u16, u16),
+	pub prefix: LootPrefix
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum LootMode {
+	// Empties the chest first
+	Replace,
+	// Only uses empty slots and keeps what's there
+	Add
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct LootTable {
+	pub entries: Vec<LootEntry>,
+	// How many stacks each chest gets, both ends included. A full chest gets fewer
+	pub rolls: (usize, usize),
+	pub mode: LootMode
+}
+
+// Which chests to restock, every condition that is set has to hold
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Default)]
+pub struct ChestFilter {
+	pub tile: Option<u16>,
+	pub style: Option<u16>,
+	pub region: Option<Region>
+}
+
+// Id 0 is the empty slot and an item with a max stack of 0 can't be placed at all, so both are turned down before anything changes
+fn placeable(catalogue: &ItemCatalogue, id: u32) -> Result<&ItemInfo, ItemProblem> {
+	if id == 0 {
+		return Err(ItemProblem::UnknownItem(id));
+	}
+	let info = catalogue.get(id).ok_or(ItemProblem::UnknownItem(id))?;
+	match info.max_stack {
+		0 => Err(ItemProblem::ExceedsMaxStack { count: 1, max: 0 }),
+		_ => Ok(info)
+	}
+}
+
+impl LootEntry {
+	pub fn new(id: u32, weight: u32, count: (u16, u16)) -> Self {
+		LootEntry { id, weight, count, prefix: LootPrefix::None }
+	}
+	
+	// Fails for items missing from the catalogue since their max stack and prefixes aren't known, and for items that can't be placed
+	pub fn roll<R: Rng>(&self, catalogue: &ItemCatalogue, rng: &mut R) -> Result<Item, ItemProblem> {
+		let info = placeable(catalogue, self.id)?;
+		let low = self.count.0.clamp(1, info.max_stack);
+		let high = self.count.1.clamp(low, info.max_stack);
+		let prefix = match &self.prefix {
+			LootPrefix::None => 0,
+			LootPrefix::OneOf(prefixes) => prefixes.choose(rng).copied().unwrap_or(0),
+			LootPrefix::Random => {
+				let valid: Vec<u8> = PREFIXES.iter().filter(|p| info.prefixes.accepts(p.category)).map(|p| p.id).collect();
+				valid.choose(rng).copied().unwrap_or(0)
+			}
+		};
+		Ok(Item { id: self.id, prefix, count: rng.gen_range(low..=high) })
+	}
+}
+
+impl LootTable {
+	// Fills `items` as one chest, returns false when the table has nothing to give.
+	// Every entry is checked against the catalogue first so a chest is never left half filled
+	pub fn fill<R: Rng>(&self, items: &mut Vec<Option<Item>>, catalogue: &ItemCatalogue, rng: &mut R) -> Result<bool, ItemProblem> {
+		for entry in &self.entries {
+			placeable(catalogue, entry.id)?;
+		}
+		let weights = match WeightedIndex::new(self.entries.iter().map(|e| e.weight)) {
+			Ok(weights) => weights,
+			Err(_) => return Ok(false)
+		};
+		items.resize(CHEST_SIZE, None);
+		if self.mode == LootMode::Replace {
+			items.iter_mut().for_each(|item| *item = None);
+		}
+		
+		let (low, high) = (self.rolls.0.min(self.rolls.1), self.rolls.0.max(self.rolls.1));
+		let rolls = rng.gen_range(low..=high);
+		for slot in items.iter_mut().filter(|item| item.is_none()).take(rolls) {
+			*slot = Some(self.entries[weights.sample(rng)].roll(catalogue, rng)?);
+		}
+		Ok(true)
+	}
+}
+
+impl ChestFilter {
+	pub fn region(region: Region) -> Self {
+		ChestFilter { region: Some(region), ..ChestFilter::default() }
+	}
+	pub fn style(tile: u16, style: u16) -> Self {
+		ChestFilter { tile: Some(tile), style: Some(style), ..ChestFilter::default() }
+	}
+}
+
+impl Wld {
+	// Block id and style of the furniture chest `index` sits on, the style being which chest of the sprite sheet it is
+	pub fn chest_style(&self, index: usize) -> Option<(u16, u16)> {
+		let chest = self.chests.get(index)?;
+		if chest.x >= self.tiles.width() || chest.y >= self.tiles.height() {
+			return None;
+		}
+		let block = self.tiles.get(chest.x, chest.y).block?;
+		let width = match TileId(block.id).name() {
+			Some("Dressers") => 3,
+			_ => 2
+		};
+		Some((block.id, block.uv.map(|uv| uv.0 / (width * 18)).unwrap_or(0)))
+	}
+	
+	pub fn chests_matching(&self, filter: &ChestFilter) -> Vec<usize> {
+		(0..self.chests.len()).filter(|&i| {
+			let chest = &self.chests[i];
+			let style = self.chest_style(i);
+			filter.region.is_none_or(|region| region.contains(chest.x, chest.y))
+				&& filter.tile.is_none_or(|tile| style.is_some_and(|s| s.0 == tile))
+				&& filter.style.is_none_or(|wanted| style.is_some_and(|s| s.1 == wanted))
+		}).collect()
+	}
+	
+	// Returns how many chests were filled
+	pub fn apply_loot<R: Rng>(&mut self, table: &LootTable, filter: &ChestFilter, catalogue: &ItemCatalogue, rng: &mut R) -> Result<usize, ItemProblem> {
+		let mut filled = 0;
+		for i in self.chests_matching(filter) {
+			if table.fill(&mut self.chests[i].items, catalogue, rng)? {
+				filled += 1;
+			}
+		}
+		Ok(filled)
+	}
+	
+	// Every stored item in chests and item holding tile entities
+	fn stored_items_mut(&mut self) -> impl Iterator<Item = &mut Option<Item>> {
+		let chests = self.chests.iter_mut().flat_map(|chest| chest.items.iter_mut());
+		let entities = self.tile_entities.iter_mut().flat_map(|entity| entity.info.slots_mut().iter_mut());
+		chests.chain(entities)
+	}
+	
+	// Turns every stack of `from` into `to`, keeping the count up to `to`'s max stack and the prefix when `to` can have it.
+	// Returns how many stacks changed, or an error without changing anything when `to` isn't in the catalogue or can't be placed
+	pub fn replace_item(&mut self, from: u32, to: u32, catalogue: &ItemCatalogue) -> Result<usize, ItemProblem> {
+		let info = placeable(catalogue, to)?;
+		let mut replaced = 0;
+		for item in self.stored_items_mut().flatten().filter(|item| item.id == from) {
+			item.id = to;
+			item.count = item.count.min(info.max_stack);
+			if !item.prefix_info().is_some_and(|p| info.prefixes.accepts(p.category)) {
+				item.prefix = 0;
+			}
+			replaced += 1;
+		}
+		Ok(replaced)
+	}
+	
+	pub fn remove_item(&mut self, id: u32) -> usize {
+		let mut removed = 0;
+		for slot in self.stored_items_mut().filter(|slot| slot.is_some_and(|item| item.id == id)) {
+			*slot = None;
+			removed += 1;
+		}
+		removed
+	}
+}
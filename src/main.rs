@@ -1,22 +1,14 @@
 
 
-use std::rc::Rc;
-
 use glium::{glutin::{dpi::{LogicalSize, PhysicalPosition, PhysicalSize}, event::{ElementState, Event, MouseButton, MouseScrollDelta, VirtualKeyCode, WindowEvent}, event_loop::{ControlFlow, EventLoop}, window::WindowBuilder, ContextBuilder}, index::PrimitiveType, texture::{MipmapsOption, RawImage2d, SrgbTexture2d, UncompressedUintFormat, UnsignedTexture2d}, uniform, uniforms::{MagnifySamplerFilter, MinifySamplerFilter, SamplerWrapFunction}, vertex::Attribute, Display, DrawParameters, IndexBuffer, Program, Surface, VertexBuffer};
 
-extern crate hashbrown;
 extern crate rand;
 extern crate glium;
+extern crate image;
+extern crate wld_edit;
 //use rand::Rng;
 
-use hashbrown::HashMap;
-use structs::{LiquidType, Slope, Tile};
-use wld::Wld;
-
-mod wld;
-mod structs;
-mod read;
-mod write;
+use wld_edit::{LiquidType, Slope, Tile, TileId, Wld};
 
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -55,61 +47,59 @@ fn load_texture(display: &Display, path: &str) -> SrgbTexture2d {
 }
 
 
-impl Tile {
-	pub fn encode(&self) -> (u32, u32, u32) {
-		let (block_exists, block_id, block_color, (u, v), inactive, slope) = match self.block {
-			Some(block) => (
-				1,
-				block.id as u32,
-				block.color.unwrap_or(0) as u32,
-				block.uv.unwrap_or((0, 0)),
-				u32::from(block.inactive),
-				match block.slope {
-					Slope::Full => 0,
-					Slope::Half => 1,
-					Slope::LowerLeft => 2,
-					Slope::LowerRight => 3,
-					Slope::UpperLeft => 4,
-					Slope::UpperRight => 5
-				}
-			),
-			None => (0, 0, 0, (0, 0), 0, 0)
-		};
-		
-		let (wall_exists, wall_id, wall_color) = match self.wall {
-			Some(wall) => (1, wall.id as u32, wall.color.unwrap_or(0) as u32),
-			None => (0, 0, 0)
-		};
-		
-		let (liquid_id, liquid_amount) = match self.liquid {
-			Some(liquid) => (match liquid.kind {
-				LiquidType::Water => 1,
-				LiquidType::Lava => 2,
-				LiquidType::Honey => 3,
-				LiquidType::Shimmer => 4
-			}, liquid.amount as u32),
-			None => (0, 0)
-		};
+fn encode_tile(tile: &Tile) -> (u32, u32, u32) {
+	let (block_exists, block_id, block_color, (u, v), inactive, slope) = match tile.block {
+		Some(block) => (
+			1,
+			block.id as u32,
+			block.color.unwrap_or(0) as u32,
+			block.uv.unwrap_or((0, 0)),
+			u32::from(block.inactive),
+			match block.slope {
+				Slope::Full => 0,
+				Slope::Half => 1,
+				Slope::LowerLeft => 2,
+				Slope::LowerRight => 3,
+				Slope::UpperLeft => 4,
+				Slope::UpperRight => 5
+			}
+		),
+		None => (0, 0, 0, (0, 0), 0, 0)
+	};
+	
+	let (wall_exists, wall_id, wall_color) = match tile.wall {
+		Some(wall) => (1, wall.id as u32, wall.color.unwrap_or(0) as u32),
+		None => (0, 0, 0)
+	};
+	
+	let (liquid_id, liquid_amount) = match tile.liquid {
+		Some(liquid) => (match liquid.kind {
+			LiquidType::Water => 1,
+			LiquidType::Lava => 2,
+			LiquidType::Honey => 3,
+			LiquidType::Shimmer => 4
+		}, liquid.amount as u32),
+		None => (0, 0)
+	};
+	
+	(
+		block_exists
+	  | wall_exists << 1
+	  | liquid_id << 2
+	  | slope << 5
+	  | inactive << 8
+	  | u32::from(tile.red_wire) << 9
+	  | u32::from(tile.green_wire) << 10
+	  | u32::from(tile.blue_wire) << 11
+	  | u32::from(tile.yellow_wire) << 12
+	  | u32::from(tile.actuator) << 13
+	  | (block_color & 0b11111) << 14
+	  | (wall_color & 0b11111) << 19
+	  | liquid_amount << 24,
 		
-		(
-			block_exists
-		  | wall_exists << 1
-		  | liquid_id << 2
-		  | slope << 5
-		  | inactive << 8
-		  | u32::from(self.red_wire) << 9
-		  | u32::from(self.green_wire) << 10
-		  | u32::from(self.blue_wire) << 11
-		  | u32::from(self.yellow_wire) << 12
-		  | u32::from(self.actuator) << 13
-		  | (block_color & 0b11111) << 14
-		  | (wall_color & 0b11111) << 19
-		  | liquid_amount << 24,
-			
-			block_id | wall_id << 16,
-			(u as u32 / 2) | (v as u32 / 2) << 16
-		)
-	}
+		block_id | wall_id << 16,
+		(u as u32 / 2) | (v as u32 / 2) << 16
+	)
 }
 
 
@@ -128,22 +118,19 @@ fn main() {
 	let default_vertex_buffer = VertexBuffer::new(&display, &DEFAULT_VERTICES).unwrap();
 	let default_index_buffer = IndexBuffer::new(&display, PrimitiveType::TrianglesList, &DEFAULT_INDICES).unwrap();
 	
-	let block_texture = load_texture(&display, &format!("tiles/21.png"));
+	let chest = TileId::from_name("Chest").unwrap();
+	let block_texture = load_texture(&display, &format!("tiles/{}.png", chest.0));
 	
 	
 	let w = Wld::read("C:\\Users\\benap\\OneDrive\\Documents\\My Games\\Terraria\\Worlds\\no.wld").unwrap();
 	
 	
-	let mut encoded_tile_set: HashMap<Rc<Tile>, (u32, u32, u32)> = HashMap::new();
-	for tile in &w.tile_set {
-		encoded_tile_set.insert(Rc::clone(&tile), tile.encode());
-	}
+	let encoded_palette: Vec<(u32, u32, u32)> = w.tiles.palette().iter().map(encode_tile).collect();
 	
-	let mut encoded_tile_data = vec![];
-	encoded_tile_data.reserve((w.width * w.height * 3) as usize);
+	let mut encoded_tile_data = Vec::with_capacity((w.width * w.height * 3) as usize);
 	for y in 0..w.height {
 		for x in 0..w.width {
-			let encoded = encoded_tile_set.get(&w.tiles[(x * w.height + y) as usize]).unwrap();
+			let encoded = encoded_palette[w.tiles.index(x, y) as usize];
 			encoded_tile_data.push(encoded.0);
 			encoded_tile_data.push(encoded.1);
 			encoded_tile_data.push(encoded.2);
@@ -175,12 +162,12 @@ fn main() {
 							ElementState::Released => false
 						};
 						match code {
-							VirtualKeyCode::Up => if state { z /= 1.25 }
-							VirtualKeyCode::Down => if state { z *= 1.25 }
-							VirtualKeyCode::W => if state { y += z * 0.05 }
-							VirtualKeyCode::S => if state { y -= z * 0.05 }
-							VirtualKeyCode::A => if state { x -= z * 0.05 }
-							VirtualKeyCode::D => if state { x += z * 0.05 }
+							VirtualKeyCode::Up if state => z /= 1.25,
+							VirtualKeyCode::Down if state => z *= 1.25,
+							VirtualKeyCode::W if state => y += z * 0.05,
+							VirtualKeyCode::S if state => y -= z * 0.05,
+							VirtualKeyCode::A if state => x -= z * 0.05,
+							VirtualKeyCode::D if state => x += z * 0.05,
 							
 							_ => ()
 						}
@@ -191,13 +178,11 @@ fn main() {
 						
 					}
 				}
-				WindowEvent::MouseInput { state, button, .. } => {
-					if let MouseButton::Left = button {
-						mouse_down = match state {
-							ElementState::Pressed => true,
-							ElementState::Released => false
-						};
-					}
+				WindowEvent::MouseInput { state, button: MouseButton::Left, .. } => {
+					mouse_down = match state {
+						ElementState::Pressed => true,
+						ElementState::Released => false
+					};
 				}
 				WindowEvent::CursorMoved { position, .. } => {
 					if mouse_down {
@@ -211,7 +196,7 @@ fn main() {
 				}
 				WindowEvent::MouseWheel { delta, .. } => {
 					let distance = match delta {
-						MouseScrollDelta::LineDelta(_, dy) => dy as f32,
+						MouseScrollDelta::LineDelta(_, dy) => dy,
 						MouseScrollDelta::PixelDelta(PhysicalPosition { y, .. }) => y as f32 / 20.0
 					};
 					
@@ -246,6 +231,7 @@ fn main() {
 					camera_position: (x, y),
 					zoom: z,
 					wld_size: (w.width as f32, w.height as f32),
+					textured_block: chest.0 as u32,
 					tile_data_buffer: tile_data_buffer.sampled().magnify_filter(MagnifySamplerFilter::Nearest).minify_filter(MinifySamplerFilter::Linear).wrap_function(SamplerWrapFunction::Repeat),
 					block_texture: block_texture.sampled().magnify_filter(MagnifySamplerFilter::Nearest).minify_filter(MinifySamplerFilter::Linear).wrap_function(SamplerWrapFunction::Repeat)
 				};
@@ -0,0 +1,281 @@
+use std::fmt;
+
+use crate::{structs::{NPC, NPCRoom}, wld::Wld};
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum TownNPC {
+	Merchant,
+	Nurse,
+	ArmsDealer,
+	Dryad,
+	Guide,
+	OldMan,
+	Demolitionist,
+	Clothier,
+	GoblinTinkerer,
+	Wizard,
+	Mechanic,
+	SantaClaus,
+	Truffle,
+	Steampunker,
+	DyeTrader,
+	PartyGirl,
+	Cyborg,
+	Painter,
+	WitchDoctor,
+	Pirate,
+	Stylist,
+	TravellingMerchant,
+	Angler,
+	TaxCollector,
+	SkeletonMerchant,
+	Tavernkeep,
+	Golfer,
+	Zoologist,
+	Cat,
+	Dog,
+	Bunny,
+	Princess,
+	BlueSlime,
+	GreenSlime,
+	OldSlime,
+	PurpleSlime,
+	RainbowSlime,
+	RedSlime,
+	YellowSlime,
+	CopperSlime
+}
+
+impl TownNPC {
+	pub const ALL: [TownNPC; 40] = [
+		TownNPC::Merchant, TownNPC::Nurse, TownNPC::ArmsDealer, TownNPC::Dryad, TownNPC::Guide, TownNPC::OldMan, TownNPC::Demolitionist, TownNPC::Clothier,
+		TownNPC::GoblinTinkerer, TownNPC::Wizard, TownNPC::Mechanic, TownNPC::SantaClaus, TownNPC::Truffle, TownNPC::Steampunker, TownNPC::DyeTrader, TownNPC::PartyGirl,
+		TownNPC::Cyborg, TownNPC::Painter, TownNPC::WitchDoctor, TownNPC::Pirate, TownNPC::Stylist, TownNPC::TravellingMerchant, TownNPC::Angler, TownNPC::TaxCollector,
+		TownNPC::SkeletonMerchant, TownNPC::Tavernkeep, TownNPC::Golfer, TownNPC::Zoologist, TownNPC::Cat, TownNPC::Dog, TownNPC::Bunny, TownNPC::Princess,
+		TownNPC::BlueSlime, TownNPC::GreenSlime, TownNPC::OldSlime, TownNPC::PurpleSlime, TownNPC::RainbowSlime, TownNPC::RedSlime, TownNPC::YellowSlime, TownNPC::CopperSlime
+	];
+	
+	// NPC type id as stored in the world file
+	pub fn id(self) -> u32 {
+		match self {
+			TownNPC::Merchant => 17,
+			TownNPC::Nurse => 18,
+			TownNPC::ArmsDealer => 19,
+			TownNPC::Dryad => 20,
+			TownNPC::Guide => 22,
+			TownNPC::OldMan => 37,
+			TownNPC::Demolitionist => 38,
+			TownNPC::Clothier => 54,
+			TownNPC::GoblinTinkerer => 107,
+			TownNPC::Wizard => 108,
+			TownNPC::Mechanic => 124,
+			TownNPC::SantaClaus => 142,
+			TownNPC::Truffle => 160,
+			TownNPC::Steampunker => 178,
+			TownNPC::DyeTrader => 207,
+			TownNPC::PartyGirl => 208,
+			TownNPC::Cyborg => 209,
+			TownNPC::Painter => 227,
+			TownNPC::WitchDoctor => 228,
+			TownNPC::Pirate => 229,
+			TownNPC::Stylist => 353,
+			TownNPC::TravellingMerchant => 368,
+			TownNPC::Angler => 369,
+			TownNPC::TaxCollector => 441,
+			TownNPC::SkeletonMerchant => 453,
+			TownNPC::Tavernkeep => 550,
+			TownNPC::Golfer => 588,
+			TownNPC::Zoologist => 633,
+			TownNPC::Cat => 637,
+			TownNPC::Dog => 638,
+			TownNPC::Bunny => 656,
+			TownNPC::Princess => 663,
+			TownNPC::BlueSlime => 670,
+			TownNPC::GreenSlime => 678,
+			TownNPC::OldSlime => 679,
+			TownNPC::PurpleSlime => 680,
+			TownNPC::RainbowSlime => 681,
+			TownNPC::RedSlime => 682,
+			TownNPC::YellowSlime => 683,
+			TownNPC::CopperSlime => 684
+		}
+	}
+	pub fn from_id(id: u32) -> Option<TownNPC> {
+		TownNPC::ALL.iter().copied().find(|kind| kind.id() == id)
+	}
+	pub fn from_name(name: &str) -> Option<TownNPC> {
+		TownNPC::ALL.iter().copied().find(|kind| kind.name().eq_ignore_ascii_case(name))
+	}
+	
+	pub fn name(self) -> &'static str {
+		match self {
+			TownNPC::Merchant => "Merchant",
+			TownNPC::Nurse => "Nurse",
+			TownNPC::ArmsDealer => "Arms Dealer",
+			TownNPC::Dryad => "Dryad",
+			TownNPC::Guide => "Guide",
+			TownNPC::OldMan => "Old Man",
+			TownNPC::Demolitionist => "Demolitionist",
+			TownNPC::Clothier => "Clothier",
+			TownNPC::GoblinTinkerer => "Goblin Tinkerer",
+			TownNPC::Wizard => "Wizard",
+			TownNPC::Mechanic => "Mechanic",
+			TownNPC::SantaClaus => "Santa Claus",
+			TownNPC::Truffle => "Truffle",
+			TownNPC::Steampunker => "Steampunker",
+			TownNPC::DyeTrader => "Dye Trader",
+			TownNPC::PartyGirl => "Party Girl",
+			TownNPC::Cyborg => "Cyborg",
+			TownNPC::Painter => "Painter",
+			TownNPC::WitchDoctor => "Witch Doctor",
+			TownNPC::Pirate => "Pirate",
+			TownNPC::Stylist => "Stylist",
+			TownNPC::TravellingMerchant => "Travelling Merchant",
+			TownNPC::Angler => "Angler",
+			TownNPC::TaxCollector => "Tax Collector",
+			TownNPC::SkeletonMerchant => "Skeleton Merchant",
+			TownNPC::Tavernkeep => "Tavernkeep",
+			TownNPC::Golfer => "Golfer",
+			TownNPC::Zoologist => "Zoologist",
+			TownNPC::Cat => "Town Cat",
+			TownNPC::Dog => "Town Dog",
+			TownNPC::Bunny => "Town Bunny",
+			TownNPC::Princess => "Princess",
+			TownNPC::BlueSlime => "Nerdy Slime",
+			TownNPC::GreenSlime => "Cool Slime",
+			TownNPC::OldSlime => "Elder Slime",
+			TownNPC::PurpleSlime => "Squire Slime",
+			TownNPC::RainbowSlime => "Diva Slime",
+			TownNPC::RedSlime => "Surly Slime",
+			TownNPC::YellowSlime => "Mystic Slime",
+			TownNPC::CopperSlime => "Clumsy Slime"
+		}
+	}
+	// Number of valid `variation_index` values, town pets come in several breeds and most others have a shimmered look
+	pub fn variations(self) -> u32 {
+		match self {
+			TownNPC::Cat | TownNPC::Dog | TownNPC::Bunny => 6,
+			TownNPC::OldMan | TownNPC::SkeletonMerchant | TownNPC::TravellingMerchant | TownNPC::Princess => 1,
+			TownNPC::BlueSlime | TownNPC::GreenSlime | TownNPC::OldSlime | TownNPC::PurpleSlime | TownNPC::RainbowSlime | TownNPC::RedSlime | TownNPC::YellowSlime | TownNPC::CopperSlime => 1,
+			_ => 2
+		}
+	}
+	pub fn can_shimmer(self) -> bool {
+		self.variations() == 2
+	}
+	// The old man, the skeleton merchant and the travelling merchant never move into a house
+	pub fn can_have_home(self) -> bool {
+		!matches!(self, TownNPC::OldMan | TownNPC::SkeletonMerchant | TownNPC::TravellingMerchant)
+	}
+}
+
+impl fmt::Display for TownNPC {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.name())
+	}
+}
+
+impl NPC {
+	pub fn kind(&self) -> Option<TownNPC> {
+		match self.is_pillar {
+			true => None,
+			false => TownNPC::from_id(self.id)
+		}
+	}
+}
+
+impl Wld {
+	pub fn town_npcs(&self) -> Vec<(TownNPC, &NPC)> {
+		self.npcs.iter().filter_map(|npc| npc.kind().map(|kind| (kind, npc))).collect()
+	}
+	pub fn town_npc(&self, kind: TownNPC) -> Option<&NPC> {
+		self.npcs.iter().find(|npc| npc.kind() == Some(kind))
+	}
+	pub fn town_npc_mut(&mut self, kind: TownNPC) -> Option<&mut NPC> {
+		self.npcs.iter_mut().find(|npc| npc.kind() == Some(kind))
+	}
+	
+	// Adds the npc, or moves it if it already lives here, keeping `npc_rooms` in sync. `home` is a tile position, None leaves it homeless at spawn.
+	// `name` is only used for a new npc, the game picks one at random from a list for each kind so there is no default
+	pub fn add_town_npc(&mut self, kind: TownNPC, name: &str, home: Option<(u32, u32)>) -> &mut NPC {
+		let home = home.filter(|_| kind.can_have_home());
+		let (x, y) = home.unwrap_or((self.spawn_x, self.spawn_y));
+		
+		self.npc_rooms.retain(|room| room.id != kind.id());
+		if let Some((x, y)) = home {
+			self.npc_rooms.push(NPCRoom { id: kind.id(), x, y });
+		}
+		
+		let i = match self.npcs.iter().position(|npc| npc.kind() == Some(kind)) {
+			Some(i) => i,
+			None => {
+				// Pillars are written in their own list after the town npcs, so new npcs go before them
+				let i = self.npcs.iter().position(|npc| npc.is_pillar).unwrap_or(self.npcs.len());
+				self.npcs.insert(i, NPC {
+					id: kind.id(),
+					name: name.to_string(),
+					x: 0.0,
+					y: 0.0,
+					homeless: true,
+					home_x: 0,
+					home_y: 0,
+					variation_index: 0,
+					shimmered: false,
+					is_pillar: false
+				});
+				i
+			}
+		};
+		
+		let npc = &mut self.npcs[i];
+		// In f32 since the pixel position of a tile near u32::MAX doesn't fit in a u32
+		npc.x = x as f32 * 16.0;
+		npc.y = y.saturating_sub(3) as f32 * 16.0;
+		npc.homeless = home.is_none();
+		npc.home_x = x;
+		npc.home_y = y;
+		npc
+	}
+	
+	// Makes the npc homeless, returns false if it isn't in the world
+	pub fn evict(&mut self, kind: TownNPC) -> bool {
+		self.npc_rooms.retain(|room| room.id != kind.id());
+		match self.town_npc_mut(kind) {
+			Some(npc) => {
+				npc.homeless = true;
+				true
+			}
+			None => false
+		}
+	}
+	
+	// Removes the npc and its room assignment, returns false if it wasn't in the world
+	pub fn remove_town_npc(&mut self, kind: TownNPC) -> bool {
+		self.npc_rooms.retain(|room| room.id != kind.id());
+		let len = self.npcs.len();
+		self.npcs.retain(|npc| npc.kind() != Some(kind));
+		self.npcs.len() != len
+	}
+	
+	pub fn rename(&mut self, kind: TownNPC, name: &str) -> bool {
+		match self.town_npc_mut(kind) {
+			Some(npc) => {
+				npc.name = name.to_string();
+				true
+			}
+			None => false
+		}
+	}
+	
+	// Shimmered npcs use the second variation
+	pub fn set_shimmered(&mut self, kind: TownNPC, shimmered: bool) -> bool {
+		match self.town_npc_mut(kind) {
+			Some(npc) if kind.can_shimmer() => {
+				npc.shimmered = shimmered;
+				npc.variation_index = shimmered as u32;
+				true
+			}
+			_ => false
+		}
+	}
+}
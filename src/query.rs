@@ -0,0 +1,484 @@
+use std::{fmt, ops::{BitAnd, BitOr, Not}, str::FromStr};
+
+use crate::{ids::{TileId, WallId}, region::Region, structs::{LiquidType, Slope, Tile}, wld::Wld};
+
+// Numbers a query can compare. Slopes and liquid kinds are compared by their position in `Slope` and `LiquidType`
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Field {
+	X,
+	Y,
+	BlockId,
+	BlockColor,
+	BlockU,
+	BlockV,
+	BlockSlope,
+	WallId,
+	WallColor,
+	LiquidKind,
+	LiquidAmount
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Flag {
+	Block,
+	Wall,
+	Liquid,
+	BlockColor,
+	WallColor,
+	Inactive,
+	Actuator,
+	RedWire,
+	GreenWire,
+	BlueWire,
+	YellowWire,
+	AnyWire
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Op {
+	Eq,
+	Ne,
+	Lt,
+	Le,
+	Gt,
+	Ge
+}
+
+// A condition on a tile and its position, built with `&`, `|` and `!` or parsed from text like
+// `block.id == Containers && block.color.is_some()`, `actuator && !block`, `liquid.kind == lava && y < 500` or `wire`
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum Query {
+	Flag(Flag),
+	// Fields the tile doesn't have never match, so `block.id != 5` is false for air
+	Compare(Field, Op, i64),
+	Not(Box<Query>),
+	And(Box<Query>, Box<Query>),
+	Or(Box<Query>, Box<Query>)
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum QueryError {
+	UnexpectedEnd,
+	UnexpectedToken { offset: usize, token: String },
+	UnknownField { offset: usize, name: String },
+	UnknownValue { offset: usize, field: Field, value: String }
+}
+
+const SLOPES: [Slope; 6] = [Slope::Full, Slope::Half, Slope::LowerLeft, Slope::LowerRight, Slope::UpperLeft, Slope::UpperRight];
+const LIQUIDS: [LiquidType; 4] = [LiquidType::Water, LiquidType::Lava, LiquidType::Honey, LiquidType::Shimmer];
+
+impl Field {
+	pub fn from_name(name: &str) -> Option<Field> {
+		Some(match name {
+			"x" => Field::X,
+			"y" => Field::Y,
+			"block.id" => Field::BlockId,
+			"block.color" => Field::BlockColor,
+			"block.u" => Field::BlockU,
+			"block.v" => Field::BlockV,
+			"block.slope" => Field::BlockSlope,
+			"wall.id" => Field::WallId,
+			"wall.color" => Field::WallColor,
+			"liquid.kind" => Field::LiquidKind,
+			"liquid.amount" => Field::LiquidAmount,
+			_ => return None
+		})
+	}
+	
+	pub fn value(self, tile: &Tile, x: u32, y: u32) -> Option<i64> {
+		match self {
+			Field::X => Some(x as i64),
+			Field::Y => Some(y as i64),
+			Field::BlockId => tile.block.map(|b| b.id as i64),
+			Field::BlockColor => tile.block.and_then(|b| b.color).map(|c| c as i64),
+			Field::BlockU => tile.block.and_then(|b| b.uv).map(|uv| uv.0 as i64),
+			Field::BlockV => tile.block.and_then(|b| b.uv).map(|uv| uv.1 as i64),
+			Field::BlockSlope => tile.block.and_then(|b| SLOPES.iter().position(|&s| s == b.slope)).map(|i| i as i64),
+			Field::WallId => tile.wall.map(|w| w.id as i64),
+			Field::WallColor => tile.wall.and_then(|w| w.color).map(|c| c as i64),
+			Field::LiquidKind => tile.liquid.and_then(|l| LIQUIDS.iter().position(|&k| k == l.kind)).map(|i| i as i64),
+			Field::LiquidAmount => tile.liquid.map(|l| l.amount as i64)
+		}
+	}
+	
+	// Names that can stand in for a number, like `Containers` for a block id or `lava` for a liquid kind
+	pub fn value_by_name(self, name: &str) -> Option<i64> {
+		let index = |names: &[&str]| names.iter().position(|n| n.eq_ignore_ascii_case(name)).map(|i| i as i64);
+		match self {
+			Field::BlockId => TileId::from_name(name).map(|id| id.0 as i64),
+			Field::WallId => WallId::from_name(name).map(|id| id.0 as i64),
+			Field::BlockSlope => index(&["full", "half", "lower_left", "lower_right", "upper_left", "upper_right"]),
+			Field::LiquidKind => index(&["water", "lava", "honey", "shimmer"]),
+			_ => None
+		}
+	}
+	
+	fn depends_on_position(self) -> bool {
+		self == Field::X || self == Field::Y
+	}
+}
+
+impl Flag {
+	pub fn from_name(name: &str) -> Option<Flag> {
+		Some(match name {
+			"block" => Flag::Block,
+			"wall" => Flag::Wall,
+			"liquid" => Flag::Liquid,
+			"block.color" => Flag::BlockColor,
+			"wall.color" => Flag::WallColor,
+			"inactive" | "block.inactive" => Flag::Inactive,
+			"actuator" => Flag::Actuator,
+			"red_wire" => Flag::RedWire,
+			"green_wire" => Flag::GreenWire,
+			"blue_wire" => Flag::BlueWire,
+			"yellow_wire" => Flag::YellowWire,
+			"wire" => Flag::AnyWire,
+			_ => return None
+		})
+	}
+	
+	pub fn matches(self, tile: &Tile) -> bool {
+		match self {
+			Flag::Block => tile.block.is_some(),
+			Flag::Wall => tile.wall.is_some(),
+			Flag::Liquid => tile.liquid.is_some(),
+			Flag::BlockColor => tile.block.is_some_and(|b| b.color.is_some()),
+			Flag::WallColor => tile.wall.is_some_and(|w| w.color.is_some()),
+			Flag::Inactive => tile.block.is_some_and(|b| b.inactive),
+			Flag::Actuator => tile.actuator,
+			Flag::RedWire => tile.red_wire,
+			Flag::GreenWire => tile.green_wire,
+			Flag::BlueWire => tile.blue_wire,
+			Flag::YellowWire => tile.yellow_wire,
+			Flag::AnyWire => tile.red_wire || tile.green_wire || tile.blue_wire || tile.yellow_wire
+		}
+	}
+}
+
+impl Op {
+	pub fn compare(self, a: i64, b: i64) -> bool {
+		match self {
+			Op::Eq => a == b,
+			Op::Ne => a != b,
+			Op::Lt => a < b,
+			Op::Le => a <= b,
+			Op::Gt => a > b,
+			Op::Ge => a >= b
+		}
+	}
+}
+
+impl Query {
+	pub fn parse(text: &str) -> Result<Query, QueryError> {
+		let tokens = tokenize(text)?;
+		let mut parser = Parser { tokens, position: 0 };
+		let query = parser.or()?;
+		match parser.tokens.get(parser.position) {
+			Some(token) => Err(token.unexpected()),
+			None => Ok(query)
+		}
+	}
+	
+	pub fn compare(field: Field, op: Op, value: i64) -> Query {
+		Query::Compare(field, op, value)
+	}
+	
+	pub fn matches(&self, tile: &Tile, x: u32, y: u32) -> bool {
+		match self {
+			Query::Flag(flag) => flag.matches(tile),
+			Query::Compare(field, op, value) => field.value(tile, x, y).is_some_and(|v| op.compare(v, *value)),
+			Query::Not(query) => !query.matches(tile, x, y),
+			Query::And(a, b) => a.matches(tile, x, y) && b.matches(tile, x, y),
+			Query::Or(a, b) => a.matches(tile, x, y) || b.matches(tile, x, y)
+		}
+	}
+	
+	// Whether the answer can change between two cells holding the same tile
+	pub fn depends_on_position(&self) -> bool {
+		match self {
+			Query::Flag(_) => false,
+			Query::Compare(field, ..) => field.depends_on_position(),
+			Query::Not(query) => query.depends_on_position(),
+			Query::And(a, b) | Query::Or(a, b) => a.depends_on_position() || b.depends_on_position()
+		}
+	}
+}
+
+impl From<Flag> for Query {
+	fn from(flag: Flag) -> Self {
+		Query::Flag(flag)
+	}
+}
+
+impl BitAnd for Query {
+	type Output = Query;
+	
+	fn bitand(self, other: Query) -> Query {
+		Query::And(Box::new(self), Box::new(other))
+	}
+}
+
+impl BitOr for Query {
+	type Output = Query;
+	
+	fn bitor(self, other: Query) -> Query {
+		Query::Or(Box::new(self), Box::new(other))
+	}
+}
+
+impl Not for Query {
+	type Output = Query;
+	
+	fn not(self) -> Query {
+		Query::Not(Box::new(self))
+	}
+}
+
+impl FromStr for Query {
+	type Err = QueryError;
+	
+	fn from_str(text: &str) -> Result<Query, QueryError> {
+		Query::parse(text)
+	}
+}
+
+impl fmt::Display for QueryError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			QueryError::UnexpectedEnd => write!(f, "query ends unexpectedly"),
+			QueryError::UnexpectedToken { offset, token } => write!(f, "unexpected \"{}\" at {}", token, offset),
+			QueryError::UnknownField { offset, name } => write!(f, "unknown field \"{}\" at {}", name, offset),
+			QueryError::UnknownValue { offset, field, value } => write!(f, "\"{}\" at {} is not a value of {:?}", value, offset, field)
+		}
+	}
+}
+
+impl std::error::Error for QueryError {}
+
+
+
+#[derive(Debug, PartialEq, Clone)]
+enum Kind {
+	Name(String),
+	Number(i64),
+	Text(String),
+	Op(Op),
+	And,
+	Or,
+	Not,
+	Open,
+	Close
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+	kind: Kind,
+	offset: usize,
+	text: String
+}
+
+impl Token {
+	fn unexpected(&self) -> QueryError {
+		QueryError::UnexpectedToken { offset: self.offset, token: self.text.clone() }
+	}
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, QueryError> {
+	let chars: Vec<(usize, char)> = text.char_indices().collect();
+	let mut tokens = vec![];
+	let mut i = 0;
+	while i < chars.len() {
+		let (offset, c) = chars[i];
+		if c.is_whitespace() {
+			i += 1;
+			continue;
+		}
+		let next = chars.get(i + 1).map(|&(_, c)| c);
+		let end = |i: usize| chars.get(i).map(|&(o, _)| o).unwrap_or(text.len());
+		
+		let (kind, length) = match (c, next) {
+			('&', Some('&')) => (Kind::And, 2),
+			('|', Some('|')) => (Kind::Or, 2),
+			('=', Some('=')) => (Kind::Op(Op::Eq), 2),
+			('!', Some('=')) => (Kind::Op(Op::Ne), 2),
+			('<', Some('=')) => (Kind::Op(Op::Le), 2),
+			('>', Some('=')) => (Kind::Op(Op::Ge), 2),
+			('=', _) => (Kind::Op(Op::Eq), 1),
+			('<', _) => (Kind::Op(Op::Lt), 1),
+			('>', _) => (Kind::Op(Op::Gt), 1),
+			('!', _) => (Kind::Not, 1),
+			('(', _) => (Kind::Open, 1),
+			(')', _) => (Kind::Close, 1),
+			('"', _) => {
+				let length = chars[i + 1..].iter().position(|&(_, c)| c == '"').ok_or(QueryError::UnexpectedEnd)? + 2;
+				(Kind::Text(text[end(i + 1)..end(i + length - 1)].to_string()), length)
+			}
+			_ if c.is_ascii_digit() || (c == '-' && next.is_some_and(|c| c.is_ascii_digit())) => {
+				let length = 1 + chars[i + 1..].iter().take_while(|&&(_, c)| c.is_ascii_digit()).count();
+				let number = &text[offset..end(i + length)];
+				(Kind::Number(number.parse().map_err(|_| QueryError::UnexpectedToken { offset, token: number.to_string() })?), length)
+			}
+			_ if c.is_alphabetic() || c == '_' => {
+				let length = chars[i..].iter().take_while(|&&(_, c)| c.is_alphanumeric() || c == '_' || c == '.').count();
+				(Kind::Name(text[offset..end(i + length)].to_string()), length)
+			}
+			_ => return Err(QueryError::UnexpectedToken { offset, token: c.to_string() })
+		};
+		tokens.push(Token { kind, offset, text: text[offset..end(i + length)].to_string() });
+		i += length;
+	}
+	Ok(tokens)
+}
+
+// or  := and ("||" and)*
+// and := not ("&&" not)*
+// not := "!" not | "(" or ")" | name op value | name | name ".is_some()" | name ".is_none()"
+struct Parser {
+	tokens: Vec<Token>,
+	position: usize
+}
+
+impl Parser {
+	fn next(&mut self) -> Result<Token, QueryError> {
+		let token = self.tokens.get(self.position).cloned().ok_or(QueryError::UnexpectedEnd)?;
+		self.position += 1;
+		Ok(token)
+	}
+	fn peek(&self) -> Option<&Kind> {
+		self.tokens.get(self.position).map(|t| &t.kind)
+	}
+	fn expect(&mut self, kind: Kind) -> Result<(), QueryError> {
+		let token = self.next()?;
+		match token.kind == kind {
+			true => Ok(()),
+			false => Err(token.unexpected())
+		}
+	}
+	
+	fn or(&mut self) -> Result<Query, QueryError> {
+		let mut query = self.and()?;
+		while self.peek() == Some(&Kind::Or) {
+			self.position += 1;
+			query = query | self.and()?;
+		}
+		Ok(query)
+	}
+	
+	fn and(&mut self) -> Result<Query, QueryError> {
+		let mut query = self.not()?;
+		while self.peek() == Some(&Kind::And) {
+			self.position += 1;
+			query = query & self.not()?;
+		}
+		Ok(query)
+	}
+	
+	fn not(&mut self) -> Result<Query, QueryError> {
+		let token = self.next()?;
+		let name = match token.kind {
+			Kind::Not => return Ok(!self.not()?),
+			Kind::Open => {
+				let query = self.or()?;
+				self.expect(Kind::Close)?;
+				return Ok(query);
+			}
+			Kind::Name(ref name) => name.clone(),
+			_ => return Err(token.unexpected())
+		};
+		
+		if let Some(Kind::Op(op)) = self.peek().cloned() {
+			self.position += 1;
+			let field = Field::from_name(&name).ok_or_else(|| QueryError::UnknownField { offset: token.offset, name: name.clone() })?;
+			let value = self.next()?;
+			let number = match value.kind {
+				Kind::Number(n) => Some(n),
+				Kind::Name(ref name) | Kind::Text(ref name) => field.value_by_name(name),
+				_ => return Err(value.unexpected())
+			};
+			let number = number.ok_or_else(|| QueryError::UnknownValue { offset: value.offset, field, value: value.text.clone() })?;
+			return Ok(Query::Compare(field, op, number));
+		}
+		
+		let (name, negated) = match (name.strip_suffix(".is_some"), name.strip_suffix(".is_none")) {
+			(Some(name), _) => (name, false),
+			(_, Some(name)) => (name, true),
+			_ => (&name[..], false)
+		};
+		if name.len() != token.text.len() {
+			self.expect(Kind::Open)?;
+			self.expect(Kind::Close)?;
+		}
+		let flag = Flag::from_name(name).ok_or_else(|| QueryError::UnknownField { offset: token.offset, name: name.to_string() })?;
+		Ok(match negated {
+			true => !Query::Flag(flag),
+			false => Query::Flag(flag)
+		})
+	}
+}
+
+
+
+impl Wld {
+	// Coordinates of every matching tile in `region`, column by column
+	pub fn find_in(&self, query: &Query, region: Region) -> Vec<(u32, u32)> {
+		let region = region.clamp(self.tiles.width(), self.tiles.height());
+		// Without x or y in the query every cell with the same palette index gives the same answer
+		let by_index: Option<Vec<bool>> = match query.depends_on_position() {
+			true => None,
+			false => Some(self.tiles.palette().iter().map(|tile| query.matches(tile, 0, 0)).collect())
+		};
+		let mut found = vec![];
+		for x in region.x..region.right() {
+			for y in region.y..region.bottom() {
+				let matches = match by_index {
+					Some(ref by_index) => by_index[self.tiles.index(x, y) as usize],
+					None => query.matches(self.tiles.get(x, y), x, y)
+				};
+				if matches {
+					found.push((x, y));
+				}
+			}
+		}
+		found
+	}
+	
+	pub fn find(&self, query: &Query) -> Vec<(u32, u32)> {
+		self.find_in(query, Region::new(0, 0, self.tiles.width(), self.tiles.height()))
+	}
+	
+	// Bounding boxes of the groups of matching tiles, tiles touching at a corner count as one group
+	pub fn find_regions(&self, query: &Query) -> Vec<Region> {
+		let found = self.find(query);
+		let height = self.tiles.height() as usize;
+		let mut group = vec![usize::MAX; self.tiles.width() as usize * height];
+		for (i, &(x, y)) in found.iter().enumerate() {
+			group[x as usize * height + y as usize] = i;
+		}
+		
+		let mut regions = vec![];
+		let mut stack = vec![];
+		for &(x, y) in &found {
+			if group[x as usize * height + y as usize] == usize::MAX { continue }
+			group[x as usize * height + y as usize] = usize::MAX;
+			stack.push((x, y));
+			let (mut left, mut top, mut right, mut bottom) = (x, y, x, y);
+			while let Some((x, y)) = stack.pop() {
+				left = left.min(x);
+				top = top.min(y);
+				right = right.max(x);
+				bottom = bottom.max(y);
+				for nx in x.saturating_sub(1)..=(x + 1).min(self.tiles.width() - 1) {
+					for ny in y.saturating_sub(1)..=(y + 1).min(self.tiles.height() - 1) {
+						let cell = &mut group[nx as usize * height + ny as usize];
+						if *cell != usize::MAX {
+							*cell = usize::MAX;
+							stack.push((nx, ny));
+						}
+					}
+				}
+			}
+			regions.push(Region::new(left, top, right - left + 1, bottom - top + 1));
+		}
+		regions
+	}
+}
@@ -1,307 +1,492 @@
-use std::{collections::VecDeque, rc::Rc};
-use hashbrown::hash_set::HashSet;
+use std::{fs::File, io::{BufReader, Cursor, ErrorKind, Read, Seek, SeekFrom}};
 
-use crate::{wld::Wld, structs::{Block, Tile, Slope, Wall, Liquid, LiquidType, Chest, Item, Sign, NPC, TileEntity, TileEntityInfo, NPCRoom, CreativePower}};
+use crate::{wld::Wld, error::{Section, WldError}, tiles::TileGrid, journal::Journal, version::*, structs::{Block, Tile, Slope, Wall, Liquid, LiquidType, Chest, Item, Sign, NPC, TileEntity, TileEntityInfo, NPCRoom, CreativePower}};
 
-struct Reader {
-	deque: VecDeque<u8>,
-	original_length: usize,
-	pub positions: Vec<usize>
+pub struct Reader<R: Read + Seek> {
+	inner: R,
+	position: usize,
+	pub positions: Vec<usize>,
+	pub unknown_data: Vec<Vec<u8>>,
+	pub section: Section,
+	pub version: u32
 }
 
-impl Reader {
-	pub fn new(buffer: Vec<u8>) -> Self {
+impl<R: Read + Seek> Reader<R> {
+	pub fn new(inner: R) -> Self {
 		Self {
-			original_length: buffer.len(),
-			deque: VecDeque::from(buffer),
-			positions: vec![]
+			inner,
+			position: 0,
+			positions: vec![],
+			unknown_data: vec![],
+			section: Section::FileHeader,
+			version: LATEST_VERSION
 		}
 	}
 	
-	pub fn _remaining(&self) -> usize {
-		self.deque.len()
-	}
 	pub fn position(&self) -> usize {
-		self.original_length - self.deque.len()
+		self.position
+	}
+	
+	// Jumps to the start of a section using the pointer table, for skipping sections that aren't needed
+	pub fn seek(&mut self, section: Section) -> Result<(), WldError> {
+		let start = match section.index() {
+			0 => 0,
+			i => match self.positions.get(i - 1) {
+				Some(&start) if start != 0 => start,
+				_ => return Err(WldError::MissingSection { section, offset: self.position })
+			}
+		};
+		self.inner.seek(SeekFrom::Start(start as u64))?;
+		self.position = start;
+		self.section = section;
+		Ok(())
+	}
+	
+	// Bytes left over before a section pointer belong to fields this crate doesn't know about yet.
+	// The game leaves unused slots of the pointer table at zero, and a pointer after the last section read (`trailing`)
+	// can point anywhere, so neither is treated as a section end
+	fn capture(&mut self, i: usize, trailing: bool) -> Result<(), WldError> {
+		let expected = match self.positions.get(i) {
+			Some(&expected) => expected,
+			None => return Err(WldError::MissingSection { section: self.section, offset: self.position })
+		};
+		if expected == 0 || (trailing && self.position > expected) {
+			self.unknown_data.resize(i + 1, vec![]);
+			return Ok(());
+		}
+		if self.position > expected {
+			return Err(WldError::SectionMismatch { section: self.section, expected, actual: self.position });
+		}
+		let data = self.vec(expected - self.position)?;
+		self.unknown_data.resize(i, vec![]);
+		self.unknown_data.push(data);
+		Ok(())
+	}
+	pub fn begin(&mut self, section: Section) -> Result<(), WldError> {
+		let i = self.section.index();
+		let end = match section {
+			Section::Footer => self.positions.len().max(i + 1),
+			_ => i + 1
+		};
+		for j in i..end {
+			self.capture(j, j > i)?;
+		}
+		self.section = section;
+		Ok(())
+	}
+	pub fn since<T: Default>(&mut self, version: u32, f: impl FnOnce(&mut Self) -> Result<T, WldError>) -> Result<T, WldError> {
+		match self.version >= version {
+			true => f(self),
+			false => Ok(T::default())
+		}
 	}
 	
-	pub fn check_position(&self, i: usize) -> Result<(), String> {
-		match self.position() == self.positions[i] {
-			true => Ok(()),
-			false => Err(format!("Position {} is wrong: {} from file, {} from data, off by {}", i, self.positions[i], self.position(), self.position() - self.positions[i]))
+	fn fill(&mut self, buffer: &mut [u8]) -> Result<(), WldError> {
+		match self.inner.read_exact(buffer) {
+			Ok(()) => {
+				self.position += buffer.len();
+				Ok(())
+			}
+			Err(e) if e.kind() == ErrorKind::UnexpectedEof => Err(WldError::Truncated { section: self.section, offset: self.position }),
+			Err(e) => Err(WldError::Io(e))
+		}
+	}
+	fn bytes<const N: usize>(&mut self) -> Result<[u8; N], WldError> {
+		let mut buffer = [0; N];
+		self.fill(&mut buffer)?;
+		Ok(buffer)
+	}
+	// Lengths come from the file, so read through `take` instead of allocating them up front
+	fn vec(&mut self, n: usize) -> Result<Vec<u8>, WldError> {
+		let mut buffer = vec![];
+		(&mut self.inner).take(n as u64).read_to_end(&mut buffer)?;
+		self.position += buffer.len();
+		match buffer.len() == n {
+			true => Ok(buffer),
+			false => Err(WldError::Truncated { section: self.section, offset: self.position })
 		}
 	}
 	
-	pub fn u8(&mut self) -> u8 {
-		self.deque.pop_front().unwrap()
+	pub fn u8(&mut self) -> Result<u8, WldError> {
+		Ok(self.bytes::<1>()?[0])
 	}
-	pub fn u16(&mut self) -> u16 {
-		self.deque.pop_front().unwrap() as u16 | ((self.deque.pop_front().unwrap() as u16) << 8)
+	pub fn u16(&mut self) -> Result<u16, WldError> {
+		Ok(u16::from_le_bytes(self.bytes()?))
 	}
-	pub fn u32(&mut self) -> u32 {
-		(0..4).map(|i| (self.deque.pop_front().unwrap() as u32) << i*8).sum()
+	pub fn u32(&mut self) -> Result<u32, WldError> {
+		Ok(u32::from_le_bytes(self.bytes()?))
 	}
-	pub fn u64(&mut self) -> u64 {
-		(0..8).map(|i| (self.deque.pop_front().unwrap() as u64) << i*8).sum()
+	pub fn u64(&mut self) -> Result<u64, WldError> {
+		Ok(u64::from_le_bytes(self.bytes()?))
 	}
-	pub fn u128(&mut self) -> u128 {
-		(0..16).map(|i| (self.deque.pop_front().unwrap() as u128) << i*8).sum()
+	pub fn u128(&mut self) -> Result<u128, WldError> {
+		Ok(u128::from_le_bytes(self.bytes()?))
 	}
-	pub fn f32(&mut self) -> f32 {
-		f32::from_bits(self.u32())
+	pub fn f32(&mut self) -> Result<f32, WldError> {
+		Ok(f32::from_bits(self.u32()?))
 	}
-	pub fn f64(&mut self) -> f64 {
-		f64::from_bits(self.u64())
+	pub fn f64(&mut self) -> Result<f64, WldError> {
+		Ok(f64::from_bits(self.u64()?))
 	}
-	pub fn bool(&mut self) -> bool {
-		self.deque.pop_front().unwrap() != 0
+	pub fn bool(&mut self) -> Result<bool, WldError> {
+		Ok(self.u8()? != 0)
 	}
-	pub fn string(&mut self) -> String {
-		String::from_utf8((0..self.u8()).map(|_| self.u8()).collect()).unwrap()
+	// .NET BinaryWriter prefixes strings with their byte length as a 7 bit varint
+	pub fn string(&mut self) -> Result<String, WldError> {
+		let offset = self.position;
+		let mut len = 0;
+		let mut shift = 0;
+		loop {
+			let n = self.u8()?;
+			len |= ((n & 127) as usize) << shift;
+			if n & 128 == 0 || shift > 28 { break }
+			shift += 7;
+		}
+		let bytes = self.vec(len)?;
+		String::from_utf8(bytes).map_err(|_| WldError::InvalidUtf8 { section: self.section, offset })
 	}
 }
 
-pub fn read(buffer: Vec<u8>) -> Wld {
-	let mut r = Reader::new(buffer);
+fn read_npc_id<R: Read + Seek>(r: &mut Reader<R>) -> Result<u32, WldError> {
+	match r.version >= NPC_ID_VERSION {
+		true => r.u32(),
+		false => {
+			let offset = r.position();
+			let name = r.string()?;
+			match legacy_npc_id(&name) {
+				Some(id) => Ok(id),
+				None => Err(WldError::UnknownNPCName { name, offset })
+			}
+		}
+	}
+}
+
+pub fn read(buffer: Vec<u8>) -> Result<Wld, WldError> {
+	read_from(Cursor::new(buffer))
+}
+
+pub fn read_from<R: Read + Seek>(inner: R) -> Result<Wld, WldError> {
+	let mut r = Reader::new(inner);
+	let mut wld = read_header(&mut r)?;
+	let version = wld.version;
+	
+	r.begin(Section::Tiles)?;
+	wld.tiles = read_tiles(&mut r, wld.width, wld.height, &wld.importance, version)?;
+	
+	r.begin(Section::Chests)?;
+	wld.chests = read_chests(&mut r)?;
+	
+	r.begin(Section::Signs)?;
+	wld.signs = read_signs(&mut r)?;
 	
-	let version = r.u32();
-	let magic_number = [r.u8(), r.u8(), r.u8(), r.u8(), r.u8(), r.u8(), r.u8()];
-	let file_type = r.u8();
-	let revision = r.u32();
-	let is_favorite = r.u64();
+	r.begin(Section::NPCs)?;
+	wld.npcs = read_npcs(&mut r)?;
 	
-	r.positions = (0..r.u16()).map(|_| r.u32() as usize).collect();
+	if version >= TILE_ENTITIES_VERSION {
+		r.begin(Section::TileEntities)?;
+		wld.tile_entities = read_tile_entities(&mut r)?;
+	}
+	if version >= PRESSURE_PLATES_VERSION {
+		r.begin(Section::PressurePlates)?;
+		wld.weighted_pressure_plates = read_pressure_plates(&mut r)?;
+	}
+	if version >= TOWN_MANAGER_VERSION {
+		r.begin(Section::TownManager)?;
+		wld.npc_rooms = read_town_manager(&mut r)?;
+	}
+	if version >= BESTIARY_VERSION {
+		r.begin(Section::Bestiary)?;
+		(wld.bestiary_kills, wld.bestiary_sights, wld.bestiary_chats) = read_bestiary(&mut r)?;
+	}
+	if version >= CREATIVE_POWERS_VERSION {
+		r.begin(Section::CreativePowers)?;
+		wld.creative_powers = read_creative_powers(&mut r)?;
+	}
+	
+	r.begin(Section::Footer)?;
+	
+	let offset = r.position();
+	if !r.bool()? || r.string()? != wld.name || r.u32()? != wld.id {
+		return Err(WldError::FooterMismatch { offset });
+	}
 	
-	let importance_len = r.u16();
+	wld.unknown_data = r.unknown_data;
+	while wld.unknown_data.last().is_some_and(|data| data.is_empty()) {
+		wld.unknown_data.pop();
+	}
+	Ok(wld)
+}
+
+// Reads the file header and world header, leaving the tiles and every later section empty
+pub fn read_header<R: Read + Seek>(r: &mut Reader<R>) -> Result<Wld, WldError> {
+	let version = r.u32()?;
+	if version < MIN_VERSION {
+		return Err(WldError::UnsupportedVersion { version });
+	}
+	r.version = version;
+	
+	let (magic_number, file_type, revision, is_favorite) = match version >= MAGIC_HEADER_VERSION {
+		true => ([r.u8()?, r.u8()?, r.u8()?, r.u8()?, r.u8()?, r.u8()?, r.u8()?], r.u8()?, r.u32()?, r.u64()?),
+		false => (MAGIC_NUMBER, 2, 0, 0)
+	};
+	
+	r.positions = (0..r.u16()?).map(|_| Ok(r.u32()? as usize)).collect::<Result<_, WldError>>()?;
+	
+	let importance = read_importance(r)?;
+	
+	r.begin(Section::Header)?;
+	
+	let name = r.string()?;
+	
+	let seed = match version {
+		179 => (r.u32()? as i32).to_string(),
+		_ => r.since(180, Reader::string)?
+	};
+	let world_gen_version = r.since(179, Reader::u64)?;
+	let guid = r.since(181, Reader::u128)?;
+	let id = r.u32()?;
+	let left = r.u32()?;
+	let right = r.u32()?;
+	let top = r.u32()?;
+	let bottom = r.u32()?;
+	let height = r.u32()?;
+	let width = r.u32()?;
+	let gamemode = match version >= GAMEMODE_VERSION {
+		true => r.u32()?,
+		false => r.since(112, Reader::bool)? as u32
+	};
+	let drunk_world = r.since(222, Reader::bool)?;
+	let good_world = r.since(227, Reader::bool)?;
+	let tenth_anniversary_world = r.since(238, Reader::bool)?;
+	let dont_starve_world = r.since(239, Reader::bool)?;
+	let notthebees_world = r.since(241, Reader::bool)?;
+	let remix_world = r.since(249, Reader::bool)?;
+	let notraps_world = r.since(266, Reader::bool)?;
+	let zenith_world = r.since(267, Reader::bool)?;
+	let creation_time = r.since(141, Reader::u64)?;
+	let moon_type = r.u8()?;
+	let tree_type_xcoords = [r.u32()?, r.u32()?, r.u32()?];
+	let tree_types = [r.u32()?, r.u32()?, r.u32()?, r.u32()?];
+	let cave_bg_xcoords = [r.u32()?, r.u32()?, r.u32()?];
+	let cave_bgs = [r.u32()?, r.u32()?, r.u32()?, r.u32()?];
+	let ice_bg = r.u32()?;
+	let jungle_bg = r.u32()?;
+	let hell_bg = r.u32()?;
+	let spawn_x = r.u32()?;
+	let spawn_y = r.u32()?;
+	let world_surface_y = r.f64()?;
+	let rock_layer_y = r.f64()?;
+	let game_time = r.f64()?;
+	let is_day = r.bool()?;
+	let moon_phase = r.u32()?;
+	let blood_moon = r.bool()?;
+	let eclipse = r.since(70, Reader::bool)?;
+	let dungeon_x = r.u32()?;
+	let dungeon_y = r.u32()?;
+	let crimson_world = r.bool()?;
+	let killed_eye_of_cthulu = r.bool()?;
+	let killed_eater_of_worlds = r.bool()?;
+	let killed_skeletron = r.bool()?;
+	let killed_queen_bee = r.since(66, Reader::bool)?;
+	let killed_the_destroyer = r.since(44, Reader::bool)?;
+	let killed_the_twins = r.since(44, Reader::bool)?;
+	let killed_skeletron_prime = r.since(44, Reader::bool)?;
+	let killed_any_hardmode_boss = r.since(44, Reader::bool)?;
+	let killed_plantera = r.since(64, Reader::bool)?;
+	let killed_golem = r.since(64, Reader::bool)?;
+	let killed_slime_king = r.since(118, Reader::bool)?;
+	let saved_goblin_tinkerer = r.bool()?;
+	let saved_wizard = r.bool()?;
+	let saved_mechanic = r.bool()?;
+	let defeated_goblin_invasion = r.bool()?;
+	let killed_clown = r.bool()?;
+	let defeated_frost_legion = r.bool()?;
+	let defeated_pirates = r.bool()?;
+	let broken_shadow_orb = r.bool()?;
+	let meteor_spawned = r.bool()?;
+	let shadow_orbs_broken_mod3 = r.u8()?;
+	let altars_smashed = r.u32()?;
+	let hard_mode = r.bool()?;
+	let after_party_of_doom = r.since(257, Reader::bool)?;
+	let goblin_invasion_delay = r.u32()?;
+	let goblin_invasion_size = r.u32()?;
+	let goblin_invasion_type = r.u32()?;
+	let goblin_invasion_x = r.f64()?;
+	let slime_rain_time = r.since(118, Reader::f64)?;
+	let sundial_cooldown = r.since(113, Reader::u8)?;
+	let is_raining = r.bool()?;
+	let rain_time = r.u32()?;
+	let max_rain = r.f32()?;
+	let tier_1_ore_id = r.u32()?;
+	let tier_2_ore_id = r.u32()?;
+	let tier_3_ore_id = r.u32()?;
+	let tree_style = r.u8()?;
+	let corruption_style = r.u8()?;
+	let jungle_style = r.u8()?;
+	let snow_style = r.u8()?;
+	let hallow_style = r.u8()?;
+	let crimson_style = r.u8()?;
+	let desert_style = r.u8()?;
+	let ocean_style = r.u8()?;
+	let cloud_bg = r.u32()?;
+	let num_clouds = r.u16()?;
+	let wind_speed = r.f32()?;
+	
+	let angler_finishers = r.since(95, |r| (0..r.u32()?).map(|_| r.string()).collect())?;
+	
+	let saved_angler = r.since(99, Reader::bool)?;
+	let angler_quest = r.since(101, Reader::u32)?;
+	let saved_stylist = r.since(104, Reader::bool)?;
+	let saved_tax_collector = r.since(140, Reader::bool)?;
+	let saved_golfer = r.since(201, Reader::bool)?;
+	let invasion_size_start = r.since(107, Reader::u32)?;
+	let temp_cultist_delay = r.since(108, Reader::u32)?;
+	
+	let kill_counts = r.since(109, |r| (0..r.u16()?).map(|_| r.u32()).collect())?;
+	
+	let fast_forward_time = r.since(128, Reader::bool)?;
+	let downed_fishron = r.since(131, Reader::bool)?;
+	let downed_martians = r.since(131, Reader::bool)?;
+	let downed_ancient_cultist = r.since(131, Reader::bool)?;
+	let downed_moonlord = r.since(131, Reader::bool)?;
+	let downed_halloween_king = r.since(131, Reader::bool)?;
+	let downed_halloween_tree = r.since(131, Reader::bool)?;
+	let downed_christmas_ice_queen = r.since(131, Reader::bool)?;
+	let downed_christmas_santank = r.since(131, Reader::bool)?;
+	let downed_christmas_tree = r.since(131, Reader::bool)?;
+	let downed_tower_solar = r.since(131, Reader::bool)?;
+	let downed_tower_vortex = r.since(131, Reader::bool)?;
+	let downed_tower_nebula = r.since(131, Reader::bool)?;
+	let downed_tower_stardust = r.since(131, Reader::bool)?;
+	let tower_active_solar = r.since(131, Reader::bool)?;
+	let tower_active_vortex = r.since(131, Reader::bool)?;
+	let tower_active_nebula = r.since(131, Reader::bool)?;
+	let tower_active_stardust = r.since(131, Reader::bool)?;
+	let lunar_apocalypse_is_up = r.since(131, Reader::bool)?;
+	let party_manual = r.since(140, Reader::bool)?;
+	let party_genuine = r.since(140, Reader::bool)?;
+	let party_cooldown = r.since(140, Reader::u32)?;
+	
+	let party_celebrating_npcs = r.since(140, |r| (0..r.u32()?).map(|_| r.u32()).collect())?;
+	
+	let sandstorm_happening = r.since(140, Reader::bool)?;
+	let sandstorm_time_left = r.since(140, Reader::u32)?;
+	let sandstorm_severity = r.since(140, Reader::f32)?;
+	let sandstorm_intended_severity = r.since(140, Reader::f32)?;
+	let saved_bartender = r.since(170, Reader::bool)?;
+	let downed_invasion_tier_1 = r.since(170, Reader::bool)?;
+	let downed_invasion_tier_2 = r.since(170, Reader::bool)?;
+	let downed_invasion_tier_3 = r.since(170, Reader::bool)?;
+	let mushroom_bg = r.since(195, Reader::u8)?;
+	let underworld_bg = r.since(215, Reader::u8)?;
+	let tree2_bg = r.since(195, Reader::u8)?;
+	let tree3_bg = r.since(195, Reader::u8)?;
+	let tree4_bg = r.since(195, Reader::u8)?;
+	let combat_book_was_used = r.since(204, Reader::bool)?;
+	let lantern_night_stuff = r.since(207, Reader::u32)?;
+	let lantern_night_more_stuff = r.since(207, |r| Ok([r.bool()?, r.bool()?, r.bool()?]))?;
+	
+	let tree_top_stuff = r.since(211, |r| (0..r.u32()?).map(|_| r.u32()).collect())?;
+	
+	let force_halloween_for_today = r.since(212, Reader::bool)?;
+	let force_xmas_for_today = r.since(212, Reader::bool)?;
+	let copper_tier = r.since(216, Reader::u32)?;
+	let iron_tier = r.since(216, Reader::u32)?;
+	let silver_tier = r.since(216, Reader::u32)?;
+	let gold_tier = r.since(216, Reader::u32)?;
+	let bought_cat = r.since(217, Reader::bool)?;
+	let bought_dog = r.since(217, Reader::bool)?;
+	let bought_bunny = r.since(217, Reader::bool)?;
+	let downed_empress_of_light = r.since(223, Reader::bool)?;
+	let downed_queen_slime = r.since(223, Reader::bool)?;
+	let downed_deerclops = r.since(240, Reader::bool)?;
+	let unlocked_slime_blue_spawn = r.since(250, Reader::bool)?;
+	let unlocked_merchant_spawn = r.since(251, Reader::bool)?;
+	let unlocked_demolitionist_spawn = r.since(251, Reader::bool)?;
+	let unlocked_party_girl_spawn = r.since(251, Reader::bool)?;
+	let unlocked_dye_trader_spawn = r.since(251, Reader::bool)?;
+	let unlocked_truffle_spawn = r.since(251, Reader::bool)?;
+	let unlocked_arms_dealer_spawn = r.since(251, Reader::bool)?;
+	let unlocked_nurse_spawn = r.since(251, Reader::bool)?;
+	let unlocked_princess_spawn = r.since(251, Reader::bool)?;
+	let combat_book_v2_was_used = r.since(259, Reader::bool)?;
+	let peddlers_satched_was_used = r.since(260, Reader::bool)?;
+	let unlocked_slime_green_spawn = r.since(261, Reader::bool)?;
+	let unlocked_slime_old_spawn = r.since(261, Reader::bool)?;
+	let unlocked_slime_purple_spawn = r.since(261, Reader::bool)?;
+	let unlocked_slime_rainbow_spawn = r.since(261, Reader::bool)?;
+	let unlocked_slime_red_spawn = r.since(261, Reader::bool)?;
+	let unlocked_slime_yellow_spawn = r.since(261, Reader::bool)?;
+	let unlocked_slime_copper_spawn = r.since(261, Reader::bool)?;
+	let fast_forward_to_dusk = r.since(264, Reader::bool)?;
+	let moondial_cooldown = r.since(264, Reader::u8)?;
+	
+	Ok(Wld {
+		version, magic_number, file_type, revision, is_favorite, importance, name, seed, world_gen_version, guid, id, left, right, top, bottom, height, width, gamemode, drunk_world, good_world, tenth_anniversary_world, dont_starve_world, notthebees_world, remix_world, notraps_world, zenith_world, creation_time, moon_type, tree_type_xcoords, tree_types, cave_bg_xcoords, cave_bgs, ice_bg, jungle_bg, hell_bg, spawn_x, spawn_y, world_surface_y, rock_layer_y, game_time, is_day, moon_phase, blood_moon, eclipse, dungeon_x, dungeon_y, crimson_world, killed_eye_of_cthulu, killed_eater_of_worlds, killed_skeletron, killed_queen_bee, killed_the_destroyer, killed_the_twins, killed_skeletron_prime, killed_any_hardmode_boss, killed_plantera, killed_golem, killed_slime_king, saved_goblin_tinkerer, saved_wizard, saved_mechanic, defeated_goblin_invasion, killed_clown, defeated_frost_legion, defeated_pirates, broken_shadow_orb, meteor_spawned, shadow_orbs_broken_mod3, altars_smashed, hard_mode, after_party_of_doom, goblin_invasion_delay, goblin_invasion_size, goblin_invasion_type, goblin_invasion_x, slime_rain_time, sundial_cooldown, is_raining, rain_time, max_rain, tier_1_ore_id, tier_2_ore_id, tier_3_ore_id, tree_style, corruption_style, jungle_style, snow_style, hallow_style, crimson_style, desert_style, ocean_style, cloud_bg, num_clouds, wind_speed, angler_finishers, saved_angler, angler_quest, saved_stylist, saved_tax_collector, saved_golfer, invasion_size_start, temp_cultist_delay, kill_counts, fast_forward_time, downed_fishron, downed_martians, downed_ancient_cultist, downed_moonlord, downed_halloween_king, downed_halloween_tree, downed_christmas_ice_queen, downed_christmas_santank, downed_christmas_tree, downed_tower_solar, downed_tower_vortex, downed_tower_nebula, downed_tower_stardust, tower_active_solar, tower_active_vortex, tower_active_nebula, tower_active_stardust, lunar_apocalypse_is_up, party_manual, party_genuine, party_cooldown, party_celebrating_npcs, sandstorm_happening, sandstorm_time_left, sandstorm_severity, sandstorm_intended_severity, saved_bartender, downed_invasion_tier_1, downed_invasion_tier_2, downed_invasion_tier_3, mushroom_bg, underworld_bg, tree2_bg, tree3_bg, tree4_bg, combat_book_was_used, lantern_night_stuff, lantern_night_more_stuff, tree_top_stuff, force_halloween_for_today, force_xmas_for_today, copper_tier, iron_tier, silver_tier, gold_tier, bought_cat, bought_dog, bought_bunny, downed_empress_of_light, downed_queen_slime, downed_deerclops, unlocked_slime_blue_spawn, unlocked_merchant_spawn, unlocked_demolitionist_spawn, unlocked_party_girl_spawn, unlocked_dye_trader_spawn, unlocked_truffle_spawn, unlocked_arms_dealer_spawn, unlocked_nurse_spawn, unlocked_princess_spawn, combat_book_v2_was_used, peddlers_satched_was_used, unlocked_slime_green_spawn, unlocked_slime_old_spawn, unlocked_slime_purple_spawn, unlocked_slime_rainbow_spawn, unlocked_slime_red_spawn, unlocked_slime_yellow_spawn, unlocked_slime_copper_spawn, fast_forward_to_dusk, moondial_cooldown,
+		
+		tiles: TileGrid::default(), chests: vec![], signs: vec![], npcs: vec![], tile_entities: vec![], weighted_pressure_plates: vec![], npc_rooms: vec![], bestiary_kills: vec![], bestiary_sights: vec![], bestiary_chats: vec![], creative_powers: vec![],
+		
+		unknown_data: vec![],
+		journal: Journal::default()
+	})
+}
+
+pub fn read_importance<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<bool>, WldError> {
+	let importance_len = r.u16()?;
 	let mut importance = vec![];
 	let mut i = 0;
 	let mut n = 0;
 	while importance.len() < importance_len as usize {
 		if i == 0 {
-			n = r.u8();
+			n = r.u8()?;
 			i = 8;
 		}
 		importance.push(n & 1 == 1);
 		n >>= 1;
 		i -= 1;
 	}
-	
-	r.check_position(0).unwrap();
-	
-	let name = r.string();
-	println!("{name}: Reading header");
-	
-	let seed = r.string();
-	let world_gen_version = r.u64();
-	let guid = r.u128();
-	let id = r.u32();
-	let left = r.u32();
-	let right = r.u32();
-	let top = r.u32();
-	let bottom = r.u32();
-	let height = r.u32();
-	let width = r.u32();
-	let gamemode = r.u32();
-	let drunk_world = r.bool();
-	let good_world = r.bool();
-	let tenth_anniversary_world = r.bool();
-	let dont_starve_world = r.bool();
-	let notthebees_world = r.bool();
-	let remix_world = r.bool();
-	let notraps_world = r.bool();
-	let zenith_world = r.bool();
-	let creation_time = r.u64();
-	let moon_type = r.u8();
-	let tree_type_xcoords = [r.u32(), r.u32(), r.u32()];
-	let tree_types = [r.u32(), r.u32(), r.u32(), r.u32()];
-	let cave_bg_xcoords = [r.u32(), r.u32(), r.u32()];
-	let cave_bgs = [r.u32(), r.u32(), r.u32(), r.u32()];
-	let ice_bg = r.u32();
-	let jungle_bg = r.u32();
-	let hell_bg = r.u32();
-	let spawn_x = r.u32();
-	let spawn_y = r.u32();
-	let world_surface_y = r.f64();
-	let rock_layer_y = r.f64();
-	let game_time = r.f64();
-	let is_day = r.bool();
-	let moon_phase = r.u32();
-	let blood_moon = r.bool();
-	let eclipse = r.bool();
-	let dungeon_x = r.u32();
-	let dungeon_y = r.u32();
-	let crimson_world = r.bool();
-	let killed_eye_of_cthulu = r.bool();
-	let killed_eater_of_worlds = r.bool();
-	let killed_skeletron = r.bool();
-	let killed_queen_bee = r.bool();
-	let killed_the_destroyer = r.bool();
-	let killed_the_twins = r.bool();
-	let killed_skeletron_prime = r.bool();
-	let killed_any_hardmode_boss = r.bool();
-	let killed_plantera = r.bool();
-	let killed_golem = r.bool();
-	let killed_slime_king = r.bool();
-	let saved_goblin_tinkerer = r.bool();
-	let saved_wizard = r.bool();
-	let saved_mechanic = r.bool();
-	let defeated_goblin_invasion = r.bool();
-	let killed_clown = r.bool();
-	let defeated_frost_legion = r.bool();
-	let defeated_pirates = r.bool();
-	let broken_shadow_orb = r.bool();
-	let meteor_spawned = r.bool();
-	let shadow_orbs_broken_mod3 = r.u8();
-	let altars_smashed = r.u32();
-	let hard_mode = r.bool();
-	let after_party_of_doom = r.bool();
-	let goblin_invasion_delay = r.u32();
-	let goblin_invasion_size = r.u32();
-	let goblin_invasion_type = r.u32();
-	let goblin_invasion_x = r.f64();
-	let slime_rain_time = r.f64();
-	let sundial_cooldown = r.u8();
-	let is_raining = r.bool();
-	let rain_time = r.u32();
-	let max_rain = r.f32();
-	let tier_1_ore_id = r.u32();
-	let tier_2_ore_id = r.u32();
-	let tier_3_ore_id = r.u32();
-	let tree_style = r.u8();
-	let corruption_style = r.u8();
-	let jungle_style = r.u8();
-	let snow_style = r.u8();
-	let hallow_style = r.u8();
-	let crimson_style = r.u8();
-	let desert_style = r.u8();
-	let ocean_style = r.u8();
-	let cloud_bg = r.u32();
-	let num_clouds = r.u16();
-	let wind_speed = r.f32();
-	
-	let angler_finishers = (0..r.u32()).map(|_| r.string()).collect();
-	
-	let saved_angler = r.bool();
-	let angler_quest = r.u32();
-	let saved_stylist = r.bool();
-	let saved_tax_collector = r.bool();
-	let saved_golfer = r.bool();
-	let invasion_size_start = r.u32();
-	let temp_cultist_delay = r.u32();
-	
-	let kill_counts = (0..r.u16()).map(|_| r.u32()).collect();
-	
-	let fast_forward_time = r.bool();
-	let downed_fishron = r.bool();
-	let downed_martians = r.bool();
-	let downed_ancient_cultist = r.bool();
-	let downed_moonlord = r.bool();
-	let downed_halloween_king = r.bool();
-	let downed_halloween_tree = r.bool();
-	let downed_christmas_ice_queen = r.bool();
-	let downed_christmas_santank = r.bool();
-	let downed_christmas_tree = r.bool();
-	let downed_tower_solar = r.bool();
-	let downed_tower_vortex = r.bool();
-	let downed_tower_nebula = r.bool();
-	let downed_tower_stardust = r.bool();
-	let tower_active_solar = r.bool();
-	let tower_active_vortex = r.bool();
-	let tower_active_nebula = r.bool();
-	let tower_active_stardust = r.bool();
-	let lunar_apocalypse_is_up = r.bool();
-	let party_manual = r.bool();
-	let party_genuine = r.bool();
-	let party_cooldown = r.u32();
-	
-	let party_celebrating_npcs = (0..r.u32()).map(|_| r.u32()).collect();
-	
-	let sandstorm_happening = r.bool();
-	let sandstorm_time_left = r.u32();
-	let sandstorm_severity = r.f32();
-	let sandstorm_intended_severity = r.f32();
-	let saved_bartender = r.bool();
-	let downed_invasion_tier_1 = r.bool();
-	let downed_invasion_tier_2 = r.bool();
-	let downed_invasion_tier_3 = r.bool();
-	let mushroom_bg = r.u8();
-	let underworld_bg = r.u8();
-	let tree2_bg = r.u8();
-	let tree3_bg = r.u8();
-	let tree4_bg = r.u8();
-	let combat_book_was_used = r.bool();
-	let lantern_night_stuff = r.u32();
-	let lantern_night_more_stuff = [r.bool(), r.bool(), r.bool()];
-	
-	let tree_top_stuff = (0..u32::min(r.u32(), 13)).map(|_| r.u32()).collect();
-	
-	let force_halloween_for_today = r.bool();
-	let force_xmas_for_today = r.bool();
-	let copper_tier = r.u32();
-	let iron_tier = r.u32();
-	let silver_tier = r.u32();
-	let gold_tier = r.u32();
-	let bought_cat = r.bool();
-	let bought_dog = r.bool();
-	let bought_bunny = r.bool();
-	let downed_empress_of_light = r.bool();
-	let downed_queen_slime = r.bool();
-	let downed_deerclops = r.bool();
-	let unlocked_slime_blue_spawn = r.bool();
-	let unlocked_merchant_spawn = r.bool();
-	let unlocked_demolitionist_spawn = r.bool();
-	let unlocked_party_girl_spawn = r.bool();
-	let unlocked_dye_trader_spawn = r.bool();
-	let unlocked_truffle_spawn = r.bool();
-	let unlocked_arms_dealer_spawn = r.bool();
-	let unlocked_nurse_spawn = r.bool();
-	let unlocked_princess_spawn = r.bool();
-	let combat_book_v2_was_used = r.bool();
-	let peddlers_satched_was_used = r.bool();
-	let unlocked_slime_green_spawn = r.bool();
-	let unlocked_slime_old_spawn = r.bool();
-	let unlocked_slime_purple_spawn = r.bool();
-	let unlocked_slime_rainbow_spawn = r.bool();
-	let unlocked_slime_red_spawn = r.bool();
-	let unlocked_slime_yellow_spawn = r.bool();
-	let unlocked_slime_copper_spawn = r.bool();
-	let fast_forward_to_dusk = r.bool();
-	let moondial_cooldown = r.u8();
-	
-	r.check_position(1).unwrap();
-	println!("{name}: Reading tiles");
-	
-	
-	let mut tile_set = HashSet::new();
-	let mut tiles = vec![];
-	
-	let mut progress = 0;
+	Ok(importance)
+}
+
+pub fn read_tiles<R: Read + Seek>(r: &mut Reader<R>, width: u32, height: u32, importance: &[bool], version: u32) -> Result<TileGrid, WldError> {
+	let mut tiles = TileGrid::empty(width, height);
 	
 	for x in 0..width {
 		let mut y = 0;
 		while y < height {
-			let a = r.u8();
+			let offset = r.position();
+			let a = r.u8()?;
 			let b = match a & 1 == 1 {
-				true => r.u8(),
+				true => r.u8()?,
 				false => 0
 			};
 			let c = match b & 1 == 1 {
-				true => r.u8(),
+				true => r.u8()?,
 				false => 0
 			};
 			
-			if c & 1 == 1 || b & 128 == 128 { panic!() }
+			if c & 1 == 1 || b & 128 == 128 {
+				return Err(WldError::InvalidTileData { x, y, offset });
+			}
 			
-			let tile = Rc::new(Tile {
-				block: (a & 2 == 2).then(|| {
+			let block = match a & 2 == 2 {
+				true => {
 					let id = match a & 32 == 32 {
-						true => r.u16(),
-						false => r.u8() as u16
+						true => r.u16()?,
+						false => r.u8()? as u16
+					};
+					let uv = match importance.get(id as usize) {
+						Some(true) => Some((r.u16()?, r.u16()?)),
+						Some(false) => None,
+						None => return Err(WldError::UnknownTileId { id, offset })
 					};
-					Block {
+					Some(Block {
 						id,
-						uv: importance[id as usize].then(|| (r.u16(), r.u16())),
-						color: (c & 8 == 8).then(|| r.u8()),
+						uv,
+						color: match c & 8 == 8 {
+							true => Some(r.u8()?),
+							false => None
+						},
 						inactive: c & 4 == 4,
 						slope: match (b >> 4) & 7 {
 							0 => Slope::Full,
@@ -312,21 +497,25 @@ pub fn read(buffer: Vec<u8>) -> Wld {
 							5 => Slope::UpperRight,
 							_ => Slope::Full
 						}
+					})
+				}
+				false => None
+			};
+			
+			let mut wall = match a & 4 == 4 {
+				true => Some(Wall {
+					id: r.u8()? as u16,
+					color: match c & 16 == 16 {
+						true => Some(r.u8()?),
+						false => None
 					}
 				}),
-				wall: 
-					match a & 4 == 4 {
-						true => Some(Wall {
-							id: match c & 64 == 64 {
-								true => r.u16(),
-								false => r.u8() as u16
-							},
-							color: (c & 16 == 16).then(|| r.u8())
-						}),
-						false => None
-					},
-				liquid: ((a >> 3) & 3 > 0).then(|| Liquid {
-					kind: match c & 128 == 128 {
+				false => None
+			};
+			
+			let liquid = match (a >> 3) & 3 > 0 {
+				true => Some(Liquid {
+					kind: match c & 128 == 128 && version >= SHIMMER_VERSION {
 						true => LiquidType::Shimmer,
 						false => match (a >> 3) & 3 {
 							2 => LiquidType::Lava,
@@ -334,84 +523,100 @@ pub fn read(buffer: Vec<u8>) -> Wld {
 							_ => LiquidType::Water
 						}
 					},
-					amount: r.u8()
+					amount: r.u8()?
 				}),
+				false => None
+			};
+			
+			// The high byte of a 16 bit wall id comes after the liquid and wires
+			if c & 64 == 64 && version >= WALL_ID_16BIT_VERSION {
+				let high = r.u8()? as u16;
+				if let Some(wall) = wall.as_mut() {
+					wall.id |= high << 8;
+				}
+			}
+			
+			let tile = Tile {
+				block,
+				wall,
+				liquid,
 				red_wire: b & 2 == 2,
 				green_wire: b & 4 == 4,
 				blue_wire: b & 8 == 8,
 				yellow_wire: c & 32 == 32,
 				actuator: c & 2 == 2
-			});
-			
-			let p = tile_set.get_or_insert(tile);
+			};
 			
 			let k = match a & 128 == 128 {
-				true => r.u16() as u32,
+				true => r.u16()? as u32,
 				false => match a & 64 == 64 {
-					true => r.u8() as u32,
+					true => r.u8()? as u32,
 					false => 0
 				}
 			};
 			
-			for _ in 0..k+1 {
-				tiles.push(Rc::clone(p));
+			if k >= height - y {
+				return Err(WldError::InvalidTileData { x, y, offset });
 			}
+			tiles.push(tile, k + 1);
 			y += k + 1;
 			
 		}
 		
-		if y != height { panic!() }
-		
-		if 10 * x / width > progress {
-			progress = 10 * x / width;
-			println!("{progress}0%")
+		if y != height {
+			return Err(WldError::InvalidTileData { x, y, offset: r.position() });
 		}
 	}
 	
-	r.check_position(2).unwrap();
-	println!("{name}: Reading chests");
-	
-	
-	let num = r.u16();
-	if r.u16() != 40 { panic!() }
+	Ok(tiles)
+}
+
+pub fn read_chests<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<Chest>, WldError> {
+	let num = r.u16()?;
+	let offset = r.position();
+	let size = r.u16()?;
+	if size != 40 {
+		return Err(WldError::InvalidChestSize { size, offset });
+	}
 	
-	let chests = (0..num).map(|_| {
-		Chest {
-			x: r.u32(),
-			y: r.u32(),
-			name: r.string(),
+	(0..num).map(|_| {
+		Ok(Chest {
+			x: r.u32()?,
+			y: r.u32()?,
+			name: r.string()?,
 			items:
 				(0..40).map(|_| {
-					let count = r.u16();
-					(count > 0).then(|| Item { id: r.u32(), prefix: r.u8(), count })
-				}).collect()
-		}
-	}).collect();
-	
-	r.check_position(3).unwrap();
-	println!("{name}: Reading signs");
-	
-	
-	let signs = (0..r.u16()).map(|_| Sign { text: r.string(), x: r.u32(), y: r.u32() }).collect();
-	
-	r.check_position(4).unwrap();
-	println!("{name}: Reading npcs");
-	
-	
+					let count = r.u16()?;
+					Ok(match count > 0 {
+						true => Some(Item { id: r.u32()?, prefix: r.u8()?, count }),
+						false => None
+					})
+				}).collect::<Result<_, WldError>>()?
+		})
+	}).collect()
+}
+
+pub fn read_signs<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<Sign>, WldError> {
+	(0..r.u16()?).map(|_| Ok(Sign { text: r.string()?, x: r.u32()?, y: r.u32()? })).collect()
+}
+
+pub fn read_npcs<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<NPC>, WldError> {
+	let version = r.version;
 	let mut npcs = vec![];
-	let shimmered: Vec<usize> = (0..r.u32()).map(|_| r.u32() as usize).collect();
+	// Shimmered town npcs are stored as a list of npc types, not positions in the npc list
+	let shimmered: Vec<u32> = r.since(268, |r| (0..r.u32()?).map(|_| r.u32()).collect())?;
 	
-	while r.bool() {
+	while r.bool()? {
 		npcs.push(NPC {
-			id: r.u32(),
-			name: r.string(),
-			x: r.f32(),
-			y: r.f32(),
-			homeless: r.bool(),
-			home_x: r.u32(),
-			home_y: r.u32(),
-			variation_index: match r.bool() {
-				true => r.u32(),
+			id: read_npc_id(r)?,
+			name: r.string()?,
+			x: r.f32()?,
+			y: r.f32()?,
+			homeless: r.bool()?,
+			home_x: r.u32()?,
+			home_y: r.u32()?,
+			variation_index: match version >= 213 && r.bool()? {
+				true => r.u32()?,
 				false => 0
 			},
 			shimmered: false,
@@ -419,15 +624,15 @@ pub fn read(buffer: Vec<u8>) -> Wld {
 		});
 	}
 	
-	for i in shimmered {
-		npcs[i].shimmered = true;
+	for npc in npcs.iter_mut() {
+		npc.shimmered = shimmered.contains(&npc.id);
 	}
 	
-	while r.bool() {
+	while version >= 140 && r.bool()? {
 		npcs.push(NPC {
-			id: r.u32(),
-			x: r.f32(),
-			y: r.f32(),
+			id: read_npc_id(r)?,
+			x: r.f32()?,
+			y: r.f32()?,
 			is_pillar: true,
 			name: String::new(),
 			homeless: false,
@@ -438,110 +643,155 @@ pub fn read(buffer: Vec<u8>) -> Wld {
 		});
 	}
 	
-	
-	r.check_position(5).unwrap();
-	println!("{name}: Reading misc");
-	
-	
-	let tile_entities = (0..r.u32()).map(|_| {
-		let t = r.u8();
-		TileEntity {
-			id: r.u32(),
-			x: r.u16(),
-			y: r.u16(),
+	Ok(npcs)
+}
+
+pub fn read_tile_entities<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<TileEntity>, WldError> {
+	(0..r.u32()?).map(|_| {
+		let offset = r.position();
+		let t = r.u8()?;
+		Ok(TileEntity {
+			id: r.u32()?,
+			x: r.u16()?,
+			y: r.u16()?,
 			info: match t {
-				0 => TileEntityInfo::TargetDummy(r.u16()),
+				0 => TileEntityInfo::TargetDummy(r.u16()?),
 				1 => TileEntityInfo::ItemFrame({
-					let id = r.u16() as u32;
-					let prefix = r.u8();
-					let count = r.u16();
-					(count > 0).then(|| Item { id, prefix, count })
+					let id = r.u16()? as u32;
+					let prefix = r.u8()?;
+					let count = r.u16()?;
+					(count > 0).then_some(Item { id, prefix, count })
 				}),
-				2 => TileEntityInfo::LogicSensor(r.u8(), r.bool()),
+				2 => TileEntityInfo::LogicSensor(r.u8()?, r.bool()?),
 				3 => TileEntityInfo::Mannequin({
 					let mut buffer = [None; 16];
-					let slots = r.u16();
-					let mut i = 0;
-					for item in (0..16).map(|i| ((slots >> i) & 1 == 1).then(
-						|| Item { id: r.u16() as u32, prefix: r.u8(), count: r.u16() }
-					)) {
-						buffer[i] = item;
-						i += 1;
-					};
+					let slots = r.u16()?;
+					for (i, slot) in buffer.iter_mut().enumerate() {
+						if (slots >> i) & 1 == 1 {
+							*slot = Some(Item { id: r.u16()? as u32, prefix: r.u8()?, count: r.u16()? });
+						}
+					}
 					buffer
 				}),
 				4 => TileEntityInfo::WeaponRack({
-					let id = r.u16() as u32;
-					let prefix = r.u8();
-					let count = r.u16();
-					(count > 0).then(|| Item { id, prefix, count })
+					let id = r.u16()? as u32;
+					let prefix = r.u8()?;
+					let count = r.u16()?;
+					(count > 0).then_some(Item { id, prefix, count })
 				}),
 				5 => TileEntityInfo::HatRack({
 					let mut buffer = [None; 4];
-					let slots = r.u8();
-					let mut i = 0;
-					for item in (0..4).map(|i| ((slots >> i) & 1 == 1).then(
-						|| Item { id: r.u16() as u32, prefix: r.u8(), count: r.u16() }
-					)) {
-						buffer[i] = item;
-						i += 1;
-					};
+					let slots = r.u8()?;
+					for (i, slot) in buffer.iter_mut().enumerate() {
+						if (slots >> i) & 1 == 1 {
+							*slot = Some(Item { id: r.u16()? as u32, prefix: r.u8()?, count: r.u16()? });
+						}
+					}
 					buffer
 				}),
 				6 => TileEntityInfo::FoodPlatter({
-					let id = r.u16() as u32;
-					let prefix = r.u8();
-					let count = r.u16();
-					(count > 0).then(|| Item { id, prefix, count })
+					let id = r.u16()? as u32;
+					let prefix = r.u8()?;
+					let count = r.u16()?;
+					(count > 0).then_some(Item { id, prefix, count })
 				}),
 				7 => TileEntityInfo::Pylon,
-				_ => panic!()
+				kind => return Err(WldError::UnknownTileEntity { kind, offset })
 			}
-		}
-	}).collect();
-	
-	r.check_position(6).unwrap();
-	
-	
-	let weighted_pressure_plates = (0..r.u32()).map(|_| (r.u32(), r.u32())).collect();
-	
-	r.check_position(7).unwrap();
-	
-	
-	let npc_rooms = (0..r.u32()).map(|_| NPCRoom { id: r.u32(), x: r.u32(), y: r.u32() }).collect();
-	
-	r.check_position(8).unwrap();
-	
-	
-	let bestiary_kills = (0..r.u32()).map(|_| (r.string(), r.u32())).collect();
-	let bestiary_sights = (0..r.u32()).map(|_| r.string()).collect();
-	let bestiary_chats = (0..r.u32()).map(|_| r.string()).collect();
-	
-	r.check_position(9).unwrap();
-	
-	
+		})
+	}).collect()
+}
+
+pub fn read_pressure_plates<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<(u32, u32)>, WldError> {
+	(0..r.u32()?).map(|_| Ok((r.u32()?, r.u32()?))).collect()
+}
+
+pub fn read_town_manager<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<NPCRoom>, WldError> {
+	(0..r.u32()?).map(|_| Ok(NPCRoom { id: r.u32()?, x: r.u32()?, y: r.u32()? })).collect()
+}
+
+pub type Bestiary = (Vec<(String, u32)>, Vec<String>, Vec<String>);
+
+pub fn read_bestiary<R: Read + Seek>(r: &mut Reader<R>) -> Result<Bestiary, WldError> {
+	Ok((
+		(0..r.u32()?).map(|_| Ok((r.string()?, r.u32()?))).collect::<Result<_, WldError>>()?,
+		(0..r.u32()?).map(|_| r.string()).collect::<Result<_, WldError>>()?,
+		(0..r.u32()?).map(|_| r.string()).collect::<Result<_, WldError>>()?
+	))
+}
+
+pub fn read_creative_powers<R: Read + Seek>(r: &mut Reader<R>) -> Result<Vec<CreativePower>, WldError> {
 	let mut creative_powers = vec![];
-	while r.bool() {
-		creative_powers.push(match r.u16() {
-			0 => CreativePower::FreezeTime(r.bool()),
-			8 => CreativePower::TimeRate(r.f32()),
-			9 => CreativePower::FreezeWeather(r.bool()),
-			10 => CreativePower::FreezeWind(r.bool()),
-			12 => CreativePower::DifficultySlider(r.f32()),
-			13 => CreativePower::FreezeSpread(r.bool()),
-			_ => panic!()
+	while r.bool()? {
+		let offset = r.position();
+		creative_powers.push(match r.u16()? {
+			0 => CreativePower::FreezeTime(r.bool()?),
+			8 => CreativePower::TimeRate(r.f32()?),
+			9 => CreativePower::FreezeWeather(r.bool()?),
+			10 => CreativePower::FreezeWind(r.bool()?),
+			12 => CreativePower::DifficultySlider(r.f32()?),
+			13 => CreativePower::FreezeSpread(r.bool()?),
+			id => return Err(WldError::UnknownCreativePower { id, offset })
 		});
 	}
+	Ok(creative_powers)
+}
+
+
+// An open world file whose header has been read, with the rest of the sections decoded on demand
+pub struct WldFile<R: Read + Seek> {
+	reader: Reader<R>,
+	pub header: Wld
+}
+
+impl WldFile<BufReader<File>> {
+	pub fn open(path: &str) -> Result<Self, WldError> {
+		Self::new(BufReader::new(File::open(path)?))
+	}
+}
+
+impl<R: Read + Seek> WldFile<R> {
+	pub fn new(inner: R) -> Result<Self, WldError> {
+		let mut reader = Reader::new(inner);
+		let header = read_header(&mut reader)?;
+		Ok(Self { reader, header })
+	}
 	
-	r.check_position(10).unwrap();
-	
-	if !r.bool() || r.string() != name || r.u32() != id { panic!() }
-	println!("{name}: Done");
-	
+	fn section<T: Default>(&mut self, section: Section, version: u32, f: impl FnOnce(&mut Reader<R>, &Wld) -> Result<T, WldError>) -> Result<T, WldError> {
+		match self.header.version >= version {
+			true => {
+				self.reader.seek(section)?;
+				f(&mut self.reader, &self.header)
+			}
+			false => Ok(T::default())
+		}
+	}
 	
-	Wld {
-		version, magic_number, file_type, revision, is_favorite, importance, name, seed, world_gen_version, guid, id, left, right, top, bottom, height, width, gamemode, drunk_world, good_world, tenth_anniversary_world, dont_starve_world, notthebees_world, remix_world, notraps_world, zenith_world, creation_time, moon_type, tree_type_xcoords, tree_types, cave_bg_xcoords, cave_bgs, ice_bg, jungle_bg, hell_bg, spawn_x, spawn_y, world_surface_y, rock_layer_y, game_time, is_day, moon_phase, blood_moon, eclipse, dungeon_x, dungeon_y, crimson_world, killed_eye_of_cthulu, killed_eater_of_worlds, killed_skeletron, killed_queen_bee, killed_the_destroyer, killed_the_twins, killed_skeletron_prime, killed_any_hardmode_boss, killed_plantera, killed_golem, killed_slime_king, saved_goblin_tinkerer, saved_wizard, saved_mechanic, defeated_goblin_invasion, killed_clown, defeated_frost_legion, defeated_pirates, broken_shadow_orb, meteor_spawned, shadow_orbs_broken_mod3, altars_smashed, hard_mode, after_party_of_doom, goblin_invasion_delay, goblin_invasion_size, goblin_invasion_type, goblin_invasion_x, slime_rain_time, sundial_cooldown, is_raining, rain_time, max_rain, tier_1_ore_id, tier_2_ore_id, tier_3_ore_id, tree_style, corruption_style, jungle_style, snow_style, hallow_style, crimson_style, desert_style, ocean_style, cloud_bg, num_clouds, wind_speed, angler_finishers, saved_angler, angler_quest, saved_stylist, saved_tax_collector, saved_golfer, invasion_size_start, temp_cultist_delay, kill_counts, fast_forward_time, downed_fishron, downed_martians, downed_ancient_cultist, downed_moonlord, downed_halloween_king, downed_halloween_tree, downed_christmas_ice_queen, downed_christmas_santank, downed_christmas_tree, downed_tower_solar, downed_tower_vortex, downed_tower_nebula, downed_tower_stardust, tower_active_solar, tower_active_vortex, tower_active_nebula, tower_active_stardust, lunar_apocalypse_is_up, party_manual, party_genuine, party_cooldown, party_celebrating_npcs, sandstorm_happening, sandstorm_time_left, sandstorm_severity, sandstorm_intended_severity, saved_bartender, downed_invasion_tier_1, downed_invasion_tier_2, downed_invasion_tier_3, mushroom_bg, underworld_bg, tree2_bg, tree3_bg, tree4_bg, combat_book_was_used, lantern_night_stuff, lantern_night_more_stuff, tree_top_stuff, force_halloween_for_today, force_xmas_for_today, copper_tier, iron_tier, silver_tier, gold_tier, bought_cat, bought_dog, bought_bunny, downed_empress_of_light, downed_queen_slime, downed_deerclops, unlocked_slime_blue_spawn, unlocked_merchant_spawn, unlocked_demolitionist_spawn, unlocked_party_girl_spawn, unlocked_dye_trader_spawn, unlocked_truffle_spawn, unlocked_arms_dealer_spawn, unlocked_nurse_spawn, unlocked_princess_spawn, combat_book_v2_was_used, peddlers_satched_was_used, unlocked_slime_green_spawn, unlocked_slime_old_spawn, unlocked_slime_purple_spawn, unlocked_slime_rainbow_spawn, unlocked_slime_red_spawn, unlocked_slime_yellow_spawn, unlocked_slime_copper_spawn, fast_forward_to_dusk, moondial_cooldown,
-		
-		tile_set, tiles, chests, signs, npcs, tile_entities, weighted_pressure_plates, npc_rooms, bestiary_kills, bestiary_sights, bestiary_chats, creative_powers
+	pub fn tiles(&mut self) -> Result<TileGrid, WldError> {
+		self.section(Section::Tiles, 0, |r, wld| read_tiles(r, wld.width, wld.height, &wld.importance, wld.version))
+	}
+	pub fn chests(&mut self) -> Result<Vec<Chest>, WldError> {
+		self.section(Section::Chests, 0, |r, _| read_chests(r))
 	}
-}
\ No newline at end of file
+	pub fn signs(&mut self) -> Result<Vec<Sign>, WldError> {
+		self.section(Section::Signs, 0, |r, _| read_signs(r))
+	}
+	pub fn npcs(&mut self) -> Result<Vec<NPC>, WldError> {
+		self.section(Section::NPCs, 0, |r, _| read_npcs(r))
+	}
+	pub fn tile_entities(&mut self) -> Result<Vec<TileEntity>, WldError> {
+		self.section(Section::TileEntities, TILE_ENTITIES_VERSION, |r, _| read_tile_entities(r))
+	}
+	pub fn weighted_pressure_plates(&mut self) -> Result<Vec<(u32, u32)>, WldError> {
+		self.section(Section::PressurePlates, PRESSURE_PLATES_VERSION, |r, _| read_pressure_plates(r))
+	}
+	pub fn npc_rooms(&mut self) -> Result<Vec<NPCRoom>, WldError> {
+		self.section(Section::TownManager, TOWN_MANAGER_VERSION, |r, _| read_town_manager(r))
+	}
+	pub fn bestiary(&mut self) -> Result<Bestiary, WldError> {
+		self.section(Section::Bestiary, BESTIARY_VERSION, |r, _| read_bestiary(r))
+	}
+	pub fn creative_powers(&mut self) -> Result<Vec<CreativePower>, WldError> {
+		self.section(Section::CreativePowers, CREATIVE_POWERS_VERSION, |r, _| read_creative_powers(r))
+	}
+}
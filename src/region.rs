@@ -0,0 +1,142 @@
+use crate::{structs::{Chest, Sign, TileEntity, TileEntityInfo}, tiles::TileGrid, transform::furniture, wld::Wld};
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct Region {
+	pub x: u32,
+	pub y: u32,
+	pub width: u32,
+	pub height: u32
+}
+
+impl Region {
+	pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+		Self { x, y, width, height }
+	}
+	
+	// Saturating so regions reaching past u32::MAX end at the last coordinate instead of overflowing
+	pub fn right(&self) -> u32 {
+		self.x.saturating_add(self.width)
+	}
+	pub fn bottom(&self) -> u32 {
+		self.y.saturating_add(self.height)
+	}
+	pub fn contains(&self, x: u32, y: u32) -> bool {
+		x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.width == 0 || self.height == 0
+	}
+	
+	// The part of this region inside a width x height world
+	pub fn clamp(&self, width: u32, height: u32) -> Region {
+		let x = self.x.min(width);
+		let y = self.y.min(height);
+		Region { x, y, width: self.right().min(width) - x, height: self.bottom().min(height) - y }
+	}
+}
+
+// A rectangle of tiles and everything anchored inside it, with coordinates relative to its top left corner
+#[derive(Debug, PartialEq, Clone)]
+pub struct Clipboard {
+	pub tiles: TileGrid,
+	pub chests: Vec<Chest>,
+	pub signs: Vec<Sign>,
+	pub tile_entities: Vec<TileEntity>,
+	pub weighted_pressure_plates: Vec<(u32, u32)>
+}
+
+impl Clipboard {
+	pub fn width(&self) -> u32 {
+		self.tiles.width()
+	}
+	pub fn height(&self) -> u32 {
+		self.tiles.height()
+	}
+}
+
+impl Wld {
+	// Copies everything inside `region`, the part outside the world is ignored. Furniture cut by the border is copied as is
+	pub fn copy(&self, region: Region) -> Clipboard {
+		let region = region.clamp(self.tiles.width(), self.tiles.height());
+		let (x, y) = (region.x, region.y);
+		Clipboard {
+			tiles: self.tiles.copy(region),
+			chests: self.chests.iter().filter(|c| region.contains(c.x, c.y)).map(|c| Chest { x: c.x - x, y: c.y - y, ..c.clone() }).collect(),
+			signs: self.signs.iter().filter(|s| region.contains(s.x, s.y)).map(|s| Sign { x: s.x - x, y: s.y - y, ..s.clone() }).collect(),
+			tile_entities: self.tile_entities.iter().filter(|e| region.contains(e.x as u32, e.y as u32)).map(|e| TileEntity { x: e.x - x as u16, y: e.y - y as u16, ..*e }).collect(),
+			weighted_pressure_plates: self.weighted_pressure_plates.iter().filter(|p| region.contains(p.0, p.1)).map(|p| (p.0 - x, p.1 - y)).collect()
+		}
+	}
+	
+	// Pastes with the clipboard's top left corner at (x, y). Whatever covers a cell whose tile changes, or is anchored where the clipboard
+	// brings its own, is replaced. Pasted tile entities get fresh ids and anything landing outside the world is dropped. Returns the area written to
+	pub fn paste(&mut self, clipboard: &Clipboard, x: i64, y: i64) -> Region {
+		let (width, height) = (self.tiles.width() as i64, self.tiles.height() as i64);
+		let left = x.clamp(0, width);
+		let top = y.clamp(0, height);
+		let target = Region::new(left as u32, top as u32, ((x + clipboard.width() as i64).clamp(0, width) - left) as u32, ((y + clipboard.height() as i64).clamp(0, height) - top) as u32);
+		
+		// Checked before pasting since it compares against the tiles being overwritten
+		let tiles = &self.tiles;
+		let furniture = furniture();
+		let size = |px: u32, py: u32| -> (u32, u32) {
+			let block = tiles.get(px, py).block;
+			block.and_then(|block| furniture.get(&block.id)).map(|&(w, h, _)| (w, h)).unwrap_or((1, 1))
+		};
+		let inside = |px: u32, py: u32| ((px as i64 - x) as u32, (py as i64 - y) as u32);
+		// Every cell of the furniture is checked, the anchor may be outside the target while the rest of it is overwritten
+		let replaced = |px: u32, py: u32, (w, h): (u32, u32), taken: &[(u32, u32)]| {
+			let changed = (px..px + w).any(|cx| (py..py + h).any(|cy| target.contains(cx, cy) && {
+				let (ix, iy) = inside(cx, cy);
+				clipboard.tiles.get(ix, iy) != tiles.get(cx, cy)
+			}));
+			changed || (target.contains(px, py) && taken.contains(&inside(px, py)))
+		};
+		let chests: Vec<(u32, u32)> = clipboard.chests.iter().map(|c| (c.x, c.y)).collect();
+		let signs: Vec<(u32, u32)> = clipboard.signs.iter().map(|s| (s.x, s.y)).collect();
+		let tile_entities: Vec<(u32, u32)> = clipboard.tile_entities.iter().map(|e| (e.x as u32, e.y as u32)).collect();
+		self.chests.retain(|c| !replaced(c.x, c.y, size(c.x, c.y), &chests));
+		self.signs.retain(|s| !replaced(s.x, s.y, size(s.x, s.y), &signs));
+		self.tile_entities.retain(|e| {
+			let (ex, ey) = (e.x as u32, e.y as u32);
+			let size = match e.info {
+				TileEntityInfo::Pylon => (3, 4),
+				_ => size(ex, ey)
+			};
+			!replaced(ex, ey, size, &tile_entities)
+		});
+		self.weighted_pressure_plates.retain(|p| !replaced(p.0, p.1, (1, 1), &clipboard.weighted_pressure_plates));
+		
+		self.journal.record_region(&self.tiles, target);
+		self.tiles.paste(&clipboard.tiles, x, y);
+		
+		let moved = |cx: u32, cy: u32| {
+			let (nx, ny) = (x + cx as i64, y + cy as i64);
+			(nx >= 0 && ny >= 0 && nx < width && ny < height).then_some((nx as u32, ny as u32))
+		};
+		for chest in &clipboard.chests {
+			if let Some((x, y)) = moved(chest.x, chest.y) {
+				self.chests.push(Chest { x, y, ..chest.clone() });
+			}
+		}
+		for sign in &clipboard.signs {
+			if let Some((x, y)) = moved(sign.x, sign.y) {
+				self.signs.push(Sign { x, y, ..sign.clone() });
+			}
+		}
+		let mut id = self.tile_entities.iter().map(|e| e.id + 1).max().unwrap_or(0);
+		for entity in &clipboard.tile_entities {
+			if let Some((x, y)) = moved(entity.x as u32, entity.y as u32) {
+				self.tile_entities.push(TileEntity { id, x: x as u16, y: y as u16, info: entity.info });
+				id += 1;
+			}
+		}
+		for &(px, py) in &clipboard.weighted_pressure_plates {
+			if let Some(p) = moved(px, py) {
+				self.weighted_pressure_plates.push(p);
+			}
+		}
+		
+		target
+	}
+}
@@ -0,0 +1,123 @@
+use crate::{error::{Section, WldError}, region::Region, structs::Tile, tiles::TileGrid, wld::Wld};
+
+// The largest world the game will load
+pub const MAX_WIDTH: u32 = 8400;
+pub const MAX_HEIGHT: u32 = 2400;
+
+// Which part of the old world stays in place when resizing
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Anchor {
+	TopLeft,
+	Top,
+	TopRight,
+	Left,
+	Center,
+	Right,
+	BottomLeft,
+	Bottom,
+	BottomRight
+}
+
+impl Anchor {
+	// How far the old tiles move when the size changes from `old` to `new`
+	fn offset(self, old: (u32, u32), new: (u32, u32)) -> (i64, i64) {
+		let dx = new.0 as i64 - old.0 as i64;
+		let dy = new.1 as i64 - old.1 as i64;
+		let x = match self {
+			Anchor::TopLeft | Anchor::Left | Anchor::BottomLeft => 0,
+			Anchor::Top | Anchor::Center | Anchor::Bottom => dx / 2,
+			Anchor::TopRight | Anchor::Right | Anchor::BottomRight => dx
+		};
+		let y = match self {
+			Anchor::TopLeft | Anchor::Top | Anchor::TopRight => 0,
+			Anchor::Left | Anchor::Center | Anchor::Right => dy / 2,
+			Anchor::BottomLeft | Anchor::Bottom | Anchor::BottomRight => dy
+		};
+		(x, y)
+	}
+}
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+impl Wld {
+	// New space is filled with air, anything pushed out of the world is dropped
+	pub fn resize(&mut self, width: u32, height: u32, anchor: Anchor) -> Result<(), WldError> {
+		if width > MAX_WIDTH || height > MAX_HEIGHT {
+			return Err(WldError::WorldTooLarge { width, height });
+		}
+		self.resize_oversized(width, height, anchor);
+		Ok(())
+	}
+	
+	// Same as `resize` without the size limit, for worlds that are only meant for tools
+	pub fn resize_oversized(&mut self, width: u32, height: u32, anchor: Anchor) {
+		let (dx, dy) = anchor.offset((self.width, self.height), (width, height));
+		self.reframe(width, height, dx, dy);
+	}
+	
+	// Keeps only the part of the world inside `region`, which becomes the whole world
+	pub fn crop(&mut self, region: Region) {
+		let region = region.clamp(self.width, self.height);
+		self.reframe(region.width, region.height, -(region.x as i64), -(region.y as i64));
+	}
+	
+	// Makes the world `width` x `height` with the old (x, y) moved to (x + dx, y + dy)
+	pub(crate) fn reframe(&mut self, width: u32, height: u32, dx: i64, dy: i64) {
+		let mut tiles = TileGrid::new(width, height, AIR);
+		tiles.paste(&self.tiles, dx, dy);
+		tiles.compact();
+		self.tiles = tiles;
+		self.journal.clear();
+		
+		let moved = |x: u32, y: u32| {
+			let (x, y) = (x as i64 + dx, y as i64 + dy);
+			(x >= 0 && y >= 0 && x < width as i64 && y < height as i64).then_some((x as u32, y as u32))
+		};
+		let clamped = |v: u32, d: i64, max: u32| (v as i64 + d).clamp(0, max.saturating_sub(1) as i64) as u32;
+		
+		self.width = width;
+		self.height = height;
+		self.right = self.left + width * 16;
+		self.bottom = self.top + height * 16;
+		self.world_surface_y = (self.world_surface_y + dy as f64).clamp(0.0, height as f64);
+		self.rock_layer_y = (self.rock_layer_y + dy as f64).clamp(0.0, height as f64);
+		self.spawn_x = clamped(self.spawn_x, dx, width);
+		self.spawn_y = clamped(self.spawn_y, dy, height);
+		self.dungeon_x = clamped(self.dungeon_x, dx, width);
+		self.dungeon_y = clamped(self.dungeon_y, dy, height);
+		for x in self.tree_type_xcoords.iter_mut().chain(self.cave_bg_xcoords.iter_mut()) {
+			*x = (*x as i64 + dx).clamp(0, width as i64) as u32;
+		}
+		
+		self.chests = self.chests.drain(..).filter_map(|mut c| moved(c.x, c.y).map(|(x, y)| { c.x = x; c.y = y; c })).collect();
+		self.signs = self.signs.drain(..).filter_map(|mut s| moved(s.x, s.y).map(|(x, y)| { s.x = x; s.y = y; s })).collect();
+		self.tile_entities = self.tile_entities.drain(..).filter_map(|mut e| moved(e.x as u32, e.y as u32).map(|(x, y)| { e.x = x as u16; e.y = y as u16; e })).collect();
+		self.weighted_pressure_plates = self.weighted_pressure_plates.drain(..).filter_map(|(x, y)| moved(x, y)).collect();
+		
+		// NPCs are in pixels, the rest in tiles
+		let (pixel_width, pixel_height) = (width as f32 * 16.0, height as f32 * 16.0);
+		self.npcs.retain_mut(|npc| {
+			npc.x += dx as f32 * 16.0;
+			npc.y += dy as f32 * 16.0;
+			npc.x >= 0.0 && npc.y >= 0.0 && npc.x < pixel_width && npc.y < pixel_height
+		});
+		for npc in self.npcs.iter_mut().filter(|npc| !npc.homeless) {
+			match moved(npc.home_x, npc.home_y) {
+				Some((x, y)) => {
+					npc.home_x = x;
+					npc.home_y = y;
+				}
+				None => npc.homeless = true
+			}
+		}
+		// Rooms of npcs that aren't around stay, the game keeps them for when the npc moves back in
+		self.npc_rooms = self.npc_rooms.drain(..).filter_map(|mut room| moved(room.x, room.y).map(|(x, y)| { room.x = x; room.y = y; room })).collect();
+		
+		// Bytes this crate can't read from sections laid out by position only fit the old size
+		for section in [Section::Tiles, Section::Chests, Section::Signs, Section::NPCs, Section::TileEntities, Section::PressurePlates, Section::TownManager] {
+			if let Some(data) = self.unknown_data.get_mut(section.index()) {
+				data.clear();
+			}
+		}
+	}
+}
@@ -0,0 +1,85 @@
+use std::{fs::{self, File}, io::{self, Write}, path::{Path, PathBuf}, time::{SystemTime, UNIX_EPOCH}};
+
+pub struct SaveOptions {
+	// Keep the previous file as `<name>.wld.bak` like the game does
+	pub backup: bool,
+	// How many `<name>.wld.<unix time>.bak` copies to keep, 0 disables them
+	pub timestamped_backups: usize
+}
+
+impl Default for SaveOptions {
+	fn default() -> Self {
+		Self { backup: true, timestamped_backups: 0 }
+	}
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+	let mut name = path.file_name().unwrap_or_default().to_os_string();
+	name.push(suffix);
+	path.with_file_name(name)
+}
+
+// Writes to a temp file next to `path`, syncs it and renames it over the original so a crash never leaves a half written world
+pub fn save(path: &Path, data: &[u8], options: &SaveOptions) -> io::Result<()> {
+	let temp = sibling(path, ".tmp");
+	let result = File::create(&temp).and_then(|mut file| {
+		file.write_all(data)?;
+		file.sync_all()
+	});
+	if let Err(e) = result {
+		let _ = fs::remove_file(&temp);
+		return Err(e);
+	}
+	
+	if path.exists() {
+		if options.backup {
+			fs::copy(path, sibling(path, ".bak"))?;
+		}
+		if options.timestamped_backups > 0 {
+			let time = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+			fs::copy(path, sibling(path, &format!(".{}.bak", time)))?;
+			prune_backups(path, options.timestamped_backups)?;
+		}
+	}
+	
+	fs::rename(&temp, path)?;
+	sync_dir(path)
+}
+
+fn prune_backups(path: &Path, keep: usize) -> io::Result<()> {
+	let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+	let dir = match path.parent() {
+		Some(dir) if !dir.as_os_str().is_empty() => dir,
+		_ => Path::new(".")
+	};
+	
+	let mut backups = vec![];
+	for entry in fs::read_dir(dir)? {
+		let entry = entry?;
+		let file_name = entry.file_name().to_string_lossy().into_owned();
+		let time = file_name.strip_prefix(&name).and_then(|s| s.strip_prefix('.')).and_then(|s| s.strip_suffix(".bak"));
+		if let Some(time) = time.and_then(|t| t.parse::<u64>().ok()) {
+			backups.push((time, entry.path()));
+		}
+	}
+	
+	backups.sort();
+	let excess = backups.len().saturating_sub(keep);
+	for (_, backup) in backups.into_iter().take(excess) {
+		fs::remove_file(backup)?;
+	}
+	Ok(())
+}
+
+#[cfg(unix)]
+fn sync_dir(path: &Path) -> io::Result<()> {
+	match path.parent() {
+		Some(dir) if !dir.as_os_str().is_empty() => File::open(dir)?.sync_all(),
+		_ => File::open(".")?.sync_all()
+	}
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_path: &Path) -> io::Result<()> {
+	Ok(())
+}
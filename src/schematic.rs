@@ -0,0 +1,82 @@
+use std::{fs::File, io::{BufReader, Cursor, Read, Seek}, path::Path};
+
+use crate::{error::{Section, WldError}, ids::default_importance, read::{self, Reader}, region::Clipboard, save::{self, SaveOptions}, version::LATEST_VERSION, write::{self, Writer}};
+
+// A TEdit `.TEditSch` file: name, world version, frame importance and size, then the tiles, chests, signs
+// and tile entities encoded like their world file sections, then the name, version and size again as a footer
+#[derive(Debug, PartialEq, Clone)]
+pub struct Schematic {
+	pub name: String,
+	pub version: u32,
+	pub importance: Vec<bool>,
+	pub clipboard: Clipboard
+}
+
+impl Schematic {
+	pub fn new(name: &str, clipboard: Clipboard) -> Self {
+		Self { name: name.to_string(), version: LATEST_VERSION, importance: default_importance(), clipboard }
+	}
+	
+	pub fn read(path: &str) -> Result<Schematic, WldError> {
+		Self::read_from(BufReader::new(File::open(path)?))
+	}
+	pub fn from_bytes(bytes: &[u8]) -> Result<Schematic, WldError> {
+		Self::read_from(Cursor::new(bytes))
+	}
+	pub fn read_from<R: Read + Seek>(inner: R) -> Result<Schematic, WldError> {
+		let mut r = Reader::new(inner);
+		let name = r.string()?;
+		let version = r.u32()?;
+		r.version = version;
+		let importance = read::read_importance(&mut r)?;
+		let (width, height) = (r.u32()?, r.u32()?);
+		
+		r.section = Section::Tiles;
+		let tiles = read::read_tiles(&mut r, width, height, &importance, version)?;
+		r.section = Section::Chests;
+		let chests = read::read_chests(&mut r)?;
+		r.section = Section::Signs;
+		let signs = read::read_signs(&mut r)?;
+		r.section = Section::TileEntities;
+		let tile_entities = read::read_tile_entities(&mut r)?;
+		
+		r.section = Section::Footer;
+		let offset = r.position();
+		if r.string()? != name || r.u32()? != version || r.u32()? != width || r.u32()? != height {
+			return Err(WldError::FooterMismatch { offset });
+		}
+		
+		Ok(Schematic {
+			name,
+			version,
+			importance,
+			clipboard: Clipboard { tiles, chests, signs, tile_entities, weighted_pressure_plates: vec![] }
+		})
+	}
+	
+	pub fn write(&self, path: &str) -> Result<(), WldError> {
+		save::save(Path::new(path), &self.to_bytes()?, &SaveOptions { backup: false, timestamped_backups: 0 })?;
+		Ok(())
+	}
+	// Weighted pressure plates have no place in the format and are left out
+	pub fn to_bytes(&self) -> Result<Vec<u8>, WldError> {
+		let (width, height) = (self.clipboard.width(), self.clipboard.height());
+		let mut w = Writer { data: vec![], version: self.version, pointers: 0, unknown_data: &[] };
+		
+		w.string(&self.name);
+		w.u32(self.version);
+		write::write_importance(&mut w, &self.importance);
+		w.u32(width);
+		w.u32(height);
+		write::write_tiles(&mut w, &self.clipboard.tiles, &self.importance)?;
+		write::write_chests(&mut w, &self.clipboard.chests)?;
+		write::write_signs(&mut w, &self.clipboard.signs)?;
+		write::write_tile_entities(&mut w, &self.clipboard.tile_entities);
+		
+		w.string(&self.name);
+		w.u32(self.version);
+		w.u32(width);
+		w.u32(height);
+		Ok(w.data)
+	}
+}
@@ -7,6 +7,7 @@ uniform float aspect_ratio;
 uniform vec2 camera_position;
 uniform float zoom;
 uniform vec2 wld_size;
+uniform uint textured_block;
 
 uniform usampler2D tile_data_buffer;
 uniform sampler2D block_texture;
@@ -23,7 +24,7 @@ void main() {
 		return;
 	}
 	
-	if ((tile_data.x & uint(1)) == uint(1) && (tile_data.y & uint(65535)) == uint(21)) {
+	if ((tile_data.x & uint(1)) == uint(1) && (tile_data.y & uint(65535)) == textured_block) {
 		color = texelFetch(block_texture, ivec2(sub_tile_position * 8.0) + ivec2(tile_data.z & uint(65535), tile_data.z >> 16), 0);
 	} else {
 		color = vec4(0.5, 0.5, 0.5, 1.0);
@@ -0,0 +1,81 @@
+use crate::{error::{Section, WldError}, resize::{MAX_HEIGHT, MAX_WIDTH}, structs::{Chest, NPCRoom, Sign, TileEntity}, wld::Wld};
+
+// Where the second world goes relative to the first
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Direction {
+	Left,
+	Right,
+	Above,
+	Below
+}
+
+fn merge_names(a: &[String], b: &[String]) -> Vec<String> {
+	let mut names = a.to_vec();
+	names.extend(b.iter().filter(|name| !a.contains(name)).cloned());
+	names
+}
+
+impl Wld {
+	// Puts `b` next to `a` in a new world. The header, spawn, dungeon, backgrounds and creative powers are `a`'s,
+	// bestiary kills are added up and seen or talked to entries merged. Town NPCs `a` already has are left out of `b`.
+	// Fails when the result is larger than the game can load
+	pub fn stitch(a: &Wld, b: &Wld, direction: Direction) -> Result<Wld, WldError> {
+		for (section, count) in [(Section::Chests, a.chests.len() + b.chests.len()), (Section::Signs, a.signs.len() + b.signs.len())] {
+			if count > u16::MAX as usize {
+				return Err(WldError::TooManyEntries { section, count });
+			}
+		}
+		
+		let (width, height) = match direction {
+			Direction::Left | Direction::Right => (a.width.saturating_add(b.width), a.height.max(b.height)),
+			Direction::Above | Direction::Below => (a.width.max(b.width), a.height.saturating_add(b.height))
+		};
+		if width > MAX_WIDTH || height > MAX_HEIGHT {
+			return Err(WldError::WorldTooLarge { width, height });
+		}
+		let ((ax, ay), (bx, by)) = match direction {
+			Direction::Right => ((0, 0), (a.width, 0)),
+			Direction::Left => ((b.width, 0), (0, 0)),
+			Direction::Below => ((0, 0), (0, a.height)),
+			Direction::Above => ((0, b.height), (0, 0))
+		};
+		
+		let mut w = a.clone();
+		w.reframe(width, height, ax as i64, ay as i64);
+		w.tiles.paste(&b.tiles, bx as i64, by as i64);
+		w.tiles.compact();
+		if b.importance.len() > w.importance.len() {
+			let len = w.importance.len();
+			w.importance.extend_from_slice(&b.importance[len..]);
+		}
+		
+		w.chests.extend(b.chests.iter().map(|c| Chest { x: c.x + bx, y: c.y + by, ..c.clone() }));
+		w.signs.extend(b.signs.iter().map(|s| Sign { x: s.x + bx, y: s.y + by, ..s.clone() }));
+		let first_id = w.tile_entities.iter().map(|e| e.id + 1).max().unwrap_or(0);
+		w.tile_entities.extend(b.tile_entities.iter().zip(first_id..).map(|(e, id)| TileEntity { id, x: e.x + bx as u16, y: e.y + by as u16, info: e.info }));
+		w.weighted_pressure_plates.extend(b.weighted_pressure_plates.iter().map(|&(x, y)| (x + bx, y + by)));
+		
+		// Pillars belong to the first world's event, each town NPC can only be there once
+		let added: Vec<u32> = b.npcs.iter().filter(|npc| !npc.is_pillar && !a.npcs.iter().any(|other| other.id == npc.id)).map(|npc| npc.id).collect();
+		for npc in b.npcs.iter().filter(|npc| added.contains(&npc.id)) {
+			let mut npc = npc.clone();
+			npc.x += bx as f32 * 16.0;
+			npc.y += by as f32 * 16.0;
+			npc.home_x += bx;
+			npc.home_y += by;
+			w.npcs.push(npc);
+		}
+		w.npc_rooms.extend(b.npc_rooms.iter().filter(|room| added.contains(&room.id)).map(|room| NPCRoom { id: room.id, x: room.x + bx, y: room.y + by }));
+		
+		for (name, kills) in &b.bestiary_kills {
+			match w.bestiary_kills.iter_mut().find(|(other, _)| other == name) {
+				Some(entry) => entry.1 = entry.1.saturating_add(*kills),
+				None => w.bestiary_kills.push((name.clone(), *kills))
+			}
+		}
+		w.bestiary_sights = merge_names(&a.bestiary_sights, &b.bestiary_sights);
+		w.bestiary_chats = merge_names(&a.bestiary_chats, &b.bestiary_chats);
+		
+		Ok(w)
+	}
+}
@@ -1,4 +1,4 @@
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Tile {
 	pub block: Option<Block>,
 	pub wall: Option<Wall>,
@@ -10,7 +10,7 @@ pub struct Tile {
 	pub actuator: bool
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Block {
 	pub id: u16,
 	pub color: Option<u8>,
@@ -19,7 +19,7 @@ pub struct Block {
 	pub slope: Slope
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum Slope {
 	Full,
 	Half,
@@ -29,19 +29,19 @@ pub enum Slope {
 	UpperRight
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Wall {
 	pub id: u16,
 	pub color: Option<u8>
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Liquid {
 	pub kind: LiquidType,
 	pub amount: u8
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum LiquidType {
 	Water,
 	Lava,
@@ -51,7 +51,7 @@ pub enum LiquidType {
 
 
 
-#[derive(Eq, PartialEq, Hash, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct Chest {
 	pub name: String,
 	pub x: u32,
@@ -59,7 +59,7 @@ pub struct Chest {
 	pub items: Vec<Option<Item>>
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Item {
 	pub id: u32,
 	pub prefix: u8,
@@ -68,7 +68,7 @@ pub struct Item {
 
 
 
-#[derive(Eq, PartialEq, Hash, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct Sign {
 	pub x: u32,
 	pub y: u32,
@@ -77,7 +77,7 @@ pub struct Sign {
 
 
 
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct NPC {
 	pub id: u32,
 	pub name: String,
@@ -93,7 +93,7 @@ pub struct NPC {
 
 
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct TileEntity {
 	pub id: u32,
 	pub x: u16,
@@ -101,7 +101,7 @@ pub struct TileEntity {
 	pub info: TileEntityInfo
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum TileEntityInfo {
 	TargetDummy(u16),
 	ItemFrame(Option<Item>),
@@ -115,7 +115,7 @@ pub enum TileEntityInfo {
 
 
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub struct NPCRoom {
 	pub id: u32,
 	pub x: u32,
@@ -124,6 +124,7 @@ pub struct NPCRoom {
 
 
 
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub enum CreativePower {
 	FreezeTime(bool),
 	TimeRate(f32),
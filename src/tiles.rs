@@ -0,0 +1,177 @@
+use std::fmt;
+
+use hashbrown::HashMap;
+
+use crate::{region::Region, structs::Tile};
+
+// Cells are stored column-major like in the world file, each one an index into a palette of unique tiles
+#[derive(Clone, Default)]
+pub struct TileGrid {
+	width: u32,
+	height: u32,
+	palette: Vec<Tile>,
+	lookup: HashMap<Tile, u32>,
+	cells: Vec<u32>
+}
+
+impl TileGrid {
+	pub fn new(width: u32, height: u32, tile: Tile) -> Self {
+		let mut grid = Self::empty(width, height);
+		let i = grid.intern(tile);
+		grid.cells = vec![i; width as usize * height as usize];
+		grid
+	}
+	
+	pub(crate) fn empty(width: u32, height: u32) -> Self {
+		Self {
+			width,
+			height,
+			palette: vec![],
+			lookup: HashMap::new(),
+			cells: Vec::with_capacity(usize::min(width as usize * height as usize, 1 << 24))
+		}
+	}
+	
+	pub fn width(&self) -> u32 {
+		self.width
+	}
+	pub fn height(&self) -> u32 {
+		self.height
+	}
+	pub fn palette(&self) -> &[Tile] {
+		&self.palette
+	}
+	
+	pub fn get(&self, x: u32, y: u32) -> &Tile {
+		&self.palette[self.index(x, y) as usize]
+	}
+	pub fn set(&mut self, x: u32, y: u32, tile: Tile) {
+		let i = self.intern(tile);
+		let p = self.cell(x, y);
+		self.cells[p] = i;
+	}
+	
+	// Palette index of the tile at (x, y), equal indices always mean equal tiles
+	pub fn index(&self, x: u32, y: u32) -> u32 {
+		self.cells[self.cell(x, y)]
+	}
+	pub fn column(&self, x: u32) -> &[u32] {
+		let start = x as usize * self.height as usize;
+		&self.cells[start..start + self.height as usize]
+	}
+	
+	pub fn intern(&mut self, tile: Tile) -> u32 {
+		match self.lookup.get(&tile) {
+			Some(&i) => i,
+			None => {
+				let i = self.palette.len() as u32;
+				self.palette.push(tile);
+				self.lookup.insert(tile, i);
+				i
+			}
+		}
+	}
+	
+	// Appends cells in file order, used while reading
+	pub(crate) fn push(&mut self, tile: Tile, count: u32) {
+		let i = self.intern(tile);
+		self.cells.extend(std::iter::repeat_n(i, count as usize));
+	}
+	
+	pub fn used(&self) -> Vec<bool> {
+		let mut used = vec![false; self.palette.len()];
+		for &i in &self.cells {
+			used[i as usize] = true;
+		}
+		used
+	}
+	
+	// Drops palette entries that no cell refers to anymore
+	pub fn compact(&mut self) {
+		let used = self.used();
+		let mut remap = vec![0; self.palette.len()];
+		let mut palette = vec![];
+		for (i, tile) in self.palette.iter().enumerate() {
+			if used[i] {
+				remap[i] = palette.len() as u32;
+				palette.push(*tile);
+			}
+		}
+		for n in self.cells.iter_mut() {
+			*n = remap[*n as usize];
+		}
+		self.lookup = palette.iter().enumerate().map(|(i, tile)| (*tile, i as u32)).collect();
+		self.palette = palette;
+	}
+	
+	// Copies the cells inside `region`, which has to fit in the grid
+	pub fn copy(&self, region: Region) -> TileGrid {
+		assert!(region.right() <= self.width && region.bottom() <= self.height, "{:?} is outside the {}x{} grid", region, self.width, self.height);
+		let mut grid = TileGrid::empty(region.width, region.height);
+		grid.palette = self.palette.clone();
+		grid.lookup = self.lookup.clone();
+		for x in region.x..region.right() {
+			let start = x as usize * self.height as usize + region.y as usize;
+			grid.cells.extend_from_slice(&self.cells[start..start + region.height as usize]);
+		}
+		grid.compact();
+		grid
+	}
+	
+	// Writes `other` with its top left corner at (x, y), cells falling outside the grid are dropped
+	pub fn paste(&mut self, other: &TileGrid, x: i64, y: i64) {
+		let remap: Vec<u32> = other.palette.iter().map(|tile| self.intern(*tile)).collect();
+		for ox in 0..other.width {
+			let tx = x + ox as i64;
+			if tx < 0 || tx >= self.width as i64 { continue }
+			for oy in 0..other.height {
+				let ty = y + oy as i64;
+				if ty < 0 || ty >= self.height as i64 { continue }
+				let p = self.cell(tx as u32, ty as u32);
+				self.cells[p] = remap[other.cells[ox as usize * other.height as usize + oy as usize] as usize];
+			}
+		}
+	}
+	
+	// Moves every cell to `f(x, y)` in a new width x height grid, `f` has to hit each cell exactly once
+	pub fn remap(&self, width: u32, height: u32, f: impl Fn(u32, u32) -> (u32, u32)) -> TileGrid {
+		let mut grid = TileGrid::empty(width, height);
+		grid.palette = self.palette.clone();
+		grid.lookup = self.lookup.clone();
+		grid.cells = vec![0; width as usize * height as usize];
+		for x in 0..self.width {
+			for (y, &i) in self.column(x).iter().enumerate() {
+				let (nx, ny) = f(x, y as u32);
+				let p = grid.cell(nx, ny);
+				grid.cells[p] = i;
+			}
+		}
+		grid
+	}
+	
+	// Replaces every palette entry with `f(tile)`, which changes all cells holding it at once
+	pub fn map(&mut self, mut f: impl FnMut(&Tile) -> Tile) {
+		for tile in self.palette.iter_mut() {
+			*tile = f(tile);
+		}
+		self.lookup = self.palette.iter().enumerate().rev().map(|(i, tile)| (*tile, i as u32)).collect();
+	}
+	
+	fn cell(&self, x: u32, y: u32) -> usize {
+		assert!(x < self.width && y < self.height, "tile ({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+		x as usize * self.height as usize + y as usize
+	}
+}
+
+// Grids are equal when every cell holds the same tile, regardless of palette order
+impl PartialEq for TileGrid {
+	fn eq(&self, other: &Self) -> bool {
+		self.width == other.width && self.height == other.height && self.cells.iter().zip(&other.cells).all(|(&a, &b)| self.palette[a as usize] == other.palette[b as usize])
+	}
+}
+
+impl fmt::Debug for TileGrid {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "TileGrid {{ width: {}, height: {}, palette: {} tiles }}", self.width, self.height, self.palette.len())
+	}
+}
@@ -0,0 +1,240 @@
+use hashbrown::HashMap;
+
+use crate::{ids::TileId, region::Clipboard, structs::{Block, Slope, Tile, TileEntityInfo}};
+
+// Rotations are clockwise
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Transform {
+	FlipHorizontal,
+	FlipVertical,
+	Rotate90,
+	Rotate180,
+	Rotate270
+}
+
+// A block left out of the transformed clipboard because it has no transformed form, with its position before the transform.
+// Its wall, liquid and wires are kept, chests, signs and tile entities anchored on it are dropped
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Rejected {
+	// Half blocks only exist as the bottom half of a tile
+	HalfBlock { x: u32, y: u32 },
+	// Frame important blocks can only be reframed for a horizontal flip of furniture known to mirror cleanly
+	Furniture { x: u32, y: u32, id: u16 }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Frames {
+	// Looks the same mirrored, only the columns are reordered
+	Symmetric,
+	// Has a left and a right facing variant next to each other in the sprite sheet
+	Directional,
+	// 1x1 with frames 22 apart: standing, on a left wall, on a right wall, on a back wall
+	Torch,
+	// Can't be mirrored, listed for its size only
+	Fixed
+}
+
+// Size and framing of multi tile furniture by internal tile name
+static FURNITURE: &[(&str, u32, u32, Frames)] = &[
+	("Torches", 1, 1, Frames::Torch),
+	("Candles", 1, 1, Frames::Symmetric),
+	("PlatinumCandle", 1, 1, Frames::Symmetric),
+	("WaterCandle", 1, 1, Frames::Symmetric),
+	("PeaceCandle", 1, 1, Frames::Symmetric),
+	("ShadowCandle", 1, 1, Frames::Symmetric),
+	("Bottles", 1, 1, Frames::Symmetric),
+	("ClosedDoor", 1, 3, Frames::Symmetric),
+	("OpenDoor", 2, 3, Frames::Directional),
+	("Chairs", 1, 2, Frames::Directional),
+	("Toilets", 1, 2, Frames::Directional),
+	("Tables", 3, 2, Frames::Symmetric),
+	("Tables2", 3, 2, Frames::Symmetric),
+	("WorkBenches", 2, 1, Frames::Symmetric),
+	("Benches", 3, 2, Frames::Symmetric),
+	("Beds", 4, 2, Frames::Directional),
+	("Bathtubs", 4, 2, Frames::Directional),
+	("Containers", 2, 2, Frames::Symmetric),
+	("Containers2", 2, 2, Frames::Symmetric),
+	("FakeContainers", 2, 2, Frames::Symmetric),
+	("FakeContainers2", 2, 2, Frames::Symmetric),
+	("Dressers", 3, 2, Frames::Symmetric),
+	("Bookcases", 3, 4, Frames::Symmetric),
+	("Chandeliers", 3, 3, Frames::Symmetric),
+	("HangingLanterns", 1, 2, Frames::Symmetric),
+	("Banners", 1, 3, Frames::Symmetric),
+	("GrandfatherClocks", 2, 5, Frames::Symmetric),
+	("Signs", 2, 2, Frames::Symmetric),
+	("Tombstones", 2, 2, Frames::Symmetric),
+	("AnnouncementBox", 2, 2, Frames::Fixed),
+	("TatteredWoodSign", 2, 2, Frames::Fixed),
+	("Furnaces", 3, 2, Frames::Symmetric),
+	("Pots", 2, 2, Frames::Symmetric),
+	("ItemFrame", 2, 2, Frames::Symmetric),
+	("TeleportationPylon", 3, 4, Frames::Symmetric),
+	("TargetDummy", 2, 3, Frames::Fixed),
+	("DisplayDoll", 2, 3, Frames::Fixed),
+	("WeaponsRack2", 3, 3, Frames::Fixed),
+	("HatRack", 3, 4, Frames::Fixed),
+	("LogicSensor", 1, 1, Frames::Fixed),
+	("FoodPlatter", 1, 1, Frames::Fixed)
+];
+
+pub(crate) fn furniture() -> HashMap<u16, (u32, u32, Frames)> {
+	FURNITURE.iter().filter_map(|&(name, w, h, frames)| TileId::from_name(name).map(|id| (id.0, (w, h, frames)))).collect()
+}
+
+impl Transform {
+	// Size of a width x height area after the transform
+	pub fn size(self, width: u32, height: u32) -> (u32, u32) {
+		match self {
+			Transform::Rotate90 | Transform::Rotate270 => (height, width),
+			_ => (width, height)
+		}
+	}
+	
+	// Where the tile at (x, y) of a width x height area ends up
+	pub fn point(self, x: u32, y: u32, width: u32, height: u32) -> (u32, u32) {
+		match self {
+			Transform::FlipHorizontal => (width - 1 - x, y),
+			Transform::FlipVertical => (x, height - 1 - y),
+			Transform::Rotate90 => (height - 1 - y, x),
+			Transform::Rotate180 => (width - 1 - x, height - 1 - y),
+			Transform::Rotate270 => (y, width - 1 - x)
+		}
+	}
+	
+	// Slopes are named after a corner of the tile, which moves like any other point. None for half blocks that would leave the bottom
+	pub fn slope(self, slope: Slope) -> Option<Slope> {
+		use crate::structs::Slope::*;
+		Some(match (self, slope) {
+			(_, Full) => Full,
+			(Transform::FlipHorizontal, Half) => Half,
+			(_, Half) => return None,
+			(Transform::FlipHorizontal, LowerLeft) => LowerRight,
+			(Transform::FlipHorizontal, LowerRight) => LowerLeft,
+			(Transform::FlipHorizontal, UpperLeft) => UpperRight,
+			(Transform::FlipHorizontal, UpperRight) => UpperLeft,
+			(Transform::FlipVertical, LowerLeft) => UpperLeft,
+			(Transform::FlipVertical, LowerRight) => UpperRight,
+			(Transform::FlipVertical, UpperLeft) => LowerLeft,
+			(Transform::FlipVertical, UpperRight) => LowerRight,
+			(Transform::Rotate90, LowerLeft) => UpperLeft,
+			(Transform::Rotate90, UpperLeft) => UpperRight,
+			(Transform::Rotate90, UpperRight) => LowerRight,
+			(Transform::Rotate90, LowerRight) => LowerLeft,
+			(Transform::Rotate180, LowerLeft) => UpperRight,
+			(Transform::Rotate180, UpperRight) => LowerLeft,
+			(Transform::Rotate180, LowerRight) => UpperLeft,
+			(Transform::Rotate180, UpperLeft) => LowerRight,
+			(Transform::Rotate270, LowerLeft) => LowerRight,
+			(Transform::Rotate270, LowerRight) => UpperRight,
+			(Transform::Rotate270, UpperRight) => UpperLeft,
+			(Transform::Rotate270, UpperLeft) => LowerLeft
+		})
+	}
+	
+	// Top left corner of a w x h object anchored at (x, y) after the transform
+	fn anchor(self, x: u32, y: u32, w: u32, h: u32, width: u32, height: u32) -> (u32, u32) {
+		let right = (x + w - 1).min(width - 1);
+		let bottom = (y + h - 1).min(height - 1);
+		let a = self.point(x, y, width, height);
+		let b = self.point(right, bottom, width, height);
+		(a.0.min(b.0), a.1.min(b.1))
+	}
+}
+
+// The transformed block, or None when it has no transformed form
+fn transform_block(t: Transform, block: Block, furniture: &HashMap<u16, (u32, u32, Frames)>, importance: &[bool]) -> Option<Block> {
+	let slope = t.slope(block.slope)?;
+	if !importance.get(block.id as usize).copied().unwrap_or(false) {
+		return Some(Block { slope, ..block });
+	}
+	
+	let (u, v) = block.uv.unwrap_or((0, 0));
+	let &(w, _, frames) = furniture.get(&block.id)?;
+	if t != Transform::FlipHorizontal {
+		return None;
+	}
+	let column = u as u32 / 18 % w;
+	let group = u as u32 / 18 / w;
+	let u = match frames {
+		Frames::Fixed => return None,
+		Frames::Torch => match u % 66 {
+			22 => u + 22,
+			44 => u - 22,
+			_ => u
+		},
+		Frames::Symmetric => ((group * w + w - 1 - column) * 18) as u16,
+		Frames::Directional => (((group ^ 1) * w + w - 1 - column) * 18) as u16
+	};
+	Some(Block { slope, uv: Some((u, v)), ..block })
+}
+
+impl Clipboard {
+	// `importance` decides which blocks are frame important, usually the `importance` of the world the clipboard came from
+	pub fn transform(&self, t: Transform, importance: &[bool]) -> (Clipboard, Vec<Rejected>) {
+		let (width, height) = (self.width(), self.height());
+		let (new_width, new_height) = t.size(width, height);
+		let furniture = furniture();
+		
+		let mut tiles = self.tiles.remap(new_width, new_height, |x, y| t.point(x, y, width, height));
+		let mut rejected_palette = vec![];
+		tiles.map(|tile| {
+			let block = tile.block.map(|block| transform_block(t, block, &furniture, importance));
+			rejected_palette.push(block == Some(None));
+			match block {
+				Some(Some(block)) => Tile { block: Some(block), ..*tile },
+				// Moving it untransformed would leave furniture with frames that don't fit its new neighbours
+				Some(None) => Tile { block: None, ..*tile },
+				None => *tile
+			}
+		});
+		
+		let mut rejected = vec![];
+		for x in 0..width {
+			for y in 0..height {
+				let i = self.tiles.index(x, y) as usize;
+				if !rejected_palette[i] { continue }
+				let block = self.tiles.palette()[i].block.unwrap();
+				rejected.push(match t.slope(block.slope) {
+					None => Rejected::HalfBlock { x, y },
+					Some(_) => Rejected::Furniture { x, y, id: block.id }
+				});
+			}
+		}
+		let removed = |x: u32, y: u32| x < width && y < height && rejected_palette[self.tiles.index(x, y) as usize];
+		
+		// Anchors are the top left tile of their furniture, which is somewhere else once the furniture is turned
+		let size = |x: u32, y: u32| -> (u32, u32) {
+			let block = self.tiles.get(x, y).block;
+			block.and_then(|block| furniture.get(&block.id)).map(|&(w, h, _)| (w, h)).unwrap_or((1, 1))
+		};
+		let mut clipboard = self.clone();
+		clipboard.tiles = tiles;
+		clipboard.chests.retain(|c| !removed(c.x, c.y));
+		clipboard.signs.retain(|s| !removed(s.x, s.y));
+		clipboard.tile_entities.retain(|e| !removed(e.x as u32, e.y as u32));
+		clipboard.weighted_pressure_plates.retain(|p| !removed(p.0, p.1));
+		for chest in clipboard.chests.iter_mut() {
+			let (w, h) = size(chest.x, chest.y);
+			(chest.x, chest.y) = t.anchor(chest.x, chest.y, w, h, width, height);
+		}
+		for sign in clipboard.signs.iter_mut() {
+			let (w, h) = size(sign.x, sign.y);
+			(sign.x, sign.y) = t.anchor(sign.x, sign.y, w, h, width, height);
+		}
+		for entity in clipboard.tile_entities.iter_mut() {
+			let (w, h) = match entity.info {
+				TileEntityInfo::Pylon => (3, 4),
+				_ => size(entity.x as u32, entity.y as u32)
+			};
+			let (x, y) = t.anchor(entity.x as u32, entity.y as u32, w, h, width, height);
+			(entity.x, entity.y) = (x as u16, y as u16);
+		}
+		for plate in clipboard.weighted_pressure_plates.iter_mut() {
+			*plate = t.point(plate.0, plate.1, width, height);
+		}
+		
+		(clipboard, rejected)
+	}
+}
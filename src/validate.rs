@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::{ids::{default_importance, TileId}, items::{ItemCatalogue, ItemProblem}, structs::{Block, TileEntityInfo}, wld::Wld};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Diagnostic {
+	ChestWithoutTile { index: usize, x: u32, y: u32 },
+	SignWithoutTile { index: usize, x: u32, y: u32 },
+	TileEntityNotAnchored { index: usize, x: u16, y: u16 },
+	InvalidNPCRoom { index: usize, id: u32, x: u32, y: u32 },
+	SpawnOutOfBounds { x: u32, y: u32 },
+	DungeonOutOfBounds { x: u32, y: u32 },
+	MissingUV { x: u32, y: u32, id: u16 },
+	ImportanceTooShort { len: usize, needed: usize },
+	InvalidItem { chest: usize, slot: usize, problem: ItemProblem }
+}
+
+impl Diagnostic {
+	// Whether `Wld::repair` can fix this without losing anything the player would miss
+	pub fn is_repairable(&self) -> bool {
+		match self {
+			Diagnostic::ChestWithoutTile { .. } => false,
+			Diagnostic::InvalidItem { problem, .. } => !matches!(problem, ItemProblem::UnknownItem(_)),
+			_ => true
+		}
+	}
+}
+
+impl fmt::Display for Diagnostic {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Diagnostic::ChestWithoutTile { index, x, y } => write!(f, "chest {} at ({}, {}) has no chest tile", index, x, y),
+			Diagnostic::SignWithoutTile { index, x, y } => write!(f, "sign {} at ({}, {}) has no sign tile", index, x, y),
+			Diagnostic::TileEntityNotAnchored { index, x, y } => write!(f, "tile entity {} at ({}, {}) is not on its furniture", index, x, y),
+			Diagnostic::InvalidNPCRoom { index, id, x, y } => write!(f, "npc room {} for npc {} at ({}, {}) is not a valid home", index, id, x, y),
+			Diagnostic::SpawnOutOfBounds { x, y } => write!(f, "spawn point ({}, {}) is outside the world", x, y),
+			Diagnostic::DungeonOutOfBounds { x, y } => write!(f, "dungeon ({}, {}) is outside the world", x, y),
+			Diagnostic::MissingUV { x, y, id } => write!(f, "frame important block {} at ({}, {}) has no uv", id, x, y),
+			Diagnostic::ImportanceTooShort { len, needed } => write!(f, "importance has {} entries but blocks need {}", len, needed),
+			Diagnostic::InvalidItem { chest, slot, problem } => write!(f, "chest {} slot {}: {}", chest, slot, problem)
+		}
+	}
+}
+
+// Names missing from the id table are left out, a check with no ids left is skipped
+fn tile_ids(names: &[&str]) -> Vec<u16> {
+	names.iter().filter_map(|name| TileId::from_name(name)).map(|id| id.0).collect()
+}
+
+// Block ids a tile entity of this kind can sit on
+fn anchor_tiles(info: &TileEntityInfo) -> Vec<u16> {
+	match info {
+		TileEntityInfo::TargetDummy(_) => tile_ids(&["TargetDummy"]),
+		TileEntityInfo::ItemFrame(_) => tile_ids(&["ItemFrame"]),
+		TileEntityInfo::LogicSensor(..) => tile_ids(&["LogicSensor"]),
+		TileEntityInfo::Mannequin(_) => tile_ids(&["DisplayDoll"]),
+		TileEntityInfo::WeaponRack(_) => tile_ids(&["WeaponsRack2"]),
+		TileEntityInfo::HatRack(_) => tile_ids(&["HatRack"]),
+		TileEntityInfo::FoodPlatter(_) => tile_ids(&["FoodPlatter"]),
+		TileEntityInfo::Pylon => tile_ids(&["TeleportationPylon"])
+	}
+}
+
+impl Wld {
+	fn block_at(&self, x: u32, y: u32) -> Option<Block> {
+		match x < self.tiles.width() && y < self.tiles.height() {
+			true => self.tiles.get(x, y).block,
+			false => None
+		}
+	}
+	fn has_block(&self, x: u32, y: u32, ids: &[u16]) -> bool {
+		self.block_at(x, y).is_some_and(|block| ids.contains(&block.id))
+	}
+	
+	pub fn validate(&self) -> Vec<Diagnostic> {
+		let mut diagnostics = vec![];
+		
+		let chest_tiles = tile_ids(&["Containers", "Containers2", "Dressers"]);
+		for (index, chest) in self.chests.iter().enumerate() {
+			if !chest_tiles.is_empty() && !self.has_block(chest.x, chest.y, &chest_tiles) {
+				diagnostics.push(Diagnostic::ChestWithoutTile { index, x: chest.x, y: chest.y });
+			}
+		}
+		
+		let sign_tiles = tile_ids(&["Signs", "Tombstones", "AnnouncementBox", "TatteredWoodSign"]);
+		for (index, sign) in self.signs.iter().enumerate() {
+			if !sign_tiles.is_empty() && !self.has_block(sign.x, sign.y, &sign_tiles) {
+				diagnostics.push(Diagnostic::SignWithoutTile { index, x: sign.x, y: sign.y });
+			}
+		}
+		
+		for (index, entity) in self.tile_entities.iter().enumerate() {
+			let anchors = anchor_tiles(&entity.info);
+			if !anchors.is_empty() && !self.has_block(entity.x as u32, entity.y as u32, &anchors) {
+				diagnostics.push(Diagnostic::TileEntityNotAnchored { index, x: entity.x, y: entity.y });
+			}
+		}
+		
+		for (index, room) in self.npc_rooms.iter().enumerate() {
+			let blocked = self.block_at(room.x, room.y).is_some_and(|block| !block.inactive && TileId(block.id).is_solid());
+			let in_bounds = room.x < self.width && room.y < self.height;
+			let has_npc = self.npcs.iter().any(|npc| npc.id == room.id && !npc.is_pillar);
+			if !in_bounds || blocked || !has_npc {
+				diagnostics.push(Diagnostic::InvalidNPCRoom { index, id: room.id, x: room.x, y: room.y });
+			}
+		}
+		
+		if self.spawn_x >= self.width || self.spawn_y >= self.height {
+			diagnostics.push(Diagnostic::SpawnOutOfBounds { x: self.spawn_x, y: self.spawn_y });
+		}
+		if self.dungeon_x >= self.width || self.dungeon_y >= self.height {
+			diagnostics.push(Diagnostic::DungeonOutOfBounds { x: self.dungeon_x, y: self.dungeon_y });
+		}
+		
+		let used = self.tiles.used();
+		let palette = self.tiles.palette();
+		let needed = palette.iter().zip(&used).filter(|(_, &used)| used).filter_map(|(tile, _)| tile.block).map(|block| block.id as usize + 1).max().unwrap_or(0);
+		if needed > self.importance.len() {
+			diagnostics.push(Diagnostic::ImportanceTooShort { len: self.importance.len(), needed });
+		}
+		
+		// Only palette entries are checked, then the cells using a bad one are looked up
+		let missing_uv: Vec<bool> = palette.iter().map(|tile| match tile.block {
+			Some(block) => block.uv.is_none() && self.importance.get(block.id as usize).copied().unwrap_or(false),
+			None => false
+		}).collect();
+		if missing_uv.contains(&true) {
+			for x in 0..self.tiles.width() {
+				for (y, &i) in self.tiles.column(x).iter().enumerate() {
+					if missing_uv[i as usize] {
+						let id = palette[i as usize].block.map(|block| block.id).unwrap_or(0);
+						diagnostics.push(Diagnostic::MissingUV { x, y: y as u32, id });
+					}
+				}
+			}
+		}
+		
+		diagnostics
+	}
+	
+	// Checks chest items against `catalogue`, which has to come from the caller since no item data is bundled
+	pub fn validate_items(&self, catalogue: &ItemCatalogue) -> Vec<Diagnostic> {
+		let mut diagnostics = vec![];
+		for (index, chest) in self.chests.iter().enumerate() {
+			for (slot, item) in chest.items.iter().enumerate() {
+				for problem in item.map(|item| item.problems(catalogue)).unwrap_or_default() {
+					diagnostics.push(Diagnostic::InvalidItem { chest: index, slot, problem });
+				}
+			}
+		}
+		diagnostics
+	}
+	
+	// Fixes what can be fixed safely and returns the diagnostics that were repaired. Dangling chests are kept since removing them would lose their items
+	pub fn repair(&mut self) -> Vec<Diagnostic> {
+		let diagnostics = self.validate();
+		self.fix(diagnostics)
+	}
+	// Fixes stack sizes and prefixes, unknown items are left alone
+	pub fn repair_items(&mut self, catalogue: &ItemCatalogue) -> Vec<Diagnostic> {
+		let diagnostics = self.validate_items(catalogue);
+		self.fix(diagnostics)
+	}
+	
+	fn fix(&mut self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+		let diagnostics: Vec<Diagnostic> = diagnostics.into_iter().filter(Diagnostic::is_repairable).collect();
+		let mut signs = vec![];
+		let mut tile_entities = vec![];
+		let mut npc_rooms = vec![];
+		
+		for diagnostic in &diagnostics {
+			match *diagnostic {
+				Diagnostic::SignWithoutTile { index, .. } => signs.push(index),
+				Diagnostic::TileEntityNotAnchored { index, .. } => tile_entities.push(index),
+				Diagnostic::InvalidNPCRoom { index, .. } => npc_rooms.push(index),
+				Diagnostic::SpawnOutOfBounds { .. } => {
+					self.spawn_x = self.spawn_x.min(self.width.saturating_sub(1));
+					self.spawn_y = self.spawn_y.min(self.height.saturating_sub(1));
+				}
+				Diagnostic::DungeonOutOfBounds { .. } => {
+					self.dungeon_x = self.dungeon_x.min(self.width.saturating_sub(1));
+					self.dungeon_y = self.dungeon_y.min(self.height.saturating_sub(1));
+				}
+				Diagnostic::MissingUV { x, y, .. } => {
+					let mut tile = *self.tiles.get(x, y);
+					if let Some(block) = tile.block.as_mut() {
+						block.uv = Some((0, 0));
+					}
+					self.set_block(x, y, tile);
+				}
+				Diagnostic::ImportanceTooShort { needed, .. } => {
+					let defaults = default_importance();
+					for id in self.importance.len()..needed {
+						self.importance.push(defaults.get(id).copied().unwrap_or(false));
+					}
+				}
+				Diagnostic::InvalidItem { chest, slot, problem } => {
+					let item = &mut self.chests[chest].items[slot];
+					match problem {
+						ItemProblem::EmptyStack => *item = None,
+						ItemProblem::ExceedsMaxStack { max, .. } => item.iter_mut().for_each(|item| item.count = max),
+						ItemProblem::UnknownPrefix(_) | ItemProblem::InvalidPrefix { .. } => item.iter_mut().for_each(|item| item.prefix = 0),
+						ItemProblem::UnknownItem(_) => ()
+					}
+				}
+				Diagnostic::ChestWithoutTile { .. } => ()
+			}
+		}
+		
+		let evicted: Vec<u32> = npc_rooms.iter().map(|&i| self.npc_rooms[i].id).collect();
+		for npc in self.npcs.iter_mut().filter(|npc| evicted.contains(&npc.id) && !npc.is_pillar) {
+			npc.homeless = true;
+		}
+		remove_indices(&mut self.signs, &signs);
+		remove_indices(&mut self.tile_entities, &tile_entities);
+		remove_indices(&mut self.npc_rooms, &npc_rooms);
+		self.tiles.compact();
+		
+		diagnostics
+	}
+}
+
+fn remove_indices<T>(list: &mut Vec<T>, indices: &[usize]) {
+	let mut i = 0;
+	list.retain(|_| {
+		i += 1;
+		!indices.contains(&(i - 1))
+	});
+}
@@ -0,0 +1,65 @@
+// World file versions this crate can read and write, from the first sectioned format (1.2) to 1.4.4.9
+pub const MIN_VERSION: u32 = 88;
+pub const LATEST_VERSION: u32 = 279;
+
+// First versions able to store each of these
+pub const MAGIC_HEADER_VERSION: u32 = 135;
+pub const YELLOW_WIRE_VERSION: u32 = 140;
+pub const WALL_ID_16BIT_VERSION: u32 = 222;
+pub const SHIMMER_VERSION: u32 = 269;
+pub const NPC_ID_VERSION: u32 = 190;
+pub const GAMEMODE_VERSION: u32 = 209;
+pub const TILE_ENTITIES_VERSION: u32 = 116;
+pub const PRESSURE_PLATES_VERSION: u32 = 170;
+pub const TOWN_MANAGER_VERSION: u32 = 189;
+pub const BESTIARY_VERSION: u32 = 210;
+pub const CREATIVE_POWERS_VERSION: u32 = 220;
+
+pub const MAGIC_NUMBER: [u8; 7] = *b"relogic";
+
+// Before version 190 npcs were saved by their english type name instead of their id
+pub(crate) const LEGACY_NPC_NAMES: [(u32, &str); 30] = [
+	(17, "Merchant"),
+	(18, "Nurse"),
+	(19, "Arms Dealer"),
+	(20, "Dryad"),
+	(22, "Guide"),
+	(37, "Old Man"),
+	(38, "Demolitionist"),
+	(54, "Clothier"),
+	(107, "Goblin Tinkerer"),
+	(108, "Wizard"),
+	(124, "Mechanic"),
+	(142, "Santa Claus"),
+	(160, "Truffle"),
+	(178, "Steampunker"),
+	(207, "Dye Trader"),
+	(208, "Party Girl"),
+	(209, "Cyborg"),
+	(227, "Painter"),
+	(228, "Witch Doctor"),
+	(229, "Pirate"),
+	(353, "Stylist"),
+	(368, "Travelling Merchant"),
+	(369, "Angler"),
+	(441, "Tax Collector"),
+	(453, "Skeleton Merchant"),
+	(550, "Tavernkeep"),
+	(422, "Vortex Pillar"),
+	(493, "Stardust Pillar"),
+	(507, "Nebula Pillar"),
+	(517, "Solar Pillar")
+];
+
+pub(crate) fn legacy_npc_id(name: &str) -> Option<u32> {
+	LEGACY_NPC_NAMES.iter().find(|(_, n)| *n == name).map(|(id, _)| *id)
+}
+
+pub(crate) fn legacy_npc_name(id: u32) -> Option<&'static str> {
+	LEGACY_NPC_NAMES.iter().find(|(i, _)| *i == id).map(|(_, n)| *n)
+}
+
+// Number of section pointers the game writes for a given version
+pub fn section_count(version: u32) -> usize {
+	[TILE_ENTITIES_VERSION, PRESSURE_PLATES_VERSION, TOWN_MANAGER_VERSION, BESTIARY_VERSION, CREATIVE_POWERS_VERSION].iter().filter(|&&v| version >= v).count() + 6
+}
@@ -0,0 +1,95 @@
+extern crate wld_edit;
+
+use wld_edit::{Block, Connectivity, Layers, Liquid, LiquidType, Region, Slope, Tile, TileGrid, TileId, Wall, Wld};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: None, inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn wall(id: u16) -> Option<Wall> {
+	Some(Wall { id, color: None })
+}
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	w.tiles = TileGrid::new(20, 20, Tile { wall: wall(1), red_wire: true, ..block(0) });
+	w
+}
+
+#[test]
+fn fill_only_touches_selected_layers() {
+	let mut w = world();
+	let water = Tile { liquid: Some(Liquid { kind: LiquidType::Water, amount: 255 }), wall: wall(5), ..block(1) };
+	
+	assert_eq!(w.fill(Region::new(2, 2, 3, 3), water, Layers::BLOCK | Layers::LIQUID), 9);
+	assert_eq!(w.tiles.get(3, 3), &Tile { liquid: water.liquid, wall: wall(1), red_wire: true, ..block(1) });
+	assert_eq!(w.tiles.get(5, 5), &Tile { wall: wall(1), red_wire: true, ..block(0) });
+	
+	// Tiles that already look right aren't counted, regions are clamped to the world
+	assert_eq!(w.fill(Region::new(2, 2, 3, 3), water, Layers::BLOCK), 0);
+	assert_eq!(w.fill(Region::new(15, 15, 10, 10), AIR, Layers::WIRES), 25);
+	assert!(!w.tiles.get(19, 19).red_wire);
+	assert_eq!(w.tiles.get(19, 19).block, block(0).block);
+	
+	assert_eq!(w.fill(Region::new(0, 0, 1, 1), AIR, Layers::ALL), 1);
+	assert_eq!(w.tiles.get(0, 0), &AIR);
+}
+
+#[test]
+fn replace_and_paint() {
+	let mut w = world();
+	let sloped = Tile { block: Some(Block { slope: Slope::UpperLeft, color: Some(3), ..block(0).block.unwrap() }), ..*w.tiles.get(1, 1) };
+	w.set_block(1, 1, sloped);
+	
+	assert_eq!(w.replace_block(0, 1), 400);
+	assert_eq!(w.tiles.get(1, 1).block, Some(Block { id: 1, ..sloped.block.unwrap() }));
+	assert_eq!(w.tiles.get(2, 2).wall, wall(1));
+	
+	// Frame important blocks need a uv
+	let torch = TileId::from_name("Torches").unwrap().0;
+	w.replace_block(1, torch);
+	assert_eq!(w.tiles.get(2, 2).block.unwrap().uv, Some((0, 0)));
+	w.replace_block(torch, 2);
+	assert_eq!(w.tiles.get(2, 2).block.unwrap().uv, None);
+	
+	w.set_block(4, 4, Tile { wall: wall(7), ..*w.tiles.get(4, 4) });
+	assert_eq!(w.paint_walls(1, Some(12)), 399);
+	assert_eq!(w.tiles.get(0, 0).wall, Some(Wall { id: 1, color: Some(12) }));
+	assert_eq!(w.tiles.get(4, 4).wall, wall(7));
+	assert_eq!(w.replace_wall(7, 1), 1);
+	assert_eq!(w.paint_blocks(2, None), 1);
+	assert_eq!(w.tiles.get(1, 1).block.unwrap().color, None);
+}
+
+#[test]
+fn flood_fill_stays_inside() {
+	let mut w = world();
+	// A ring of stone around (5, 5) with a diagonal gap at the corner
+	for i in 3..8 {
+		for &(x, y) in &[(i, 3), (i, 7), (3, i), (7, i)] {
+			w.set_block(x, y, block(1));
+		}
+	}
+	w.set_block(7, 7, block(0));
+	
+	let dirt = |tile: &Tile| tile.block.map(|b| b.id) == Some(0);
+	assert_eq!(w.flood_fill(5, 5, block(2), Layers::BLOCK, Connectivity::Four, dirt), 9);
+	assert_eq!(w.tiles.get(5, 5).block.unwrap().id, 2);
+	assert_eq!(w.tiles.get(5, 5).wall, wall(1));
+	assert_eq!(w.tiles.get(8, 8).block.unwrap().id, 0);
+	
+	// Eight way filling leaks out through the corner
+	let grass = |tile: &Tile| tile.block.map(|b| b.id) == Some(2);
+	assert_eq!(w.flood_fill(5, 5, block(0), Layers::BLOCK, Connectivity::Eight, grass), 9);
+	assert_eq!(w.flood_fill(5, 5, block(2), Layers::BLOCK, Connectivity::Eight, dirt), 400 - 15);
+	assert_eq!(w.flood_fill(3, 3, block(2), Layers::BLOCK, Connectivity::Eight, dirt), 0);
+	
+	// Everything goes through the journal
+	w.begin();
+	w.flood_fill(0, 0, block(9), Layers::BLOCK, Connectivity::Four, grass);
+	w.commit();
+	w.undo();
+	assert_eq!(w.tiles.get(0, 0).block.unwrap().id, 2);
+}
@@ -0,0 +1,60 @@
+extern crate wld_edit;
+
+use wld_edit::{ids::{default_importance, TILES, WALLS}, TileId, WallId, Wld};
+
+#[test]
+fn lookups_by_name() {
+	assert_eq!(TileId::from_name("Chest"), Some(TileId(21)));
+	assert_eq!(TileId::from_name("Containers"), Some(TileId(21)));
+	assert_eq!(TileId::from_name("dirt block"), Some(TileId(0)));
+	assert_eq!(TileId::from_name("Not a tile"), None);
+	assert_eq!(WallId::from_name("Wood Wall"), Some(WallId(4)));
+	assert_eq!(TileId(21).display_name(), Some("Chest"));
+	assert_eq!(TileId(u16::MAX).info(), None);
+}
+
+#[test]
+fn shared_display_names_are_ambiguous() {
+	assert_eq!(TileId::from_name("Closed Door"), Some(TileId(10)));
+	assert_eq!(TileId::from_name("Door"), None);
+	assert_eq!(WallId::from_name("Dirt Wall"), None);
+	assert_eq!(WallId::from_name("Dirt"), Some(WallId(16)));
+}
+
+#[test]
+fn ids_match_positions() {
+	for (i, tile) in TILES.iter().enumerate() {
+		assert_eq!(tile.id as usize, i, "{}", tile.name);
+		assert_eq!(TileId::from_name(tile.name).map(|id| id.0), Some(tile.id));
+	}
+	for (i, wall) in WALLS.iter().enumerate() {
+		assert_eq!(wall.id as usize, i, "{}", wall.name);
+		assert_eq!(WallId::from_name(wall.name).map(|id| id.0), Some(wall.id));
+	}
+}
+
+#[test]
+fn default_importance_matches_new_worlds() {
+	let importance = default_importance();
+	assert_eq!(Wld::_new().importance, importance);
+	assert!(importance[TileId::from_name("Chest").unwrap().0 as usize]);
+	assert!(importance[TileId::from_name("Torch").unwrap().0 as usize]);
+	assert!(!importance[TileId::from_name("Dirt Block").unwrap().0 as usize]);
+	assert!(!importance[TileId::from_name("Stone Block").unwrap().0 as usize]);
+	for tree in ["Trees", "MushroomTrees", "PalmTree", "TreeTopaz", "TreeAmber", "VanityTreeSakura", "VanityTreeYellowWillow", "TreeAsh"] {
+		assert!(importance[TileId::from_name(tree).unwrap().0 as usize], "{}", tree);
+	}
+}
+
+#[test]
+fn tables_cover_the_latest_version() {
+	assert_eq!(TILES.len(), 693);
+	assert_eq!(WALLS.len(), 347);
+	assert_eq!(default_importance().len(), 693);
+	assert_eq!(WallId(346).name(), Some("RainbowMossBlockWall"));
+	assert_eq!(WallId::from_name("Ancient Gold Brick Wall"), Some(WallId(334)));
+	let mut names: Vec<String> = TILES.iter().map(|tile| tile.display_name.to_lowercase()).collect();
+	names.sort();
+	names.dedup();
+	assert_eq!(names.len(), TILES.len());
+}
@@ -0,0 +1,44 @@
+extern crate wld_edit;
+
+use wld_edit::{Chest, Container, Item, ItemLocation, ItemTotal, TileEntity, TileEntityInfo, Wld};
+
+fn item(id: u32, prefix: u8, count: u16) -> Option<Item> {
+	Some(Item { id, prefix, count })
+}
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	let mut items = vec![None; 40];
+	items[0] = item(3063, 81, 1);
+	items[5] = item(29, 0, 4);
+	w.chests.push(Chest { name: "Storage".to_string(), x: 10, y: 20, items });
+	w.chests.push(Chest { name: String::new(), x: 30, y: 20, items: vec![item(29, 0, 2), None, item(29, 0, 3)] });
+	w.tile_entities.push(TileEntity { id: 0, x: 50, y: 50, info: TileEntityInfo::Pylon });
+	w.tile_entities.push(TileEntity { id: 1, x: 60, y: 50, info: TileEntityInfo::WeaponRack(item(3063, 0, 1)) });
+	let mut mannequin = [None; 16];
+	mannequin[9] = item(29, 81, 1);
+	w.tile_entities.push(TileEntity { id: 2, x: 70, y: 50, info: TileEntityInfo::Mannequin(mannequin) });
+	w
+}
+
+#[test]
+fn lookups() {
+	let index = world().item_index();
+	assert_eq!(index.locations().len(), 6);
+	assert_eq!(index.with_item(3063), vec![
+		&ItemLocation { container: Container::Chest(0), slot: 0, x: 10, y: 20, item: item(3063, 81, 1).unwrap() },
+		&ItemLocation { container: Container::TileEntity(1), slot: 0, x: 60, y: 50, item: item(3063, 0, 1).unwrap() }
+	]);
+	assert_eq!(index.with_item(1), Vec::<&ItemLocation>::new());
+	assert_eq!(index.with_prefix(81).iter().map(|l| (l.item.id, l.slot)).collect::<Vec<_>>(), vec![(3063, 0), (29, 9)]);
+	assert_eq!(index.in_chests_named("stor").len(), 2);
+	assert_eq!(index.containers_with(29), vec![Container::Chest(0), Container::Chest(1), Container::TileEntity(2)]);
+}
+
+#[test]
+fn totals() {
+	let index = world().item_index();
+	assert_eq!(index.total(29), Some(ItemTotal { id: 29, count: 10, stacks: 4, positions: vec![(10, 20), (30, 20), (70, 50)] }));
+	assert_eq!(index.total(1), None);
+	assert_eq!(index.totals().iter().map(|t| (t.id, t.count)).collect::<Vec<_>>(), vec![(29, 10), (3063, 2)]);
+}
@@ -0,0 +1,110 @@
+extern crate wld_edit;
+
+use wld_edit::{items::{item_info, prefix_by_name, ITEMS, PREFIXES}, Item, ItemCatalogue, ItemInfo, ItemProblem, PrefixCategory, TileEntityInfo};
+
+fn catalogue() -> ItemCatalogue {
+	vec![
+		ItemInfo::new(2, "DirtBlock", "Dirt Block", 9999, PrefixCategory::None),
+		ItemInfo::new(54, "HermesBoots", "Hermes Boots", 1, PrefixCategory::Accessory),
+		ItemInfo::new(71, "CopperCoin", "Copper Coin", 100, PrefixCategory::None),
+		ItemInfo::new(98, "Minishark", "Minishark", 1, PrefixCategory::Ranged),
+		ItemInfo::new(188, "HealingPotion", "Healing Potion", 9999, PrefixCategory::None),
+		ItemInfo::new(273, "NightsEdge", "Night's Edge", 1, PrefixCategory::Melee),
+		ItemInfo::new(389, "DaoofPow", "Dao of Pow", 1, PrefixCategory::Weapon)
+	].into_iter().collect()
+}
+
+fn item(name: &str, prefix: &str, count: u16) -> Item {
+	Item {
+		id: catalogue().by_name(name).unwrap().id,
+		prefix: match prefix.is_empty() {
+			true => 0,
+			false => prefix_by_name(prefix).unwrap().id
+		},
+		count
+	}
+}
+
+#[test]
+fn display() {
+	let items = catalogue();
+	assert_eq!(items.describe(&item("Night's Edge", "Legendary", 1)), "Legendary Night's Edge");
+	assert_eq!(items.describe(&item("HealingPotion", "", 30)), "Healing Potion (30)");
+	assert_eq!(item("Night's Edge", "Legendary", 1).to_string(), "Legendary Night's Edge");
+	assert_eq!(Item { id: 100_000, prefix: 200, count: 1 }.to_string(), "prefix #200 item #100000");
+	assert_eq!(items.describe(&Item { id: 100_000, prefix: 0, count: 2 }), "item #100000 (2)");
+}
+
+#[test]
+fn catalogue_lookups() {
+	let mut items = catalogue();
+	assert_eq!(items.len(), 7);
+	assert_eq!(items.by_name("dao of pow").map(|i| i.id), Some(389));
+	assert!(items.by_name("Terra Blade").is_none());
+	// Id 0 is the empty slot
+	items.insert(ItemInfo::new(0, "None", "None", 1, PrefixCategory::None));
+	assert!(items.get(0).is_none());
+	// Two items sharing a display name can only be found by their internal names
+	items.insert(ItemInfo::new(5000, "DirtBlock2", "Dirt Block", 9999, PrefixCategory::None));
+	assert!(items.by_name("Dirt Block").is_none());
+	assert_eq!(items.by_name("DirtBlock2").map(|i| i.id), Some(5000));
+}
+
+#[test]
+fn builtin_catalogue() {
+	let items = ItemCatalogue::builtin();
+	assert_eq!(items.len(), ITEMS.len() - 1);
+	for (i, info) in ITEMS.iter().enumerate() {
+		assert_eq!(info.id as usize, i, "{}", info.name);
+	}
+	// The hand-written catalogue agrees with the table
+	let mine = catalogue();
+	for id in [2, 54, 71, 98, 188, 273, 389] {
+		assert_eq!(items.get(id), mine.get(id));
+	}
+	assert_eq!(items.by_name("Terra Blade").map(|i| i.id), Some(757));
+	assert_eq!(items.by_name("Celeb2").map(|i| i.display_name.as_ref()), Some("Celebration Mk2"));
+	assert!(item_info(0).is_none());
+	assert!(Item { id: 757, prefix: 81, count: 1 }.is_valid(items));
+	assert_eq!(Item { id: 3063, prefix: 0, count: 2 }.to_string(), "Meowmere (2)");
+}
+
+#[test]
+fn validation() {
+	let items = catalogue();
+	assert!(item("Night's Edge", "Legendary", 1).is_valid(&items));
+	assert!(item("Minishark", "Unreal", 1).is_valid(&items));
+	assert!(item("Dao of Pow", "Godly", 1).is_valid(&items));
+	assert!(item("Hermes Boots", "Warding", 1).is_valid(&items));
+	assert!(item("Dirt Block", "", 9999).is_valid(&items));
+	
+	assert_eq!(item("Dirt Block", "", 10000).problems(&items), vec![ItemProblem::ExceedsMaxStack { count: 10000, max: 9999 }]);
+	assert_eq!(item("Copper Coin", "", 101).problems(&items), vec![ItemProblem::ExceedsMaxStack { count: 101, max: 100 }]);
+	assert_eq!(item("Night's Edge", "", 0).problems(&items), vec![ItemProblem::EmptyStack]);
+	assert_eq!(item("Dao of Pow", "Legendary", 1).problems(&items).len(), 1);
+	assert_eq!(item("Hermes Boots", "Godly", 1).problems(&items).len(), 1);
+	assert_eq!(item("Minishark", "Warding", 1).problems(&items).len(), 1);
+	assert_eq!(item("Dirt Block", "Keen", 1).problems(&items).len(), 1);
+	assert_eq!(item("Hermes Boots", "Godly", 1).problems(&items)[0].to_string(), "prefix Godly is invalid for item #54");
+	assert_eq!(Item { id: 2, prefix: 250, count: 1 }.problems(&items), vec![ItemProblem::UnknownPrefix(250)]);
+	assert_eq!(Item { id: 0, prefix: 0, count: 1 }.problems(&items), vec![ItemProblem::UnknownItem(0)]);
+	assert_eq!(Item { id: 5000, prefix: 250, count: 0 }.problems(&items), vec![ItemProblem::UnknownItem(5000), ItemProblem::UnknownPrefix(250), ItemProblem::EmptyStack]);
+}
+
+#[test]
+fn prefix_table() {
+	for (i, info) in PREFIXES.iter().enumerate() {
+		assert_eq!(info.id as usize, i, "{}", info.name);
+	}
+	assert_eq!(PREFIXES.len(), 85);
+}
+
+#[test]
+fn tile_entity_items() {
+	let sword = item("Night's Edge", "", 1);
+	let mut slots = [None; 4];
+	slots[2] = Some(sword);
+	assert_eq!(TileEntityInfo::HatRack(slots).items(), vec![sword]);
+	assert_eq!(TileEntityInfo::ItemFrame(Some(sword)).items(), vec![sword]);
+	assert!(TileEntityInfo::Pylon.items().is_empty());
+}
@@ -0,0 +1,106 @@
+extern crate wld_edit;
+
+use wld_edit::{Anchor, Block, Chest, Region, Slope, Tile, TileGrid, Wld};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: None, inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	w.tiles = TileGrid::new(100, 100, block(0));
+	w
+}
+
+#[test]
+fn undo_and_redo_tiles() {
+	let mut w = world();
+	let original = w.clone();
+	
+	w.begin();
+	w.set_block(1, 1, block(1));
+	w.set_block(1, 1, block(2));
+	w.set_block(2, 2, block(1));
+	assert!(w.journal.is_recording());
+	assert!(w.commit());
+	let edited = w.clone();
+	
+	w.begin();
+	w.set_block(3, 3, block(1));
+	w.commit();
+	
+	assert!(w.undo());
+	assert_eq!(w.tiles.get(3, 3), &block(0));
+	assert_eq!(w.tiles.get(1, 1), &block(2));
+	assert!(w.undo());
+	assert_eq!(w.tiles.get(1, 1), &block(0));
+	assert_eq!(w, original);
+	assert!(!w.undo());
+	
+	assert!(w.redo());
+	assert_eq!(w.tiles.get(1, 1), &block(2));
+	assert_eq!(w, edited);
+	assert!(w.redo());
+	assert_eq!(w.tiles.get(3, 3), &block(1));
+	assert!(!w.redo());
+}
+
+#[test]
+fn entities_and_paste() {
+	let mut w = world();
+	w.chests.push(Chest { name: "a".to_string(), x: 10, y: 10, items: vec![] });
+	let original = w.clone();
+	let clip = w.copy(Region::new(9, 9, 3, 3));
+	
+	w.begin();
+	w.chests[0].name = "b".to_string();
+	w.set_block(50, 50, block(3));
+	w.paste(&clip, 60, 60);
+	w.commit();
+	assert_eq!(w.chests.len(), 2);
+	
+	w.undo();
+	assert_eq!(w, original);
+	w.redo();
+	assert_eq!(w.chests[0].name, "b");
+	assert_eq!(w.chests[1].x, 61);
+	assert_eq!(w.tiles.get(50, 50), &block(3));
+}
+
+#[test]
+fn empty_and_unrecorded_changes() {
+	let mut w = world();
+	
+	// Changing a tile back before committing leaves nothing to undo
+	w.begin();
+	w.set_block(1, 1, block(1));
+	w.set_block(1, 1, block(0));
+	assert!(!w.commit());
+	assert!(!w.journal.can_undo());
+	
+	// Outside a transaction nothing is recorded
+	w.set_block(1, 1, block(1));
+	assert!(!w.undo());
+	
+	// A new transaction drops what could be redone
+	w.begin();
+	w.set_block(2, 2, block(1));
+	w.commit();
+	w.undo();
+	assert!(w.journal.can_redo());
+	w.begin();
+	w.set_block(3, 3, block(1));
+	w.commit();
+	assert!(!w.journal.can_redo());
+	
+	// Undo commits an open transaction first
+	w.begin();
+	w.set_block(4, 4, block(1));
+	assert!(w.undo());
+	assert_eq!(w.tiles.get(4, 4), &block(0));
+	
+	w.resize(50, 50, Anchor::Center).unwrap();
+	assert!(!w.journal.can_undo() && !w.journal.can_redo());
+}
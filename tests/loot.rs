@@ -0,0 +1,173 @@
+extern crate rand;
+extern crate wld_edit;
+
+use rand::{rngs::StdRng, SeedableRng};
+use wld_edit::{Block, Chest, ChestFilter, Item, ItemCatalogue, ItemInfo, ItemProblem, LootEntry, LootMode, LootPrefix, LootTable, PrefixCategory, Region, Slope, Tile, TileEntity, TileEntityInfo, TileGrid, TileId, Wld};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn chest_tile(id: u16, u: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: Some((u, 0)), inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn catalogue() -> ItemCatalogue {
+	vec![
+		ItemInfo::new(1, "IronPickaxe", "Iron Pickaxe", 1, PrefixCategory::Melee),
+		ItemInfo::new(2, "DirtBlock", "Dirt Block", 9999, PrefixCategory::None),
+		ItemInfo::new(4, "IronBroadsword", "Iron Broadsword", 1, PrefixCategory::Melee),
+		ItemInfo::new(5, "Mushroom", "Mushroom", 9999, PrefixCategory::None),
+		ItemInfo::new(6, "IronShortsword", "Iron Shortsword", 1, PrefixCategory::Melee)
+	].into_iter().collect()
+}
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	w.tiles = TileGrid::new(100, 100, AIR);
+	let containers = TileId::from_name("Containers").unwrap().0;
+	let dressers = TileId::from_name("Dressers").unwrap().0;
+	// A wooden chest, a gold chest, another gold chest far away and a dresser of the second style
+	for &(x, u) in &[(10, 0), (20, 36), (80, 36)] {
+		w.tiles.set(x, 10, chest_tile(containers, u));
+		w.chests.push(Chest { name: String::new(), x, y: 10, items: vec![None; 40] });
+	}
+	w.tiles.set(30, 10, chest_tile(dressers, 54));
+	w.chests.push(Chest { name: String::new(), x: 30, y: 10, items: vec![Some(Item { id: 2, prefix: 0, count: 5 })] });
+	w
+}
+
+fn table(mode: LootMode) -> LootTable {
+	LootTable {
+		entries: vec![
+			LootEntry::new(2, 3, (10, 20)),
+			LootEntry { id: 4, weight: 1, count: (1, 5), prefix: LootPrefix::OneOf(vec![81]) }
+		],
+		rolls: (3, 5),
+		mode
+	}
+}
+
+#[test]
+fn chest_styles() {
+	let w = world();
+	let containers = TileId::from_name("Containers").unwrap().0;
+	assert_eq!(w.chest_style(1), Some((containers, 1)));
+	assert_eq!(w.chest_style(3), Some((TileId::from_name("Dressers").unwrap().0, 1)));
+	assert_eq!(w.chests_matching(&ChestFilter::style(containers, 1)), vec![1, 2]);
+	assert_eq!(w.chests_matching(&ChestFilter { region: Some(Region::new(0, 0, 50, 50)), ..ChestFilter::style(containers, 1) }), vec![1]);
+	assert_eq!(w.chests_matching(&ChestFilter::default()).len(), 4);
+}
+
+#[test]
+fn apply_loot() {
+	let mut w = world();
+	let items = catalogue();
+	let mut rng = StdRng::seed_from_u64(7);
+	assert_eq!(w.apply_loot(&table(LootMode::Replace), &ChestFilter::region(Region::new(0, 0, 50, 50)), &items, &mut rng), Ok(3));
+	assert!(w.chests[2].items.iter().all(|item| item.is_none()));
+	for chest in [&w.chests[0], &w.chests[1], &w.chests[3]] {
+		assert_eq!(chest.items.len(), 40);
+		let items: Vec<Item> = chest.items.iter().flatten().copied().collect();
+		assert!((3..=5).contains(&items.len()));
+		for item in items {
+			match item.id {
+				2 => assert!((10..=20).contains(&item.count) && item.prefix == 0),
+				4 => assert!(item.count == 1 && item.prefix == 81),
+				_ => panic!("{:?}", item)
+			}
+		}
+	}
+	
+	// Adding keeps what's there and never goes past 40 slots
+	w.chests[2].items[0] = Some(Item { id: 2, prefix: 0, count: 5 });
+	let mut full = table(LootMode::Add);
+	full.rolls = (100, 100);
+	w.apply_loot(&full, &ChestFilter::default(), &items, &mut rng).unwrap();
+	assert!(w.chests.iter().all(|chest| chest.items.len() == 40 && chest.items.iter().all(|item| item.is_some())));
+	assert_eq!(w.chests[2].items[0], Some(Item { id: 2, prefix: 0, count: 5 }));
+	
+	let empty = LootTable { entries: vec![], rolls: (1, 1), mode: LootMode::Replace };
+	assert_eq!(w.apply_loot(&empty, &ChestFilter::default(), &items, &mut rng), Ok(0));
+	
+	// Unknown items fail before any chest is touched
+	let mut unknown = table(LootMode::Replace);
+	unknown.entries.push(LootEntry::new(5000, 1, (1, 1)));
+	let before = w.chests.clone();
+	assert_eq!(w.apply_loot(&unknown, &ChestFilter::default(), &items, &mut rng), Err(ItemProblem::UnknownItem(5000)));
+	assert_eq!(w.chests, before);
+}
+
+#[test]
+fn random_prefixes() {
+	let items = catalogue();
+	let mut rng = StdRng::seed_from_u64(1);
+	let sword = LootEntry { id: 4, weight: 1, count: (1, 1), prefix: LootPrefix::Random };
+	for _ in 0..20 {
+		assert!(sword.roll(&items, &mut rng).unwrap().is_valid(&items));
+	}
+	let dirt = LootEntry { id: 2, weight: 1, count: (1, 1), prefix: LootPrefix::Random };
+	assert_eq!(dirt.roll(&items, &mut rng).unwrap().prefix, 0);
+	let unknown = LootEntry { id: 5000, weight: 1, count: (1, 1), prefix: LootPrefix::Random };
+	assert_eq!(unknown.roll(&items, &mut rng), Err(ItemProblem::UnknownItem(5000)));
+}
+
+#[test]
+fn builtin_catalogue_rolls() {
+	let items = ItemCatalogue::builtin();
+	let mut rng = StdRng::seed_from_u64(2);
+	// Hermes Boots and the Minishark take accessory and ranged prefixes without a hand filled catalogue
+	for &id in &[54, 98] {
+		let entry = LootEntry { id, weight: 1, count: (1, 1), prefix: LootPrefix::Random };
+		for _ in 0..20 {
+			let item = entry.roll(items, &mut rng).unwrap();
+			assert_ne!(item.prefix, 0);
+			assert!(item.is_valid(items));
+		}
+	}
+}
+
+#[test]
+fn replace_and_remove() {
+	let items = catalogue();
+	let mut w = world();
+	w.chests[0].items[3] = Some(Item { id: 4, prefix: 81, count: 1 });
+	w.chests[1].items[0] = Some(Item { id: 2, prefix: 0, count: 9999 });
+	w.tile_entities.push(TileEntity { id: 0, x: 50, y: 50, info: TileEntityInfo::WeaponRack(Some(Item { id: 4, prefix: 81, count: 1 })) });
+	
+	// Iron Broadsword to Iron Shortsword keeps Legendary
+	assert_eq!(w.replace_item(4, 6, &items), Ok(2));
+	assert_eq!(w.chests[0].items[3], Some(Item { id: 6, prefix: 81, count: 1 }));
+	assert_eq!(w.tile_entities[0].info, TileEntityInfo::WeaponRack(Some(Item { id: 6, prefix: 81, count: 1 })));
+	// A mushroom can't be Legendary and a pickaxe can't stack
+	assert_eq!(w.replace_item(6, 5, &items), Ok(2));
+	assert_eq!(w.chests[0].items[3], Some(Item { id: 5, prefix: 0, count: 1 }));
+	assert_eq!(w.replace_item(2, 1, &items), Ok(2));
+	assert_eq!(w.chests[1].items[0], Some(Item { id: 1, prefix: 0, count: 1 }));
+	
+	assert_eq!(w.replace_item(1, 5000, &items), Err(ItemProblem::UnknownItem(5000)));
+	assert_eq!(w.chests[1].items[0], Some(Item { id: 1, prefix: 0, count: 1 }));
+	
+	assert_eq!(w.remove_item(1), 2);
+	assert_eq!(w.remove_item(5), 2);
+	assert!(w.item_index().locations().is_empty());
+	assert!(w.chests.iter().all(|chest| chest.items.len() == 40 || chest.items.len() == 1));
+}
+
+#[test]
+fn empty_and_unstackable_items_are_rejected() {
+	let mut items = catalogue();
+	items.insert(ItemInfo::new(7, "Broken", "Broken", 0, PrefixCategory::None));
+	let mut rng = StdRng::seed_from_u64(3);
+	assert_eq!(LootEntry::new(0, 1, (1, 5)).roll(&items, &mut rng), Err(ItemProblem::UnknownItem(0)));
+	assert_eq!(LootEntry::new(7, 1, (1, 5)).roll(&items, &mut rng), Err(ItemProblem::ExceedsMaxStack { count: 1, max: 0 }));
+	
+	let mut w = world();
+	let mut broken = table(LootMode::Replace);
+	broken.entries.push(LootEntry::new(7, 1, (1, 1)));
+	let before = w.chests.clone();
+	assert!(w.apply_loot(&broken, &ChestFilter::default(), &items, &mut rng).is_err());
+	assert_eq!(w.chests, before);
+	
+	assert_eq!(w.replace_item(2, 0, &items), Err(ItemProblem::UnknownItem(0)));
+	assert_eq!(w.replace_item(2, 7, &items), Err(ItemProblem::ExceedsMaxStack { count: 1, max: 0 }));
+	assert_eq!(w.chests[3].items[0], Some(Item { id: 2, prefix: 0, count: 5 }));
+}
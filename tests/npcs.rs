@@ -0,0 +1,73 @@
+extern crate wld_edit;
+
+use wld_edit::{TownNPC, Wld, NPC};
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.spawn_x = 50;
+	w.spawn_y = 40;
+	w.npcs.push(NPC { id: 422, name: String::new(), x: 10.0, y: 10.0, homeless: false, home_x: 0, home_y: 0, variation_index: 0, shimmered: false, is_pillar: true });
+	w
+}
+
+#[test]
+fn catalogue() {
+	for kind in TownNPC::ALL {
+		assert_eq!(TownNPC::from_id(kind.id()), Some(kind));
+		assert_eq!(TownNPC::from_name(kind.name()), Some(kind));
+	}
+	assert_eq!(TownNPC::from_id(22), Some(TownNPC::Guide));
+	assert_eq!(TownNPC::from_name("arms dealer"), Some(TownNPC::ArmsDealer));
+	assert_eq!(TownNPC::from_id(1), None);
+	assert_eq!(TownNPC::Cat.variations(), 6);
+	assert!(TownNPC::Guide.can_shimmer());
+	assert!(!TownNPC::OldMan.can_have_home());
+}
+
+#[test]
+fn housing() {
+	let mut w = world();
+	w.add_town_npc(TownNPC::Guide, "Andrew", Some((20, 30)));
+	w.add_town_npc(TownNPC::Merchant, "Alfred", None);
+	
+	assert_eq!(w.town_npcs().len(), 2);
+	assert!(w.npcs.last().unwrap().is_pillar);
+	assert_eq!(w.npc_rooms.len(), 1);
+	assert_eq!((w.npc_rooms[0].id, w.npc_rooms[0].x, w.npc_rooms[0].y), (22, 20, 30));
+	
+	let merchant = w.town_npc(TownNPC::Merchant).unwrap();
+	assert!(merchant.homeless);
+	assert_eq!(merchant.name, "Alfred");
+	assert_eq!((merchant.home_x, merchant.home_y), (50, 40));
+	assert_eq!((merchant.x, merchant.y), (800.0, 592.0));
+	
+	// Adding again moves the npc instead of duplicating it, and keeps its name
+	w.add_town_npc(TownNPC::Guide, "Brandon", Some((60, 30)));
+	assert_eq!(w.town_npcs().len(), 2);
+	assert_eq!(w.npc_rooms.len(), 1);
+	assert_eq!(w.town_npc(TownNPC::Guide).unwrap().home_x, 60);
+	assert_eq!(w.town_npc(TownNPC::Guide).unwrap().name, "Andrew");
+	
+	// Positions far past any real world don't overflow
+	w.add_town_npc(TownNPC::Guide, "Andrew", Some((u32::MAX, u32::MAX)));
+	assert_eq!(w.town_npc(TownNPC::Guide).unwrap().x, u32::MAX as f32 * 16.0);
+	w.add_town_npc(TownNPC::Guide, "Andrew", Some((60, 30)));
+	
+	assert!(w.rename(TownNPC::Guide, "Andrew"));
+	assert!(w.evict(TownNPC::Guide));
+	assert!(w.town_npc(TownNPC::Guide).unwrap().homeless);
+	assert!(w.npc_rooms.is_empty());
+	assert!(!w.rename(TownNPC::Nurse, "Abigail"));
+	
+	assert!(w.set_shimmered(TownNPC::Guide, true));
+	assert!(!w.set_shimmered(TownNPC::Cat, true));
+	
+	let read = Wld::from_bytes(&w.to_bytes().unwrap()).unwrap();
+	assert_eq!(read.npcs, w.npcs);
+	assert_eq!(read.town_npc(TownNPC::Guide).unwrap().name, "Andrew");
+	
+	assert!(w.remove_town_npc(TownNPC::Guide));
+	assert!(!w.remove_town_npc(TownNPC::Guide));
+	assert_eq!(w.town_npcs().len(), 1);
+}
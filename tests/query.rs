@@ -0,0 +1,65 @@
+extern crate wld_edit;
+
+use wld_edit::{Block, Liquid, LiquidType, Query, QueryError, Region, Slope, Tile, TileGrid, TileId, Wld};
+use wld_edit::query::{Field, Flag, Op};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: None, inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	w.tiles = TileGrid::new(20, 20, AIR);
+	let chest = TileId::from_name("Containers").unwrap().0;
+	for &(x, y) in &[(2, 2), (3, 2), (2, 3), (3, 3)] {
+		w.set_block(x, y, Tile { block: Some(Block { color: Some(4), uv: Some((0, 0)), ..block(chest).block.unwrap() }), ..AIR });
+	}
+	w.set_block(10, 10, block(chest));
+	w.set_block(5, 5, Tile { actuator: true, ..AIR });
+	w.set_block(6, 6, Tile { actuator: true, ..block(1) });
+	for x in 0..3 {
+		w.set_block(x, 15, Tile { liquid: Some(Liquid { kind: LiquidType::Lava, amount: 255 }), ..AIR });
+		w.set_block(x, 5, Tile { liquid: Some(Liquid { kind: LiquidType::Lava, amount: 255 }), ..AIR });
+	}
+	w.set_block(18, 1, Tile { yellow_wire: true, ..AIR });
+	w
+}
+
+#[test]
+fn parse() {
+	let q = Query::parse("block.id == 21 && block.color.is_some()").unwrap();
+	assert_eq!(q, Query::compare(Field::BlockId, Op::Eq, 21) & Query::Flag(Flag::BlockColor));
+	assert_eq!(Query::parse("block.id == Containers").unwrap(), Query::parse("block.id=21").unwrap());
+	assert_eq!(Query::parse("block.id == \"Wooden Chest\"").unwrap_err(), QueryError::UnknownValue { offset: 12, field: Field::BlockId, value: "\"Wooden Chest\"".to_string() });
+	assert_eq!("!(a || b)".parse::<Query>().unwrap_err(), QueryError::UnknownField { offset: 2, name: "a".to_string() });
+	assert_eq!(Query::parse("block &&").unwrap_err(), QueryError::UnexpectedEnd);
+	assert_eq!(Query::parse("block block").unwrap_err(), QueryError::UnexpectedToken { offset: 6, token: "block".to_string() });
+	assert_eq!(Query::parse("y < -5 || wall.is_none()").unwrap(), Query::compare(Field::Y, Op::Lt, -5) | !Query::Flag(Flag::Wall));
+	// && binds tighter than ||
+	assert_eq!(Query::parse("wire || block && liquid").unwrap(), Query::Flag(Flag::AnyWire) | (Query::Flag(Flag::Block) & Query::Flag(Flag::Liquid)));
+}
+
+#[test]
+fn find() {
+	let w = world();
+	let q = Query::parse("block.id == Containers && block.color.is_some()").unwrap();
+	assert_eq!(w.find(&q), vec![(2, 2), (2, 3), (3, 2), (3, 3)]);
+	assert_eq!(w.find(&Query::parse("actuator && !block").unwrap()), vec![(5, 5)]);
+	assert_eq!(w.find(&Query::parse("liquid.kind == lava && y < 10").unwrap()), vec![(0, 5), (1, 5), (2, 5)]);
+	assert_eq!(w.find(&Query::parse("wire").unwrap()), vec![(18, 1)]);
+	assert_eq!(w.find_in(&Query::parse("block").unwrap(), Region::new(0, 0, 8, 8)).len(), 5);
+	// Missing fields never match, even for !=
+	assert_eq!(w.find(&Query::parse("block.color != 4").unwrap()), vec![]);
+}
+
+#[test]
+fn find_regions() {
+	let w = world();
+	let chests = w.find_regions(&Query::parse("block.id == Containers").unwrap());
+	assert_eq!(chests, vec![Region::new(2, 2, 2, 2), Region::new(10, 10, 1, 1)]);
+	// Corners touching join groups
+	let lava = w.find_regions(&Query::parse("liquid || actuator").unwrap());
+	assert_eq!(lava, vec![Region::new(0, 5, 3, 1), Region::new(0, 15, 3, 1), Region::new(5, 5, 2, 2)]);
+}
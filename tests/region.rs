@@ -0,0 +1,144 @@
+extern crate wld_edit;
+
+use wld_edit::{Block, Chest, Item, Region, Sign, Slope, Tile, TileEntity, TileEntityInfo, TileGrid, TileId, Wld};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: TileId(id).is_frame_important().then_some((0, 0)), inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn world(width: u32, height: u32) -> Wld {
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.width = width;
+	w.height = height;
+	w.tiles = TileGrid::new(width, height, AIR);
+	w
+}
+
+fn build() -> Wld {
+	let mut w = world(30, 20);
+	for x in 0..30 {
+		for y in 0..20 {
+			w.tiles.set(x, y, block(((x * 7 + y) % 5) as u16));
+		}
+	}
+	w.tiles.set(5, 5, block(21));
+	w.chests.push(Chest { name: "loot".to_string(), x: 5, y: 5, items: vec![Some(Item { id: 2, prefix: 0, count: 10 })] });
+	w.signs.push(Sign { x: 6, y: 6, text: "hello".to_string() });
+	w.tile_entities.push(TileEntity { id: 0, x: 7, y: 7, info: TileEntityInfo::TargetDummy(0) });
+	w.tile_entities.push(TileEntity { id: 1, x: 25, y: 15, info: TileEntityInfo::Pylon });
+	w.weighted_pressure_plates.push((8, 8));
+	w
+}
+
+#[test]
+fn copy_keeps_relative_coordinates() {
+	let w = build();
+	let clip = w.copy(Region::new(4, 4, 6, 6));
+	assert_eq!((clip.width(), clip.height()), (6, 6));
+	assert_eq!(clip.tiles.get(1, 1), &block(21));
+	assert_eq!((clip.chests[0].x, clip.chests[0].y), (1, 1));
+	assert_eq!((clip.signs[0].x, clip.signs[0].y), (2, 2));
+	assert_eq!(clip.tile_entities.len(), 1);
+	assert_eq!(clip.weighted_pressure_plates, vec![(4, 4)]);
+	
+	// Regions hanging off the world are clamped
+	let clip = w.copy(Region::new(25, 15, 100, 100));
+	assert_eq!((clip.width(), clip.height()), (5, 5));
+}
+
+#[test]
+fn paste_into_another_world() {
+	let source = build();
+	let clip = source.copy(Region::new(4, 4, 6, 6));
+	let mut w = world(50, 50);
+	w.tile_entities.push(TileEntity { id: 9, x: 40, y: 40, info: TileEntityInfo::Pylon });
+	
+	assert_eq!(w.paste(&clip, 20, 30), Region::new(20, 30, 6, 6));
+	for x in 0..6 {
+		for y in 0..6 {
+			assert_eq!(w.tiles.get(20 + x, 30 + y), source.tiles.get(4 + x, 4 + y));
+		}
+	}
+	assert_eq!((w.chests[0].x, w.chests[0].y), (21, 31));
+	assert_eq!((w.signs[0].x, w.signs[0].y), (22, 32));
+	let dummy = w.tile_entities.iter().find(|e| e.info == TileEntityInfo::TargetDummy(0)).unwrap();
+	assert_eq!((dummy.id, dummy.x, dummy.y), (10, 23, 33));
+	assert_eq!(w.weighted_pressure_plates, vec![(24, 34)]);
+	
+	let read = Wld::from_bytes(&w.to_bytes().unwrap()).unwrap();
+	assert_eq!(read.tiles, w.tiles);
+}
+
+#[test]
+fn paste_over_itself_and_off_the_edge() {
+	let mut w = build();
+	let before = w.clone();
+	let clip = w.copy(Region::new(4, 4, 6, 6));
+	
+	// Overlapping the source: the old chest is replaced by the pasted one instead of duplicated
+	w.paste(&clip, 5, 5);
+	assert_eq!(w.chests.len(), 1);
+	assert_eq!((w.chests[0].x, w.chests[0].y), (6, 6));
+	assert_eq!(w.signs.len(), 1);
+	assert_eq!(w.tiles.get(6, 6), &block(21));
+	assert_eq!(w.tiles.get(4, 4), before.tiles.get(4, 4));
+	
+	// Only the part inside the world is written
+	let written = w.paste(&clip, -3, 17);
+	assert_eq!(written, Region::new(0, 17, 3, 3));
+	assert_eq!(w.tiles.get(0, 17), before.tiles.get(7, 4));
+	assert_eq!(w.chests.len(), 1);
+}
+
+#[test]
+fn paste_keeps_what_it_does_not_overwrite() {
+	let mut w = build();
+	// Out of the chest's 2x2 footprint
+	w.signs[0].x = 8;
+	w.signs[0].y = 4;
+	let mut clip = w.copy(Region::new(4, 4, 6, 6));
+	clip.chests.clear();
+	clip.signs.clear();
+	clip.tiles.set(4, 0, AIR);
+	
+	// The chest's tiles are pasted back unchanged so the chest stays, the sign's tile is replaced with air so the sign goes
+	w.paste(&clip, 4, 4);
+	assert_eq!(w.chests.len(), 1);
+	assert_eq!(w.chests[0].items, vec![Some(Item { id: 2, prefix: 0, count: 10 })]);
+	assert!(w.signs.is_empty());
+	assert_eq!(w.tile_entities.len(), 2);
+}
+
+#[test]
+fn paste_over_part_of_furniture() {
+	let containers = TileId::from_name("Containers").unwrap().0;
+	let mut w = build();
+	for (i, j) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+		w.tiles.set(5 + i, 5 + j, block(containers));
+	}
+	w.signs.clear();
+	
+	// Only the chest's bottom right cell is overwritten, its anchor is outside the pasted area
+	let clip = world(2, 2).copy(Region::new(0, 0, 2, 2));
+	w.paste(&clip, 6, 6);
+	assert!(w.chests.is_empty());
+	
+	// Pasting next to it leaves it alone
+	let mut w = build();
+	for (i, j) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+		w.tiles.set(5 + i, 5 + j, block(containers));
+	}
+	w.paste(&clip, 7, 5);
+	assert_eq!(w.chests.len(), 1);
+}
+
+#[test]
+fn regions_near_the_limit() {
+	let region = Region::new(u32::MAX - 5, 10, 100, 5);
+	assert_eq!(region.right(), u32::MAX);
+	assert!(region.contains(u32::MAX - 1, 12));
+	assert_eq!(region.clamp(u32::MAX - 2, 100), Region::new(u32::MAX - 5, 10, 3, 5));
+}
@@ -0,0 +1,131 @@
+extern crate wld_edit;
+
+use wld_edit::{Anchor, Block, Chest, NPCRoom, Region, Sign, Slope, Tile, TileEntity, TileEntityInfo, TileGrid, Section, Wld, WldError, NPC};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: None, inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn npc(id: u32, x: u32, y: u32) -> NPC {
+	NPC { id, name: String::new(), x: x as f32 * 16.0, y: y as f32 * 16.0, homeless: false, home_x: x, home_y: y, variation_index: 0, shimmered: false, is_pillar: false }
+}
+
+fn build() -> Wld {
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.width = 40;
+	w.height = 30;
+	w.right = 640;
+	w.bottom = 480;
+	w.tiles = TileGrid::new(40, 30, AIR);
+	w.tiles.set(10, 20, block(1));
+	w.tiles.set(35, 5, block(2));
+	w.world_surface_y = 10.0;
+	w.rock_layer_y = 20.0;
+	w.spawn_x = 20;
+	w.spawn_y = 9;
+	w.dungeon_x = 38;
+	w.dungeon_y = 8;
+	w.tree_type_xcoords = [10, 20, 30];
+	w.cave_bg_xcoords = [5, 15, 35];
+	w.chests.push(Chest { name: String::new(), x: 10, y: 19, items: vec![None; 40] });
+	w.chests.push(Chest { name: String::new(), x: 35, y: 4, items: vec![None; 40] });
+	w.signs.push(Sign { x: 12, y: 12, text: "hi".to_string() });
+	w.tile_entities.push(TileEntity { id: 0, x: 36, y: 6, info: TileEntityInfo::Pylon });
+	w.weighted_pressure_plates.push((11, 11));
+	w.npcs.push(npc(17, 12, 15));
+	w.npcs.push(npc(22, 36, 15));
+	w.npc_rooms.push(NPCRoom { id: 17, x: 12, y: 15 });
+	w.npc_rooms.push(NPCRoom { id: 22, x: 36, y: 15 });
+	w
+}
+
+#[test]
+fn grow_shifts_everything() {
+	let mut w = build();
+	w.resize(60, 40, Anchor::BottomRight).unwrap();
+	assert_eq!((w.width, w.height, w.right, w.bottom), (60, 40, 960, 640));
+	assert_eq!((w.tiles.width(), w.tiles.height()), (60, 40));
+	assert_eq!(w.tiles.get(30, 30), &block(1));
+	assert_eq!(w.tiles.get(0, 0), &AIR);
+	assert_eq!((w.world_surface_y, w.rock_layer_y), (20.0, 30.0));
+	assert_eq!((w.spawn_x, w.spawn_y, w.dungeon_x, w.dungeon_y), (40, 19, 58, 18));
+	assert_eq!(w.tree_type_xcoords, [30, 40, 50]);
+	assert_eq!((w.chests[0].x, w.chests[0].y), (30, 29));
+	assert_eq!((w.signs[0].x, w.signs[0].y), (32, 22));
+	assert_eq!((w.tile_entities[0].x, w.tile_entities[0].y), (56, 16));
+	assert_eq!(w.weighted_pressure_plates, vec![(31, 21)]);
+	assert_eq!((w.npcs[0].x, w.npcs[0].home_x, w.npc_rooms[0].x), (32.0 * 16.0, 32, 32));
+	
+	let bytes = w.to_bytes().unwrap();
+	assert_eq!(Wld::from_bytes(&bytes).unwrap(), w);
+}
+
+#[test]
+fn crop_drops_what_is_outside() {
+	let mut w = build();
+	w.crop(Region::new(5, 5, 25, 100));
+	assert_eq!((w.width, w.height), (25, 25));
+	assert_eq!(w.tiles.get(5, 15), &block(1));
+	assert_eq!(w.chests.len(), 1);
+	assert_eq!((w.chests[0].x, w.chests[0].y), (5, 14));
+	assert!(w.tile_entities.is_empty());
+	// The spawn stays inside, the dungeon is pulled back in
+	assert_eq!((w.spawn_x, w.spawn_y), (15, 4));
+	assert_eq!((w.dungeon_x, w.dungeon_y), (24, 3));
+	assert_eq!(w.cave_bg_xcoords, [0, 10, 25]);
+	assert_eq!(w.npcs.len(), 1);
+	assert_eq!(w.npc_rooms, vec![NPCRoom { id: 17, x: 7, y: 10 }]);
+	assert!(w.validate().iter().all(|d| !matches!(d, wld_edit::Diagnostic::InvalidNPCRoom { .. })));
+}
+
+#[test]
+fn homes_outside_make_npcs_homeless() {
+	let mut w = build();
+	w.npcs[1].x = 100.0;
+	w.resize(30, 30, Anchor::TopLeft).unwrap();
+	assert_eq!(w.npcs.len(), 2);
+	assert!(w.npcs[1].homeless);
+	assert_eq!(w.npc_rooms.len(), 1);
+}
+
+#[test]
+fn rooms_only_go_when_outside() {
+	let mut w = build();
+	// A room for an npc that isn't in the world, and one for a homeless npc
+	w.npc_rooms.push(NPCRoom { id: 18, x: 20, y: 15 });
+	w.npcs[0].homeless = true;
+	w.resize(60, 40, Anchor::BottomRight).unwrap();
+	assert_eq!(w.npc_rooms, vec![NPCRoom { id: 17, x: 32, y: 25 }, NPCRoom { id: 22, x: 56, y: 25 }, NPCRoom { id: 18, x: 40, y: 25 }]);
+}
+
+#[test]
+fn size_limit() {
+	let mut w = build();
+	assert!(matches!(w.resize(8401, 100, Anchor::Center), Err(WldError::WorldTooLarge { width: 8401, height: 100 })));
+	assert_eq!(w.width, 40);
+	w.resize_oversized(20, 2401, Anchor::Center);
+	assert_eq!((w.width, w.height), (20, 2401));
+	assert_eq!(w.tiles.get(0, 1205), &block(1));
+}
+
+#[test]
+fn unknown_tile_data_is_dropped() {
+	let mut w = build();
+	w.unknown_data = vec![vec![7]; Section::ALL.len()];
+	let mut resized = w.clone();
+	resized.resize(60, 40, Anchor::Center).unwrap();
+	let mut oversized = w.clone();
+	oversized.resize_oversized(20, 2401, Anchor::Center);
+	let mut cropped = w.clone();
+	cropped.crop(Region::new(5, 5, 25, 100));
+	for w in &[resized, oversized, cropped] {
+		assert_eq!(w.unknown_data[Section::Header.index()], vec![7]);
+		assert_eq!(w.unknown_data[Section::Bestiary.index()], vec![7]);
+		assert!(w.unknown_data[Section::Tiles.index()].is_empty());
+		assert!(w.unknown_data[Section::PressurePlates.index()].is_empty());
+		assert!(w.unknown_data[Section::TownManager.index()].is_empty());
+	}
+}
@@ -0,0 +1,356 @@
+extern crate rand;
+extern crate wld_edit;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use wld_edit::{Block, Chest, CreativePower, Item, Liquid, LiquidType, NPCRoom, Sign, Slope, Tile, TileEntity, TileEntityInfo, TileGrid, Wall, Wld, WldError, WldFile, NPC};
+
+const SLOPES: [Slope; 6] = [Slope::Full, Slope::Half, Slope::LowerLeft, Slope::LowerRight, Slope::UpperLeft, Slope::UpperRight];
+const LIQUIDS: [LiquidType; 4] = [LiquidType::Water, LiquidType::Lava, LiquidType::Honey, LiquidType::Shimmer];
+const BLOCK_IDS: u16 = 700;
+const WALL_IDS: u16 = 350;
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn paint(rng: &mut StdRng) -> Option<u8> {
+	match rng.gen_bool(0.3) {
+		true => Some(rng.gen_range(1..32)),
+		false => None
+	}
+}
+
+fn random_item(rng: &mut StdRng) -> Item {
+	Item { id: rng.gen_range(1..5500), prefix: rng.gen_range(0..85), count: rng.gen_range(1..=9999) }
+}
+
+fn random_slot(rng: &mut StdRng) -> Option<Item> {
+	match rng.gen_bool(0.5) {
+		true => Some(random_item(rng)),
+		false => None
+	}
+}
+
+fn random_string(rng: &mut StdRng, max: usize) -> String {
+	let chars = ['a', 'Z', '7', ' ', '!', 'é', '木', '🌲'];
+	(0..rng.gen_range(0..=max)).map(|_| chars[rng.gen_range(0..chars.len())]).collect()
+}
+
+fn random_tile(rng: &mut StdRng, importance: &[bool]) -> Tile {
+	let block = match rng.gen_bool(0.6) {
+		true => {
+			let id = rng.gen_range(0..BLOCK_IDS);
+			Some(Block {
+				id,
+				color: paint(rng),
+				uv: importance[id as usize].then(|| (rng.gen_range(0..1000) * 2, rng.gen_range(0..1000) * 2)),
+				inactive: rng.gen_bool(0.1),
+				slope: SLOPES[rng.gen_range(0..SLOPES.len())]
+			})
+		}
+		false => None
+	};
+	let wall = match rng.gen_bool(0.5) {
+		true => Some(Wall { id: rng.gen_range(1..WALL_IDS), color: paint(rng) }),
+		false => None
+	};
+	let liquid = match rng.gen_bool(0.3) {
+		true => Some(Liquid { kind: LIQUIDS[rng.gen_range(0..LIQUIDS.len())], amount: rng.gen_range(1..=255) }),
+		false => None
+	};
+	Tile {
+		block,
+		wall,
+		liquid,
+		red_wire: rng.gen_bool(0.2),
+		green_wire: rng.gen_bool(0.2),
+		blue_wire: rng.gen_bool(0.2),
+		yellow_wire: rng.gen_bool(0.2),
+		actuator: rng.gen_bool(0.1)
+	}
+}
+
+fn npc(id: u32, name: &str, is_pillar: bool) -> NPC {
+	NPC { id, name: name.to_string(), x: 0.0, y: 0.0, homeless: false, home_x: 0, home_y: 0, variation_index: 0, shimmered: false, is_pillar }
+}
+
+fn generate(seed: u64, width: u32, height: u32) -> Wld {
+	let mut rng = StdRng::seed_from_u64(seed);
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.name = format!("Round Trip {} {}", seed, "x".repeat(150));
+	w.seed = rng.gen_range(0..i32::MAX).to_string();
+	w.id = rng.gen();
+	w.guid = rng.gen();
+	w.width = width;
+	w.height = height;
+	w.gamemode = 3;
+	w.drunk_world = true;
+	w.zenith_world = true;
+	w.ice_bg = 1;
+	w.jungle_bg = 2;
+	w.hell_bg = 3;
+	w.spawn_x = width / 2;
+	w.spawn_y = height / 3;
+	w.world_surface_y = height as f64 * 0.3;
+	w.rock_layer_y = height as f64 * 0.4;
+	w.killed_plantera = true;
+	w.downed_moonlord = true;
+	w.angler_finishers = vec![random_string(&mut rng, 20), random_string(&mut rng, 200)];
+	w.kill_counts = (0..688).map(|_| rng.gen_range(0..100)).collect();
+	w.party_celebrating_npcs = vec![17, 18];
+	w.tree_top_stuff = (0..13).map(|_| rng.gen_range(0..10)).collect();
+	w.importance = (0..BLOCK_IDS).map(|_| rng.gen_bool(0.3)).collect();
+	
+	// Every slope and liquid combination at least once, then random tiles drawn from a small pool so runs form
+	w.tiles = TileGrid::new(width, height, AIR);
+	let mut i = 0;
+	for slope in SLOPES {
+		for kind in LIQUIDS {
+			let id = (0..BLOCK_IDS).rev().find(|&id| w.importance[id as usize]).unwrap();
+			let tile = Tile {
+				block: Some(Block { id, color: Some(5), uv: Some((18, 36)), inactive: true, slope }),
+				wall: Some(Wall { id: 300, color: Some(7) }),
+				liquid: Some(Liquid { kind, amount: 128 }),
+				red_wire: true,
+				green_wire: true,
+				blue_wire: true,
+				yellow_wire: true,
+				actuator: true
+			};
+			w.tiles.set(i / height, i % height, tile);
+			i += 1;
+		}
+	}
+	let pool: Vec<Tile> = (0..64).map(|_| random_tile(&mut rng, &w.importance)).collect();
+	while i < width * height {
+		let tile = pool[rng.gen_range(0..pool.len())];
+		for _ in 0..rng.gen_range(1..50) {
+			if i == width * height { break }
+			w.tiles.set(i / height, i % height, tile);
+			i += 1;
+		}
+	}
+	
+	w.chests = (0..20).map(|_| Chest {
+		x: rng.gen_range(0..width),
+		y: rng.gen_range(0..height),
+		name: random_string(&mut rng, 30),
+		items: (0..40).map(|_| random_slot(&mut rng)).collect()
+	}).collect();
+	
+	w.signs = (0..10).map(|_| Sign { x: rng.gen_range(0..width), y: rng.gen_range(0..height), text: random_string(&mut rng, 300) }).collect();
+	
+	let mut guide = npc(22, "Andrew", false);
+	guide.homeless = true;
+	let mut merchant = npc(17, "Seamus", false);
+	merchant.home_x = 40;
+	merchant.home_y = 50;
+	merchant.variation_index = 1;
+	merchant.shimmered = true;
+	let mut nurse = npc(18, "Molly", false);
+	nurse.x = 123.5;
+	nurse.y = 456.25;
+	w.npcs = vec![guide, merchant, nurse, npc(422, "", true), npc(493, "", true), npc(507, "", true), npc(517, "", true)];
+	
+	let infos = vec![
+		TileEntityInfo::TargetDummy(3),
+		TileEntityInfo::ItemFrame(None),
+		TileEntityInfo::ItemFrame(Some(random_item(&mut rng))),
+		TileEntityInfo::LogicSensor(2, true),
+		TileEntityInfo::Mannequin([None; 16]),
+		TileEntityInfo::Mannequin([(); 16].map(|_| random_slot(&mut rng))),
+		TileEntityInfo::WeaponRack(None),
+		TileEntityInfo::WeaponRack(Some(random_item(&mut rng))),
+		TileEntityInfo::HatRack([(); 4].map(|_| random_slot(&mut rng))),
+		TileEntityInfo::FoodPlatter(None),
+		TileEntityInfo::FoodPlatter(Some(random_item(&mut rng))),
+		TileEntityInfo::Pylon
+	];
+	w.tile_entities = infos.into_iter().enumerate().map(|(i, info)| TileEntity { id: i as u32, x: rng.gen_range(0..width) as u16, y: rng.gen_range(0..height) as u16, info }).collect();
+	
+	w.weighted_pressure_plates = (0..5).map(|_| (rng.gen_range(0..width), rng.gen_range(0..height))).collect();
+	w.npc_rooms = vec![NPCRoom { id: 17, x: 40, y: 50 }, NPCRoom { id: 18, x: 60, y: 50 }];
+	w.bestiary_kills = vec![(String::from("BlueSlime"), 12), (String::from("Zombie"), 300)];
+	w.bestiary_sights = vec![String::from("DemonEye")];
+	w.bestiary_chats = vec![String::from("Guide"), String::from("Merchant")];
+	w.creative_powers = vec![
+		CreativePower::FreezeTime(true),
+		CreativePower::TimeRate(4.0),
+		CreativePower::FreezeWeather(false),
+		CreativePower::FreezeWind(true),
+		CreativePower::DifficultySlider(0.75),
+		CreativePower::FreezeSpread(true)
+	];
+	
+	w
+}
+
+fn assert_same(a: &Wld, b: &Wld) {
+	assert_eq!(a.tiles.width(), b.tiles.width());
+	assert_eq!(a.tiles.height(), b.tiles.height());
+	for x in 0..a.tiles.width() {
+		for y in 0..a.tiles.height() {
+			assert_eq!(a.tiles.get(x, y), b.tiles.get(x, y), "tile ({}, {})", x, y);
+		}
+	}
+	assert_eq!(a.importance, b.importance);
+	assert_eq!(a.chests, b.chests);
+	assert_eq!(a.signs, b.signs);
+	assert_eq!(a.npcs, b.npcs);
+	assert_eq!(a.tile_entities, b.tile_entities);
+	assert_eq!(a.weighted_pressure_plates, b.weighted_pressure_plates);
+	assert_eq!(a.npc_rooms, b.npc_rooms);
+	assert_eq!(a.bestiary_kills, b.bestiary_kills);
+	assert_eq!(a.bestiary_sights, b.bestiary_sights);
+	assert_eq!(a.bestiary_chats, b.bestiary_chats);
+	assert_eq!(a.creative_powers, b.creative_powers);
+	assert_eq!(a.unknown_data, b.unknown_data);
+	assert!(a == b, "header fields differ");
+}
+
+#[test]
+fn round_trip() {
+	for seed in 0..4 {
+		let w = generate(seed, 120, 90);
+		let bytes = w.to_bytes().unwrap();
+		let read = Wld::from_bytes(&bytes).unwrap();
+		assert_same(&w, &read);
+		assert!(read.to_bytes().unwrap() == bytes, "rewrite of seed {} is not byte-identical", seed);
+	}
+}
+
+#[test]
+fn round_trip_through_reader_and_writer() {
+	let w = generate(10, 60, 40);
+	let mut bytes = vec![];
+	w.to_writer(&mut bytes).unwrap();
+	let read = Wld::from_reader(&bytes[..]).unwrap();
+	assert_same(&w, &read);
+}
+
+#[test]
+fn round_trip_tall_runs() {
+	let mut w = generate(20, 3, 2400);
+	let tile = *w.tiles.get(2, 0);
+	for y in 0..2400 {
+		w.tiles.set(2, y, tile);
+	}
+	let bytes = w.to_bytes().unwrap();
+	let read = Wld::from_bytes(&bytes).unwrap();
+	assert_same(&w, &read);
+	assert!(read.to_bytes().unwrap() == bytes);
+}
+
+#[test]
+fn round_trip_unknown_data() {
+	let mut w = generate(30, 20, 20);
+	w.unknown_data = vec![vec![]; 11];
+	w.unknown_data[1] = vec![1, 2, 3];
+	w.unknown_data[5] = vec![4];
+	w.unknown_data.push(vec![5, 6, 7, 8]);
+	let bytes = w.to_bytes().unwrap();
+	let read = Wld::from_bytes(&bytes).unwrap();
+	assert_same(&w, &read);
+	assert!(read.to_bytes().unwrap() == bytes);
+}
+
+#[test]
+fn round_trip_short_chests() {
+	let mut w = generate(50, 20, 20);
+	w.chests[0].items.truncate(3);
+	let read = Wld::from_bytes(&w.to_bytes().unwrap()).unwrap();
+	assert_eq!(read.chests[0].items.len(), 40);
+	assert_eq!(read.chests[0].items[..3], w.chests[0].items[..]);
+	assert!(read.chests[0].items[3..].iter().all(|item| item.is_none()));
+	
+	w.chests[1].items.resize(41, None);
+	assert!(w.to_bytes().is_err());
+}
+
+// Strips everything the oldest supported version can't store
+fn downgrade(w: &mut Wld) {
+	w.gamemode = 0;
+	w.chests.clear();
+	w.tile_entities.clear();
+	w.weighted_pressure_plates.clear();
+	w.npc_rooms.clear();
+	w.bestiary_kills.clear();
+	w.bestiary_sights.clear();
+	w.bestiary_chats.clear();
+	w.creative_powers.clear();
+	w.npcs.retain(|npc| !npc.is_pillar);
+	for npc in w.npcs.iter_mut() {
+		npc.variation_index = 0;
+		npc.shimmered = false;
+	}
+	for x in 0..w.tiles.width() {
+		for y in 0..w.tiles.height() {
+			let mut tile = *w.tiles.get(x, y);
+			tile.yellow_wire = false;
+			tile.wall = tile.wall.map(|wall| Wall { id: wall.id % 256, ..wall });
+			tile.liquid = tile.liquid.map(|liquid| Liquid { kind: match liquid.kind { LiquidType::Shimmer => LiquidType::Water, kind => kind }, ..liquid });
+			w.tiles.set(x, y, tile);
+		}
+	}
+}
+
+#[test]
+fn round_trip_older_versions() {
+	let mut w = generate(40, 30, 30);
+	downgrade(&mut w);
+	
+	for version in [88, 102, 135, 140, 170, 179, 190, 210, 222, 238, 260, 269] {
+		let bytes = wld_edit::write::write_version(&w, version).unwrap();
+		let read = Wld::from_bytes(&bytes).unwrap();
+		assert_eq!(read.version, version);
+		assert!(read.tiles == w.tiles, "tiles differ at version {}", version);
+		assert_eq!(read.signs, w.signs);
+		assert_eq!(read.npcs, w.npcs);
+		assert!(read.to_bytes().unwrap() == bytes, "rewrite at version {} is not byte-identical", version);
+	}
+}
+
+// Adds `extra` entries to the end of the pointer table, shifting the real pointers past the inserted bytes
+fn pad_pointers(bytes: &[u8], version: u32, extra: &[u32]) -> Vec<u8> {
+	let table = match version >= 135 {
+		true => 24,
+		false => 4
+	};
+	let count = u16::from_le_bytes([bytes[table], bytes[table + 1]]) as usize;
+	let shift = extra.len() as u32 * 4;
+	let mut padded = bytes[..table].to_vec();
+	padded.extend_from_slice(&((count + extra.len()) as u16).to_le_bytes());
+	for i in 0..count {
+		let p = table + 2 + i * 4;
+		let pointer = u32::from_le_bytes([bytes[p], bytes[p + 1], bytes[p + 2], bytes[p + 3]]);
+		padded.extend_from_slice(&(pointer + shift).to_le_bytes());
+	}
+	for pointer in extra {
+		padded.extend_from_slice(&pointer.to_le_bytes());
+	}
+	padded.extend_from_slice(&bytes[table + 2 + count * 4..]);
+	padded
+}
+
+#[test]
+fn zero_and_trailing_pointers_are_skipped() {
+	let mut w = generate(60, 30, 20);
+	downgrade(&mut w);
+	for version in [102, 170] {
+		let bytes = wld_edit::write::write_version(&w, version).unwrap();
+		// Older games write a fixed size table and leave what they don't use at zero
+		let read = Wld::from_bytes(&pad_pointers(&bytes, version, &[0, 0])).unwrap();
+		assert!(read.tiles == w.tiles, "tiles differ at version {}", version);
+		assert_eq!(read.signs, w.signs);
+		// A trailing pointer behind the footer isn't a section end either
+		let read = Wld::from_bytes(&pad_pointers(&bytes, version, &[100])).unwrap();
+		assert!(read.tiles == w.tiles, "tiles differ at version {}", version);
+	}
+}
+
+#[test]
+fn headers_without_tiles_are_not_written() {
+	let w = generate(50, 30, 20);
+	let header = WldFile::new(std::io::Cursor::new(w.to_bytes().unwrap())).unwrap().header;
+	assert_eq!((header.width, header.height), (30, 20));
+	assert!(matches!(header.to_bytes(), Err(WldError::TileGridMismatch { width: 30, height: 20, tiles_width: 0, tiles_height: 0 })));
+}
@@ -0,0 +1,116 @@
+extern crate wld_edit;
+
+use wld_edit::{Block, Chest, Direction, NPCRoom, Section, Sign, Slope, Tile, TileEntity, TileEntityInfo, TileGrid, Wld, WldError, NPC};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv: None, inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn npc(id: u32, x: u32, y: u32) -> NPC {
+	NPC { id, name: String::new(), x: x as f32 * 16.0, y: y as f32 * 16.0, homeless: false, home_x: x, home_y: y, variation_index: 0, shimmered: false, is_pillar: false }
+}
+
+fn world(width: u32, height: u32, id: u16) -> Wld {
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.width = width;
+	w.height = height;
+	w.right = width * 16;
+	w.bottom = height * 16;
+	w.tiles = TileGrid::new(width, height, block(id));
+	w.spawn_x = width / 2;
+	w.spawn_y = height / 2;
+	w.chests.push(Chest { name: format!("chest {}", id), x: 1, y: 1, items: vec![None; 40] });
+	w.signs.push(Sign { x: 2, y: 2, text: format!("sign {}", id) });
+	w.tile_entities.push(TileEntity { id: 0, x: 3, y: 3, info: TileEntityInfo::Pylon });
+	w.weighted_pressure_plates.push((4, 4));
+	w.npcs.push(npc(22, 5, 5));
+	w.npc_rooms.push(NPCRoom { id: 22, x: 5, y: 5 });
+	w
+}
+
+#[test]
+fn side_by_side() {
+	let mut a = world(20, 10, 1);
+	a.name = "first".to_string();
+	a.bestiary_kills = vec![("Zombie".to_string(), 3)];
+	a.bestiary_sights = vec!["Bunny".to_string()];
+	let mut b = world(30, 15, 2);
+	b.name = "second".to_string();
+	b.npcs.push(npc(17, 6, 6));
+	b.npc_rooms.push(NPCRoom { id: 17, x: 6, y: 6 });
+	b.bestiary_kills = vec![("Slime".to_string(), 1), ("Zombie".to_string(), 2)];
+	b.bestiary_sights = vec!["Bird".to_string(), "Bunny".to_string()];
+	
+	let w = Wld::stitch(&a, &b, Direction::Right).unwrap();
+	assert_eq!((w.width, w.height, w.right, w.bottom), (50, 15, 800, 240));
+	assert_eq!(w.name, "first");
+	assert_eq!((w.spawn_x, w.spawn_y), (10, 5));
+	assert_eq!(w.tiles.get(19, 9), &block(1));
+	assert_eq!(w.tiles.get(19, 10), &AIR);
+	assert_eq!(w.tiles.get(20, 14), &block(2));
+	assert_eq!(w.chests.iter().map(|c| (c.x, c.y)).collect::<Vec<_>>(), vec![(1, 1), (21, 1)]);
+	assert_eq!(w.signs[1].x, 22);
+	assert_eq!(w.tile_entities.iter().map(|e| (e.id, e.x)).collect::<Vec<_>>(), vec![(0, 3), (1, 23)]);
+	assert_eq!(w.weighted_pressure_plates, vec![(4, 4), (24, 4)]);
+	// The second guide is left out, the merchant comes along with their room
+	assert_eq!(w.npcs.iter().map(|n| (n.id, n.home_x)).collect::<Vec<_>>(), vec![(22, 5), (17, 26)]);
+	assert_eq!(w.npc_rooms, vec![NPCRoom { id: 22, x: 5, y: 5 }, NPCRoom { id: 17, x: 26, y: 6 }]);
+	assert_eq!(w.bestiary_kills, vec![("Zombie".to_string(), 5), ("Slime".to_string(), 1)]);
+	assert_eq!(w.bestiary_sights, vec!["Bunny".to_string(), "Bird".to_string()]);
+	
+	let bytes = w.to_bytes().unwrap();
+	assert_eq!(Wld::from_bytes(&bytes).unwrap(), w);
+}
+
+#[test]
+fn directions() {
+	let a = world(20, 10, 1);
+	let b = world(30, 15, 2);
+	
+	let w = Wld::stitch(&a, &b, Direction::Left).unwrap();
+	assert_eq!(w.tiles.get(30, 0), &block(1));
+	assert_eq!(w.chests[0].x, 31);
+	assert_eq!(w.spawn_x, 40);
+	
+	let w = Wld::stitch(&a, &b, Direction::Above).unwrap();
+	assert_eq!((w.width, w.height), (30, 25));
+	assert_eq!(w.tiles.get(0, 15), &block(1));
+	assert_eq!(w.tiles.get(25, 20), &AIR);
+	assert_eq!((w.chests[0].y, w.chests[1].y), (16, 1));
+	
+	let w = Wld::stitch(&a, &b, Direction::Below).unwrap();
+	assert_eq!(w.tiles.get(0, 10), &block(2));
+	assert_eq!(w.chests[1].y, 11);
+}
+
+#[test]
+fn chest_limit() {
+	let a = world(20, 10, 1);
+	let mut b = world(20, 10, 2);
+	b.chests = vec![Chest { name: String::new(), x: 0, y: 0, items: vec![] }; u16::MAX as usize];
+	assert!(matches!(Wld::stitch(&a, &b, Direction::Right), Err(WldError::TooManyEntries { section: Section::Chests, count: 65536 })));
+}
+
+#[test]
+fn size_limit() {
+	let a = world(8000, 10, 1);
+	let b = world(1000, 10, 2);
+	assert!(matches!(Wld::stitch(&a, &b, Direction::Left), Err(WldError::WorldTooLarge { width: 9000, height: 10 })));
+	let b = world(10, 2000, 2);
+	assert!(matches!(Wld::stitch(&world(10, 1000, 1), &b, Direction::Below), Err(WldError::WorldTooLarge { width: 10, height: 3000 })));
+}
+
+#[test]
+fn unknown_tile_data_is_dropped() {
+	let mut a = world(20, 10, 1);
+	a.unknown_data = vec![vec![7]; Section::ALL.len()];
+	let w = Wld::stitch(&a, &world(20, 10, 2), Direction::Right).unwrap();
+	assert_eq!(w.unknown_data[Section::Header.index()], vec![7]);
+	assert_eq!(w.unknown_data[Section::Bestiary.index()], vec![7]);
+	assert!(w.unknown_data[Section::Tiles.index()].is_empty());
+	assert!(w.unknown_data[Section::Chests.index()].is_empty());
+	assert!(w.unknown_data[Section::TownManager.index()].is_empty());
+}
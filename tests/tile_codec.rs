@@ -0,0 +1,162 @@
+extern crate rand;
+extern crate wld_edit;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use wld_edit::{Block, Liquid, LiquidType, Slope, Tile, TileGrid, Wall, Wld, WldError};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn random_tile(rng: &mut StdRng) -> Tile {
+	let id = rng.gen_range(0..600);
+	Tile {
+		block: rng.gen_bool(0.5).then(|| Block {
+			id,
+			color: rng.gen_bool(0.3).then(|| rng.gen_range(1..32)),
+			uv: (id % 4 == 0).then(|| (rng.gen(), rng.gen())),
+			inactive: rng.gen_bool(0.2),
+			slope: [Slope::Full, Slope::Half, Slope::LowerLeft, Slope::LowerRight, Slope::UpperLeft, Slope::UpperRight][rng.gen_range(0..6)]
+		}),
+		wall: rng.gen_bool(0.5).then(|| Wall { id: rng.gen_range(1..400), color: rng.gen_bool(0.3).then(|| rng.gen_range(1..32)) }),
+		liquid: rng.gen_bool(0.3).then(|| Liquid {
+			kind: [LiquidType::Water, LiquidType::Lava, LiquidType::Honey, LiquidType::Shimmer][rng.gen_range(0..4)],
+			amount: rng.gen()
+		}),
+		red_wire: rng.gen_bool(0.2),
+		green_wire: rng.gen_bool(0.2),
+		blue_wire: rng.gen_bool(0.2),
+		yellow_wire: rng.gen_bool(0.2),
+		actuator: rng.gen_bool(0.2)
+	}
+}
+
+fn world(width: u32, height: u32) -> Wld {
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.width = width;
+	w.height = height;
+	w.importance = (0..600).map(|id| id % 4 == 0).collect();
+	w.tiles = TileGrid::new(width, height, AIR);
+	w
+}
+
+// Fills every column with runs of random length drawn from a few tiles, so both short and long runs appear
+fn fill(rng: &mut StdRng, w: &mut Wld, max_run: u32) {
+	let pool: Vec<Tile> = (0..rng.gen_range(1..6)).map(|_| random_tile(rng)).collect();
+	for x in 0..w.width {
+		let mut y = 0;
+		while y < w.height {
+			let tile = pool[rng.gen_range(0..pool.len())];
+			for _ in 0..rng.gen_range(1..=max_run) {
+				if y == w.height { break }
+				w.tiles.set(x, y, tile);
+				y += 1;
+			}
+		}
+	}
+}
+
+fn assert_round_trip(w: &Wld) {
+	let bytes = w.to_bytes().unwrap();
+	let read = Wld::from_bytes(&bytes).unwrap();
+	assert!(read.tiles == w.tiles, "tiles differ for a {}x{} world", w.width, w.height);
+	assert!(read.to_bytes().unwrap() == bytes, "rewrite of a {}x{} world is not byte-identical", w.width, w.height);
+}
+
+fn tile_section_start(bytes: &[u8]) -> usize {
+	u32::from_le_bytes([bytes[30], bytes[31], bytes[32], bytes[33]]) as usize
+}
+
+#[test]
+fn every_column_height() {
+	let mut rng = StdRng::seed_from_u64(0);
+	for height in (1..=600).chain([1023, 1024, 1025, 2400, 65535, 65536, 65537]) {
+		let mut w = world(3, height);
+		fill(&mut rng, &mut w, 300);
+		assert_round_trip(&w);
+	}
+}
+
+#[test]
+fn runs_longer_than_u16() {
+	let mut rng = StdRng::seed_from_u64(1);
+	let tile = random_tile(&mut rng);
+	let mut w = world(2, 200_000);
+	for y in 0..200_000 {
+		w.tiles.set(0, y, tile);
+	}
+	for y in 65530..65545 {
+		w.tiles.set(1, y, tile);
+	}
+	for y in 131071..200_000 {
+		w.tiles.set(1, y, tile);
+	}
+	assert_round_trip(&w);
+}
+
+#[test]
+fn random_columns() {
+	let mut rng = StdRng::seed_from_u64(2);
+	for _ in 0..300 {
+		let mut w = world(rng.gen_range(1..8), rng.gen_range(1..700));
+		let max_run = rng.gen_range(1..800);
+		fill(&mut rng, &mut w, max_run);
+		assert_round_trip(&w);
+	}
+}
+
+#[test]
+fn block_without_importance_entry() {
+	let mut w = world(2, 2);
+	let mut tile = AIR;
+	tile.block = Some(Block { id: 600, color: None, uv: None, inactive: false, slope: Slope::Full });
+	w.tiles.set(1, 0, tile);
+	assert!(matches!(w.to_bytes(), Err(WldError::UnknownTileId { id: 600, .. })));
+}
+
+#[test]
+fn arbitrary_tile_bytes_never_panic() {
+	let mut rng = StdRng::seed_from_u64(3);
+	let mut w = world(4, 50);
+	fill(&mut rng, &mut w, 20);
+	let valid = w.to_bytes().unwrap();
+	let start = tile_section_start(&valid);
+	
+	for _ in 0..3000 {
+		let mut bytes = valid[..start].to_vec();
+		bytes.extend((0..rng.gen_range(0..600)).map(|_| rng.gen::<u8>()));
+		let _ = Wld::from_bytes(&bytes);
+	}
+}
+
+#[test]
+fn mutated_files_never_panic() {
+	let mut rng = StdRng::seed_from_u64(4);
+	let mut w = world(4, 50);
+	fill(&mut rng, &mut w, 20);
+	let valid = w.to_bytes().unwrap();
+	
+	for _ in 0..3000 {
+		let mut bytes = valid.clone();
+		for _ in 0..rng.gen_range(1..8) {
+			let i = rng.gen_range(0..bytes.len());
+			bytes[i] = rng.gen();
+		}
+		if rng.gen_bool(0.3) {
+			bytes.truncate(rng.gen_range(0..bytes.len()));
+		}
+		let _ = Wld::from_bytes(&bytes);
+	}
+}
+
+#[test]
+fn arbitrary_bytes_never_panic() {
+	let mut rng = StdRng::seed_from_u64(5);
+	for _ in 0..3000 {
+		let mut bytes: Vec<u8> = (0..rng.gen_range(0..400)).map(|_| rng.gen()).collect();
+		// Mostly start with a plausible version so the decoder gets past the first check
+		if bytes.len() >= 4 && rng.gen_bool(0.8) {
+			bytes[..4].copy_from_slice(&rng.gen_range(88u32..300).to_le_bytes());
+		}
+		let _ = Wld::from_bytes(&bytes);
+	}
+}
@@ -0,0 +1,186 @@
+extern crate wld_edit;
+
+use wld_edit::{Block, Chest, Clipboard, Region, Rejected, Slope, Tile, TileGrid, TileId, Transform, Wall, Wld};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16, slope: Slope, uv: Option<(u16, u16)>) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv, inactive: false, slope }), ..AIR }
+}
+
+fn id(name: &str) -> u16 {
+	TileId::from_name(name).unwrap().0
+}
+
+fn clipboard(width: u32, height: u32) -> Clipboard {
+	let mut w = Wld::_new();
+	w.width = width;
+	w.height = height;
+	w.tiles = TileGrid::new(width, height, AIR);
+	for x in 0..width {
+		for y in 0..height {
+			w.tiles.set(x, y, block(((x * 2 + y * 5) % 3) as u16, Slope::Full, None));
+		}
+	}
+	w.copy(Region::new(0, 0, width, height))
+}
+
+fn same(a: &Clipboard, b: &Clipboard) {
+	assert_eq!((a.width(), a.height()), (b.width(), b.height()));
+	for x in 0..a.width() {
+		for y in 0..a.height() {
+			assert_eq!(a.tiles.get(x, y), b.tiles.get(x, y));
+		}
+	}
+	assert_eq!(a.chests, b.chests);
+}
+
+#[test]
+fn slopes_follow_their_corner() {
+	assert_eq!(Transform::FlipHorizontal.slope(Slope::LowerLeft), Some(Slope::LowerRight));
+	assert_eq!(Transform::FlipVertical.slope(Slope::LowerLeft), Some(Slope::UpperLeft));
+	assert_eq!(Transform::Rotate90.slope(Slope::LowerLeft), Some(Slope::UpperLeft));
+	assert_eq!(Transform::Rotate270.slope(Slope::UpperLeft), Some(Slope::LowerLeft));
+	assert_eq!(Transform::Rotate180.slope(Slope::UpperRight), Some(Slope::LowerLeft));
+	assert_eq!(Transform::FlipHorizontal.slope(Slope::Half), Some(Slope::Half));
+	assert_eq!(Transform::Rotate90.slope(Slope::Half), None);
+	
+	let mut clip = clipboard(3, 2);
+	clip.tiles.set(0, 0, block(1, Slope::LowerLeft, None));
+	let (turned, rejected) = clip.transform(Transform::Rotate90, &wld_edit::ids::default_importance());
+	assert!(rejected.is_empty());
+	assert_eq!((turned.width(), turned.height()), (2, 3));
+	assert_eq!(turned.tiles.get(1, 0), &block(1, Slope::UpperLeft, None));
+}
+
+#[test]
+fn involutions_and_full_turns() {
+	let importance = wld_edit::ids::default_importance();
+	let mut clip = clipboard(5, 3);
+	clip.tiles.set(1, 1, block(1, Slope::UpperRight, None));
+	clip.tiles.set(3, 2, block(2, Slope::LowerRight, None));
+	
+	for &t in &[Transform::FlipHorizontal, Transform::FlipVertical, Transform::Rotate180] {
+		let (once, _) = clip.transform(t, &importance);
+		let (twice, _) = once.transform(t, &importance);
+		same(&twice, &clip);
+	}
+	
+	let mut turned = clip.clone();
+	for _ in 0..4 {
+		turned = turned.transform(Transform::Rotate90, &importance).0;
+	}
+	same(&turned, &clip);
+	
+	let (right, _) = clip.transform(Transform::Rotate90, &importance);
+	let (left, _) = right.transform(Transform::Rotate270, &importance);
+	same(&left, &clip);
+}
+
+#[test]
+fn furniture_is_mirrored() {
+	let importance = wld_edit::ids::default_importance();
+	let mut clip = clipboard(6, 2);
+	
+	// A chair facing left, then a bed facing left
+	let chair = id("Chairs");
+	clip.tiles.set(0, 0, block(chair, Slope::Full, Some((0, 0))));
+	clip.tiles.set(0, 1, block(chair, Slope::Full, Some((0, 18))));
+	let bed = id("Beds");
+	for i in 0..4 {
+		for j in 0..2 {
+			clip.tiles.set(2 + i, j, block(bed, Slope::Full, Some((i as u16 * 18, j as u16 * 18))));
+		}
+	}
+	clip.chests.push(Chest { name: String::new(), x: 2, y: 0, items: vec![] });
+	
+	let (flipped, rejected) = clip.transform(Transform::FlipHorizontal, &importance);
+	assert!(rejected.is_empty());
+	assert_eq!(flipped.tiles.get(5, 0), &block(chair, Slope::Full, Some((18, 0))));
+	assert_eq!(flipped.tiles.get(5, 1), &block(chair, Slope::Full, Some((18, 18))));
+	// The left column of the right facing bed is the mirror of the old right column
+	assert_eq!(flipped.tiles.get(0, 0), &block(bed, Slope::Full, Some((4 * 18, 0))));
+	assert_eq!(flipped.tiles.get(3, 1), &block(bed, Slope::Full, Some((4 * 18 + 3 * 18, 18))));
+	// The anchor stays on the top left tile of the bed
+	assert_eq!((flipped.chests[0].x, flipped.chests[0].y), (0, 0));
+	
+	same(&flipped.transform(Transform::FlipHorizontal, &importance).0, &clip);
+}
+
+#[test]
+fn untransformable_tiles_are_reported() {
+	let importance = wld_edit::ids::default_importance();
+	let mut clip = clipboard(4, 4);
+	clip.tiles.set(1, 2, block(1, Slope::Half, None));
+	let chest = block(id("Containers"), Slope::Full, Some((0, 0)));
+	clip.tiles.set(2, 0, chest);
+	clip.tiles.set(3, 0, chest);
+	
+	let (turned, rejected) = clip.transform(Transform::Rotate90, &importance);
+	assert_eq!(rejected.len(), 3);
+	assert!(rejected.contains(&Rejected::HalfBlock { x: 1, y: 2 }));
+	assert!(rejected.contains(&Rejected::Furniture { x: 2, y: 0, id: id("Containers") }));
+	// Rejected blocks are left out, everything else in their cells moves
+	assert_eq!(turned.tiles.get(1, 1), &AIR);
+	assert_eq!(turned.tiles.get(3, 2), &AIR);
+	
+	let (_, rejected) = clip.transform(Transform::FlipHorizontal, &importance);
+	assert!(rejected.is_empty());
+}
+
+#[test]
+fn rotated_chests_are_left_out() {
+	let importance = wld_edit::ids::default_importance();
+	let mut clip = clipboard(4, 3);
+	let containers = id("Containers");
+	for i in 0..2 {
+		for j in 0..2 {
+			let mut tile = block(containers, Slope::Full, Some((i * 18, j * 18)));
+			tile.wall = Some(Wall { id: 4, color: None });
+			clip.tiles.set(1 + i as u32, j as u32, tile);
+		}
+	}
+	clip.chests.push(Chest { name: "loot".to_string(), x: 1, y: 0, items: vec![] });
+	
+	for &t in &[Transform::Rotate90, Transform::Rotate180, Transform::Rotate270, Transform::FlipVertical] {
+		let (turned, rejected) = clip.transform(t, &importance);
+		assert_eq!(rejected.len(), 4, "{:?}", t);
+		for i in 0..2 {
+			for j in 0..2 {
+				assert!(rejected.contains(&Rejected::Furniture { x: 1 + i, y: j, id: containers }));
+				let (x, y) = t.point(1 + i, j, 4, 3);
+				let tile = turned.tiles.get(x, y);
+				assert_eq!(tile.block, None, "{:?}", t);
+				assert_eq!(tile.wall, Some(Wall { id: 4, color: None }));
+			}
+		}
+		// No cell holds chest frames in the wrong place and the chest isn't left without its tiles
+		for x in 0..turned.width() {
+			for y in 0..turned.height() {
+				assert!(turned.tiles.get(x, y).block.is_none_or(|block| block.id != containers));
+			}
+		}
+		assert!(turned.chests.is_empty());
+	}
+	
+	// A horizontal flip can mirror the chest, so it and its items stay
+	let (flipped, rejected) = clip.transform(Transform::FlipHorizontal, &importance);
+	assert!(rejected.is_empty());
+	assert_eq!((flipped.chests[0].x, flipped.chests[0].y), (1, 0));
+	assert_eq!(flipped.tiles.get(1, 0).block.unwrap().uv, Some((0, 0)));
+}
+
+#[test]
+fn rejected_pressure_plates_are_left_out() {
+	let importance = wld_edit::ids::default_importance();
+	let plates = id("PressurePlates");
+	let mut clip = clipboard(3, 3);
+	clip.tiles.set(1, 2, block(plates, Slope::Full, Some((0, 126))));
+	clip.weighted_pressure_plates.push((1, 2));
+	
+	for &t in &[Transform::Rotate90, Transform::FlipHorizontal] {
+		let (turned, rejected) = clip.transform(t, &importance);
+		assert_eq!(rejected, vec![Rejected::Furniture { x: 1, y: 2, id: plates }]);
+		assert!(turned.weighted_pressure_plates.is_empty(), "{:?}", t);
+	}
+}
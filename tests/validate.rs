@@ -0,0 +1,103 @@
+extern crate wld_edit;
+
+use wld_edit::{ids::default_importance, Block, Chest, Diagnostic, Item, ItemCatalogue, ItemInfo, ItemProblem, NPCRoom, PrefixCategory, Sign, Slope, Tile, TileEntity, TileEntityInfo, TileGrid, TownNPC, Wld};
+
+const AIR: Tile = Tile { block: None, wall: None, liquid: None, red_wire: false, green_wire: false, blue_wire: false, yellow_wire: false, actuator: false };
+
+fn block(id: u16, uv: Option<(u16, u16)>) -> Tile {
+	Tile { block: Some(Block { id, color: None, uv, inactive: false, slope: Slope::Full }), ..AIR }
+}
+
+fn world() -> Wld {
+	let mut w = Wld::_new();
+	w.magic_number = *b"relogic";
+	w.width = 40;
+	w.height = 30;
+	w.spawn_x = 20;
+	w.spawn_y = 10;
+	w.dungeon_x = 5;
+	w.dungeon_y = 10;
+	w.tiles = TileGrid::new(40, 30, AIR);
+	w
+}
+
+#[test]
+fn valid_world_has_no_diagnostics() {
+	let mut w = world();
+	w.tiles.set(3, 3, block(21, Some((0, 0))));
+	w.tiles.set(6, 3, block(55, Some((0, 0))));
+	w.tiles.set(9, 3, block(378, Some((0, 0))));
+	w.chests.push(Chest { name: String::new(), x: 3, y: 3, items: vec![Some(Item { id: 2, prefix: 0, count: 50 }), None] });
+	w.signs.push(Sign { x: 6, y: 3, text: "hi".to_string() });
+	w.tile_entities.push(TileEntity { id: 0, x: 9, y: 3, info: TileEntityInfo::TargetDummy(0) });
+	w.add_town_npc(TownNPC::Guide, "Andrew", Some((15, 20)));
+	assert_eq!(w.validate(), vec![]);
+}
+
+#[test]
+fn reports_and_repairs() {
+	let mut w = world();
+	w.tiles.set(1, 1, block(21, None));
+	w.tiles.set(2, 2, block(700, None));
+	w.chests.push(Chest { name: String::new(), x: 8, y: 8, items: vec![Some(Item { id: 2, prefix: 1, count: 20000 })] });
+	w.signs.push(Sign { x: 6, y: 3, text: String::new() });
+	w.tile_entities.push(TileEntity { id: 0, x: 9, y: 3, info: TileEntityInfo::Pylon });
+	w.npc_rooms.push(NPCRoom { id: 22, x: 100, y: 3 });
+	w.spawn_x = 40;
+	w.dungeon_y = 99;
+	
+	let diagnostics = w.validate();
+	for expected in [
+		Diagnostic::ChestWithoutTile { index: 0, x: 8, y: 8 },
+		Diagnostic::SignWithoutTile { index: 0, x: 6, y: 3 },
+		Diagnostic::TileEntityNotAnchored { index: 0, x: 9, y: 3 },
+		Diagnostic::InvalidNPCRoom { index: 0, id: 22, x: 100, y: 3 },
+		Diagnostic::SpawnOutOfBounds { x: 40, y: 10 },
+		Diagnostic::DungeonOutOfBounds { x: 5, y: 99 },
+		Diagnostic::MissingUV { x: 1, y: 1, id: 21 },
+		Diagnostic::ImportanceTooShort { len: w.importance.len(), needed: 701 }
+	] {
+		assert!(diagnostics.contains(&expected), "missing {}", expected);
+	}
+	assert!(!diagnostics.iter().any(|d| matches!(d, Diagnostic::InvalidItem { .. })));
+	
+	let repaired = w.repair();
+	assert_eq!(repaired.len(), diagnostics.len() - 1);
+	assert_eq!(w.validate(), vec![Diagnostic::ChestWithoutTile { index: 0, x: 8, y: 8 }]);
+	assert!(w.signs.is_empty() && w.tile_entities.is_empty() && w.npc_rooms.is_empty());
+	assert_eq!(w.tiles.get(1, 1).block.unwrap().uv, Some((0, 0)));
+	
+	// The repaired world can be written, before the importance fix the writer would index past the end
+	w.to_bytes().unwrap();
+}
+
+#[test]
+fn reports_and_repairs_items() {
+	let mut w = world();
+	let items: ItemCatalogue = vec![ItemInfo::new(2, "DirtBlock", "Dirt Block", 9999, PrefixCategory::None)].into_iter().collect();
+	w.chests.push(Chest { name: String::new(), x: 3, y: 3, items: vec![Some(Item { id: 2, prefix: 1, count: 20000 }), Some(Item { id: 5000, prefix: 0, count: 1 })] });
+	
+	let diagnostics = w.validate_items(&items);
+	assert_eq!(diagnostics, vec![
+		Diagnostic::InvalidItem { chest: 0, slot: 0, problem: ItemProblem::ExceedsMaxStack { count: 20000, max: 9999 } },
+		Diagnostic::InvalidItem { chest: 0, slot: 0, problem: ItemProblem::InvalidPrefix { prefix: 1, item: 2 } },
+		Diagnostic::InvalidItem { chest: 0, slot: 1, problem: ItemProblem::UnknownItem(5000) }
+	]);
+	
+	// Items the catalogue doesn't know are reported but kept
+	assert_eq!(w.repair_items(&items).len(), 2);
+	assert_eq!(w.chests[0].items[0], Some(Item { id: 2, prefix: 0, count: 9999 }));
+	assert_eq!(w.chests[0].items[1], Some(Item { id: 5000, prefix: 0, count: 1 }));
+	assert_eq!(w.validate_items(&items).len(), 1);
+}
+
+#[test]
+fn importance_repair_uses_the_tile_table() {
+	let mut w = world();
+	let last = default_importance().len() - 1;
+	w.importance.truncate(10);
+	w.tiles.set(1, 1, block(last as u16, Some((0, 0))));
+	assert_eq!(w.validate(), vec![Diagnostic::ImportanceTooShort { len: 10, needed: last + 1 }]);
+	w.repair();
+	assert_eq!(w.importance, default_importance());
+}